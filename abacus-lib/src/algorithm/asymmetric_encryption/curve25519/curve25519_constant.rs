@@ -0,0 +1,24 @@
+pub const CURVE25519_ELEMENT_BYTE_LENGTH: usize = 32;
+pub const CURVE25519_LIMB_BIT_LENGTH: usize = 51;
+pub const CURVE25519_LIMB_MASK: u64 = (1 << CURVE25519_LIMB_BIT_LENGTH) - 1;
+pub const CURVE25519_A24: u64 = 121665;
+
+pub const CURVE25519_P_MINUS_2: [u8; CURVE25519_ELEMENT_BYTE_LENGTH] = [0xEB, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F];
+pub const CURVE25519_P_MINUS_5_DIV_8: [u8; CURVE25519_ELEMENT_BYTE_LENGTH] = [0xFD, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x0F];
+pub const CURVE25519_SQRT_M1: [u8; CURVE25519_ELEMENT_BYTE_LENGTH] = [0xB0, 0xA0, 0x0E, 0x4A, 0x27, 0x1B, 0xEE, 0xC4, 0x78, 0xE4, 0x2F, 0xAD, 0x06, 0x18, 0x43, 0x2F, 0xA7, 0xD7, 0xFB, 0x3D, 0x99, 0x00, 0x4D, 0x2B, 0x0B, 0xDF, 0xC1, 0x4F, 0x80, 0x24, 0x83, 0x2B];
+
+pub const X25519_KEY_BYTE_LENGTH: usize = 32;
+pub const X25519_BASE_POINT: [u8; X25519_KEY_BYTE_LENGTH] = [0x09, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+
+pub const ED25519_D: [u8; CURVE25519_ELEMENT_BYTE_LENGTH] = [0xA3, 0x78, 0x59, 0x13, 0xCA, 0x4D, 0xEB, 0x75, 0xAB, 0xD8, 0x41, 0x41, 0x4D, 0x0A, 0x70, 0x00, 0x98, 0xE8, 0x79, 0x77, 0x79, 0x40, 0xC7, 0x8C, 0x73, 0xFE, 0x6F, 0x2B, 0xEE, 0x6C, 0x03, 0x52];
+pub const ED25519_D2: [u8; CURVE25519_ELEMENT_BYTE_LENGTH] = [0x59, 0xF1, 0xB2, 0x26, 0x94, 0x9B, 0xD6, 0xEB, 0x56, 0xB1, 0x83, 0x82, 0x9A, 0x14, 0xE0, 0x00, 0x30, 0xD1, 0xF3, 0xEE, 0xF2, 0x80, 0x8E, 0x19, 0xE7, 0xFC, 0xDF, 0x56, 0xDC, 0xD9, 0x06, 0x24];
+pub const ED25519_BASE_X: [u8; CURVE25519_ELEMENT_BYTE_LENGTH] = [0x1A, 0xD5, 0x25, 0x8F, 0x60, 0x2D, 0x56, 0xC9, 0xB2, 0xA7, 0x25, 0x95, 0x60, 0xC7, 0x2C, 0x69, 0x5C, 0xDC, 0xD6, 0xFD, 0x31, 0xE2, 0xA4, 0xC0, 0xFE, 0x53, 0x6E, 0xCD, 0xD3, 0x36, 0x69, 0x21];
+pub const ED25519_BASE_Y: [u8; CURVE25519_ELEMENT_BYTE_LENGTH] = [0x58, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66];
+pub const ED25519_L: [u64; 4] = [0x5812631A5CF5D3ED, 0x14DEF9DEA2F79CD6, 0x0000000000000000, 0x1000000000000000];
+pub const ED25519_COFACTOR: u64 = 8;
+pub const ED25519_SEED_BYTE_LENGTH: usize = 32;
+pub const ED25519_PUBLIC_KEY_BYTE_LENGTH: usize = 32;
+pub const ED25519_SCALAR_BYTE_LENGTH: usize = 32;
+pub const ED25519_SIGNATURE_BYTE_LENGTH: usize = 64;
+pub const ED25519_SCALAR_CHUNK_BIT_LENGTH: usize = 248;
+pub const ED25519_BATCH_COEFFICIENT_BYTE_LENGTH: usize = 16;
@@ -0,0 +1,240 @@
+use crate::math::bigint::limb_arithmetic::{limbs_select, limbs_conditional_swap};
+use super::curve25519_constant::{CURVE25519_ELEMENT_BYTE_LENGTH, CURVE25519_LIMB_BIT_LENGTH, CURVE25519_LIMB_MASK, CURVE25519_P_MINUS_2, CURVE25519_P_MINUS_5_DIV_8};
+
+const SUBTRACT_BIAS: [u64; 5] = [0x7FFFFFFFFFFED0, 0x7FFFFFFFFFFFF0, 0x7FFFFFFFFFFFF0, 0x7FFFFFFFFFFFF0, 0x7FFFFFFFFFFFF0];
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Curve25519Element {
+    limbs: [u64; 5],
+}
+
+impl Curve25519Element {
+    pub(crate) fn zero() -> Curve25519Element {
+        Curve25519Element::from_limbs([0; 5])
+    }
+
+    pub(crate) fn one() -> Curve25519Element {
+        Curve25519Element::from_limbs([1, 0, 0, 0, 0])
+    }
+
+    fn from_limbs(limbs: [u64; 5]) -> Curve25519Element {
+        Curve25519Element {
+            limbs,
+        }
+    }
+
+    pub(crate) fn from_bytes(bytes: &[u8; CURVE25519_ELEMENT_BYTE_LENGTH]) -> Curve25519Element {
+        let mut words = [0u64; 4];
+        for i in 0..4 {
+            words[i] = u64::from_le_bytes(bytes[(i * 8)..(i * 8 + 8)].try_into().unwrap());
+        }
+        Curve25519Element::from_limbs([
+            words[0] & CURVE25519_LIMB_MASK,
+            ((words[0] >> 51) | (words[1] << 13)) & CURVE25519_LIMB_MASK,
+            ((words[1] >> 38) | (words[2] << 26)) & CURVE25519_LIMB_MASK,
+            ((words[2] >> 25) | (words[3] << 39)) & CURVE25519_LIMB_MASK,
+            (words[3] >> 12) & CURVE25519_LIMB_MASK,
+        ])
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    pub(crate) fn to_bytes(self) -> [u8; CURVE25519_ELEMENT_BYTE_LENGTH] {
+        let mut limbs = Curve25519Element::carry(Curve25519Element::carry(self.limbs));
+        let mut q = (limbs[0] + 19) >> CURVE25519_LIMB_BIT_LENGTH;
+        for i in 1..5 {
+            q = (limbs[i] + q) >> CURVE25519_LIMB_BIT_LENGTH;
+        }
+        limbs[0] += 19 * q;
+        for i in 0..4 {
+            limbs[i + 1] += limbs[i] >> CURVE25519_LIMB_BIT_LENGTH;
+            limbs[i] &= CURVE25519_LIMB_MASK;
+        }
+        limbs[4] &= CURVE25519_LIMB_MASK;
+        let words = [
+            limbs[0] | (limbs[1] << 51),
+            (limbs[1] >> 13) | (limbs[2] << 38),
+            (limbs[2] >> 26) | (limbs[3] << 25),
+            (limbs[3] >> 39) | (limbs[4] << 12),
+        ];
+        let mut bytes = [0; CURVE25519_ELEMENT_BYTE_LENGTH];
+        for i in 0..4 {
+            bytes[(i * 8)..(i * 8 + 8)].copy_from_slice(&words[i].to_le_bytes());
+        }
+        bytes
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    fn carry(mut limbs: [u64; 5]) -> [u64; 5] {
+        let mut carry: u64 = 0;
+        for i in 0..5 {
+            limbs[i] += carry;
+            carry = limbs[i] >> CURVE25519_LIMB_BIT_LENGTH;
+            limbs[i] &= CURVE25519_LIMB_MASK;
+        }
+        limbs[0] += carry * 19;
+        limbs
+    }
+
+    fn carry_wide(limbs: [u128; 5]) -> Curve25519Element {
+        let mut result = [0u64; 5];
+        let mut carry: u128 = 0;
+        for i in 0..5 {
+            let t = limbs[i] + carry;
+            result[i] = (t as u64) & CURVE25519_LIMB_MASK;
+            carry = t >> CURVE25519_LIMB_BIT_LENGTH;
+        }
+        let t = result[0] as u128 + carry * 19;
+        result[0] = (t as u64) & CURVE25519_LIMB_MASK;
+        result[1] += (t >> CURVE25519_LIMB_BIT_LENGTH) as u64;
+        Curve25519Element::from_limbs(result)
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    pub(crate) fn add(&self, other: &Curve25519Element) -> Curve25519Element {
+        let mut limbs = [0; 5];
+        for i in 0..5 {
+            limbs[i] = self.limbs[i] + other.limbs[i];
+        }
+        Curve25519Element::from_limbs(Curve25519Element::carry(limbs))
+    }
+
+    pub(crate) fn subtract(&self, other: &Curve25519Element) -> Curve25519Element {
+        let mut limbs = [0; 5];
+        for i in 0..5 {
+            limbs[i] = self.limbs[i] + SUBTRACT_BIAS[i] - other.limbs[i];
+        }
+        Curve25519Element::from_limbs(Curve25519Element::carry(limbs))
+    }
+
+    pub(crate) fn negate(&self) -> Curve25519Element {
+        Curve25519Element::zero().subtract(self)
+    }
+
+    pub(crate) fn multiply(&self, other: &Curve25519Element) -> Curve25519Element {
+        let a = self.limbs.map(|limb| limb as u128);
+        let b = other.limbs.map(|limb| limb as u128);
+        let b19 = [b[0], b[1] * 19, b[2] * 19, b[3] * 19, b[4] * 19];
+        Curve25519Element::carry_wide([
+            a[0] * b[0] + a[1] * b19[4] + a[2] * b19[3] + a[3] * b19[2] + a[4] * b19[1],
+            a[0] * b[1] + a[1] * b[0] + a[2] * b19[4] + a[3] * b19[3] + a[4] * b19[2],
+            a[0] * b[2] + a[1] * b[1] + a[2] * b[0] + a[3] * b19[4] + a[4] * b19[3],
+            a[0] * b[3] + a[1] * b[2] + a[2] * b[1] + a[3] * b[0] + a[4] * b19[4],
+            a[0] * b[4] + a[1] * b[3] + a[2] * b[2] + a[3] * b[1] + a[4] * b[0],
+        ])
+    }
+
+    pub(crate) fn square(&self) -> Curve25519Element {
+        self.multiply(self)
+    }
+
+    pub(crate) fn multiply_small(&self, value: u64) -> Curve25519Element {
+        Curve25519Element::carry_wide(self.limbs.map(|limb| limb as u128 * value as u128))
+    }
+
+    fn power(&self, exponent: &[u8; CURVE25519_ELEMENT_BYTE_LENGTH]) -> Curve25519Element {
+        let mut result = Curve25519Element::one();
+        for i in (0..(CURVE25519_ELEMENT_BYTE_LENGTH * 8)).rev() {
+            result = result.square();
+            if (exponent[i / 8] >> (i % 8)) & 1 == 1 {
+                result = result.multiply(self);
+            }
+        }
+        result
+    }
+
+    pub(crate) fn invert(&self) -> Curve25519Element {
+        self.power(&CURVE25519_P_MINUS_2)
+    }
+
+    pub(crate) fn power_p_minus_5_div_8(&self) -> Curve25519Element {
+        self.power(&CURVE25519_P_MINUS_5_DIV_8)
+    }
+
+    pub(crate) fn is_zero(&self) -> bool {
+        self.to_bytes().iter().fold(0, |acc, b| acc | b) == 0
+    }
+
+    pub(crate) fn is_negative(&self) -> bool {
+        self.to_bytes()[0] & 1 == 1
+    }
+
+    pub(crate) fn select(a: &Curve25519Element, b: &Curve25519Element, choice: u64) -> Curve25519Element {
+        let mut result = Curve25519Element::zero();
+        limbs_select(&a.limbs, &b.limbs, choice, &mut result.limbs);
+        result
+    }
+
+    pub(crate) fn conditional_swap(a: &mut Curve25519Element, b: &mut Curve25519Element, choice: u64) {
+        limbs_conditional_swap(&mut a.limbs, &mut b.limbs, choice)
+    }
+}
+
+impl PartialEq for Curve25519Element {
+    fn eq(&self, other: &Curve25519Element) -> bool {
+        self.to_bytes() == other.to_bytes()
+    }
+}
+
+impl Eq for Curve25519Element {}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::hex::{hex_decode, hex_encode};
+    use super::super::curve25519_constant::CURVE25519_SQRT_M1;
+    use super::Curve25519Element;
+
+    const P_MINUS_ONE: &str = "ecffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f";
+
+    fn element(data: &str) -> Curve25519Element {
+        Curve25519Element::from_bytes(&hex_decode(data).unwrap().try_into().unwrap())
+    }
+
+    fn small(value: u8) -> Curve25519Element {
+        let mut bytes = [0; 32];
+        bytes[0] = value;
+        Curve25519Element::from_bytes(&bytes)
+    }
+
+    #[test]
+    fn canonical_encoding() {
+        let p_minus_one = element(P_MINUS_ONE);
+        assert_eq!(hex_encode(&p_minus_one.to_bytes()), P_MINUS_ONE);
+        assert_eq!(p_minus_one, Curve25519Element::one().negate());
+        assert!(p_minus_one.add(&Curve25519Element::one()).is_zero());
+        assert!(element("edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f").is_zero());
+        assert_eq!(element("eeffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f"), Curve25519Element::one());
+        assert_eq!(element("0100000000000000000000000000000000000000000000000000000000000080"), Curve25519Element::one());
+        assert!(!p_minus_one.is_negative() && Curve25519Element::one().is_negative());
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = element("0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcd0f");
+        let b = element(P_MINUS_ONE);
+        assert_eq!(a.multiply(&a.invert()), Curve25519Element::one());
+        assert_eq!(b.multiply(&b), Curve25519Element::one());
+        assert_eq!(a.square(), a.multiply(&a));
+        assert_eq!(a.subtract(&a), Curve25519Element::zero());
+        assert_eq!(a.add(&b), a.subtract(&Curve25519Element::one()));
+        assert_eq!(a.multiply_small(121665), a.multiply(&element("41db010000000000000000000000000000000000000000000000000000000000")));
+        assert_eq!(small(2).multiply(&small(3)), small(6));
+    }
+
+    #[test]
+    fn square_root_of_minus_one() {
+        let sqrt_m1 = Curve25519Element::from_bytes(&CURVE25519_SQRT_M1);
+        assert_eq!(sqrt_m1.square(), Curve25519Element::one().negate());
+    }
+
+    #[test]
+    fn conditional_swap() {
+        let mut a = small(1);
+        let mut b = small(2);
+        Curve25519Element::conditional_swap(&mut a, &mut b, 0);
+        assert_eq!((a, b), (small(1), small(2)));
+        Curve25519Element::conditional_swap(&mut a, &mut b, 1);
+        assert_eq!((a, b), (small(2), small(1)));
+        assert_eq!(Curve25519Element::select(&a, &b, 0), small(2));
+        assert_eq!(Curve25519Element::select(&a, &b, 1), small(1));
+    }
+}
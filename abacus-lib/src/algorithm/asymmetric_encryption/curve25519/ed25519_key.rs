@@ -0,0 +1,93 @@
+use crate::error::Error;
+use crate::algorithm::digest::sha2::sha2_512_digest::SHA2_512Digest;
+use crate::util::random::fill_random_bytes;
+use super::curve25519_constant::{ED25519_SEED_BYTE_LENGTH, ED25519_PUBLIC_KEY_BYTE_LENGTH, ED25519_SCALAR_BYTE_LENGTH};
+use super::ed25519_point::Ed25519Point;
+
+#[derive(Clone, Debug)]
+pub struct Ed25519PublicKey {
+    bytes: [u8; ED25519_PUBLIC_KEY_BYTE_LENGTH],
+    point: Ed25519Point,
+}
+
+impl Ed25519PublicKey {
+    pub fn from_bytes(bytes: [u8; ED25519_PUBLIC_KEY_BYTE_LENGTH]) -> Result<Ed25519PublicKey, Error> {
+        let point = Ed25519Point::decompress(&bytes).ok_or(Error::InvalidKey)?;
+        let instance = Ed25519PublicKey {
+            bytes,
+            point,
+        };
+        Ok(instance)
+    }
+
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Ed25519PublicKey, Error> {
+        let bytes: [u8; ED25519_PUBLIC_KEY_BYTE_LENGTH] = bytes.try_into().map_err(|_| Error::InvalidKeyLength)?;
+        Ed25519PublicKey::from_bytes(bytes)
+    }
+
+    pub fn to_bytes(&self) -> [u8; ED25519_PUBLIC_KEY_BYTE_LENGTH] {
+        self.bytes
+    }
+
+    pub(crate) fn point(&self) -> &Ed25519Point {
+        &self.point
+    }
+}
+
+#[derive(Clone)]
+pub struct Ed25519PrivateKey {
+    seed: [u8; ED25519_SEED_BYTE_LENGTH],
+    scalar: [u8; ED25519_SCALAR_BYTE_LENGTH],
+    prefix: [u8; ED25519_SCALAR_BYTE_LENGTH],
+    public_key: Ed25519PublicKey,
+}
+
+impl Ed25519PrivateKey {
+    pub fn generate() -> Result<Ed25519PrivateKey, Error> {
+        let mut seed = [0; ED25519_SEED_BYTE_LENGTH];
+        fill_random_bytes(&mut seed)?;
+        Ok(Ed25519PrivateKey::from_bytes(seed))
+    }
+
+    pub fn from_bytes(seed: [u8; ED25519_SEED_BYTE_LENGTH]) -> Ed25519PrivateKey {
+        let mut sha512 = SHA2_512Digest::new();
+        sha512.push_data(&seed);
+        sha512.compute_digest();
+        let digest = sha512.get_digest_bytes();
+        let mut scalar: [u8; ED25519_SCALAR_BYTE_LENGTH] = digest[..ED25519_SCALAR_BYTE_LENGTH].try_into().unwrap();
+        scalar[0] &= 248;
+        scalar[31] &= 127;
+        scalar[31] |= 64;
+        let point = Ed25519Point::generator().multiply(&scalar);
+        Ed25519PrivateKey {
+            seed,
+            scalar,
+            prefix: digest[ED25519_SCALAR_BYTE_LENGTH..].try_into().unwrap(),
+            public_key: Ed25519PublicKey {
+                bytes: point.compress(),
+                point,
+            },
+        }
+    }
+
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Ed25519PrivateKey, Error> {
+        let seed: [u8; ED25519_SEED_BYTE_LENGTH] = bytes.try_into().map_err(|_| Error::InvalidKeyLength)?;
+        Ok(Ed25519PrivateKey::from_bytes(seed))
+    }
+
+    pub fn to_bytes(&self) -> [u8; ED25519_SEED_BYTE_LENGTH] {
+        self.seed
+    }
+
+    pub fn public_key(&self) -> &Ed25519PublicKey {
+        &self.public_key
+    }
+
+    pub(crate) fn scalar(&self) -> &[u8; ED25519_SCALAR_BYTE_LENGTH] {
+        &self.scalar
+    }
+
+    pub(crate) fn prefix(&self) -> &[u8; ED25519_SCALAR_BYTE_LENGTH] {
+        &self.prefix
+    }
+}
@@ -0,0 +1,221 @@
+use super::curve25519_constant::{CURVE25519_ELEMENT_BYTE_LENGTH, CURVE25519_SQRT_M1, ED25519_D, ED25519_D2, ED25519_BASE_X, ED25519_BASE_Y};
+use super::curve25519_field::Curve25519Element;
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Ed25519Point {
+    x: Curve25519Element,
+    y: Curve25519Element,
+    z: Curve25519Element,
+    t: Curve25519Element,
+}
+
+impl Ed25519Point {
+    pub(crate) fn identity() -> Ed25519Point {
+        Ed25519Point {
+            x: Curve25519Element::zero(),
+            y: Curve25519Element::one(),
+            z: Curve25519Element::one(),
+            t: Curve25519Element::zero(),
+        }
+    }
+
+    pub(crate) fn generator() -> Ed25519Point {
+        Ed25519Point::from_affine(&Curve25519Element::from_bytes(&ED25519_BASE_X), &Curve25519Element::from_bytes(&ED25519_BASE_Y))
+    }
+
+    fn from_affine(x: &Curve25519Element, y: &Curve25519Element) -> Ed25519Point {
+        Ed25519Point {
+            x: *x,
+            y: *y,
+            z: Curve25519Element::one(),
+            t: x.multiply(y),
+        }
+    }
+
+    pub(crate) fn decompress(bytes: &[u8; CURVE25519_ELEMENT_BYTE_LENGTH]) -> Option<Ed25519Point> {
+        let sign = bytes[31] >> 7;
+        let mut y_bytes = *bytes;
+        y_bytes[31] &= 0x7F;
+        let y = Curve25519Element::from_bytes(&y_bytes);
+        if y.to_bytes() != y_bytes {
+            return None;
+        }
+        let y2 = y.square();
+        let u = y2.subtract(&Curve25519Element::one());
+        let v = y2.multiply(&Curve25519Element::from_bytes(&ED25519_D)).add(&Curve25519Element::one());
+        let v3 = v.square().multiply(&v);
+        let v7 = v3.square().multiply(&v);
+        let mut x = u.multiply(&v3).multiply(&u.multiply(&v7).power_p_minus_5_div_8());
+        let vx2 = v.multiply(&x.square());
+        if vx2 == u.negate() {
+            x = x.multiply(&Curve25519Element::from_bytes(&CURVE25519_SQRT_M1));
+        } else if vx2 != u {
+            return None;
+        }
+        if x.is_zero() && sign == 1 {
+            return None;
+        }
+        if x.is_negative() != (sign == 1) {
+            x = x.negate();
+        }
+        Some(Ed25519Point::from_affine(&x, &y))
+    }
+
+    pub(crate) fn compress(&self) -> [u8; CURVE25519_ELEMENT_BYTE_LENGTH] {
+        let z_inverse = self.z.invert();
+        let x = self.x.multiply(&z_inverse);
+        let mut bytes = self.y.multiply(&z_inverse).to_bytes();
+        bytes[31] |= (x.is_negative() as u8) << 7;
+        bytes
+    }
+
+    pub(crate) fn is_identity(&self) -> bool {
+        self.x.is_zero() && self.y == self.z
+    }
+
+    pub(crate) fn negate(&self) -> Ed25519Point {
+        Ed25519Point {
+            x: self.x.negate(),
+            y: self.y,
+            z: self.z,
+            t: self.t.negate(),
+        }
+    }
+
+    fn select(a: &Ed25519Point, b: &Ed25519Point, choice: u64) -> Ed25519Point {
+        Ed25519Point {
+            x: Curve25519Element::select(&a.x, &b.x, choice),
+            y: Curve25519Element::select(&a.y, &b.y, choice),
+            z: Curve25519Element::select(&a.z, &b.z, choice),
+            t: Curve25519Element::select(&a.t, &b.t, choice),
+        }
+    }
+
+    pub(crate) fn add(&self, other: &Ed25519Point) -> Ed25519Point {
+        let a = self.y.subtract(&self.x).multiply(&other.y.subtract(&other.x));
+        let b = self.y.add(&self.x).multiply(&other.y.add(&other.x));
+        let c = self.t.multiply(&Curve25519Element::from_bytes(&ED25519_D2)).multiply(&other.t);
+        let d = self.z.add(&self.z).multiply(&other.z);
+        let e = b.subtract(&a);
+        let f = d.subtract(&c);
+        let g = d.add(&c);
+        let h = b.add(&a);
+        Ed25519Point {
+            x: e.multiply(&f),
+            y: g.multiply(&h),
+            z: f.multiply(&g),
+            t: e.multiply(&h),
+        }
+    }
+
+    pub(crate) fn double(&self) -> Ed25519Point {
+        let a = self.x.square();
+        let b = self.y.square();
+        let c = self.z.square().multiply_small(2);
+        let h = a.add(&b);
+        let e = h.subtract(&self.x.add(&self.y).square());
+        let g = a.subtract(&b);
+        let f = c.add(&g);
+        Ed25519Point {
+            x: e.multiply(&f),
+            y: g.multiply(&h),
+            z: f.multiply(&g),
+            t: e.multiply(&h),
+        }
+    }
+
+    pub(crate) fn multiply_by_cofactor(&self) -> Ed25519Point {
+        self.double().double().double()
+    }
+
+    pub(crate) fn multiply(&self, scalar: &[u8; CURVE25519_ELEMENT_BYTE_LENGTH]) -> Ed25519Point {
+        let mut result = Ed25519Point::identity();
+        for i in (0..(CURVE25519_ELEMENT_BYTE_LENGTH * 8)).rev() {
+            result = result.double();
+            let added = result.add(self);
+            result = Ed25519Point::select(&result, &added, ((scalar[i / 8] >> (i % 8)) & 1) as u64);
+        }
+        result
+    }
+
+    pub(crate) fn multiscalar_multiply_vartime(scalars: &[[u8; CURVE25519_ELEMENT_BYTE_LENGTH]], points: &[Ed25519Point]) -> Ed25519Point {
+        let mut result = Ed25519Point::identity();
+        for i in (0..(CURVE25519_ELEMENT_BYTE_LENGTH * 8)).rev() {
+            result = result.double();
+            for j in 0..points.len() {
+                if (scalars[j][i / 8] >> (i % 8)) & 1 == 1 {
+                    result = result.add(&points[j]);
+                }
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::hex::{hex_decode, hex_encode};
+    use super::Ed25519Point;
+
+    const GENERATOR: &str = "5866666666666666666666666666666666666666666666666666666666666666";
+    const ORDER: &str = "edd3f55c1a631258d69cf7a2def9de1400000000000000000000000000000010";
+
+    fn bytes(data: &str) -> [u8; 32] {
+        hex_decode(data).unwrap().try_into().unwrap()
+    }
+
+    fn scalar(value: u8) -> [u8; 32] {
+        let mut scalar = [0; 32];
+        scalar[0] = value;
+        scalar
+    }
+
+    #[test]
+    fn generator_compression() {
+        let generator = Ed25519Point::generator();
+        assert_eq!(hex_encode(&generator.compress()), GENERATOR);
+        let decompressed = Ed25519Point::decompress(&bytes(GENERATOR)).unwrap();
+        assert_eq!(decompressed.compress(), generator.compress());
+        let negated = generator.negate().compress();
+        assert_eq!(negated[31], 0xe6);
+        assert_eq!(Ed25519Point::decompress(&negated).unwrap().negate().compress(), generator.compress());
+    }
+
+    #[test]
+    fn generator_order() {
+        let generator = Ed25519Point::generator();
+        assert!(generator.multiply(&bytes(ORDER)).is_identity());
+        assert!(!generator.multiply(&scalar(1)).is_identity());
+        assert!(generator.add(&generator.negate()).is_identity());
+        assert!(Ed25519Point::identity().multiply_by_cofactor().is_identity());
+    }
+
+    #[test]
+    fn addition_is_consistent() {
+        let generator = Ed25519Point::generator();
+        assert_eq!(generator.add(&generator).compress(), generator.double().compress());
+        assert_eq!(generator.multiply(&scalar(2)).compress(), generator.double().compress());
+        let five = generator.multiply(&scalar(5));
+        assert_eq!(generator.double().double().add(&generator).compress(), five.compress());
+        let points = [generator, generator.double()];
+        let combined = Ed25519Point::multiscalar_multiply_vartime(&[scalar(3), scalar(1)], &points);
+        assert_eq!(combined.compress(), five.compress());
+        assert_eq!(generator.add(&Ed25519Point::identity()).compress(), generator.compress());
+    }
+
+    #[test]
+    fn small_order_point_is_cleared_by_cofactor() {
+        let point = Ed25519Point::decompress(&bytes("ecffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f")).unwrap();
+        assert!(!point.is_identity());
+        assert!(point.double().is_identity());
+        assert!(point.multiply_by_cofactor().is_identity());
+    }
+
+    #[test]
+    fn invalid_encoding_is_rejected() {
+        assert!(Ed25519Point::decompress(&bytes("edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f")).is_none());
+        assert!(Ed25519Point::decompress(&bytes("0200000000000000000000000000000000000000000000000000000000000000")).is_none());
+        assert!(Ed25519Point::decompress(&bytes("0100000000000000000000000000000000000000000000000000000000000080")).is_none());
+        assert!(Ed25519Point::decompress(&bytes("0100000000000000000000000000000000000000000000000000000000000000")).unwrap().is_identity());
+    }
+}
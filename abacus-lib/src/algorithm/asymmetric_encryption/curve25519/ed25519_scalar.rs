@@ -0,0 +1,73 @@
+use crate::math::bigint::big_uint::BigUint;
+use crate::math::bigint::montgomery_context::MontgomeryContext;
+use super::curve25519_constant::{ED25519_L, ED25519_SCALAR_BYTE_LENGTH, ED25519_SCALAR_CHUNK_BIT_LENGTH};
+
+pub(crate) fn ed25519_order() -> BigUint {
+    BigUint::from_limbs(ED25519_L.to_vec())
+}
+
+pub(crate) fn ed25519_scalar_field() -> MontgomeryContext {
+    MontgomeryContext::with_odd_modulus(&ed25519_order())
+}
+
+fn number_from_le_bytes(bytes: &[u8]) -> BigUint {
+    let mut be_bytes = bytes.to_vec();
+    be_bytes.reverse();
+    BigUint::from_be_bytes(&be_bytes)
+}
+
+pub(crate) fn scalar_reduce(scalar_field: &MontgomeryContext, bytes: &[u8]) -> BigUint {
+    let chunk_byte_length = ED25519_SCALAR_CHUNK_BIT_LENGTH / 8;
+    let shift = &BigUint::one() << ED25519_SCALAR_CHUNK_BIT_LENGTH;
+    let mut result = BigUint::zero();
+    for chunk in bytes.chunks(chunk_byte_length).rev() {
+        result = scalar_field.mod_add(&scalar_field.mod_mul(&result, &shift), &number_from_le_bytes(chunk));
+    }
+    result
+}
+
+pub(crate) fn scalar_from_canonical_bytes(bytes: &[u8; ED25519_SCALAR_BYTE_LENGTH]) -> Option<BigUint> {
+    let scalar = number_from_le_bytes(bytes);
+    if scalar < ed25519_order() {
+        Some(scalar)
+    } else {
+        None
+    }
+}
+
+pub(crate) fn scalar_to_bytes(scalar: &BigUint) -> [u8; ED25519_SCALAR_BYTE_LENGTH] {
+    let mut bytes: [u8; ED25519_SCALAR_BYTE_LENGTH] = scalar.to_be_bytes_with_length(ED25519_SCALAR_BYTE_LENGTH).unwrap().try_into().unwrap();
+    bytes.reverse();
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::bigint::big_uint::BigUint;
+    use crate::util::hex::{hex_decode, hex_encode};
+    use super::{ed25519_order, ed25519_scalar_field, scalar_from_canonical_bytes, scalar_reduce, scalar_to_bytes};
+
+    const ORDER_MINUS_ONE: &str = "ecd3f55c1a631258d69cf7a2def9de1400000000000000000000000000000010";
+
+    fn bytes(data: &str) -> [u8; 32] {
+        hex_decode(data).unwrap().try_into().unwrap()
+    }
+
+    #[test]
+    fn reduce_wide_value() {
+        let scalar_field = ed25519_scalar_field();
+        let reduced = scalar_reduce(&scalar_field, &[0xff; 64]);
+        assert_eq!(hex_encode(&scalar_to_bytes(&reduced)), "000f9c44e31106a447938568a71b0ed065bef517d273ecce3d9a307c1b419903");
+        assert!(scalar_reduce(&scalar_field, &scalar_to_bytes(&ed25519_order())).is_zero());
+        assert_eq!(scalar_reduce(&scalar_field, &[7]), BigUint::from_u64(7));
+    }
+
+    #[test]
+    fn canonical_scalar() {
+        let order_minus_one = scalar_from_canonical_bytes(&bytes(ORDER_MINUS_ONE)).unwrap();
+        assert_eq!(hex_encode(&scalar_to_bytes(&order_minus_one)), ORDER_MINUS_ONE);
+        assert!(scalar_from_canonical_bytes(&scalar_to_bytes(&ed25519_order())).is_none());
+        assert!(scalar_from_canonical_bytes(&[0xff; 32]).is_none());
+        assert!(scalar_from_canonical_bytes(&[0; 32]).unwrap().is_zero());
+    }
+}
@@ -0,0 +1,183 @@
+use crate::error::Error;
+use crate::algorithm::digest::sha2::sha2_512_digest::SHA2_512Digest;
+use crate::math::bigint::big_uint::BigUint;
+use crate::math::bigint::montgomery_context::MontgomeryContext;
+use crate::util::random::fill_random_bytes;
+use super::curve25519_constant::{ED25519_SIGNATURE_BYTE_LENGTH, ED25519_SCALAR_BYTE_LENGTH, ED25519_BATCH_COEFFICIENT_BYTE_LENGTH};
+use super::ed25519_key::{Ed25519PrivateKey, Ed25519PublicKey};
+use super::ed25519_point::Ed25519Point;
+use super::ed25519_scalar::{ed25519_scalar_field, scalar_reduce, scalar_from_canonical_bytes, scalar_to_bytes};
+
+fn compute_hash_scalar(scalar_field: &MontgomeryContext, parts: &[&[u8]]) -> BigUint {
+    let mut sha512 = SHA2_512Digest::new();
+    for part in parts {
+        sha512.push_data(part);
+    }
+    sha512.compute_digest();
+    scalar_reduce(scalar_field, &sha512.get_digest_bytes())
+}
+
+struct Ed25519Signature {
+    r: Ed25519Point,
+    r_bytes: [u8; ED25519_SCALAR_BYTE_LENGTH],
+    s: BigUint,
+}
+
+impl Ed25519Signature {
+    fn from_bytes(signature: &[u8]) -> Option<Ed25519Signature> {
+        if signature.len() != ED25519_SIGNATURE_BYTE_LENGTH {
+            return None;
+        }
+        let r_bytes: [u8; ED25519_SCALAR_BYTE_LENGTH] = signature[..ED25519_SCALAR_BYTE_LENGTH].try_into().unwrap();
+        let instance = Ed25519Signature {
+            r: Ed25519Point::decompress(&r_bytes)?,
+            r_bytes,
+            s: scalar_from_canonical_bytes(signature[ED25519_SCALAR_BYTE_LENGTH..].try_into().unwrap())?,
+        };
+        Some(instance)
+    }
+}
+
+pub fn ed25519_sign_data(data: &[u8], private_key: &Ed25519PrivateKey) -> [u8; ED25519_SIGNATURE_BYTE_LENGTH] {
+    let scalar_field = ed25519_scalar_field();
+    let public_key_bytes = private_key.public_key().to_bytes();
+    let r = compute_hash_scalar(&scalar_field, &[private_key.prefix(), data]);
+    let r_bytes = Ed25519Point::generator().multiply(&scalar_to_bytes(&r)).compress();
+    let k = compute_hash_scalar(&scalar_field, &[&r_bytes, &public_key_bytes, data]);
+    let a = scalar_reduce(&scalar_field, private_key.scalar());
+    let s = scalar_field.mod_add(&r, &scalar_field.mod_mul(&k, &a));
+    let mut signature = [0; ED25519_SIGNATURE_BYTE_LENGTH];
+    signature[..ED25519_SCALAR_BYTE_LENGTH].copy_from_slice(&r_bytes);
+    signature[ED25519_SCALAR_BYTE_LENGTH..].copy_from_slice(&scalar_to_bytes(&s));
+    signature
+}
+
+pub fn ed25519_verify_data(data: &[u8], public_key: &Ed25519PublicKey, signature: &[u8]) -> bool {
+    let signature = match Ed25519Signature::from_bytes(signature) {
+        Some(signature) => signature,
+        None => return false,
+    };
+    let k = compute_hash_scalar(&ed25519_scalar_field(), &[&signature.r_bytes, &public_key.to_bytes(), data]);
+    let scalars = [scalar_to_bytes(&signature.s), scalar_to_bytes(&BigUint::one()), scalar_to_bytes(&k)];
+    let points = [Ed25519Point::generator(), signature.r.negate(), public_key.point().negate()];
+    Ed25519Point::multiscalar_multiply_vartime(&scalars, &points).multiply_by_cofactor().is_identity()
+}
+
+pub fn ed25519_verify_batch(entries: &[(&[u8], &Ed25519PublicKey, &[u8])]) -> Result<bool, Error> {
+    let scalar_field = ed25519_scalar_field();
+    let mut generator_scalar = BigUint::zero();
+    let mut scalars = vec![[0; ED25519_SCALAR_BYTE_LENGTH]];
+    let mut points = vec![Ed25519Point::generator()];
+    for (data, public_key, signature) in entries {
+        let signature = match Ed25519Signature::from_bytes(signature) {
+            Some(signature) => signature,
+            None => return Ok(false),
+        };
+        let k = compute_hash_scalar(&scalar_field, &[&signature.r_bytes, &public_key.to_bytes(), data]);
+        let mut z_bytes = [0; ED25519_BATCH_COEFFICIENT_BYTE_LENGTH];
+        fill_random_bytes(&mut z_bytes)?;
+        let z = BigUint::from_be_bytes(&z_bytes);
+        generator_scalar = scalar_field.mod_add(&generator_scalar, &scalar_field.mod_mul(&z, &signature.s));
+        scalars.push(scalar_to_bytes(&z));
+        points.push(signature.r);
+        scalars.push(scalar_to_bytes(&scalar_field.mod_mul(&z, &k)));
+        points.push(*public_key.point());
+    }
+    scalars[0] = scalar_to_bytes(&scalar_field.mod_sub(&BigUint::zero(), &generator_scalar));
+    Ok(Ed25519Point::multiscalar_multiply_vartime(&scalars, &points).multiply_by_cofactor().is_identity())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::hex::{hex_decode, hex_encode};
+    use super::super::ed25519_key::Ed25519PrivateKey;
+    use super::{ed25519_sign_data, ed25519_verify_batch, ed25519_verify_data};
+
+    const TEST_VECTORS: [(&str, &str, &str, &str); 3] = [
+        (
+            "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+            "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+            "",
+            concat!(
+                "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e06522490155",
+                "5fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+            ),
+        ),
+        (
+            "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+            "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+            "72",
+            concat!(
+                "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da",
+                "085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00",
+            ),
+        ),
+        (
+            "c5aa8df43f9f837bedb7442f31dcb7b166d38535076f094b85ce3a2e0b4458f7",
+            "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+            "af82",
+            concat!(
+                "6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac",
+                "18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a",
+            ),
+        ),
+    ];
+
+    fn hex(data: &str) -> Vec<u8> {
+        hex_decode(data).unwrap()
+    }
+
+    fn private_key(data: &str) -> Ed25519PrivateKey {
+        Ed25519PrivateKey::try_from_bytes(&hex(data)).unwrap()
+    }
+
+    #[test]
+    fn rfc_8032_test_vectors() {
+        for (seed, public_key, data, signature) in TEST_VECTORS {
+            let private_key = private_key(seed);
+            assert_eq!(hex_encode(&private_key.public_key().to_bytes()), public_key);
+            assert_eq!(hex_encode(&ed25519_sign_data(&hex(data), &private_key)), signature);
+            assert!(ed25519_verify_data(&hex(data), private_key.public_key(), &hex(signature)));
+        }
+    }
+
+    #[test]
+    fn non_canonical_s_is_rejected() {
+        let (seed, _, data, _) = TEST_VECTORS[0];
+        let private_key = private_key(seed);
+        let signature = hex(concat!(
+            "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e06522490155",
+            "4c8c7872aa064e049dbb3013fbf29380d25bf5f0595bbe24655141438e7a101b",
+        ));
+        assert!(!ed25519_verify_data(&hex(data), private_key.public_key(), &signature));
+        let mut signature = hex(TEST_VECTORS[0].3);
+        signature[32..].fill(0xff);
+        assert!(!ed25519_verify_data(&hex(data), private_key.public_key(), &signature));
+    }
+
+    #[test]
+    fn tampered_data_is_rejected() {
+        let (seed, _, data, signature) = TEST_VECTORS[2];
+        let private_key = private_key(seed);
+        let mut data = hex(data);
+        data[1] ^= 1;
+        assert!(!ed25519_verify_data(&data, private_key.public_key(), &hex(signature)));
+        let mut signature = hex(signature);
+        signature[0] ^= 1;
+        assert!(!ed25519_verify_data(&hex(TEST_VECTORS[2].2), private_key.public_key(), &signature));
+        assert!(!ed25519_verify_data(&hex(TEST_VECTORS[2].2), private_key.public_key(), &signature[..63]));
+    }
+
+    #[test]
+    fn verify_batch() {
+        let private_keys: Vec<Ed25519PrivateKey> = TEST_VECTORS.iter().map(|(seed, _, _, _)| private_key(seed)).collect();
+        let data: Vec<Vec<u8>> = TEST_VECTORS.iter().map(|(_, _, data, _)| hex(data)).collect();
+        let mut signatures: Vec<Vec<u8>> = TEST_VECTORS.iter().map(|(_, _, _, signature)| hex(signature)).collect();
+        let entries: Vec<(&[u8], _, &[u8])> = (0..3).map(|i| (data[i].as_slice(), private_keys[i].public_key(), signatures[i].as_slice())).collect();
+        assert!(ed25519_verify_batch(&entries).unwrap());
+        assert!(ed25519_verify_batch(&[]).unwrap());
+        signatures[1] = ed25519_sign_data(&data[1], &private_keys[2]).to_vec();
+        let entries: Vec<(&[u8], _, &[u8])> = (0..3).map(|i| (data[i].as_slice(), private_keys[i].public_key(), signatures[i].as_slice())).collect();
+        assert!(!ed25519_verify_batch(&entries).unwrap());
+    }
+}
@@ -0,0 +1,7 @@
+pub mod curve25519_constant;
+pub mod curve25519_field;
+pub mod x25519_key_agreement;
+pub mod ed25519_point;
+pub mod ed25519_scalar;
+pub mod ed25519_key;
+pub mod ed25519_signature;
@@ -0,0 +1,177 @@
+use crate::error::Error;
+use crate::util::random::fill_random_bytes;
+use super::curve25519_constant::{CURVE25519_A24, X25519_KEY_BYTE_LENGTH, X25519_BASE_POINT};
+use super::curve25519_field::Curve25519Element;
+
+fn clamp_scalar(scalar: &[u8; X25519_KEY_BYTE_LENGTH]) -> [u8; X25519_KEY_BYTE_LENGTH] {
+    let mut clamped = *scalar;
+    clamped[0] &= 248;
+    clamped[31] &= 127;
+    clamped[31] |= 64;
+    clamped
+}
+
+pub fn x25519(scalar: &[u8; X25519_KEY_BYTE_LENGTH], u: &[u8; X25519_KEY_BYTE_LENGTH]) -> [u8; X25519_KEY_BYTE_LENGTH] {
+    let k = clamp_scalar(scalar);
+    let x1 = Curve25519Element::from_bytes(u);
+    let mut x2 = Curve25519Element::one();
+    let mut z2 = Curve25519Element::zero();
+    let mut x3 = x1;
+    let mut z3 = Curve25519Element::one();
+    let mut swap: u64 = 0;
+    for t in (0..255).rev() {
+        let k_t = ((k[t / 8] >> (t % 8)) & 1) as u64;
+        swap ^= k_t;
+        Curve25519Element::conditional_swap(&mut x2, &mut x3, swap);
+        Curve25519Element::conditional_swap(&mut z2, &mut z3, swap);
+        swap = k_t;
+        let a = x2.add(&z2);
+        let aa = a.square();
+        let b = x2.subtract(&z2);
+        let bb = b.square();
+        let e = aa.subtract(&bb);
+        let c = x3.add(&z3);
+        let d = x3.subtract(&z3);
+        let da = d.multiply(&a);
+        let cb = c.multiply(&b);
+        x3 = da.add(&cb).square();
+        z3 = x1.multiply(&da.subtract(&cb).square());
+        x2 = aa.multiply(&bb);
+        z2 = e.multiply(&aa.add(&e.multiply_small(CURVE25519_A24)));
+    }
+    Curve25519Element::conditional_swap(&mut x2, &mut x3, swap);
+    Curve25519Element::conditional_swap(&mut z2, &mut z3, swap);
+    x2.multiply(&z2.invert()).to_bytes()
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct X25519PublicKey {
+    bytes: [u8; X25519_KEY_BYTE_LENGTH],
+}
+
+impl X25519PublicKey {
+    pub fn from_bytes(bytes: [u8; X25519_KEY_BYTE_LENGTH]) -> X25519PublicKey {
+        X25519PublicKey {
+            bytes,
+        }
+    }
+
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<X25519PublicKey, Error> {
+        let bytes: [u8; X25519_KEY_BYTE_LENGTH] = bytes.try_into().map_err(|_| Error::InvalidKeyLength)?;
+        Ok(X25519PublicKey::from_bytes(bytes))
+    }
+
+    pub fn to_bytes(&self) -> [u8; X25519_KEY_BYTE_LENGTH] {
+        self.bytes
+    }
+}
+
+#[derive(Clone)]
+pub struct X25519PrivateKey {
+    scalar: [u8; X25519_KEY_BYTE_LENGTH],
+    public_key: X25519PublicKey,
+}
+
+impl X25519PrivateKey {
+    pub fn generate() -> Result<X25519PrivateKey, Error> {
+        let mut scalar = [0; X25519_KEY_BYTE_LENGTH];
+        fill_random_bytes(&mut scalar)?;
+        Ok(X25519PrivateKey::from_bytes(scalar))
+    }
+
+    pub fn from_bytes(scalar: [u8; X25519_KEY_BYTE_LENGTH]) -> X25519PrivateKey {
+        X25519PrivateKey {
+            public_key: X25519PublicKey::from_bytes(x25519(&scalar, &X25519_BASE_POINT)),
+            scalar,
+        }
+    }
+
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<X25519PrivateKey, Error> {
+        let scalar: [u8; X25519_KEY_BYTE_LENGTH] = bytes.try_into().map_err(|_| Error::InvalidKeyLength)?;
+        Ok(X25519PrivateKey::from_bytes(scalar))
+    }
+
+    pub fn to_bytes(&self) -> [u8; X25519_KEY_BYTE_LENGTH] {
+        self.scalar
+    }
+
+    pub fn public_key(&self) -> &X25519PublicKey {
+        &self.public_key
+    }
+}
+
+pub fn x25519_compute_shared_secret(private_key: &X25519PrivateKey, peer_public_key: &X25519PublicKey) -> Result<[u8; X25519_KEY_BYTE_LENGTH], Error> {
+    let shared_secret = x25519(&private_key.scalar, &peer_public_key.bytes);
+    if shared_secret.iter().fold(0, |acc, b| acc | b) == 0 {
+        return Err(Error::InvalidKey);
+    }
+    Ok(shared_secret)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::util::hex::{hex_decode, hex_encode};
+    use super::super::curve25519_constant::X25519_BASE_POINT;
+    use super::{X25519PrivateKey, X25519PublicKey, x25519, x25519_compute_shared_secret};
+
+    const ALICE_PRIVATE_KEY: &str = "77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a";
+    const ALICE_PUBLIC_KEY: &str = "8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a";
+    const BOB_PRIVATE_KEY: &str = "5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb";
+    const BOB_PUBLIC_KEY: &str = "de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f";
+    const SHARED_SECRET: &str = "4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742";
+
+    fn bytes(data: &str) -> [u8; 32] {
+        hex_decode(data).unwrap().try_into().unwrap()
+    }
+
+    #[test]
+    fn rfc_7748_scalar_multiplication() {
+        let scalar = bytes("a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4");
+        let u = bytes("e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c");
+        assert_eq!(hex_encode(&x25519(&scalar, &u)), "c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552");
+        let scalar = bytes("4b66e9d4d1b4673c5ad22691957d6af5c11b6421e0ea01d42ca4169e7918ba0d");
+        let u = bytes("e5210f12786811d3f4b7959d0538ae2c31dbe7106fc03c3efc4cd549c715a493");
+        assert_eq!(hex_encode(&x25519(&scalar, &u)), "95cbde9476e8907d7aade45cb4b873f88b595a68799fa152e6f8f7647aac7957");
+    }
+
+    #[test]
+    fn rfc_7748_iterated_ladder() {
+        let mut k = X25519_BASE_POINT;
+        let mut u = X25519_BASE_POINT;
+        for i in 1..=1000 {
+            let result = x25519(&k, &u);
+            u = k;
+            k = result;
+            if i == 1 {
+                assert_eq!(hex_encode(&k), "422c8e7a6227d7bca1350b3e2bb7279f7897b87bb6854b783c60e80311ae3079");
+            }
+        }
+        assert_eq!(hex_encode(&k), "684cf59ba83309552800ef566f2f4d3c1c3887c49360e3875f2eb94d99532c51");
+    }
+
+    #[test]
+    fn rfc_7748_key_agreement() {
+        let alice = X25519PrivateKey::from_bytes(bytes(ALICE_PRIVATE_KEY));
+        let bob = X25519PrivateKey::from_bytes(bytes(BOB_PRIVATE_KEY));
+        assert_eq!(hex_encode(&alice.public_key().to_bytes()), ALICE_PUBLIC_KEY);
+        assert_eq!(hex_encode(&bob.public_key().to_bytes()), BOB_PUBLIC_KEY);
+        assert_eq!(hex_encode(&x25519_compute_shared_secret(&alice, bob.public_key()).unwrap()), SHARED_SECRET);
+        assert_eq!(hex_encode(&x25519_compute_shared_secret(&bob, alice.public_key()).unwrap()), SHARED_SECRET);
+    }
+
+    #[test]
+    fn low_order_point_is_rejected() {
+        let alice = X25519PrivateKey::from_bytes(bytes(ALICE_PRIVATE_KEY));
+        assert!(matches!(x25519_compute_shared_secret(&alice, &X25519PublicKey::from_bytes([0; 32])), Err(Error::InvalidKey)));
+        let mut one = [0; 32];
+        one[0] = 1;
+        assert!(matches!(x25519_compute_shared_secret(&alice, &X25519PublicKey::from_bytes(one)), Err(Error::InvalidKey)));
+    }
+
+    #[test]
+    fn invalid_key_length_is_rejected() {
+        assert!(matches!(X25519PrivateKey::try_from_bytes(&[0; 31]), Err(Error::InvalidKeyLength)));
+        assert!(matches!(X25519PublicKey::try_from_bytes(&[0; 33]), Err(Error::InvalidKeyLength)));
+    }
+}
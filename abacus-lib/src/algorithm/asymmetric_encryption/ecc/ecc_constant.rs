@@ -0,0 +1,27 @@
+pub const ECC_UNCOMPRESSED_POINT_TAG: u8 = 0x04;
+pub const ECC_COMPRESSED_EVEN_POINT_TAG: u8 = 0x02;
+pub const ECC_COMPRESSED_ODD_POINT_TAG: u8 = 0x03;
+
+pub const ECC_P256_LIMB_LENGTH: usize = 4;
+pub const ECC_P256_P: [u64; ECC_P256_LIMB_LENGTH] = [0xFFFFFFFFFFFFFFFF, 0x00000000FFFFFFFF, 0x0000000000000000, 0xFFFFFFFF00000001];
+pub const ECC_P256_A: [u64; ECC_P256_LIMB_LENGTH] = [0xFFFFFFFFFFFFFFFC, 0x00000000FFFFFFFF, 0x0000000000000000, 0xFFFFFFFF00000001];
+pub const ECC_P256_B: [u64; ECC_P256_LIMB_LENGTH] = [0x3BCE3C3E27D2604B, 0x651D06B0CC53B0F6, 0xB3EBBD55769886BC, 0x5AC635D8AA3A93E7];
+pub const ECC_P256_N: [u64; ECC_P256_LIMB_LENGTH] = [0xF3B9CAC2FC632551, 0xBCE6FAADA7179E84, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFF00000000];
+pub const ECC_P256_GX: [u64; ECC_P256_LIMB_LENGTH] = [0xF4A13945D898C296, 0x77037D812DEB33A0, 0xF8BCE6E563A440F2, 0x6B17D1F2E12C4247];
+pub const ECC_P256_GY: [u64; ECC_P256_LIMB_LENGTH] = [0xCBB6406837BF51F5, 0x2BCE33576B315ECE, 0x8EE7EB4A7C0F9E16, 0x4FE342E2FE1A7F9B];
+
+pub const ECC_P384_LIMB_LENGTH: usize = 6;
+pub const ECC_P384_P: [u64; ECC_P384_LIMB_LENGTH] = [0x00000000FFFFFFFF, 0xFFFFFFFF00000000, 0xFFFFFFFFFFFFFFFE, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF];
+pub const ECC_P384_A: [u64; ECC_P384_LIMB_LENGTH] = [0x00000000FFFFFFFC, 0xFFFFFFFF00000000, 0xFFFFFFFFFFFFFFFE, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF];
+pub const ECC_P384_B: [u64; ECC_P384_LIMB_LENGTH] = [0x2A85C8EDD3EC2AEF, 0xC656398D8A2ED19D, 0x0314088F5013875A, 0x181D9C6EFE814112, 0x988E056BE3F82D19, 0xB3312FA7E23EE7E4];
+pub const ECC_P384_N: [u64; ECC_P384_LIMB_LENGTH] = [0xECEC196ACCC52973, 0x581A0DB248B0A77A, 0xC7634D81F4372DDF, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF];
+pub const ECC_P384_GX: [u64; ECC_P384_LIMB_LENGTH] = [0x3A545E3872760AB7, 0x5502F25DBF55296C, 0x59F741E082542A38, 0x6E1D3B628BA79B98, 0x8EB1C71EF320AD74, 0xAA87CA22BE8B0537];
+pub const ECC_P384_GY: [u64; ECC_P384_LIMB_LENGTH] = [0x7A431D7C90EA0E5F, 0x0A60B1CE1D7E819D, 0xE9DA3113B5F0B8C0, 0xF8F41DBD289A147C, 0x5D9E98BF9292DC29, 0x3617DE4A96262C6F];
+
+pub const ECC_SM2_LIMB_LENGTH: usize = 4;
+pub const ECC_SM2_P: [u64; ECC_SM2_LIMB_LENGTH] = [0xFFFFFFFFFFFFFFFF, 0xFFFFFFFF00000000, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFEFFFFFFFF];
+pub const ECC_SM2_A: [u64; ECC_SM2_LIMB_LENGTH] = [0xFFFFFFFFFFFFFFFC, 0xFFFFFFFF00000000, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFEFFFFFFFF];
+pub const ECC_SM2_B: [u64; ECC_SM2_LIMB_LENGTH] = [0xDDBCBD414D940E93, 0xF39789F515AB8F92, 0x4D5A9E4BCF6509A7, 0x28E9FA9E9D9F5E34];
+pub const ECC_SM2_N: [u64; ECC_SM2_LIMB_LENGTH] = [0x53BBF40939D54123, 0x7203DF6B21C6052B, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFEFFFFFFFF];
+pub const ECC_SM2_GX: [u64; ECC_SM2_LIMB_LENGTH] = [0x715A4589334C74C7, 0x8FE30BBFF2660BE1, 0x5F9904466A39C994, 0x32C4AE2C1F198119];
+pub const ECC_SM2_GY: [u64; ECC_SM2_LIMB_LENGTH] = [0x02DF32E52139F0A0, 0xD0A9877CC62A4740, 0x59BDCEE36B692153, 0xBC3736A2F4F6779C];
@@ -0,0 +1,279 @@
+use crate::error::Error;
+use crate::math::bigint::big_uint::BigUint;
+use crate::math::bigint::montgomery_context::MontgomeryContext;
+use super::ecc_constant::{ECC_P256_P, ECC_P256_A, ECC_P256_B, ECC_P256_N, ECC_P256_GX, ECC_P256_GY};
+use super::ecc_constant::{ECC_P384_P, ECC_P384_A, ECC_P384_B, ECC_P384_N, ECC_P384_GX, ECC_P384_GY};
+use super::ecc_constant::{ECC_SM2_P, ECC_SM2_A, ECC_SM2_B, ECC_SM2_N, ECC_SM2_GX, ECC_SM2_GY};
+use super::ecc_point::ECPoint;
+
+#[derive(Clone, Debug)]
+pub struct ECCurve {
+    p: BigUint,
+    a: BigUint,
+    b: BigUint,
+    n: BigUint,
+    gx: BigUint,
+    gy: BigUint,
+    field: MontgomeryContext,
+    order: MontgomeryContext,
+    a_montgomery: Vec<u64>,
+    b3_montgomery: Vec<u64>,
+}
+
+impl ECCurve {
+    pub fn p256() -> ECCurve {
+        ECCurve::with_parameters(
+            BigUint::from_limbs(ECC_P256_P.to_vec()),
+            BigUint::from_limbs(ECC_P256_A.to_vec()),
+            BigUint::from_limbs(ECC_P256_B.to_vec()),
+            BigUint::from_limbs(ECC_P256_N.to_vec()),
+            BigUint::from_limbs(ECC_P256_GX.to_vec()),
+            BigUint::from_limbs(ECC_P256_GY.to_vec()),
+        )
+    }
+
+    pub fn p384() -> ECCurve {
+        ECCurve::with_parameters(
+            BigUint::from_limbs(ECC_P384_P.to_vec()),
+            BigUint::from_limbs(ECC_P384_A.to_vec()),
+            BigUint::from_limbs(ECC_P384_B.to_vec()),
+            BigUint::from_limbs(ECC_P384_N.to_vec()),
+            BigUint::from_limbs(ECC_P384_GX.to_vec()),
+            BigUint::from_limbs(ECC_P384_GY.to_vec()),
+        )
+    }
+
+    pub fn sm2() -> ECCurve {
+        ECCurve::with_parameters(
+            BigUint::from_limbs(ECC_SM2_P.to_vec()),
+            BigUint::from_limbs(ECC_SM2_A.to_vec()),
+            BigUint::from_limbs(ECC_SM2_B.to_vec()),
+            BigUint::from_limbs(ECC_SM2_N.to_vec()),
+            BigUint::from_limbs(ECC_SM2_GX.to_vec()),
+            BigUint::from_limbs(ECC_SM2_GY.to_vec()),
+        )
+    }
+
+    pub fn new(p: &[u8], a: &[u8], b: &[u8], n: &[u8], gx: &[u8], gy: &[u8]) -> Result<ECCurve, Error> {
+        let p = BigUint::from_be_bytes(p);
+        let a = BigUint::from_be_bytes(a);
+        let b = BigUint::from_be_bytes(b);
+        let n = BigUint::from_be_bytes(n);
+        let gx = BigUint::from_be_bytes(gx);
+        let gy = BigUint::from_be_bytes(gy);
+        ECCurve::check_parameter(&p, &a, &b, &n)?;
+        let curve = ECCurve::with_parameters(p, a, b, n, gx, gy);
+        if !curve.is_on_curve(&curve.gx, &curve.gy) || !curve.generator().multiply(&curve, &curve.n).is_identity() {
+            return Err(Error::InvalidParameter);
+        }
+        Ok(curve)
+    }
+
+    fn check_parameter(p: &BigUint, a: &BigUint, b: &BigUint, n: &BigUint) -> Result<(), Error> {
+        if p.bit_length() < 3 || p.is_even() || n.bit_length() < 2 || n.is_even() || a >= p || b >= p {
+            return Err(Error::InvalidParameter);
+        }
+        if n.bit_length() + 1 < p.bit_length() {
+            return Err(Error::InvalidParameter);
+        }
+        let discriminant = (&(&(a * a) * a) * &BigUint::from_u64(4) + &(b * b) * &BigUint::from_u64(27)) % p;
+        if discriminant.is_zero() {
+            return Err(Error::InvalidParameter);
+        }
+        Ok(())
+    }
+
+    fn with_parameters(p: BigUint, a: BigUint, b: BigUint, n: BigUint, gx: BigUint, gy: BigUint) -> ECCurve {
+        let field = MontgomeryContext::with_odd_modulus(&p);
+        let order = MontgomeryContext::with_odd_modulus(&n);
+        let a_montgomery = field.to_montgomery_limbs(&a);
+        let b3_montgomery = field.to_montgomery_limbs(&(&b * &BigUint::from_u64(3)));
+        ECCurve {
+            p,
+            a,
+            b,
+            n,
+            gx,
+            gy,
+            field,
+            order,
+            a_montgomery,
+            b3_montgomery,
+        }
+    }
+
+    pub fn field_modulus(&self) -> &BigUint {
+        &self.p
+    }
+
+    pub fn coefficient_a(&self) -> &BigUint {
+        &self.a
+    }
+
+    pub fn coefficient_b(&self) -> &BigUint {
+        &self.b
+    }
+
+    pub fn order(&self) -> &BigUint {
+        &self.n
+    }
+
+    pub fn generator_x(&self) -> &BigUint {
+        &self.gx
+    }
+
+    pub fn generator_y(&self) -> &BigUint {
+        &self.gy
+    }
+
+    pub fn field_byte_length(&self) -> usize {
+        self.p.bit_length().div_ceil(8)
+    }
+
+    pub fn scalar_byte_length(&self) -> usize {
+        self.n.bit_length().div_ceil(8)
+    }
+
+    pub(crate) fn field(&self) -> &MontgomeryContext {
+        &self.field
+    }
+
+    pub(crate) fn scalar_field(&self) -> &MontgomeryContext {
+        &self.order
+    }
+
+    pub(crate) fn a_montgomery(&self) -> &[u64] {
+        &self.a_montgomery
+    }
+
+    pub(crate) fn b3_montgomery(&self) -> &[u64] {
+        &self.b3_montgomery
+    }
+
+    pub(crate) fn generator(&self) -> ECPoint {
+        ECPoint::from_affine(self, &self.gx, &self.gy)
+    }
+
+    fn curve_equation(&self, x: &BigUint) -> BigUint {
+        let x_cubed = self.field.mod_mul(&self.field.mod_mul(x, x), x);
+        self.field.mod_add(&self.field.mod_add(&x_cubed, &self.field.mod_mul(&self.a, x)), &self.b)
+    }
+
+    pub(crate) fn is_on_curve(&self, x: &BigUint, y: &BigUint) -> bool {
+        x < &self.p && y < &self.p && self.field.mod_mul(y, y) == self.curve_equation(x)
+    }
+
+    pub(crate) fn recover_y(&self, x: &BigUint, is_odd: bool) -> Option<BigUint> {
+        if x >= &self.p {
+            return None;
+        }
+        let y = self.square_root(&self.curve_equation(x))?;
+        if y.is_odd() == is_odd {
+            Some(y)
+        } else if y.is_zero() {
+            None
+        } else {
+            Some(&self.p - &y)
+        }
+    }
+
+    fn square_root(&self, value: &BigUint) -> Option<BigUint> {
+        let one = BigUint::one();
+        if value.is_zero() {
+            return Some(BigUint::zero());
+        }
+        let p_minus_one = &self.p - &one;
+        if self.field.mod_pow(value, &(&p_minus_one >> 1)) != one {
+            return None;
+        }
+        if self.p.bit(1) {
+            return Some(self.field.mod_pow(value, &((&self.p + &one) >> 2)));
+        }
+        let s = p_minus_one.trailing_zeros();
+        let q = &p_minus_one >> s;
+        let mut z = BigUint::from_u64(2);
+        while self.field.mod_pow(&z, &(&p_minus_one >> 1)) != p_minus_one {
+            z = &z + &one;
+        }
+        let mut m = s;
+        let mut c = self.field.mod_pow(&z, &q);
+        let mut t = self.field.mod_pow(value, &q);
+        let mut r = self.field.mod_pow(value, &((&q + &one) >> 1));
+        while !t.is_one() {
+            let mut i = 0;
+            let mut t_power = t.clone();
+            while !t_power.is_one() {
+                t_power = self.field.mod_mul(&t_power, &t_power);
+                i += 1;
+            }
+            let mut b = c.clone();
+            for _ in 0..(m - i - 1) {
+                b = self.field.mod_mul(&b, &b);
+            }
+            m = i;
+            c = self.field.mod_mul(&b, &b);
+            t = self.field.mod_mul(&t, &c);
+            r = self.field.mod_mul(&r, &b);
+        }
+        Some(r)
+    }
+}
+
+impl PartialEq for ECCurve {
+    fn eq(&self, other: &ECCurve) -> bool {
+        self.p == other.p && self.a == other.a && self.b == other.b && self.n == other.n && self.gx == other.gx && self.gy == other.gy
+    }
+}
+
+impl Eq for ECCurve {}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::math::bigint::big_uint::BigUint;
+    use super::ECCurve;
+
+    fn curve_parameters(curve: &ECCurve) -> [Vec<u8>; 6] {
+        [
+            curve.field_modulus().to_be_bytes(),
+            curve.coefficient_a().to_be_bytes(),
+            curve.coefficient_b().to_be_bytes(),
+            curve.order().to_be_bytes(),
+            curve.generator_x().to_be_bytes(),
+            curve.generator_y().to_be_bytes(),
+        ]
+    }
+
+    #[test]
+    fn named_curve_parameters_are_consistent() {
+        for curve in [ECCurve::p256(), ECCurve::p384(), ECCurve::sm2()] {
+            let [p, a, b, n, gx, gy] = curve_parameters(&curve);
+            assert!(ECCurve::new(&p, &a, &b, &n, &gx, &gy).unwrap() == curve);
+            assert!(curve.is_on_curve(curve.generator_x(), curve.generator_y()));
+            assert_eq!(curve.recover_y(curve.generator_x(), curve.generator_y().is_odd()).as_ref(), Some(curve.generator_y()));
+        }
+        assert_eq!(ECCurve::p256().field_byte_length(), 32);
+        assert_eq!(ECCurve::p384().scalar_byte_length(), 48);
+    }
+
+    #[test]
+    fn invalid_parameters_are_rejected() {
+        let [p, a, b, n, gx, gy] = curve_parameters(&ECCurve::p256());
+        let mut wrong_gy = gy.clone();
+        wrong_gy[31] ^= 0x01;
+        assert!(matches!(ECCurve::new(&p, &a, &b, &n, &gx, &wrong_gy), Err(Error::InvalidParameter)));
+        let wrong_n = (&BigUint::from_be_bytes(&n) - &BigUint::from_u64(2)).to_be_bytes();
+        assert!(matches!(ECCurve::new(&p, &a, &b, &wrong_n, &gx, &gy), Err(Error::InvalidParameter)));
+        assert!(matches!(ECCurve::new(&n[..16], &a, &b, &n, &gx, &gy), Err(Error::InvalidParameter)));
+        assert!(matches!(ECCurve::new(&p, &p, &b, &n, &gx, &gy), Err(Error::InvalidParameter)));
+        assert!(matches!(ECCurve::new(&[0x17], &[0x00], &[0x00], &[0x17], &[0x01], &[0x01]), Err(Error::InvalidParameter)));
+    }
+
+    #[test]
+    fn off_curve_coordinates_are_rejected() {
+        let curve = ECCurve::p256();
+        assert!(!curve.is_on_curve(curve.generator_x(), &(curve.generator_y() + &BigUint::one())));
+        assert!(!curve.is_on_curve(&(curve.generator_x() + curve.field_modulus()), curve.generator_y()));
+        assert!(curve.recover_y(curve.field_modulus(), false).is_none());
+    }
+}
@@ -0,0 +1,191 @@
+use crate::error::Error;
+use crate::math::bigint::big_uint::BigUint;
+use super::ecc_constant::{ECC_UNCOMPRESSED_POINT_TAG, ECC_COMPRESSED_EVEN_POINT_TAG, ECC_COMPRESSED_ODD_POINT_TAG};
+use super::ecc_curve::ECCurve;
+use super::ecc_point::ECPoint;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ECPointFormat {
+    Uncompressed,
+    Compressed,
+}
+
+#[derive(Clone, Debug)]
+pub struct ECPublicKey {
+    curve: ECCurve,
+    x: BigUint,
+    y: BigUint,
+}
+
+impl ECPublicKey {
+    pub fn from_sec1_bytes(curve: &ECCurve, bytes: &[u8]) -> Result<ECPublicKey, Error> {
+        let field_byte_length = curve.field_byte_length();
+        let (x, y) = match bytes.first() {
+            Some(&ECC_UNCOMPRESSED_POINT_TAG) if bytes.len() == 1 + 2 * field_byte_length => {
+                let x = BigUint::from_be_bytes(&bytes[1..(1 + field_byte_length)]);
+                let y = BigUint::from_be_bytes(&bytes[(1 + field_byte_length)..]);
+                if !curve.is_on_curve(&x, &y) {
+                    return Err(Error::InvalidKey);
+                }
+                (x, y)
+            }
+            Some(&tag @ (ECC_COMPRESSED_EVEN_POINT_TAG | ECC_COMPRESSED_ODD_POINT_TAG)) if bytes.len() == 1 + field_byte_length => {
+                let x = BigUint::from_be_bytes(&bytes[1..]);
+                let y = curve.recover_y(&x, tag == ECC_COMPRESSED_ODD_POINT_TAG).ok_or(Error::InvalidKey)?;
+                (x, y)
+            }
+            Some(&(ECC_UNCOMPRESSED_POINT_TAG | ECC_COMPRESSED_EVEN_POINT_TAG | ECC_COMPRESSED_ODD_POINT_TAG)) => return Err(Error::InvalidKeyLength),
+            _ => return Err(Error::InvalidEncoding),
+        };
+        let instance = ECPublicKey {
+            curve: curve.clone(),
+            x,
+            y,
+        };
+        Ok(instance)
+    }
+
+    pub fn to_sec1_bytes(&self, format: ECPointFormat) -> Vec<u8> {
+        let field_byte_length = self.curve.field_byte_length();
+        let x = self.x.to_be_bytes_with_length(field_byte_length).unwrap();
+        match format {
+            ECPointFormat::Uncompressed => [&[ECC_UNCOMPRESSED_POINT_TAG][..], &x, &self.y.to_be_bytes_with_length(field_byte_length).unwrap()].concat(),
+            ECPointFormat::Compressed => {
+                let tag = if self.y.is_odd() { ECC_COMPRESSED_ODD_POINT_TAG } else { ECC_COMPRESSED_EVEN_POINT_TAG };
+                [&[tag][..], &x].concat()
+            }
+        }
+    }
+
+    pub fn curve(&self) -> &ECCurve {
+        &self.curve
+    }
+
+    pub(crate) fn point(&self) -> ECPoint {
+        ECPoint::from_affine(&self.curve, &self.x, &self.y)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ECPrivateKey {
+    d: BigUint,
+    public_key: ECPublicKey,
+}
+
+impl ECPrivateKey {
+    pub(crate) fn with_scalar(curve: &ECCurve, d: BigUint) -> ECPrivateKey {
+        let (x, y) = curve.generator().multiply(curve, &d).affine_coordinates(curve).unwrap();
+        ECPrivateKey {
+            d,
+            public_key: ECPublicKey {
+                curve: curve.clone(),
+                x,
+                y,
+            },
+        }
+    }
+
+    pub fn generate(curve: &ECCurve) -> Result<ECPrivateKey, Error> {
+        let d = BigUint::random_range(&BigUint::one(), curve.order())?;
+        Ok(ECPrivateKey::with_scalar(curve, d))
+    }
+
+    pub fn from_bytes(curve: &ECCurve, bytes: &[u8]) -> Result<ECPrivateKey, Error> {
+        if bytes.len() != curve.scalar_byte_length() {
+            return Err(Error::InvalidKeyLength);
+        }
+        let d = BigUint::from_be_bytes(bytes);
+        if d.is_zero() || &d >= curve.order() {
+            return Err(Error::InvalidKey);
+        }
+        Ok(ECPrivateKey::with_scalar(curve, d))
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.d.to_be_bytes_with_length(self.curve().scalar_byte_length()).unwrap()
+    }
+
+    pub fn curve(&self) -> &ECCurve {
+        &self.public_key.curve
+    }
+
+    pub fn public_key(&self) -> &ECPublicKey {
+        &self.public_key
+    }
+
+    pub(crate) fn scalar(&self) -> &BigUint {
+        &self.d
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::util::hex::{hex_decode, hex_encode};
+    use super::super::ecc_curve::ECCurve;
+    use super::{ECPointFormat, ECPrivateKey, ECPublicKey};
+
+    const P256_PRIVATE_KEY: &str = "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721";
+    const P256_PUBLIC_KEY: &str = concat!(
+        "0460fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb6",
+        "7903fe1008b8bc99a41ae9e95628bc64f2f1b20c2d7e9f5177a3c294d4462299",
+    );
+    const P256_COMPRESSED_PUBLIC_KEY: &str = "0360fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb6";
+    const P384_PRIVATE_KEY: &str = "6b9d3dad2e1b8c1c05b19875b6659f4de23c3b667bf297ba9aa47740787137d896d5724e4c70a825f872c9ea60d2edf5";
+    const P384_PUBLIC_KEY: &str = concat!(
+        "04ec3a4e415b4e19a4568618029f427fa5da9a8bc4ae92e02e06aae5286b300c64def8f0ea9055866064a254515480bc13",
+        "8015d9b72d7d57244ea8ef9ac0c621896708a59367f9dfb9f54ca84b3f1c9db1288b231c3ae0d4fe7344fd2533264720",
+    );
+
+    #[test]
+    fn public_key_from_private_key() {
+        let private_key = ECPrivateKey::from_bytes(&ECCurve::p256(), &hex_decode(P256_PRIVATE_KEY).unwrap()).unwrap();
+        assert_eq!(hex_encode(&private_key.public_key().to_sec1_bytes(ECPointFormat::Uncompressed)), P256_PUBLIC_KEY);
+        assert_eq!(hex_encode(&private_key.to_bytes()), P256_PRIVATE_KEY);
+        let private_key = ECPrivateKey::from_bytes(&ECCurve::p384(), &hex_decode(P384_PRIVATE_KEY).unwrap()).unwrap();
+        assert_eq!(hex_encode(&private_key.public_key().to_sec1_bytes(ECPointFormat::Uncompressed)), P384_PUBLIC_KEY);
+    }
+
+    #[test]
+    fn sec1_round_trip() {
+        let curve = ECCurve::p256();
+        let public_key = ECPublicKey::from_sec1_bytes(&curve, &hex_decode(P256_PUBLIC_KEY).unwrap()).unwrap();
+        assert_eq!(hex_encode(&public_key.to_sec1_bytes(ECPointFormat::Compressed)), P256_COMPRESSED_PUBLIC_KEY);
+        let public_key = ECPublicKey::from_sec1_bytes(&curve, &hex_decode(P256_COMPRESSED_PUBLIC_KEY).unwrap()).unwrap();
+        assert_eq!(hex_encode(&public_key.to_sec1_bytes(ECPointFormat::Uncompressed)), P256_PUBLIC_KEY);
+        let curve = ECCurve::p384();
+        let public_key = ECPublicKey::from_sec1_bytes(&curve, &hex_decode(P384_PUBLIC_KEY).unwrap()).unwrap();
+        let compressed_public_key = public_key.to_sec1_bytes(ECPointFormat::Compressed);
+        assert_eq!(compressed_public_key[0], 0x02);
+        let public_key = ECPublicKey::from_sec1_bytes(&curve, &compressed_public_key).unwrap();
+        assert_eq!(hex_encode(&public_key.to_sec1_bytes(ECPointFormat::Uncompressed)), P384_PUBLIC_KEY);
+    }
+
+    #[test]
+    fn invalid_points_are_rejected() {
+        let curve = ECCurve::p256();
+        let mut off_curve_point = hex_decode(P256_PUBLIC_KEY).unwrap();
+        off_curve_point[64] ^= 0x01;
+        assert!(matches!(ECPublicKey::from_sec1_bytes(&curve, &off_curve_point), Err(Error::InvalidKey)));
+        assert!(matches!(ECPublicKey::from_sec1_bytes(&curve, &[0x00]), Err(Error::InvalidEncoding)));
+        assert!(matches!(ECPublicKey::from_sec1_bytes(&curve, &[0x04; 65][..1]), Err(Error::InvalidKeyLength)));
+        assert!(matches!(ECPublicKey::from_sec1_bytes(&curve, &[0x05; 65]), Err(Error::InvalidEncoding)));
+        let p = curve.field_modulus().to_be_bytes();
+        let mut large_x_point = hex_decode(P256_PUBLIC_KEY).unwrap();
+        large_x_point[1..33].copy_from_slice(&p);
+        assert!(matches!(ECPublicKey::from_sec1_bytes(&curve, &large_x_point), Err(Error::InvalidKey)));
+        assert!(matches!(ECPublicKey::from_sec1_bytes(&curve, &[&[0x02][..], &p].concat()), Err(Error::InvalidKey)));
+        assert!(matches!(ECPublicKey::from_sec1_bytes(&curve, &[&[0x02][..], &[0x01; 32]].concat()), Err(Error::InvalidKey)));
+        let x = curve.generator_x().to_be_bytes();
+        let y = (curve.generator_y() + curve.field_modulus()).to_be_bytes_with_length(33).unwrap();
+        assert!(matches!(ECPublicKey::from_sec1_bytes(&curve, &[&[0x04][..], &x, &y[1..]].concat()), Err(Error::InvalidKey)));
+    }
+
+    #[test]
+    fn invalid_private_keys_are_rejected() {
+        let curve = ECCurve::p256();
+        assert!(matches!(ECPrivateKey::from_bytes(&curve, &[0; 32]), Err(Error::InvalidKey)));
+        assert!(matches!(ECPrivateKey::from_bytes(&curve, &curve.order().to_be_bytes()), Err(Error::InvalidKey)));
+        assert!(matches!(ECPrivateKey::from_bytes(&curve, &[0x01; 31]), Err(Error::InvalidKeyLength)));
+    }
+}
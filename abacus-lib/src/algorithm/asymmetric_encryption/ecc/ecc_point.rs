@@ -0,0 +1,162 @@
+use crate::math::bigint::big_uint::BigUint;
+use crate::math::bigint::bigint_constant::LIMB_BIT_LENGTH;
+use crate::math::bigint::limb_arithmetic::{limbs_select, limbs_constant_time_is_zero};
+use super::ecc_curve::ECCurve;
+
+#[derive(Clone, Debug)]
+pub(crate) struct ECPoint {
+    x: Vec<u64>,
+    y: Vec<u64>,
+    z: Vec<u64>,
+}
+
+impl ECPoint {
+    pub(crate) fn identity(curve: &ECCurve) -> ECPoint {
+        let field = curve.field();
+        ECPoint {
+            x: vec![0; field.limb_length()],
+            y: field.montgomery_one(),
+            z: vec![0; field.limb_length()],
+        }
+    }
+
+    pub(crate) fn from_affine(curve: &ECCurve, x: &BigUint, y: &BigUint) -> ECPoint {
+        let field = curve.field();
+        ECPoint {
+            x: field.to_montgomery_limbs(x),
+            y: field.to_montgomery_limbs(y),
+            z: field.montgomery_one(),
+        }
+    }
+
+    pub(crate) fn affine_coordinates(&self, curve: &ECCurve) -> Option<(BigUint, BigUint)> {
+        if self.is_identity() {
+            return None;
+        }
+        let field = curve.field();
+        let z_inverse = field.to_montgomery_limbs(&field.mod_inverse_prime_constant_time(&field.reduce_montgomery_limbs(&self.z)));
+        let x = field.reduce_montgomery_limbs(&field.montgomery_multiply(&self.x, &z_inverse));
+        let y = field.reduce_montgomery_limbs(&field.montgomery_multiply(&self.y, &z_inverse));
+        Some((x, y))
+    }
+
+    pub(crate) fn is_identity(&self) -> bool {
+        limbs_constant_time_is_zero(&self.z) == 1
+    }
+
+    fn select(a: &ECPoint, b: &ECPoint, choice: u64) -> ECPoint {
+        let mut instance = a.clone();
+        limbs_select(&a.x, &b.x, choice, &mut instance.x);
+        limbs_select(&a.y, &b.y, choice, &mut instance.y);
+        limbs_select(&a.z, &b.z, choice, &mut instance.z);
+        instance
+    }
+
+    pub(crate) fn add(&self, curve: &ECCurve, other: &ECPoint) -> ECPoint {
+        let field = curve.field();
+        let multiply = |a: &[u64], b: &[u64]| field.montgomery_multiply(a, b);
+        let add = |a: &[u64], b: &[u64]| field.add_limbs(a, b);
+        let subtract = |a: &[u64], b: &[u64]| field.subtract_limbs(a, b);
+        let (x1, y1, z1) = (&self.x, &self.y, &self.z);
+        let (x2, y2, z2) = (&other.x, &other.y, &other.z);
+        let a = curve.a_montgomery();
+        let b3 = curve.b3_montgomery();
+        let mut t0 = multiply(x1, x2);
+        let mut t1 = multiply(y1, y2);
+        let mut t2 = multiply(z1, z2);
+        let mut t3 = add(x1, y1);
+        let mut t4 = add(x2, y2);
+        t3 = multiply(&t3, &t4);
+        t4 = add(&t0, &t1);
+        t3 = subtract(&t3, &t4);
+        t4 = add(x1, z1);
+        let mut t5 = add(x2, z2);
+        t4 = multiply(&t4, &t5);
+        t5 = add(&t0, &t2);
+        t4 = subtract(&t4, &t5);
+        t5 = add(y1, z1);
+        let mut x3 = add(y2, z2);
+        t5 = multiply(&t5, &x3);
+        x3 = add(&t1, &t2);
+        t5 = subtract(&t5, &x3);
+        let mut z3 = multiply(a, &t4);
+        x3 = multiply(b3, &t2);
+        z3 = add(&x3, &z3);
+        x3 = subtract(&t1, &z3);
+        z3 = add(&t1, &z3);
+        let mut y3 = multiply(&x3, &z3);
+        t1 = add(&t0, &t0);
+        t1 = add(&t1, &t0);
+        t2 = multiply(a, &t2);
+        t4 = multiply(b3, &t4);
+        t1 = add(&t1, &t2);
+        t2 = subtract(&t0, &t2);
+        t2 = multiply(a, &t2);
+        t4 = add(&t4, &t2);
+        t0 = multiply(&t1, &t4);
+        y3 = add(&y3, &t0);
+        t0 = multiply(&t5, &t4);
+        x3 = multiply(&x3, &t3);
+        x3 = subtract(&x3, &t0);
+        t0 = multiply(&t3, &t1);
+        z3 = multiply(&t5, &z3);
+        z3 = add(&z3, &t0);
+        ECPoint {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    pub(crate) fn double(&self, curve: &ECCurve) -> ECPoint {
+        self.add(curve, self)
+    }
+
+    pub(crate) fn multiply(&self, curve: &ECCurve, k: &BigUint) -> ECPoint {
+        let scalar_field = curve.scalar_field();
+        let k = k.to_limbs_with_length(k.limbs().len().max(scalar_field.limb_length())).unwrap();
+        let mut result = ECPoint::identity(curve);
+        for i in (0..(k.len() * LIMB_BIT_LENGTH)).rev() {
+            result = result.double(curve);
+            let added = result.add(curve, self);
+            result = ECPoint::select(&result, &added, (k[i / LIMB_BIT_LENGTH] >> (i % LIMB_BIT_LENGTH)) & 1);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::bigint::big_uint::BigUint;
+    use super::super::ecc_curve::ECCurve;
+    use super::ECPoint;
+
+    #[test]
+    fn group_order_and_negation() {
+        for curve in [ECCurve::p256(), ECCurve::p384(), ECCurve::sm2()] {
+            let generator = curve.generator();
+            assert!(generator.multiply(&curve, curve.order()).is_identity());
+            assert!(generator.multiply(&curve, curve.order()).affine_coordinates(&curve).is_none());
+            let (x, y) = generator.multiply(&curve, &(curve.order() - &BigUint::one())).affine_coordinates(&curve).unwrap();
+            assert_eq!(&x, curve.generator_x());
+            assert_eq!(y, curve.field_modulus() - curve.generator_y());
+        }
+    }
+
+    #[test]
+    fn addition_matches_scalar_multiplication() {
+        let curve = ECCurve::p256();
+        let generator = curve.generator();
+        let double = generator.double(&curve).affine_coordinates(&curve).unwrap();
+        assert_eq!(generator.add(&curve, &generator).affine_coordinates(&curve).unwrap(), double);
+        assert_eq!(generator.multiply(&curve, &BigUint::from_u64(2)).affine_coordinates(&curve).unwrap(), double);
+        let p = generator.multiply(&curve, &BigUint::from_u64(5));
+        let q = generator.multiply(&curve, &BigUint::from_u64(7));
+        let sum = generator.multiply(&curve, &BigUint::from_u64(12)).affine_coordinates(&curve).unwrap();
+        assert_eq!(p.add(&curve, &q).affine_coordinates(&curve).unwrap(), sum);
+        assert_eq!(q.add(&curve, &p).affine_coordinates(&curve).unwrap(), sum);
+        let identity = ECPoint::identity(&curve);
+        assert_eq!(identity.add(&curve, &p).affine_coordinates(&curve), p.affine_coordinates(&curve));
+        assert!(generator.multiply(&curve, &BigUint::zero()).is_identity());
+    }
+}
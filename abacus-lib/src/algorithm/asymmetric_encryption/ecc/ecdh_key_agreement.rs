@@ -0,0 +1,45 @@
+use crate::error::Error;
+use super::ecc_key::{ECPrivateKey, ECPublicKey};
+
+pub fn ecdh_compute_shared_secret(private_key: &ECPrivateKey, peer_public_key: &ECPublicKey) -> Result<Vec<u8>, Error> {
+    let curve = private_key.curve();
+    if curve != peer_public_key.curve() {
+        return Err(Error::InvalidKey);
+    }
+    let (x, _) = peer_public_key.point().multiply(curve, private_key.scalar()).affine_coordinates(curve).ok_or(Error::InvalidKey)?;
+    Ok(x.to_be_bytes_with_length(curve.field_byte_length()).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::util::hex::{hex_decode, hex_encode};
+    use super::super::ecc_curve::ECCurve;
+    use super::super::ecc_key::{ECPrivateKey, ECPublicKey};
+    use super::ecdh_compute_shared_secret;
+
+    const PRIVATE_KEY: &str = "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721";
+    const PEER_PRIVATE_KEY: &str = "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+    const PEER_PUBLIC_KEY: &str = concat!(
+        "04d8cd12ea5c67f2f8a00c1124893edcfa6754c4d6cede6be13bdf2295c810a97f",
+        "a5a89d2d2a360c0ca9a4d6c7c9ed4b28d3e199d6627f2e696d689c310a5b0f48",
+    );
+    const SHARED_SECRET: &str = "8c339726b1d968756182352fc15018109527f618c7ee1de136728624edd2afe3";
+
+    #[test]
+    fn p256_shared_secret() {
+        let curve = ECCurve::p256();
+        let private_key = ECPrivateKey::from_bytes(&curve, &hex_decode(PRIVATE_KEY).unwrap()).unwrap();
+        let peer_private_key = ECPrivateKey::from_bytes(&curve, &hex_decode(PEER_PRIVATE_KEY).unwrap()).unwrap();
+        let peer_public_key = ECPublicKey::from_sec1_bytes(&curve, &hex_decode(PEER_PUBLIC_KEY).unwrap()).unwrap();
+        assert_eq!(hex_encode(&ecdh_compute_shared_secret(&private_key, &peer_public_key).unwrap()), SHARED_SECRET);
+        assert_eq!(hex_encode(&ecdh_compute_shared_secret(&peer_private_key, private_key.public_key()).unwrap()), SHARED_SECRET);
+    }
+
+    #[test]
+    fn curve_mismatch_is_rejected() {
+        let private_key = ECPrivateKey::from_bytes(&ECCurve::p256(), &hex_decode(PRIVATE_KEY).unwrap()).unwrap();
+        let peer_private_key = ECPrivateKey::generate(&ECCurve::sm2()).unwrap();
+        assert!(matches!(ecdh_compute_shared_secret(&private_key, peer_private_key.public_key()), Err(Error::InvalidKey)));
+    }
+}
@@ -0,0 +1,105 @@
+use std::marker::PhantomData;
+use crate::algorithm::digest::digest_trait::Digest;
+use crate::algorithm::mac::hmac::hmac_digest::HMACDigest;
+use crate::math::bigint::big_uint::BigUint;
+
+pub(crate) fn bits_to_number(bytes: &[u8], bit_length: usize) -> BigUint {
+    let number = BigUint::from_be_bytes(bytes);
+    if bytes.len() * 8 > bit_length {
+        number >> (bytes.len() * 8 - bit_length)
+    } else {
+        number
+    }
+}
+
+pub struct ECDSANonceGenerator<D: Digest> {
+    k: Vec<u8>,
+    v: Vec<u8>,
+    order: BigUint,
+    digest: PhantomData<D>,
+}
+
+impl<D: Digest> ECDSANonceGenerator<D> {
+    pub fn new(private_key: &BigUint, digest: &[u8], order: &BigUint) -> ECDSANonceGenerator<D> {
+        let byte_length = order.bit_length().div_ceil(8);
+        let x = private_key.to_be_bytes_with_length(byte_length).unwrap();
+        let h = (bits_to_number(digest, order.bit_length()) % order).to_be_bytes_with_length(byte_length).unwrap();
+        let mut instance = ECDSANonceGenerator {
+            k: vec![0x00; D::DIGEST_BYTE_LENGTH],
+            v: vec![0x01; D::DIGEST_BYTE_LENGTH],
+            order: order.clone(),
+            digest: PhantomData,
+        };
+        instance.k = instance.compute_mac(&[&instance.v, &[0x00][..], &x, &h]);
+        instance.v = instance.compute_mac(&[&instance.v]);
+        instance.k = instance.compute_mac(&[&instance.v, &[0x01][..], &x, &h]);
+        instance.v = instance.compute_mac(&[&instance.v]);
+        instance
+    }
+
+    fn compute_mac(&self, data: &[&[u8]]) -> Vec<u8> {
+        let mut hmac = HMACDigest::<D>::new(&self.k);
+        for part in data {
+            hmac.push_data(part);
+        }
+        hmac.compute_digest();
+        hmac.get_digest_bytes().as_ref().to_vec()
+    }
+
+    pub fn next_nonce(&mut self) -> BigUint {
+        let bit_length = self.order.bit_length();
+        loop {
+            let mut t = Vec::with_capacity(bit_length.div_ceil(8) + D::DIGEST_BYTE_LENGTH);
+            while t.len() * 8 < bit_length {
+                self.v = self.compute_mac(&[&self.v]);
+                t.extend_from_slice(&self.v);
+            }
+            let k = bits_to_number(&t, bit_length);
+            self.k = self.compute_mac(&[&self.v, &[0x00][..]]);
+            self.v = self.compute_mac(&[&self.v]);
+            if !k.is_zero() && k < self.order {
+                return k;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithm::digest::digest_trait::Digest;
+    use crate::algorithm::digest::digest_utils::DigestUtils;
+    use crate::algorithm::digest::sha2::sha2_256_digest::SHA2_256Digest;
+    use crate::algorithm::digest::sha2::sha2_384_digest::SHA2_384Digest;
+    use crate::math::bigint::big_uint::BigUint;
+    use crate::util::hex::{hex_decode, hex_encode};
+    use super::super::ecc_curve::ECCurve;
+    use super::ECDSANonceGenerator;
+
+    const P256_PRIVATE_KEY: &str = "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721";
+    const P384_PRIVATE_KEY: &str = "6b9d3dad2e1b8c1c05b19875b6659f4de23c3b667bf297ba9aa47740787137d896d5724e4c70a825f872c9ea60d2edf5";
+
+    fn check_nonce<D: Digest>(curve: &ECCurve, private_key: &str, message: &[u8], nonce: &str) {
+        let private_key = BigUint::from_be_bytes(&hex_decode(private_key).unwrap());
+        let digest = DigestUtils::data_digest::<D>(message);
+        let mut nonce_generator = ECDSANonceGenerator::<D>::new(&private_key, digest.as_ref(), curve.order());
+        assert_eq!(hex_encode(&nonce_generator.next_nonce().to_be_bytes_with_length(curve.scalar_byte_length()).unwrap()), nonce);
+    }
+
+    #[test]
+    fn rfc_6979_p256_nonces() {
+        let curve = ECCurve::p256();
+        check_nonce::<SHA2_256Digest>(&curve, P256_PRIVATE_KEY, b"sample", "a6e3c57dd01abe90086538398355dd4c3b17aa873382b0f24d6129493d8aad60");
+        check_nonce::<SHA2_256Digest>(&curve, P256_PRIVATE_KEY, b"test", "d16b6ae827f17175e040871a1c7ec3500192c4c92677336ec2537acaee0008e0");
+        check_nonce::<SHA2_384Digest>(&curve, P256_PRIVATE_KEY, b"sample", "09f634b188cefd98e7ec88b1aa9852d734d0bc272f7d2a47decc6ebeb375aad4");
+        check_nonce::<SHA2_384Digest>(&curve, P256_PRIVATE_KEY, b"test", "16aeffa357260b04b1dd199693960740066c1a8f3e8edd79070aa914d361b3b8");
+    }
+
+    #[test]
+    fn rfc_6979_p384_nonces() {
+        let curve = ECCurve::p384();
+        check_nonce::<SHA2_256Digest>(&curve, P384_PRIVATE_KEY, b"sample", "180ae9f9aec5438a44bc159a1fcb277c7be54fa20e7cf404b490650a8acc414e375572342863c899f9f2edf9747a9b60");
+        check_nonce::<SHA2_256Digest>(&curve, P384_PRIVATE_KEY, b"test", "0cfac37587532347dc3389fdc98286bba8c73807285b184c83e62e26c401c0faa48dd070ba79921a3457abff2d630ad7");
+        check_nonce::<SHA2_384Digest>(&curve, P384_PRIVATE_KEY, b"sample", "94ed910d1a099dad3254e9242ae85abde4ba15168eaf0ca87a555fd56d10fbca2907e3e83ba95368623b8c4686915cf9");
+        check_nonce::<SHA2_384Digest>(&curve, P384_PRIVATE_KEY, b"test", "015ee46a5bf88773ed9123a5ab0807962d193719503c527b031b4c2d225092ada71f4a459bc0da98adb95837db8312ea");
+    }
+}
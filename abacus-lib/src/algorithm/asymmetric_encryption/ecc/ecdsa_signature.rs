@@ -0,0 +1,250 @@
+use crate::error::Error;
+use crate::algorithm::digest::digest_trait::Digest;
+use crate::algorithm::digest::digest_utils::DigestUtils;
+use crate::math::bigint::big_uint::BigUint;
+use crate::util::der::{der_encode_integer, der_encode_sequence, DERReader};
+use super::ecc_curve::ECCurve;
+use super::ecc_key::{ECPrivateKey, ECPublicKey};
+use super::ecdsa_nonce::{ECDSANonceGenerator, bits_to_number};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ECDSASignatureFormat {
+    Raw,
+    DER,
+}
+
+fn signature_from_bytes(signature: &[u8], curve: &ECCurve, format: ECDSASignatureFormat) -> Result<(BigUint, BigUint), Error> {
+    let scalar_byte_length = curve.scalar_byte_length();
+    let (r, s) = match format {
+        ECDSASignatureFormat::Raw => {
+            if signature.len() != 2 * scalar_byte_length {
+                return Err(Error::InvalidDataLength);
+            }
+            let (r, s) = signature.split_at(scalar_byte_length);
+            (BigUint::from_be_bytes(r), BigUint::from_be_bytes(s))
+        }
+        ECDSASignatureFormat::DER => {
+            let mut reader = DERReader::new(signature);
+            let mut sequence = reader.read_sequence()?;
+            let r = sequence.read_integer()?;
+            let s = sequence.read_integer()?;
+            if !sequence.is_empty() || !reader.is_empty() || r.len() > scalar_byte_length || s.len() > scalar_byte_length {
+                return Err(Error::InvalidEncoding);
+            }
+            (BigUint::from_be_bytes(r), BigUint::from_be_bytes(s))
+        }
+    };
+    if r.is_zero() || s.is_zero() || &r >= curve.order() || &s >= curve.order() {
+        return Err(Error::InvalidParameter);
+    }
+    Ok((r, s))
+}
+
+fn signature_to_bytes(r: &BigUint, s: &BigUint, curve: &ECCurve, format: ECDSASignatureFormat) -> Vec<u8> {
+    let scalar_byte_length = curve.scalar_byte_length();
+    let r = r.to_be_bytes_with_length(scalar_byte_length).unwrap();
+    let s = s.to_be_bytes_with_length(scalar_byte_length).unwrap();
+    match format {
+        ECDSASignatureFormat::Raw => [r, s].concat(),
+        ECDSASignatureFormat::DER => der_encode_sequence(&[der_encode_integer(&r), der_encode_integer(&s)].concat()),
+    }
+}
+
+pub fn ecdsa_sign_digest<D: Digest>(digest: &[u8], private_key: &ECPrivateKey, format: ECDSASignatureFormat) -> Result<Vec<u8>, Error> {
+    if digest.len() != D::DIGEST_BYTE_LENGTH {
+        return Err(Error::InvalidDataLength);
+    }
+    let curve = private_key.curve();
+    let scalar_field = curve.scalar_field();
+    let e = bits_to_number(digest, curve.order().bit_length());
+    let mut nonce_generator = ECDSANonceGenerator::<D>::new(private_key.scalar(), digest, curve.order());
+    loop {
+        let k = nonce_generator.next_nonce();
+        let (x1, _) = curve.generator().multiply(curve, &k).affine_coordinates(curve).unwrap();
+        let r = &x1 % curve.order();
+        if r.is_zero() {
+            continue;
+        }
+        let k_inverse = scalar_field.mod_inverse_prime_constant_time(&k);
+        let s = scalar_field.mod_mul(&k_inverse, &scalar_field.mod_add(&e, &scalar_field.mod_mul(&r, private_key.scalar())));
+        if s.is_zero() {
+            continue;
+        }
+        return Ok(signature_to_bytes(&r, &s, curve, format));
+    }
+}
+
+pub fn ecdsa_verify_digest<D: Digest>(digest: &[u8], public_key: &ECPublicKey, signature: &[u8], format: ECDSASignatureFormat) -> bool {
+    if digest.len() != D::DIGEST_BYTE_LENGTH {
+        return false;
+    }
+    let curve = public_key.curve();
+    let (r, s) = match signature_from_bytes(signature, curve, format) {
+        Ok(signature) => signature,
+        Err(_) => return false,
+    };
+    let scalar_field = curve.scalar_field();
+    let e = bits_to_number(digest, curve.order().bit_length());
+    let w = match s.mod_inverse(curve.order()) {
+        Some(w) => w,
+        None => return false,
+    };
+    let u1 = scalar_field.mod_mul(&e, &w);
+    let u2 = scalar_field.mod_mul(&r, &w);
+    let point = curve.generator().multiply(curve, &u1).add(curve, &public_key.point().multiply(curve, &u2));
+    match point.affine_coordinates(curve) {
+        Some((x1, _)) => &x1 % curve.order() == r,
+        None => false,
+    }
+}
+
+pub fn ecdsa_convert_signature(signature: &[u8], curve: &ECCurve, from_format: ECDSASignatureFormat, to_format: ECDSASignatureFormat) -> Result<Vec<u8>, Error> {
+    let (r, s) = signature_from_bytes(signature, curve, from_format)?;
+    Ok(signature_to_bytes(&r, &s, curve, to_format))
+}
+
+pub struct ECDSASigner<D: Digest> {
+    private_key: ECPrivateKey,
+    digest: D,
+}
+
+impl<D: Digest> ECDSASigner<D> {
+    pub fn new(private_key: &ECPrivateKey) -> ECDSASigner<D> {
+        ECDSASigner {
+            private_key: private_key.clone(),
+            digest: D::new(),
+        }
+    }
+
+    pub fn push_data(&mut self, data: &[u8]) {
+        self.digest.push_data(data)
+    }
+
+    pub fn sign(mut self, format: ECDSASignatureFormat) -> Result<Vec<u8>, Error> {
+        self.digest.compute_digest();
+        ecdsa_sign_digest::<D>(self.digest.get_digest_bytes().as_ref(), &self.private_key, format)
+    }
+}
+
+pub struct ECDSAVerifier<D: Digest> {
+    public_key: ECPublicKey,
+    digest: D,
+}
+
+impl<D: Digest> ECDSAVerifier<D> {
+    pub fn new(public_key: &ECPublicKey) -> ECDSAVerifier<D> {
+        ECDSAVerifier {
+            public_key: public_key.clone(),
+            digest: D::new(),
+        }
+    }
+
+    pub fn push_data(&mut self, data: &[u8]) {
+        self.digest.push_data(data)
+    }
+
+    pub fn verify(mut self, signature: &[u8], format: ECDSASignatureFormat) -> bool {
+        self.digest.compute_digest();
+        ecdsa_verify_digest::<D>(self.digest.get_digest_bytes().as_ref(), &self.public_key, signature, format)
+    }
+}
+
+pub fn ecdsa_sign_data<D: Digest>(data: &[u8], private_key: &ECPrivateKey, format: ECDSASignatureFormat) -> Result<Vec<u8>, Error> {
+    ecdsa_sign_digest::<D>(DigestUtils::data_digest::<D>(data).as_ref(), private_key, format)
+}
+
+pub fn ecdsa_verify_data<D: Digest>(data: &[u8], public_key: &ECPublicKey, signature: &[u8], format: ECDSASignatureFormat) -> bool {
+    ecdsa_verify_digest::<D>(DigestUtils::data_digest::<D>(data).as_ref(), public_key, signature, format)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithm::digest::digest_trait::Digest;
+    use crate::algorithm::digest::sha2::sha2_256_digest::SHA2_256Digest;
+    use crate::algorithm::digest::sha2::sha2_384_digest::SHA2_384Digest;
+    use crate::error::Error;
+    use crate::util::hex::{hex_decode, hex_encode};
+    use super::super::ecc_curve::ECCurve;
+    use super::super::ecc_key::ECPrivateKey;
+    use super::{ECDSASignatureFormat, ECDSASigner, ECDSAVerifier, ecdsa_convert_signature, ecdsa_sign_data, ecdsa_verify_data};
+
+    const P256_PRIVATE_KEY: &str = "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721";
+    const P384_PRIVATE_KEY: &str = "6b9d3dad2e1b8c1c05b19875b6659f4de23c3b667bf297ba9aa47740787137d896d5724e4c70a825f872c9ea60d2edf5";
+    const P256_SHA2_256_SAMPLE_SIGNATURE: &str = concat!(
+        "efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716",
+        "f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8",
+    );
+    const P256_SHA2_256_TEST_SIGNATURE: &str = concat!(
+        "f1abb023518351cd71d881567b1ea663ed3efcf6c5132b354f28d3b0b7d38367",
+        "019f4113742a2b14bd25926b49c649155f267e60d3814b4c0cc84250e46f0083",
+    );
+    const P384_SHA2_384_SAMPLE_SIGNATURE: &str = concat!(
+        "94edbb92a5ecb8aad4736e56c691916b3f88140666ce9fa73d64c4ea95ad133c81a648152e44acf96e36dd1e80fabe46",
+        "99ef4aeb15f178cea1fe40db2603138f130e740a19624526203b6351d0a3a94fa329c145786e679e7b82c71a38628ac8",
+    );
+    const P384_SHA2_384_TEST_SIGNATURE: &str = concat!(
+        "8203b63d3c853e8d77227fb377bcf7b7b772e97892a80f36ab775d509d7a5feb0542a7f0812998da8f1dd3ca3cf023db",
+        "ddd0760448d42d8a43af45af836fce4de8be06b485e9b61b827c2f13173923e06a739f040649a667bf3b828246baa5a5",
+    );
+
+    fn check_signature<D: Digest>(curve: &ECCurve, private_key: &str, message: &[u8], signature: &str) {
+        let private_key = ECPrivateKey::from_bytes(curve, &hex_decode(private_key).unwrap()).unwrap();
+        assert_eq!(hex_encode(&ecdsa_sign_data::<D>(message, &private_key, ECDSASignatureFormat::Raw).unwrap()), signature);
+        assert!(ecdsa_verify_data::<D>(message, private_key.public_key(), &hex_decode(signature).unwrap(), ECDSASignatureFormat::Raw));
+    }
+
+    #[test]
+    fn rfc_6979_p256_signatures() {
+        let curve = ECCurve::p256();
+        check_signature::<SHA2_256Digest>(&curve, P256_PRIVATE_KEY, b"sample", P256_SHA2_256_SAMPLE_SIGNATURE);
+        check_signature::<SHA2_256Digest>(&curve, P256_PRIVATE_KEY, b"test", P256_SHA2_256_TEST_SIGNATURE);
+    }
+
+    #[test]
+    fn rfc_6979_p384_signatures() {
+        let curve = ECCurve::p384();
+        check_signature::<SHA2_384Digest>(&curve, P384_PRIVATE_KEY, b"sample", P384_SHA2_384_SAMPLE_SIGNATURE);
+        check_signature::<SHA2_384Digest>(&curve, P384_PRIVATE_KEY, b"test", P384_SHA2_384_TEST_SIGNATURE);
+    }
+
+    #[test]
+    fn streaming_signer_and_der_format() {
+        let curve = ECCurve::p256();
+        let private_key = ECPrivateKey::from_bytes(&curve, &hex_decode(P256_PRIVATE_KEY).unwrap()).unwrap();
+        let mut signer = ECDSASigner::<SHA2_256Digest>::new(&private_key);
+        signer.push_data(b"sam");
+        signer.push_data(b"ple");
+        let signature = signer.sign(ECDSASignatureFormat::DER).unwrap();
+        let raw_signature = ecdsa_convert_signature(&signature, &curve, ECDSASignatureFormat::DER, ECDSASignatureFormat::Raw).unwrap();
+        assert_eq!(hex_encode(&raw_signature), P256_SHA2_256_SAMPLE_SIGNATURE);
+        let mut verifier = ECDSAVerifier::<SHA2_256Digest>::new(private_key.public_key());
+        verifier.push_data(b"sample");
+        assert!(verifier.verify(&signature, ECDSASignatureFormat::DER));
+    }
+
+    #[test]
+    fn tampered_message_or_signature_is_rejected() {
+        let curve = ECCurve::p256();
+        let private_key = ECPrivateKey::from_bytes(&curve, &hex_decode(P256_PRIVATE_KEY).unwrap()).unwrap();
+        let public_key = private_key.public_key();
+        let mut signature = hex_decode(P256_SHA2_256_SAMPLE_SIGNATURE).unwrap();
+        assert!(!ecdsa_verify_data::<SHA2_256Digest>(b"samplf", public_key, &signature, ECDSASignatureFormat::Raw));
+        assert!(!ecdsa_verify_data::<SHA2_384Digest>(b"sample", public_key, &signature, ECDSASignatureFormat::Raw));
+        assert!(!ecdsa_verify_data::<SHA2_256Digest>(b"sample", public_key, &signature[1..], ECDSASignatureFormat::Raw));
+        signature[40] ^= 0x01;
+        assert!(!ecdsa_verify_data::<SHA2_256Digest>(b"sample", public_key, &signature, ECDSASignatureFormat::Raw));
+    }
+
+    #[test]
+    fn out_of_range_signature_scalars_are_rejected() {
+        let curve = ECCurve::p256();
+        let private_key = ECPrivateKey::from_bytes(&curve, &hex_decode(P256_PRIVATE_KEY).unwrap()).unwrap();
+        let signature = hex_decode(P256_SHA2_256_SAMPLE_SIGNATURE).unwrap();
+        let (r, s) = signature.split_at(32);
+        let n = curve.order().to_be_bytes();
+        for forged_signature in [[&[0; 32][..], s].concat(), [r, &[0; 32][..]].concat(), [&n[..], s].concat(), [r, &n[..]].concat(), [&[0xff; 32][..], s].concat()] {
+            assert!(!ecdsa_verify_data::<SHA2_256Digest>(b"sample", private_key.public_key(), &forged_signature, ECDSASignatureFormat::Raw));
+            assert!(matches!(ecdsa_convert_signature(&forged_signature, &curve, ECDSASignatureFormat::Raw, ECDSASignatureFormat::DER), Err(Error::InvalidParameter)));
+        }
+    }
+}
@@ -0,0 +1,7 @@
+pub mod ecc_constant;
+pub mod ecc_curve;
+pub mod ecc_point;
+pub mod ecc_key;
+pub mod ecdsa_nonce;
+pub mod ecdsa_signature;
+pub mod ecdh_key_agreement;
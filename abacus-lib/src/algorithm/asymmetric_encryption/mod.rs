@@ -0,0 +1,4 @@
+pub mod sm2;
+pub mod rsa;
+pub mod ecc;
+pub mod curve25519;
@@ -0,0 +1,9 @@
+pub mod rsa_constant;
+pub mod rsa_prime;
+pub mod rsa_key;
+pub mod rsa_raw;
+pub mod rsa_mgf1;
+pub mod rsa_digest_info;
+pub mod rsa_pkcs1_v15;
+pub mod rsa_oaep;
+pub mod rsa_pss;
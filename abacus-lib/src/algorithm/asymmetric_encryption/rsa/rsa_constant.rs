@@ -0,0 +1,28 @@
+pub const RSA_DEFAULT_PUBLIC_EXPONENT: u64 = 65537;
+pub const RSA_MIN_MODULUS_BIT_LENGTH: usize = 1024;
+pub const RSA_MAX_MODULUS_BIT_LENGTH: usize = 16384;
+pub const RSA_MILLER_RABIN_ROUNDS: usize = 64;
+pub const RSA_PRIME_DISTANCE_BIT_LENGTH: usize = 100;
+
+pub const RSA_PKCS1_V15_MIN_PADDING_BYTE_LENGTH: usize = 11;
+pub const RSA_PKCS1_V15_ENCRYPTION_BLOCK_TYPE: u8 = 0x02;
+pub const RSA_PKCS1_V15_SIGNATURE_BLOCK_TYPE: u8 = 0x01;
+pub const RSA_OAEP_SEPARATOR: u8 = 0x01;
+pub const RSA_PSS_SEPARATOR: u8 = 0x01;
+pub const RSA_PSS_TRAILER: u8 = 0xBC;
+pub const RSA_PSS_PREFIX_BYTE_LENGTH: usize = 8;
+
+pub const RSA_SMALL_PRIMES: [u64; 53] = [3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97, 101, 103, 107, 109, 113, 127, 131, 137, 139, 149, 151, 157, 163, 167, 173, 179, 181, 191, 193, 197, 199, 211, 223, 227, 229, 233, 239, 241, 251];
+
+pub const RSA_MD5_OID: &[u8] = &[0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x02, 0x05];
+pub const RSA_SHA2_224_OID: &[u8] = &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x04];
+pub const RSA_SHA2_256_OID: &[u8] = &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01];
+pub const RSA_SHA2_384_OID: &[u8] = &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x02];
+pub const RSA_SHA2_512_OID: &[u8] = &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x03];
+pub const RSA_SHA2_512_224_OID: &[u8] = &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x05];
+pub const RSA_SHA2_512_256_OID: &[u8] = &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x06];
+pub const RSA_SHA3_224_OID: &[u8] = &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x07];
+pub const RSA_SHA3_256_OID: &[u8] = &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x08];
+pub const RSA_SHA3_384_OID: &[u8] = &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x09];
+pub const RSA_SHA3_512_OID: &[u8] = &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x0A];
+pub const RSA_SM3_OID: &[u8] = &[0x2A, 0x81, 0x1C, 0xCF, 0x55, 0x01, 0x83, 0x11];
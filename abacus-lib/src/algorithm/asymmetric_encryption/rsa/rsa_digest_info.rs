@@ -0,0 +1,72 @@
+use crate::algorithm::digest::digest_trait::Digest;
+use crate::algorithm::digest::md5::md5_digest::MD5Digest;
+use crate::algorithm::digest::sha2::sha2_224_digest::SHA2_224Digest;
+use crate::algorithm::digest::sha2::sha2_256_digest::SHA2_256Digest;
+use crate::algorithm::digest::sha2::sha2_384_digest::SHA2_384Digest;
+use crate::algorithm::digest::sha2::sha2_512_digest::SHA2_512Digest;
+use crate::algorithm::digest::sha2::sha2_512_224_digest::SHA2_512_224Digest;
+use crate::algorithm::digest::sha2::sha2_512_256_digest::SHA2_512_256Digest;
+use crate::algorithm::digest::sha3::sha3_224_digest::SHA3_224Digest;
+use crate::algorithm::digest::sha3::sha3_256_digest::SHA3_256Digest;
+use crate::algorithm::digest::sha3::sha3_384_digest::SHA3_384Digest;
+use crate::algorithm::digest::sha3::sha3_512_digest::SHA3_512Digest;
+use crate::algorithm::digest::sm3::sm3_digest::SM3Digest;
+use crate::util::der::{der_encode_null, der_encode_object_identifier, der_encode_octet_string, der_encode_sequence};
+use super::rsa_constant::{RSA_MD5_OID, RSA_SHA2_224_OID, RSA_SHA2_256_OID, RSA_SHA2_384_OID, RSA_SHA2_512_OID, RSA_SHA2_512_224_OID, RSA_SHA2_512_256_OID, RSA_SHA3_224_OID, RSA_SHA3_256_OID, RSA_SHA3_384_OID, RSA_SHA3_512_OID, RSA_SM3_OID};
+
+pub trait RSADigestInfo: Digest {
+    const DIGEST_ALGORITHM_OID: &'static [u8];
+
+    fn encode_digest_info(digest: &[u8]) -> Vec<u8> {
+        let algorithm_identifier = der_encode_sequence(&[der_encode_object_identifier(Self::DIGEST_ALGORITHM_OID), der_encode_null()].concat());
+        der_encode_sequence(&[algorithm_identifier, der_encode_octet_string(digest)].concat())
+    }
+}
+
+impl RSADigestInfo for MD5Digest {
+    const DIGEST_ALGORITHM_OID: &'static [u8] = RSA_MD5_OID;
+}
+
+impl RSADigestInfo for SHA2_224Digest {
+    const DIGEST_ALGORITHM_OID: &'static [u8] = RSA_SHA2_224_OID;
+}
+
+impl RSADigestInfo for SHA2_256Digest {
+    const DIGEST_ALGORITHM_OID: &'static [u8] = RSA_SHA2_256_OID;
+}
+
+impl RSADigestInfo for SHA2_384Digest {
+    const DIGEST_ALGORITHM_OID: &'static [u8] = RSA_SHA2_384_OID;
+}
+
+impl RSADigestInfo for SHA2_512Digest {
+    const DIGEST_ALGORITHM_OID: &'static [u8] = RSA_SHA2_512_OID;
+}
+
+impl RSADigestInfo for SHA2_512_224Digest {
+    const DIGEST_ALGORITHM_OID: &'static [u8] = RSA_SHA2_512_224_OID;
+}
+
+impl RSADigestInfo for SHA2_512_256Digest {
+    const DIGEST_ALGORITHM_OID: &'static [u8] = RSA_SHA2_512_256_OID;
+}
+
+impl RSADigestInfo for SHA3_224Digest {
+    const DIGEST_ALGORITHM_OID: &'static [u8] = RSA_SHA3_224_OID;
+}
+
+impl RSADigestInfo for SHA3_256Digest {
+    const DIGEST_ALGORITHM_OID: &'static [u8] = RSA_SHA3_256_OID;
+}
+
+impl RSADigestInfo for SHA3_384Digest {
+    const DIGEST_ALGORITHM_OID: &'static [u8] = RSA_SHA3_384_OID;
+}
+
+impl RSADigestInfo for SHA3_512Digest {
+    const DIGEST_ALGORITHM_OID: &'static [u8] = RSA_SHA3_512_OID;
+}
+
+impl RSADigestInfo for SM3Digest {
+    const DIGEST_ALGORITHM_OID: &'static [u8] = RSA_SM3_OID;
+}
@@ -0,0 +1,330 @@
+use crate::error::Error;
+use crate::math::bigint::big_uint::BigUint;
+use crate::math::bigint::montgomery_context::MontgomeryContext;
+use crate::util::der::{der_encode_integer, der_encode_sequence, DERReader};
+use super::rsa_constant::{RSA_DEFAULT_PUBLIC_EXPONENT, RSA_MIN_MODULUS_BIT_LENGTH, RSA_MAX_MODULUS_BIT_LENGTH, RSA_PRIME_DISTANCE_BIT_LENGTH};
+use super::rsa_prime::rsa_generate_prime;
+
+#[derive(Clone, Debug)]
+pub struct RSAPublicKey {
+    n: BigUint,
+    e: BigUint,
+    n_context: MontgomeryContext,
+}
+
+impl RSAPublicKey {
+    pub fn new(modulus: &[u8], public_exponent: &[u8]) -> Result<RSAPublicKey, Error> {
+        RSAPublicKey::with_components(BigUint::from_be_bytes(modulus), BigUint::from_be_bytes(public_exponent))
+    }
+
+    fn with_components(n: BigUint, e: BigUint) -> Result<RSAPublicKey, Error> {
+        RSAPublicKey::check_parameter(&n, &e)?;
+        let instance = RSAPublicKey {
+            n_context: MontgomeryContext::new(&n)?,
+            n,
+            e,
+        };
+        Ok(instance)
+    }
+
+    fn check_parameter(n: &BigUint, e: &BigUint) -> Result<(), Error> {
+        if n.bit_length() > RSA_MAX_MODULUS_BIT_LENGTH {
+            return Err(Error::InvalidKeyLength);
+        }
+        if n.is_even() || e.is_even() || e.bit_length() < 2 || e >= n {
+            return Err(Error::InvalidKey);
+        }
+        Ok(())
+    }
+
+    pub fn from_pkcs1_der(der_data: &[u8]) -> Result<RSAPublicKey, Error> {
+        let mut reader = DERReader::new(der_data);
+        let mut sequence = reader.read_sequence()?;
+        let n = sequence.read_integer()?;
+        let e = sequence.read_integer()?;
+        if !sequence.is_empty() || !reader.is_empty() {
+            return Err(Error::InvalidEncoding);
+        }
+        RSAPublicKey::new(n, e)
+    }
+
+    pub fn to_pkcs1_der(&self) -> Vec<u8> {
+        let content = [
+            der_encode_integer(&self.n.to_be_bytes()),
+            der_encode_integer(&self.e.to_be_bytes()),
+        ].concat();
+        der_encode_sequence(&content)
+    }
+
+    pub fn modulus(&self) -> &BigUint {
+        &self.n
+    }
+
+    pub fn public_exponent(&self) -> &BigUint {
+        &self.e
+    }
+
+    pub fn modulus_bit_length(&self) -> usize {
+        self.n.bit_length()
+    }
+
+    pub fn modulus_byte_length(&self) -> usize {
+        self.n.bit_length().div_ceil(8)
+    }
+
+    pub(crate) fn public_operation(&self, m: &BigUint) -> BigUint {
+        self.n_context.mod_pow(m, &self.e)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RSAPrivateKey {
+    public_key: RSAPublicKey,
+    d: BigUint,
+    p: BigUint,
+    q: BigUint,
+    dp: BigUint,
+    dq: BigUint,
+    q_inverse: BigUint,
+    p_context: MontgomeryContext,
+    q_context: MontgomeryContext,
+}
+
+impl RSAPrivateKey {
+    pub fn generate(bit_length: usize) -> Result<RSAPrivateKey, Error> {
+        RSAPrivateKey::generate_with_exponent(bit_length, RSA_DEFAULT_PUBLIC_EXPONENT)
+    }
+
+    pub fn generate_with_exponent(bit_length: usize, public_exponent: u64) -> Result<RSAPrivateKey, Error> {
+        if !(RSA_MIN_MODULUS_BIT_LENGTH..=RSA_MAX_MODULUS_BIT_LENGTH).contains(&bit_length) {
+            return Err(Error::InvalidKeyLength);
+        }
+        if public_exponent < 3 || public_exponent.is_multiple_of(2) {
+            return Err(Error::InvalidParameter);
+        }
+        let e = BigUint::from_u64(public_exponent);
+        let one = BigUint::one();
+        loop {
+            let p = rsa_generate_prime(bit_length.div_ceil(2), &e)?;
+            let q = rsa_generate_prime(bit_length / 2, &e)?;
+            let distance = if p > q { &p - &q } else { &q - &p };
+            if distance.bit_length() <= bit_length / 2 - RSA_PRIME_DISTANCE_BIT_LENGTH {
+                continue;
+            }
+            let p_minus_one = &p - &one;
+            let q_minus_one = &q - &one;
+            let lambda = &(&p_minus_one * &q_minus_one) / &p_minus_one.gcd(&q_minus_one);
+            let d = match e.mod_inverse(&lambda) {
+                Some(d) => d,
+                None => continue,
+            };
+            if d.bit_length() <= bit_length / 2 {
+                continue;
+            }
+            return RSAPrivateKey::with_components(&p * &q, e, d, p, q);
+        }
+    }
+
+    pub fn new(modulus: &[u8], public_exponent: &[u8], private_exponent: &[u8], prime1: &[u8], prime2: &[u8]) -> Result<RSAPrivateKey, Error> {
+        RSAPrivateKey::with_components(
+            BigUint::from_be_bytes(modulus),
+            BigUint::from_be_bytes(public_exponent),
+            BigUint::from_be_bytes(private_exponent),
+            BigUint::from_be_bytes(prime1),
+            BigUint::from_be_bytes(prime2),
+        )
+    }
+
+    fn with_components(n: BigUint, e: BigUint, d: BigUint, p: BigUint, q: BigUint) -> Result<RSAPrivateKey, Error> {
+        let public_key = RSAPublicKey::with_components(n, e)?;
+        let one = BigUint::one();
+        if p.bit_length() < 2 || q.bit_length() < 2 || p == q || &p * &q != public_key.n || d.is_zero() || d >= public_key.n {
+            return Err(Error::InvalidKey);
+        }
+        let p_minus_one = &p - &one;
+        let q_minus_one = &q - &one;
+        let dp = &d % &p_minus_one;
+        let dq = &d % &q_minus_one;
+        if !public_key.e.mod_mul(&dp, &p_minus_one).is_one() || !public_key.e.mod_mul(&dq, &q_minus_one).is_one() {
+            return Err(Error::InvalidKey);
+        }
+        let q_inverse = q.mod_inverse(&p).ok_or(Error::InvalidKey)?;
+        let instance = RSAPrivateKey {
+            p_context: MontgomeryContext::new(&p)?,
+            q_context: MontgomeryContext::new(&q)?,
+            public_key,
+            d,
+            p,
+            q,
+            dp,
+            dq,
+            q_inverse,
+        };
+        Ok(instance)
+    }
+
+    pub fn from_pkcs1_der(der_data: &[u8]) -> Result<RSAPrivateKey, Error> {
+        let mut reader = DERReader::new(der_data);
+        let mut sequence = reader.read_sequence()?;
+        if !sequence.read_integer()?.is_empty() {
+            return Err(Error::InvalidEncoding);
+        }
+        let n = sequence.read_integer()?;
+        let e = sequence.read_integer()?;
+        let d = sequence.read_integer()?;
+        let p = sequence.read_integer()?;
+        let q = sequence.read_integer()?;
+        let dp = sequence.read_integer()?;
+        let dq = sequence.read_integer()?;
+        let q_inverse = sequence.read_integer()?;
+        if !sequence.is_empty() || !reader.is_empty() {
+            return Err(Error::InvalidEncoding);
+        }
+        let private_key = RSAPrivateKey::new(n, e, d, p, q)?;
+        if BigUint::from_be_bytes(dp) != private_key.dp || BigUint::from_be_bytes(dq) != private_key.dq || BigUint::from_be_bytes(q_inverse) != private_key.q_inverse {
+            return Err(Error::InvalidKey);
+        }
+        Ok(private_key)
+    }
+
+    pub fn to_pkcs1_der(&self) -> Vec<u8> {
+        let content = [
+            der_encode_integer(&[]),
+            der_encode_integer(&self.public_key.n.to_be_bytes()),
+            der_encode_integer(&self.public_key.e.to_be_bytes()),
+            der_encode_integer(&self.d.to_be_bytes()),
+            der_encode_integer(&self.p.to_be_bytes()),
+            der_encode_integer(&self.q.to_be_bytes()),
+            der_encode_integer(&self.dp.to_be_bytes()),
+            der_encode_integer(&self.dq.to_be_bytes()),
+            der_encode_integer(&self.q_inverse.to_be_bytes()),
+        ].concat();
+        der_encode_sequence(&content)
+    }
+
+    pub fn public_key(&self) -> &RSAPublicKey {
+        &self.public_key
+    }
+
+    pub fn private_exponent(&self) -> &BigUint {
+        &self.d
+    }
+
+    pub fn prime1(&self) -> &BigUint {
+        &self.p
+    }
+
+    pub fn prime2(&self) -> &BigUint {
+        &self.q
+    }
+
+    pub fn modulus_byte_length(&self) -> usize {
+        self.public_key.modulus_byte_length()
+    }
+
+    pub(crate) fn private_operation(&self, c: &BigUint) -> Result<BigUint, Error> {
+        let n = &self.public_key.n;
+        let blinding = loop {
+            let r = BigUint::random_range(&BigUint::from_u64(2), n)?;
+            if let Some(r_inverse) = r.mod_inverse(n) {
+                break (r, r_inverse);
+            }
+        };
+        let blinded = self.public_key.n_context.mod_mul(c, &self.public_key.public_operation(&blinding.0));
+        let m1 = self.p_context.mod_pow_constant_time(&blinded, &self.dp);
+        let m2 = self.q_context.mod_pow_constant_time(&blinded, &self.dq);
+        let h = self.p_context.mod_mul(&self.q_inverse, &self.p_context.mod_sub(&m1, &m2));
+        let m = self.public_key.n_context.mod_mul(&(&m2 + &(&h * &self.q)), &blinding.1);
+        if &self.public_key.public_operation(&m) != c {
+            return Err(Error::InvalidKey);
+        }
+        Ok(m)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::error::Error;
+    use crate::util::hex::{hex_decode, hex_encode};
+    use super::{RSAPrivateKey, RSAPublicKey};
+
+    pub(crate) const PRIVATE_KEY_DER: &str = concat!(
+        "3082025c02010002818100cff09d2ba021a9d5715ff7c4e4cecb48b88473af24",
+        "421061443ca940d6ba60dca7c0eb9142e3267bdcb7c89aaaa63723fcfa9e11cc",
+        "a34a2aa7b35c47098a1ab9587695403e362fa59e01416ceda92d4b6c4f7b59aa",
+        "15c100cb487be216260aaca236a0445ea5b2e75e0ba8084c9feb61e1a0005730",
+        "ca5924478f64c23d1d896702030100010281801690bdfda5fb013b307a81a0d4",
+        "53bcabbf9a60137993d1c33c6c78d1987bd15720bd46d314b721cdff3dd27f36",
+        "55a3563c75d601887b8fe8e11a0dc187bf7cf6be061ba5da4fe5b573cfd01ec1",
+        "ec0263cdabec07e0d1703b5c621dd904e2b578051944beeeddf1f9dfc7c73ae1",
+        "63e8177fe556191fb8443fc59cb19de4c3b941024100f30448cda43149f31a4b",
+        "bf464a00585d30a86386d5031a06ec23eb603c680b4464f926976d4181d9d53c",
+        "4cbd3b045b25d07350ad0760419564964a7f39d80b3b024100db0c964fd4d843",
+        "17d2b5a7a3411382de1ad5ae9475222a1d69be8b7fbc860c4470738bcf5e5aa8",
+        "907eabf90076ba08c4d9b61e02a5305b3a7237e81019f65fc5024046d3ffd817",
+        "bbd9dec5d23fec5f931c2e05097f0084430429f25741b9e9c1354f78d27921f7",
+        "50f964a0d693532fc9443b90369bd3e26b7691f1203094462aa693024100c685",
+        "32d8e8fa925bcde04a2c9fb6d21cfecef7e89dfbb7956e977d0fe848c867624e",
+        "a5bfeb3058a293dac1d194c86758184380113faa8293869e7a57d55fbf4d0240",
+        "51c30eb8c56fa0b6b271af83c8f5f3a311e36bce383a2353b9df01eac519496d",
+        "268ece6305a5406963c2ecbe4a4a62d52a69000f73d40a28385dff3025d3707c",
+    );
+    const PUBLIC_KEY_DER: &str = concat!(
+        "30818902818100cff09d2ba021a9d5715ff7c4e4cecb48b88473af2442106144",
+        "3ca940d6ba60dca7c0eb9142e3267bdcb7c89aaaa63723fcfa9e11cca34a2aa7",
+        "b35c47098a1ab9587695403e362fa59e01416ceda92d4b6c4f7b59aa15c100cb",
+        "487be216260aaca236a0445ea5b2e75e0ba8084c9feb61e1a0005730ca592447",
+        "8f64c23d1d89670203010001",
+    );
+
+    pub(crate) fn private_key() -> RSAPrivateKey {
+        RSAPrivateKey::from_pkcs1_der(&hex_decode(PRIVATE_KEY_DER).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn pkcs1_der_round_trip() {
+        let private_key = private_key();
+        assert_eq!(hex_encode(&private_key.to_pkcs1_der()), PRIVATE_KEY_DER);
+        assert_eq!(hex_encode(&private_key.public_key().to_pkcs1_der()), PUBLIC_KEY_DER);
+        let public_key = RSAPublicKey::from_pkcs1_der(&hex_decode(PUBLIC_KEY_DER).unwrap()).unwrap();
+        assert_eq!(hex_encode(&public_key.to_pkcs1_der()), PUBLIC_KEY_DER);
+        assert_eq!(public_key.modulus(), private_key.public_key().modulus());
+        assert_eq!(public_key.modulus_bit_length(), 1024);
+        assert_eq!(public_key.public_exponent().limbs(), [65537]);
+    }
+
+    #[test]
+    fn malformed_der_is_rejected() {
+        let der_data = hex_decode(PRIVATE_KEY_DER).unwrap();
+        assert!(matches!(RSAPrivateKey::from_pkcs1_der(&der_data[..(der_data.len() - 1)]), Err(Error::InvalidEncoding)));
+        let mut trailing_data = der_data.clone();
+        trailing_data.push(0x00);
+        assert!(matches!(RSAPrivateKey::from_pkcs1_der(&trailing_data), Err(Error::InvalidEncoding)));
+        let mut tampered_data = der_data;
+        let last_index = tampered_data.len() - 1;
+        tampered_data[last_index] ^= 0x01;
+        assert!(matches!(RSAPrivateKey::from_pkcs1_der(&tampered_data), Err(Error::InvalidKey)));
+        assert!(RSAPublicKey::from_pkcs1_der(&hex_decode(PRIVATE_KEY_DER).unwrap()).is_err());
+    }
+
+    #[test]
+    fn inconsistent_components_are_rejected() {
+        let private_key = private_key();
+        let n = private_key.public_key().modulus().to_be_bytes();
+        let e = private_key.public_key().public_exponent().to_be_bytes();
+        let d = private_key.private_exponent().to_be_bytes();
+        let p = private_key.prime1().to_be_bytes();
+        let q = private_key.prime2().to_be_bytes();
+        assert!(RSAPrivateKey::new(&n, &e, &d, &p, &q).is_ok());
+        assert!(matches!(RSAPrivateKey::new(&n, &e, &d, &p, &p), Err(Error::InvalidKey)));
+        assert!(matches!(RSAPrivateKey::new(&n, &[0x03], &d, &p, &q), Err(Error::InvalidKey)));
+        assert!(matches!(RSAPublicKey::new(&n, &[0x01, 0x00]), Err(Error::InvalidKey)));
+        assert!(matches!(RSAPublicKey::new(&e, &n), Err(Error::InvalidKey)));
+    }
+
+    #[test]
+    fn invalid_generation_parameters_are_rejected() {
+        assert!(matches!(RSAPrivateKey::generate(512), Err(Error::InvalidKeyLength)));
+        assert!(matches!(RSAPrivateKey::generate_with_exponent(1024, 4), Err(Error::InvalidParameter)));
+    }
+}
@@ -0,0 +1,23 @@
+use crate::algorithm::digest::digest_trait::Digest;
+
+pub fn rsa_mgf1<D: Digest>(seed: &[u8], mask_byte_length: usize) -> Vec<u8> {
+    let mut mask = Vec::with_capacity(mask_byte_length.div_ceil(D::DIGEST_BYTE_LENGTH) * D::DIGEST_BYTE_LENGTH);
+    let mut counter: u32 = 0;
+    while mask.len() < mask_byte_length {
+        let mut digest = D::new();
+        digest.push_data(seed);
+        digest.push_data(&counter.to_be_bytes());
+        digest.compute_digest();
+        mask.extend_from_slice(digest.get_digest_bytes().as_ref());
+        counter += 1;
+    }
+    mask.truncate(mask_byte_length);
+    mask
+}
+
+pub(crate) fn rsa_mgf1_mask<D: Digest>(data: &mut [u8], seed: &[u8]) {
+    let mask = rsa_mgf1::<D>(seed, data.len());
+    for i in 0..data.len() {
+        data[i] ^= mask[i];
+    }
+}
@@ -0,0 +1,109 @@
+use crate::error::Error;
+use crate::algorithm::digest::digest_trait::Digest;
+use crate::algorithm::digest::digest_utils::DigestUtils;
+use crate::util::constant_time::constant_time_eq;
+use crate::util::random::fill_random_bytes;
+use super::rsa_constant::RSA_OAEP_SEPARATOR;
+use super::rsa_key::{RSAPrivateKey, RSAPublicKey};
+use super::rsa_mgf1::rsa_mgf1_mask;
+use super::rsa_raw::{rsa_raw_encrypt_data, rsa_raw_decrypt_data};
+
+pub fn rsa_oaep_encrypt_data<D: Digest>(origin_data: &[u8], public_key: &RSAPublicKey, label: &[u8]) -> Result<Vec<u8>, Error> {
+    let k = public_key.modulus_byte_length();
+    let h = D::DIGEST_BYTE_LENGTH;
+    if k < 2 * h + 2 {
+        return Err(Error::InvalidKeyLength);
+    }
+    if origin_data.len() > k - 2 * h - 2 {
+        return Err(Error::InvalidDataLength);
+    }
+    let mut encoded_data = vec![0; k];
+    let (seed, data_block) = encoded_data[1..].split_at_mut(h);
+    data_block[..h].copy_from_slice(DigestUtils::data_digest::<D>(label).as_ref());
+    data_block[k - h - 2 - origin_data.len()] = RSA_OAEP_SEPARATOR;
+    data_block[(k - h - 1 - origin_data.len())..].copy_from_slice(origin_data);
+    fill_random_bytes(seed)?;
+    rsa_mgf1_mask::<D>(data_block, seed);
+    rsa_mgf1_mask::<D>(seed, data_block);
+    rsa_raw_encrypt_data(&encoded_data, public_key)
+}
+
+#[allow(clippy::needless_range_loop)]
+pub fn rsa_oaep_decrypt_data<D: Digest>(enciphered_data: &[u8], private_key: &RSAPrivateKey, label: &[u8]) -> Result<Vec<u8>, Error> {
+    let k = private_key.modulus_byte_length();
+    let h = D::DIGEST_BYTE_LENGTH;
+    if k < 2 * h + 2 {
+        return Err(Error::InvalidKeyLength);
+    }
+    let mut encoded_data = rsa_raw_decrypt_data(enciphered_data, private_key)?;
+    let first_byte = encoded_data[0];
+    let (seed, data_block) = encoded_data[1..].split_at_mut(h);
+    rsa_mgf1_mask::<D>(seed, data_block);
+    rsa_mgf1_mask::<D>(data_block, seed);
+    let label_digest_matches = constant_time_eq(&data_block[..h], DigestUtils::data_digest::<D>(label).as_ref());
+    let mut invalid = first_byte as usize | (!label_digest_matches) as usize;
+    let mut looking_for_separator: usize = 1;
+    let mut separator_index: usize = 0;
+    for i in h..data_block.len() {
+        let is_zero = ((data_block[i] as usize).wrapping_sub(1) >> (usize::BITS - 1)) & 1;
+        let is_separator = (((data_block[i] ^ RSA_OAEP_SEPARATOR) as usize).wrapping_sub(1) >> (usize::BITS - 1)) & 1;
+        separator_index |= i & (is_separator & looking_for_separator).wrapping_neg();
+        invalid |= looking_for_separator & !is_zero & !is_separator & 1;
+        looking_for_separator &= !is_separator;
+    }
+    invalid |= looking_for_separator;
+    if std::hint::black_box(invalid) != 0 {
+        return Err(Error::InvalidPadding);
+    }
+    Ok(data_block[(separator_index + 1)..].to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithm::digest::sha2::sha2_256_digest::SHA2_256Digest;
+    use crate::error::Error;
+    use crate::util::hex::hex_decode;
+    use super::super::rsa_key::tests::private_key;
+    use super::{rsa_oaep_decrypt_data, rsa_oaep_encrypt_data};
+
+    const MESSAGE: &[u8] = b"abacus rsa test message";
+    const LABEL: &[u8] = b"label";
+    const OAEP_CIPHERTEXT: &str = concat!(
+        "1022b3ced519fcce704291d8e1752260d05de407f0d48794ebc3022e19a69a15",
+        "040e73f0a91f9c987e65e4b4e8cdd4aa66ae4cd268c1bf4e70fafdcfe2b1c37e",
+        "6f2f53ec10e2066bb188e97cceb5abee9700d85eabf845a5d412411623a25bc8",
+        "0e0277076669a675c52b1d7b32afa78ca8bee97ce5ee8c9e7d7f682fa4d9dc2f",
+    );
+
+    #[test]
+    fn round_trip() {
+        let private_key = private_key();
+        let enciphered_data = rsa_oaep_encrypt_data::<SHA2_256Digest>(MESSAGE, private_key.public_key(), LABEL).unwrap();
+        assert_eq!(rsa_oaep_decrypt_data::<SHA2_256Digest>(&enciphered_data, &private_key, LABEL).unwrap(), MESSAGE);
+        let enciphered_data = rsa_oaep_encrypt_data::<SHA2_256Digest>(&[], private_key.public_key(), &[]).unwrap();
+        assert!(rsa_oaep_decrypt_data::<SHA2_256Digest>(&enciphered_data, &private_key, &[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn external_ciphertext() {
+        let origin_data = rsa_oaep_decrypt_data::<SHA2_256Digest>(&hex_decode(OAEP_CIPHERTEXT).unwrap(), &private_key(), LABEL).unwrap();
+        assert_eq!(origin_data, MESSAGE);
+    }
+
+    #[test]
+    fn wrong_label_or_tampered_ciphertext_is_rejected() {
+        let private_key = private_key();
+        let mut enciphered_data = hex_decode(OAEP_CIPHERTEXT).unwrap();
+        assert!(matches!(rsa_oaep_decrypt_data::<SHA2_256Digest>(&enciphered_data, &private_key, b"other"), Err(Error::InvalidPadding)));
+        enciphered_data[127] ^= 0x01;
+        assert!(matches!(rsa_oaep_decrypt_data::<SHA2_256Digest>(&enciphered_data, &private_key, LABEL), Err(Error::InvalidPadding)));
+        assert!(matches!(rsa_oaep_decrypt_data::<SHA2_256Digest>(&enciphered_data[1..], &private_key, LABEL), Err(Error::InvalidDataLength)));
+    }
+
+    #[test]
+    fn oversized_data_is_rejected() {
+        let private_key = private_key();
+        assert!(matches!(rsa_oaep_encrypt_data::<SHA2_256Digest>(&[0; 63], private_key.public_key(), &[]), Err(Error::InvalidDataLength)));
+        assert!(rsa_oaep_encrypt_data::<SHA2_256Digest>(&[0; 62], private_key.public_key(), &[]).is_ok());
+    }
+}
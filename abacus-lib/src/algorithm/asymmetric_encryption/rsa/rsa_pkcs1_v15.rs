@@ -0,0 +1,172 @@
+use crate::error::Error;
+use crate::algorithm::digest::digest_utils::DigestUtils;
+use crate::util::constant_time::constant_time_eq;
+use crate::util::random::fill_random_bytes;
+use super::rsa_constant::{RSA_PKCS1_V15_MIN_PADDING_BYTE_LENGTH, RSA_PKCS1_V15_ENCRYPTION_BLOCK_TYPE, RSA_PKCS1_V15_SIGNATURE_BLOCK_TYPE};
+use super::rsa_digest_info::RSADigestInfo;
+use super::rsa_key::{RSAPrivateKey, RSAPublicKey};
+use super::rsa_raw::{rsa_raw_encrypt_data, rsa_raw_decrypt_data};
+
+fn fill_nonzero_random_bytes(buffer: &mut [u8]) -> Result<(), Error> {
+    fill_random_bytes(buffer)?;
+    let mut replacement = [0u8; 1];
+    for byte in buffer.iter_mut() {
+        while *byte == 0 {
+            fill_random_bytes(&mut replacement)?;
+            *byte = replacement[0];
+        }
+    }
+    Ok(())
+}
+
+pub fn rsa_pkcs1_v15_encrypt_data(origin_data: &[u8], public_key: &RSAPublicKey) -> Result<Vec<u8>, Error> {
+    let k = public_key.modulus_byte_length();
+    if origin_data.len() + RSA_PKCS1_V15_MIN_PADDING_BYTE_LENGTH > k {
+        return Err(Error::InvalidDataLength);
+    }
+    let mut encoded_data = vec![0; k];
+    encoded_data[1] = RSA_PKCS1_V15_ENCRYPTION_BLOCK_TYPE;
+    fill_nonzero_random_bytes(&mut encoded_data[2..(k - origin_data.len() - 1)])?;
+    encoded_data[(k - origin_data.len())..].copy_from_slice(origin_data);
+    rsa_raw_encrypt_data(&encoded_data, public_key)
+}
+
+#[allow(clippy::needless_range_loop)]
+pub fn rsa_pkcs1_v15_decrypt_data(enciphered_data: &[u8], private_key: &RSAPrivateKey) -> Result<Vec<u8>, Error> {
+    let k = private_key.modulus_byte_length();
+    if k < RSA_PKCS1_V15_MIN_PADDING_BYTE_LENGTH {
+        return Err(Error::InvalidKeyLength);
+    }
+    let encoded_data = rsa_raw_decrypt_data(enciphered_data, private_key)?;
+    let mut invalid = encoded_data[0] as usize | (encoded_data[1] ^ RSA_PKCS1_V15_ENCRYPTION_BLOCK_TYPE) as usize;
+    let mut looking_for_separator: usize = 1;
+    let mut separator_index: usize = 0;
+    for i in 2..k {
+        let is_zero = ((encoded_data[i] as usize).wrapping_sub(1) >> (usize::BITS - 1)) & looking_for_separator;
+        separator_index |= i & is_zero.wrapping_neg();
+        looking_for_separator &= !is_zero;
+    }
+    invalid |= looking_for_separator;
+    invalid |= (separator_index.wrapping_sub(RSA_PKCS1_V15_MIN_PADDING_BYTE_LENGTH - 1) >> (usize::BITS - 1)) & 1;
+    if std::hint::black_box(invalid) != 0 {
+        return Err(Error::InvalidPadding);
+    }
+    Ok(encoded_data[(separator_index + 1)..].to_vec())
+}
+
+fn encode_signature_data<D: RSADigestInfo>(digest: &[u8], k: usize) -> Result<Vec<u8>, Error> {
+    if digest.len() != D::DIGEST_BYTE_LENGTH {
+        return Err(Error::InvalidDataLength);
+    }
+    let digest_info = D::encode_digest_info(digest);
+    if digest_info.len() + RSA_PKCS1_V15_MIN_PADDING_BYTE_LENGTH > k {
+        return Err(Error::InvalidKeyLength);
+    }
+    let mut encoded_data = vec![0xFF; k];
+    encoded_data[0] = 0x00;
+    encoded_data[1] = RSA_PKCS1_V15_SIGNATURE_BLOCK_TYPE;
+    encoded_data[k - digest_info.len() - 1] = 0x00;
+    encoded_data[(k - digest_info.len())..].copy_from_slice(&digest_info);
+    Ok(encoded_data)
+}
+
+pub fn rsa_pkcs1_v15_sign_digest<D: RSADigestInfo>(digest: &[u8], private_key: &RSAPrivateKey) -> Result<Vec<u8>, Error> {
+    let encoded_data = encode_signature_data::<D>(digest, private_key.modulus_byte_length())?;
+    rsa_raw_decrypt_data(&encoded_data, private_key)
+}
+
+pub fn rsa_pkcs1_v15_verify_digest<D: RSADigestInfo>(digest: &[u8], public_key: &RSAPublicKey, signature: &[u8]) -> bool {
+    let encoded_data = match encode_signature_data::<D>(digest, public_key.modulus_byte_length()) {
+        Ok(encoded_data) => encoded_data,
+        Err(_) => return false,
+    };
+    match rsa_raw_encrypt_data(signature, public_key) {
+        Ok(recovered_data) => constant_time_eq(&recovered_data, &encoded_data),
+        Err(_) => false,
+    }
+}
+
+pub fn rsa_pkcs1_v15_sign_data<D: RSADigestInfo>(data: &[u8], private_key: &RSAPrivateKey) -> Result<Vec<u8>, Error> {
+    rsa_pkcs1_v15_sign_digest::<D>(DigestUtils::data_digest::<D>(data).as_ref(), private_key)
+}
+
+pub fn rsa_pkcs1_v15_verify_data<D: RSADigestInfo>(data: &[u8], public_key: &RSAPublicKey, signature: &[u8]) -> bool {
+    rsa_pkcs1_v15_verify_digest::<D>(DigestUtils::data_digest::<D>(data).as_ref(), public_key, signature)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithm::digest::sha2::sha2_256_digest::SHA2_256Digest;
+    use crate::algorithm::digest::sha2::sha2_384_digest::SHA2_384Digest;
+    use crate::error::Error;
+    use crate::util::hex::{hex_decode, hex_encode};
+    use super::super::rsa_key::tests::private_key;
+    use super::super::rsa_raw::rsa_raw_encrypt_data;
+    use super::{rsa_pkcs1_v15_decrypt_data, rsa_pkcs1_v15_encrypt_data, rsa_pkcs1_v15_sign_data, rsa_pkcs1_v15_verify_data};
+
+    const MESSAGE: &[u8] = b"abacus rsa test message";
+    const PKCS1_V15_SIGNATURE: &str = concat!(
+        "bf16feec1fd470450aa4062649bde7b744433c370ff87fcf9eb0a53a21005551",
+        "7afd16a68f5ce973b0721f12b5b9ad0bd846ea6f08c9bd8439f9527db2aa6977",
+        "d8abe1e4a2756f87a498e03f219790f560fa0aa0b4f7da099bd9c5a6e36f27a3",
+        "de2af7b2a9d4132f2d4eaf492f3c6ca8ba0b39e1d3e8b6d16b5a0ffe8a501d85",
+    );
+    const PKCS1_V15_CIPHERTEXT: &str = concat!(
+        "cca5e9664ac03419de26010f777c0197d0b5ac4e0d8925e515919a9757ebb547",
+        "ca4a54921aa2164b39c07956fa8e5893344b60eb195482aa6aaf4658a47a4fe9",
+        "e6e8bd22c0041887554b839279ec97ce01b9f3dc92f7540dc0a261a55d64a84c",
+        "1eadb7b332d9f0d5bd52b603ca85926dfce152dca7c2bfab7ea0b022d42dad55",
+    );
+
+    #[test]
+    fn signature_known_answer() {
+        let private_key = private_key();
+        let signature = rsa_pkcs1_v15_sign_data::<SHA2_256Digest>(MESSAGE, &private_key).unwrap();
+        assert_eq!(hex_encode(&signature), PKCS1_V15_SIGNATURE);
+        assert!(rsa_pkcs1_v15_verify_data::<SHA2_256Digest>(MESSAGE, private_key.public_key(), &signature));
+    }
+
+    #[test]
+    fn tampered_signature_or_wrong_hash_is_rejected() {
+        let private_key = private_key();
+        let mut signature = hex_decode(PKCS1_V15_SIGNATURE).unwrap();
+        assert!(!rsa_pkcs1_v15_verify_data::<SHA2_384Digest>(MESSAGE, private_key.public_key(), &signature));
+        assert!(!rsa_pkcs1_v15_verify_data::<SHA2_256Digest>(&MESSAGE[1..], private_key.public_key(), &signature));
+        signature[127] ^= 0x01;
+        assert!(!rsa_pkcs1_v15_verify_data::<SHA2_256Digest>(MESSAGE, private_key.public_key(), &signature));
+        assert!(!rsa_pkcs1_v15_verify_data::<SHA2_256Digest>(MESSAGE, private_key.public_key(), &signature[1..]));
+    }
+
+    #[test]
+    fn encryption_round_trip() {
+        let private_key = private_key();
+        let enciphered_data = rsa_pkcs1_v15_encrypt_data(MESSAGE, private_key.public_key()).unwrap();
+        assert_eq!(rsa_pkcs1_v15_decrypt_data(&enciphered_data, &private_key).unwrap(), MESSAGE);
+        assert_eq!(rsa_pkcs1_v15_decrypt_data(&hex_decode(PKCS1_V15_CIPHERTEXT).unwrap(), &private_key).unwrap(), MESSAGE);
+        assert!(matches!(rsa_pkcs1_v15_encrypt_data(&[0; 118], private_key.public_key()), Err(Error::InvalidDataLength)));
+        assert!(rsa_pkcs1_v15_encrypt_data(&[0; 117], private_key.public_key()).is_ok());
+    }
+
+    #[test]
+    fn malformed_padding_is_rejected() {
+        let private_key = private_key();
+        let mut encoded_data = [0x5a; 128];
+        encoded_data[0] = 0x00;
+        encoded_data[1] = 0x02;
+        let enciphered_data = rsa_raw_encrypt_data(&encoded_data, private_key.public_key()).unwrap();
+        assert!(matches!(rsa_pkcs1_v15_decrypt_data(&enciphered_data, &private_key), Err(Error::InvalidPadding)));
+        encoded_data[1] = 0x01;
+        encoded_data[100] = 0x00;
+        let enciphered_data = rsa_raw_encrypt_data(&encoded_data, private_key.public_key()).unwrap();
+        assert!(matches!(rsa_pkcs1_v15_decrypt_data(&enciphered_data, &private_key), Err(Error::InvalidPadding)));
+        encoded_data[1] = 0x02;
+        encoded_data[100] = 0x5a;
+        encoded_data[9] = 0x00;
+        let enciphered_data = rsa_raw_encrypt_data(&encoded_data, private_key.public_key()).unwrap();
+        assert!(matches!(rsa_pkcs1_v15_decrypt_data(&enciphered_data, &private_key), Err(Error::InvalidPadding)));
+        encoded_data[9] = 0x5a;
+        encoded_data[10] = 0x00;
+        let enciphered_data = rsa_raw_encrypt_data(&encoded_data, private_key.public_key()).unwrap();
+        assert_eq!(rsa_pkcs1_v15_decrypt_data(&enciphered_data, &private_key).unwrap(), [0x5a; 117]);
+    }
+}
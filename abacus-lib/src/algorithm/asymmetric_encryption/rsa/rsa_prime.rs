@@ -0,0 +1,97 @@
+use crate::error::Error;
+use crate::math::bigint::big_uint::BigUint;
+use crate::math::bigint::montgomery_context::MontgomeryContext;
+use super::rsa_constant::{RSA_MILLER_RABIN_ROUNDS, RSA_SMALL_PRIMES};
+
+fn has_small_factor(candidate: &BigUint) -> bool {
+    RSA_SMALL_PRIMES.iter().any(|prime| (candidate % &BigUint::from_u64(*prime)).is_zero())
+}
+
+fn miller_rabin_test(candidate: &BigUint, rounds: usize) -> Result<bool, Error> {
+    let context = MontgomeryContext::new(candidate)?;
+    let one = BigUint::one();
+    let candidate_minus_one = candidate - &one;
+    let s = candidate_minus_one.trailing_zeros();
+    let d = &candidate_minus_one >> s;
+    let lower = BigUint::from_u64(2);
+    for _ in 0..rounds {
+        let a = BigUint::random_range(&lower, &candidate_minus_one)?;
+        let mut x = context.mod_pow_constant_time(&a, &d);
+        if x == one || x == candidate_minus_one {
+            continue;
+        }
+        let mut is_witness = true;
+        for _ in 1..s {
+            x = context.mod_mul(&x, &x);
+            if x == candidate_minus_one {
+                is_witness = false;
+                break;
+            }
+        }
+        if is_witness {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+pub fn rsa_is_probable_prime(candidate: &BigUint) -> Result<bool, Error> {
+    if candidate.bit_length() <= 8 {
+        let value = candidate.limbs().first().copied().unwrap_or(0);
+        return Ok(value == 2 || RSA_SMALL_PRIMES.contains(&value));
+    }
+    if candidate.is_even() || has_small_factor(candidate) {
+        return Ok(false);
+    }
+    miller_rabin_test(candidate, RSA_MILLER_RABIN_ROUNDS)
+}
+
+pub fn rsa_generate_prime(bit_length: usize, public_exponent: &BigUint) -> Result<BigUint, Error> {
+    if bit_length < 16 {
+        return Err(Error::InvalidParameter);
+    }
+    let one = BigUint::one();
+    loop {
+        let mut candidate = BigUint::random_bits(bit_length)?;
+        candidate.set_bit(bit_length - 1);
+        candidate.set_bit(bit_length - 2);
+        candidate.set_bit(0);
+        if has_small_factor(&candidate) || !(&candidate - &one).gcd(public_exponent).is_one() {
+            continue;
+        }
+        if miller_rabin_test(&candidate, RSA_MILLER_RABIN_ROUNDS)? {
+            return Ok(candidate);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::math::bigint::big_uint::BigUint;
+    use super::{rsa_generate_prime, rsa_is_probable_prime};
+
+    #[test]
+    fn known_primes_and_composites() {
+        for prime in [2u64, 3, 251, 257, 65537, 1_000_000_007, 0xffff_ffff_ffff_ffc5] {
+            assert!(rsa_is_probable_prime(&BigUint::from_u64(prime)).unwrap(), "{}", prime);
+        }
+        for composite in [0u64, 1, 4, 255, 561, 41041, 1_000_000_007 * 3, 4_294_967_291 * 4_294_967_279] {
+            assert!(!rsa_is_probable_prime(&BigUint::from_u64(composite)).unwrap(), "{}", composite);
+        }
+        let mersenne_127 = &(&BigUint::one() << 127) - &BigUint::one();
+        assert!(rsa_is_probable_prime(&mersenne_127).unwrap());
+        assert!(!rsa_is_probable_prime(&(&mersenne_127 * &BigUint::from_u64(0xffff_ffff_ffff_ffc5))).unwrap());
+    }
+
+    #[test]
+    fn generated_prime_shape() {
+        let e = BigUint::from_u64(65537);
+        let prime = rsa_generate_prime(128, &e).unwrap();
+        assert_eq!(prime.bit_length(), 128);
+        assert!(prime.bit(126));
+        assert!(rsa_is_probable_prime(&prime).unwrap());
+        assert!((&prime - &BigUint::one()).gcd(&e).is_one());
+        assert!(matches!(rsa_generate_prime(15, &e), Err(Error::InvalidParameter)));
+    }
+}
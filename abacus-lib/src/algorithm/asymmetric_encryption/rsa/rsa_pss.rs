@@ -0,0 +1,126 @@
+use crate::error::Error;
+use crate::algorithm::digest::digest_trait::Digest;
+use crate::algorithm::digest::digest_utils::DigestUtils;
+use crate::util::constant_time::constant_time_eq;
+use crate::util::random::fill_random_bytes;
+use super::rsa_constant::{RSA_PSS_SEPARATOR, RSA_PSS_TRAILER, RSA_PSS_PREFIX_BYTE_LENGTH};
+use super::rsa_key::{RSAPrivateKey, RSAPublicKey};
+use super::rsa_mgf1::rsa_mgf1_mask;
+use super::rsa_raw::{rsa_raw_encrypt_data, rsa_raw_decrypt_data};
+
+fn compute_salted_digest<D: Digest>(digest: &[u8], salt: &[u8]) -> D::DigestBytes {
+    let mut salted_digest = D::new();
+    salted_digest.push_data(&[0; RSA_PSS_PREFIX_BYTE_LENGTH]);
+    salted_digest.push_data(digest);
+    salted_digest.push_data(salt);
+    salted_digest.compute_digest();
+    salted_digest.get_digest_bytes()
+}
+
+pub fn rsa_pss_sign_digest<D: Digest>(digest: &[u8], private_key: &RSAPrivateKey, salt_byte_length: usize) -> Result<Vec<u8>, Error> {
+    let h = D::DIGEST_BYTE_LENGTH;
+    if digest.len() != h {
+        return Err(Error::InvalidDataLength);
+    }
+    let k = private_key.modulus_byte_length();
+    let encoded_bit_length = private_key.public_key().modulus_bit_length() - 1;
+    let encoded_byte_length = encoded_bit_length.div_ceil(8);
+    if encoded_byte_length < h + salt_byte_length + 2 {
+        return Err(Error::InvalidParameter);
+    }
+    let mut salt = vec![0; salt_byte_length];
+    fill_random_bytes(&mut salt)?;
+    let salted_digest = compute_salted_digest::<D>(digest, &salt);
+    let mut encoded_data = vec![0; k];
+    let (data_block, remains_data) = encoded_data[(k - encoded_byte_length)..].split_at_mut(encoded_byte_length - h - 1);
+    data_block[encoded_byte_length - h - salt_byte_length - 2] = RSA_PSS_SEPARATOR;
+    data_block[(encoded_byte_length - h - salt_byte_length - 1)..].copy_from_slice(&salt);
+    rsa_mgf1_mask::<D>(data_block, salted_digest.as_ref());
+    data_block[0] &= 0xFF >> (8 * encoded_byte_length - encoded_bit_length);
+    remains_data[..h].copy_from_slice(salted_digest.as_ref());
+    remains_data[h] = RSA_PSS_TRAILER;
+    rsa_raw_decrypt_data(&encoded_data, private_key)
+}
+
+pub fn rsa_pss_verify_digest<D: Digest>(digest: &[u8], public_key: &RSAPublicKey, signature: &[u8], salt_byte_length: usize) -> bool {
+    let h = D::DIGEST_BYTE_LENGTH;
+    let k = public_key.modulus_byte_length();
+    let encoded_bit_length = public_key.modulus_bit_length() - 1;
+    let encoded_byte_length = encoded_bit_length.div_ceil(8);
+    if digest.len() != h || encoded_byte_length < h + salt_byte_length + 2 {
+        return false;
+    }
+    let mut encoded_data = match rsa_raw_encrypt_data(signature, public_key) {
+        Ok(encoded_data) => encoded_data,
+        Err(_) => return false,
+    };
+    if encoded_data[..(k - encoded_byte_length)].iter().any(|b| *b != 0) || encoded_data[k - 1] != RSA_PSS_TRAILER {
+        return false;
+    }
+    let top_bit_mask = 0xFF >> (8 * encoded_byte_length - encoded_bit_length);
+    let (data_block, remains_data) = encoded_data[(k - encoded_byte_length)..].split_at_mut(encoded_byte_length - h - 1);
+    let salted_digest = &remains_data[..h];
+    if data_block[0] & !top_bit_mask != 0 {
+        return false;
+    }
+    rsa_mgf1_mask::<D>(data_block, salted_digest);
+    data_block[0] &= top_bit_mask;
+    let separator_index = encoded_byte_length - h - salt_byte_length - 2;
+    if data_block[..separator_index].iter().any(|b| *b != 0) || data_block[separator_index] != RSA_PSS_SEPARATOR {
+        return false;
+    }
+    let salt = &data_block[(separator_index + 1)..];
+    constant_time_eq(compute_salted_digest::<D>(digest, salt).as_ref(), salted_digest)
+}
+
+pub fn rsa_pss_sign_data<D: Digest>(data: &[u8], private_key: &RSAPrivateKey, salt_byte_length: usize) -> Result<Vec<u8>, Error> {
+    rsa_pss_sign_digest::<D>(DigestUtils::data_digest::<D>(data).as_ref(), private_key, salt_byte_length)
+}
+
+pub fn rsa_pss_verify_data<D: Digest>(data: &[u8], public_key: &RSAPublicKey, signature: &[u8], salt_byte_length: usize) -> bool {
+    rsa_pss_verify_digest::<D>(DigestUtils::data_digest::<D>(data).as_ref(), public_key, signature, salt_byte_length)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithm::digest::sha2::sha2_256_digest::SHA2_256Digest;
+    use crate::algorithm::digest::sha2::sha2_384_digest::SHA2_384Digest;
+    use crate::error::Error;
+    use crate::util::hex::hex_decode;
+    use super::super::rsa_key::tests::private_key;
+    use super::{rsa_pss_sign_data, rsa_pss_verify_data};
+
+    const MESSAGE: &[u8] = b"abacus rsa test message";
+    const PSS_SIGNATURE: &str = concat!(
+        "a2d002a991cf1214e10c75d27c2b67ec8ce244fceffe5e8b17c9e09b18805107",
+        "69004efdec3ab055506a6abbdc41601f814e1f314c3c0055704b264ba10bbf52",
+        "f8b0311ef3d549c0b447a3db4a81e7f9dc926521f3eb052f0c2df6ff83c840f0",
+        "89c48e5968821a633c939c06ee490ed88821087a5bf3f921e0ef79861b09a536",
+    );
+
+    #[test]
+    fn round_trip() {
+        let private_key = private_key();
+        for salt_byte_length in [0, 20, 32, 94] {
+            let signature = rsa_pss_sign_data::<SHA2_256Digest>(MESSAGE, &private_key, salt_byte_length).unwrap();
+            assert!(rsa_pss_verify_data::<SHA2_256Digest>(MESSAGE, private_key.public_key(), &signature, salt_byte_length));
+        }
+        assert!(matches!(rsa_pss_sign_data::<SHA2_256Digest>(MESSAGE, &private_key, 95), Err(Error::InvalidParameter)));
+    }
+
+    #[test]
+    fn external_signature() {
+        assert!(rsa_pss_verify_data::<SHA2_256Digest>(MESSAGE, private_key().public_key(), &hex_decode(PSS_SIGNATURE).unwrap(), 32));
+    }
+
+    #[test]
+    fn tampered_signature_or_wrong_hash_is_rejected() {
+        let private_key = private_key();
+        let mut signature = hex_decode(PSS_SIGNATURE).unwrap();
+        assert!(!rsa_pss_verify_data::<SHA2_384Digest>(MESSAGE, private_key.public_key(), &signature, 32));
+        assert!(!rsa_pss_verify_data::<SHA2_256Digest>(MESSAGE, private_key.public_key(), &signature, 20));
+        assert!(!rsa_pss_verify_data::<SHA2_256Digest>(&MESSAGE[1..], private_key.public_key(), &signature, 32));
+        signature[64] ^= 0x01;
+        assert!(!rsa_pss_verify_data::<SHA2_256Digest>(MESSAGE, private_key.public_key(), &signature, 32));
+    }
+}
@@ -0,0 +1,58 @@
+use crate::error::Error;
+use crate::math::bigint::big_uint::BigUint;
+use super::rsa_key::{RSAPrivateKey, RSAPublicKey};
+
+pub(crate) fn rsa_data_to_number(data: &[u8], public_key: &RSAPublicKey) -> Result<BigUint, Error> {
+    if data.len() != public_key.modulus_byte_length() {
+        return Err(Error::InvalidDataLength);
+    }
+    let number = BigUint::from_be_bytes(data);
+    if &number >= public_key.modulus() {
+        return Err(Error::InvalidParameter);
+    }
+    Ok(number)
+}
+
+pub(crate) fn rsa_number_to_data(number: &BigUint, public_key: &RSAPublicKey) -> Vec<u8> {
+    number.to_be_bytes_with_length(public_key.modulus_byte_length()).unwrap()
+}
+
+pub fn rsa_raw_encrypt_data(origin_data: &[u8], public_key: &RSAPublicKey) -> Result<Vec<u8>, Error> {
+    let m = rsa_data_to_number(origin_data, public_key)?;
+    Ok(rsa_number_to_data(&public_key.public_operation(&m), public_key))
+}
+
+pub fn rsa_raw_decrypt_data(enciphered_data: &[u8], private_key: &RSAPrivateKey) -> Result<Vec<u8>, Error> {
+    let c = rsa_data_to_number(enciphered_data, private_key.public_key())?;
+    Ok(rsa_number_to_data(&private_key.private_operation(&c)?, private_key.public_key()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::math::bigint::big_uint::BigUint;
+    use super::super::rsa_key::tests::private_key;
+    use super::{rsa_raw_decrypt_data, rsa_raw_encrypt_data};
+
+    #[test]
+    fn crt_matches_plain_exponentiation() {
+        let private_key = private_key();
+        let n = private_key.public_key().modulus();
+        for seed in [0x01u8, 0x5a, 0xa5] {
+            let c = &BigUint::from_be_bytes(&[seed; 128]) % n;
+            let enciphered_data = c.to_be_bytes_with_length(128).unwrap();
+            let origin_data = rsa_raw_decrypt_data(&enciphered_data, &private_key).unwrap();
+            assert_eq!(BigUint::from_be_bytes(&origin_data), c.mod_pow(private_key.private_exponent(), n));
+            assert_eq!(rsa_raw_encrypt_data(&origin_data, private_key.public_key()).unwrap(), enciphered_data);
+        }
+    }
+
+    #[test]
+    fn out_of_range_input_is_rejected() {
+        let private_key = private_key();
+        let n = private_key.public_key().modulus().to_be_bytes();
+        assert!(matches!(rsa_raw_encrypt_data(&n, private_key.public_key()), Err(Error::InvalidParameter)));
+        assert!(matches!(rsa_raw_decrypt_data(&[0xff; 128], &private_key), Err(Error::InvalidParameter)));
+        assert!(matches!(rsa_raw_decrypt_data(&[0x01; 127], &private_key), Err(Error::InvalidDataLength)));
+    }
+}
@@ -0,0 +1,6 @@
+pub mod sm2_constant;
+pub mod sm2_key;
+pub mod sm2_signature;
+pub mod sm2_kdf;
+pub mod sm2_encryption;
+pub mod sm2_key_exchange;
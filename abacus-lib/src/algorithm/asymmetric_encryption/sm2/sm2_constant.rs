@@ -0,0 +1,9 @@
+pub const SM2_NUMBER_BYTE_LENGTH: usize = 32;
+pub const SM2_PRIVATE_KEY_BYTE_LENGTH: usize = 32;
+pub const SM2_PUBLIC_KEY_BYTE_LENGTH: usize = 65;
+pub const SM2_SIGNATURE_BYTE_LENGTH: usize = 64;
+pub const SM2_ID_BYTE_MAX_LENGTH: usize = 0x1FFF;
+
+pub const SM2_DEFAULT_ID: &[u8] = b"1234567812345678";
+
+pub const SM2_C3_BYTE_LENGTH: usize = 32;
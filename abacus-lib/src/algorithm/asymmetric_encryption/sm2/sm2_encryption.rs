@@ -0,0 +1,183 @@
+use crate::error::Error;
+use crate::algorithm::digest::sm3::sm3_digest::SM3Digest;
+use crate::util::constant_time::constant_time_eq;
+use crate::util::der::{der_encode_integer, der_encode_octet_string, der_encode_sequence, DERReader};
+use crate::math::bigint::big_uint::BigUint;
+use super::sm2_constant::{SM2_NUMBER_BYTE_LENGTH, SM2_PUBLIC_KEY_BYTE_LENGTH, SM2_C3_BYTE_LENGTH};
+use super::sm2_kdf::sm2_kdf;
+use super::sm2_key::{SM2PrivateKey, SM2PublicKey};
+
+#[derive(Clone, Copy)]
+pub enum SM2CiphertextFormat {
+    C1C3C2,
+    C1C2C3,
+    DER,
+}
+
+struct SM2Ciphertext {
+    c1: SM2PublicKey,
+    c3: [u8; SM2_C3_BYTE_LENGTH],
+    c2: Vec<u8>,
+}
+
+impl SM2Ciphertext {
+    fn from_bytes(enciphered_data: &[u8], format: SM2CiphertextFormat) -> Result<SM2Ciphertext, Error> {
+        let (c1, c3, c2) = match format {
+            SM2CiphertextFormat::C1C3C2 | SM2CiphertextFormat::C1C2C3 => {
+                if enciphered_data.len() <= SM2_PUBLIC_KEY_BYTE_LENGTH + SM2_C3_BYTE_LENGTH {
+                    return Err(Error::InvalidDataLength);
+                }
+                let (c1, remains_data) = enciphered_data.split_at(SM2_PUBLIC_KEY_BYTE_LENGTH);
+                let (c3, c2) = match format {
+                    SM2CiphertextFormat::C1C3C2 => remains_data.split_at(SM2_C3_BYTE_LENGTH),
+                    _ => {
+                        let (c2, c3) = remains_data.split_at(remains_data.len() - SM2_C3_BYTE_LENGTH);
+                        (c3, c2)
+                    }
+                };
+                (SM2PublicKey::from_bytes(c1)?, c3, c2)
+            }
+            SM2CiphertextFormat::DER => {
+                let mut reader = DERReader::new(enciphered_data);
+                let mut sequence = reader.read_sequence()?;
+                let x = sequence.read_integer()?;
+                let y = sequence.read_integer()?;
+                let c3 = sequence.read_octet_string()?;
+                let c2 = sequence.read_octet_string()?;
+                if !sequence.is_empty() || !reader.is_empty() || x.len() > SM2_NUMBER_BYTE_LENGTH || y.len() > SM2_NUMBER_BYTE_LENGTH {
+                    return Err(Error::InvalidEncoding);
+                }
+                if c3.len() != SM2_C3_BYTE_LENGTH || c2.is_empty() {
+                    return Err(Error::InvalidDataLength);
+                }
+                let mut c1 = [0; SM2_PUBLIC_KEY_BYTE_LENGTH - 1];
+                c1[(SM2_NUMBER_BYTE_LENGTH - x.len())..SM2_NUMBER_BYTE_LENGTH].copy_from_slice(x);
+                c1[(SM2_PUBLIC_KEY_BYTE_LENGTH - 1 - y.len())..].copy_from_slice(y);
+                (SM2PublicKey::from_bytes(&c1)?, c3, c2)
+            }
+        };
+        let instance = SM2Ciphertext {
+            c1,
+            c3: c3.try_into().unwrap(),
+            c2: c2.to_vec(),
+        };
+        Ok(instance)
+    }
+
+    fn to_bytes(&self, format: SM2CiphertextFormat) -> Vec<u8> {
+        let c1 = self.c1.to_bytes();
+        match format {
+            SM2CiphertextFormat::C1C3C2 => [&c1[..], &self.c3, &self.c2].concat(),
+            SM2CiphertextFormat::C1C2C3 => [&c1[..], &self.c2, &self.c3].concat(),
+            SM2CiphertextFormat::DER => {
+                let content = [
+                    der_encode_integer(&c1[1..(1 + SM2_NUMBER_BYTE_LENGTH)]),
+                    der_encode_integer(&c1[(1 + SM2_NUMBER_BYTE_LENGTH)..]),
+                    der_encode_octet_string(&self.c3),
+                    der_encode_octet_string(&self.c2),
+                ].concat();
+                der_encode_sequence(&content)
+            }
+        }
+    }
+}
+
+fn compute_c3(x2: &[u8], data: &[u8], y2: &[u8]) -> [u8; SM2_C3_BYTE_LENGTH] {
+    let mut sm3 = SM3Digest::new();
+    sm3.push_data(x2);
+    sm3.push_data(data);
+    sm3.push_data(y2);
+    sm3.compute_digest();
+    sm3.get_digest_bytes()
+}
+
+pub fn sm2_encrypt_data(origin_data: &[u8], public_key: &SM2PublicKey, format: SM2CiphertextFormat) -> Result<Vec<u8>, Error> {
+    if origin_data.is_empty() {
+        return Err(Error::InvalidDataLength);
+    }
+    let curve = public_key.curve();
+    loop {
+        let k = BigUint::random_range(&BigUint::one(), curve.order())?;
+        let (x1, y1) = curve.generator().multiply(curve, &k).affine_coordinates(curve).unwrap();
+        let (x2, y2) = public_key.point().multiply(curve, &k).affine_coordinates(curve).unwrap();
+        let (x2, y2) = (x2.to_be_bytes_with_length(SM2_NUMBER_BYTE_LENGTH).unwrap(), y2.to_be_bytes_with_length(SM2_NUMBER_BYTE_LENGTH).unwrap());
+        let mut c2 = sm2_kdf(&[&x2[..], &y2[..]].concat(), origin_data.len());
+        if c2.iter().all(|b| *b == 0) {
+            continue;
+        }
+        for i in 0..c2.len() {
+            c2[i] ^= origin_data[i];
+        }
+        let ciphertext = SM2Ciphertext {
+            c1: SM2PublicKey::from_bytes(&[x1.to_be_bytes_with_length(SM2_NUMBER_BYTE_LENGTH).unwrap(), y1.to_be_bytes_with_length(SM2_NUMBER_BYTE_LENGTH).unwrap()].concat())?,
+            c3: compute_c3(&x2, origin_data, &y2),
+            c2,
+        };
+        return Ok(ciphertext.to_bytes(format));
+    }
+}
+
+pub fn sm2_decrypt_data(enciphered_data: &[u8], private_key: &SM2PrivateKey, format: SM2CiphertextFormat) -> Result<Vec<u8>, Error> {
+    let ciphertext = SM2Ciphertext::from_bytes(enciphered_data, format)?;
+    let curve = private_key.curve();
+    let (x2, y2) = ciphertext.c1.point().multiply(curve, private_key.scalar()).affine_coordinates(curve).ok_or(Error::InvalidParameter)?;
+    let (x2, y2) = (x2.to_be_bytes_with_length(SM2_NUMBER_BYTE_LENGTH).unwrap(), y2.to_be_bytes_with_length(SM2_NUMBER_BYTE_LENGTH).unwrap());
+    let mut origin_data = sm2_kdf(&[&x2[..], &y2[..]].concat(), ciphertext.c2.len());
+    if origin_data.iter().all(|b| *b == 0) {
+        return Err(Error::AuthenticationFailed);
+    }
+    for (byte, &c2_byte) in origin_data.iter_mut().zip(ciphertext.c2.iter()) {
+        *byte ^= c2_byte;
+    }
+    if !constant_time_eq(&compute_c3(&x2, &origin_data, &y2), &ciphertext.c3) {
+        origin_data.fill(0);
+        return Err(Error::AuthenticationFailed);
+    }
+    Ok(origin_data)
+}
+
+pub fn sm2_convert_enciphered_data(enciphered_data: &[u8], from_format: SM2CiphertextFormat, to_format: SM2CiphertextFormat) -> Result<Vec<u8>, Error> {
+    Ok(SM2Ciphertext::from_bytes(enciphered_data, from_format)?.to_bytes(to_format))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::util::hex::hex_decode;
+    use super::super::sm2_key::SM2PrivateKey;
+    use super::{SM2CiphertextFormat, sm2_convert_enciphered_data, sm2_decrypt_data, sm2_encrypt_data};
+
+    const PRIVATE_KEY: &str = "3945208f7b2144b13f36e38ac6d39f95889393692860b51a42fb81ef4df7c5b8";
+    const ENCIPHERED_DATA: &str = concat!(
+        "307d0221008c22564f12ce1842977c5584908fd94941b2334e76436de452a5ee",
+        "582b6d0c5a022100ac4d4acd2b663e3738b7b9ffa176256bcbf1b531aa92015d",
+        "26baa1e5b2263f7f0420d5c7aa068f0d69fefc44cbb6769377bd99302abe1095",
+        "74bf0dae9ab968d6286d041335bcc5f2385b70eea9bb7d750dcc9cd0498d5f",
+    );
+
+    fn private_key() -> SM2PrivateKey {
+        SM2PrivateKey::try_from_bytes(&hex_decode(PRIVATE_KEY).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn openssl_ciphertext_is_decrypted() {
+        let enciphered_data = hex_decode(ENCIPHERED_DATA).unwrap();
+        assert_eq!(sm2_decrypt_data(&enciphered_data, &private_key(), SM2CiphertextFormat::DER).unwrap(), b"encryption standard");
+        let c1c3c2 = sm2_convert_enciphered_data(&enciphered_data, SM2CiphertextFormat::DER, SM2CiphertextFormat::C1C3C2).unwrap();
+        assert_eq!(c1c3c2.len(), 65 + 32 + 19);
+        assert_eq!(sm2_decrypt_data(&c1c3c2, &private_key(), SM2CiphertextFormat::C1C3C2).unwrap(), b"encryption standard");
+        let mut tampered = c1c3c2.clone();
+        tampered[100] ^= 1;
+        assert!(matches!(sm2_decrypt_data(&tampered, &private_key(), SM2CiphertextFormat::C1C3C2), Err(Error::AuthenticationFailed)));
+    }
+
+    #[test]
+    fn ciphertext_round_trips_in_every_format() {
+        let private_key = private_key();
+        for format in [SM2CiphertextFormat::C1C3C2, SM2CiphertextFormat::C1C2C3, SM2CiphertextFormat::DER] {
+            let enciphered_data = sm2_encrypt_data(b"encryption standard", private_key.public_key(), format).unwrap();
+            assert_eq!(sm2_decrypt_data(&enciphered_data, &private_key, format).unwrap(), b"encryption standard");
+        }
+        assert!(matches!(sm2_encrypt_data(b"", private_key.public_key(), SM2CiphertextFormat::C1C3C2), Err(Error::InvalidDataLength)));
+    }
+}
@@ -0,0 +1,17 @@
+use crate::algorithm::digest::sm3::sm3_constant::SM3_DIGEST_BYTE_LENGTH;
+use crate::algorithm::digest::sm3::sm3_digest::SM3Digest;
+
+pub fn sm2_kdf(z: &[u8], key_byte_length: usize) -> Vec<u8> {
+    let mut key = Vec::with_capacity(key_byte_length.div_ceil(SM3_DIGEST_BYTE_LENGTH) * SM3_DIGEST_BYTE_LENGTH);
+    let mut counter: u32 = 1;
+    while key.len() < key_byte_length {
+        let mut sm3 = SM3Digest::new();
+        sm3.push_data(z);
+        sm3.push_data(&counter.to_be_bytes());
+        sm3.compute_digest();
+        key.extend_from_slice(&sm3.get_digest_bytes());
+        counter += 1;
+    }
+    key.truncate(key_byte_length);
+    key
+}
@@ -0,0 +1,154 @@
+use crate::error::Error;
+use crate::math::bigint::big_uint::BigUint;
+use super::super::ecc::ecc_constant::ECC_UNCOMPRESSED_POINT_TAG;
+use super::super::ecc::ecc_curve::ECCurve;
+use super::super::ecc::ecc_key::{ECPointFormat, ECPrivateKey, ECPublicKey};
+use super::super::ecc::ecc_point::ECPoint;
+use super::sm2_constant::{SM2_PRIVATE_KEY_BYTE_LENGTH, SM2_PUBLIC_KEY_BYTE_LENGTH};
+
+#[inline(always)]
+fn is_valid_private_scalar(curve: &ECCurve, d: &BigUint) -> bool {
+    !d.is_zero() && d < &(curve.order() - &BigUint::one())
+}
+
+#[derive(Clone)]
+pub struct SM2PublicKey {
+    public_key: ECPublicKey,
+}
+
+impl SM2PublicKey {
+    pub fn from_bytes(bytes: &[u8]) -> Result<SM2PublicKey, Error> {
+        let coordinates = match bytes.len() {
+            SM2_PUBLIC_KEY_BYTE_LENGTH if bytes[0] == ECC_UNCOMPRESSED_POINT_TAG => &bytes[1..],
+            SM2_PUBLIC_KEY_BYTE_LENGTH => return Err(Error::InvalidKey),
+            length if length == SM2_PUBLIC_KEY_BYTE_LENGTH - 1 => bytes,
+            _ => return Err(Error::InvalidKeyLength),
+        };
+        let public_key = ECPublicKey::from_sec1_bytes(&ECCurve::sm2(), &[&[ECC_UNCOMPRESSED_POINT_TAG][..], coordinates].concat())?;
+        let instance = SM2PublicKey {
+            public_key,
+        };
+        Ok(instance)
+    }
+
+    pub fn to_bytes(&self) -> [u8; SM2_PUBLIC_KEY_BYTE_LENGTH] {
+        self.public_key.to_sec1_bytes(ECPointFormat::Uncompressed).try_into().unwrap()
+    }
+
+    pub(crate) fn curve(&self) -> &ECCurve {
+        self.public_key.curve()
+    }
+
+    pub(crate) fn point(&self) -> ECPoint {
+        self.public_key.point()
+    }
+}
+
+#[derive(Clone)]
+pub struct SM2PrivateKey {
+    private_key: ECPrivateKey,
+    public_key: SM2PublicKey,
+}
+
+impl SM2PrivateKey {
+    fn with_scalar(curve: &ECCurve, d: BigUint) -> SM2PrivateKey {
+        let private_key = ECPrivateKey::with_scalar(curve, d);
+        let public_key = SM2PublicKey {
+            public_key: private_key.public_key().clone(),
+        };
+        SM2PrivateKey {
+            private_key,
+            public_key,
+        }
+    }
+
+    pub fn generate() -> Result<SM2PrivateKey, Error> {
+        let curve = ECCurve::sm2();
+        let d = BigUint::random_range(&BigUint::one(), &(curve.order() - &BigUint::one()))?;
+        Ok(SM2PrivateKey::with_scalar(&curve, d))
+    }
+
+    pub fn from_bytes(bytes: [u8; SM2_PRIVATE_KEY_BYTE_LENGTH]) -> Result<SM2PrivateKey, Error> {
+        let curve = ECCurve::sm2();
+        let d = BigUint::from_be_bytes(&bytes);
+        if !is_valid_private_scalar(&curve, &d) {
+            return Err(Error::InvalidKey);
+        }
+        Ok(SM2PrivateKey::with_scalar(&curve, d))
+    }
+
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<SM2PrivateKey, Error> {
+        let bytes: [u8; SM2_PRIVATE_KEY_BYTE_LENGTH] = bytes.try_into().map_err(|_| Error::InvalidKeyLength)?;
+        SM2PrivateKey::from_bytes(bytes)
+    }
+
+    pub fn to_bytes(&self) -> [u8; SM2_PRIVATE_KEY_BYTE_LENGTH] {
+        self.private_key.to_bytes().try_into().unwrap()
+    }
+
+    pub fn public_key(&self) -> &SM2PublicKey {
+        &self.public_key
+    }
+
+    pub(crate) fn curve(&self) -> &ECCurve {
+        self.private_key.curve()
+    }
+
+    pub(crate) fn scalar(&self) -> &BigUint {
+        self.private_key.scalar()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::util::hex::{hex_decode, hex_encode};
+    use super::{SM2PrivateKey, SM2PublicKey};
+
+    const PRIVATE_KEY: &str = "3945208f7b2144b13f36e38ac6d39f95889393692860b51a42fb81ef4df7c5b8";
+    const PUBLIC_KEY: &str = concat!(
+        "04",
+        "09f9df311e5421a150dd7d161e4bc5c672179fad1833fc076bb08ff356f35020",
+        "ccea490ce26775a52dc6ea718cc1aa600aed05fbf35e084a6632f6072da9ad13",
+    );
+    const ORDER_MINUS_ONE: &str = "fffffffeffffffffffffffffffffffff7203df6b21c6052b53bbf40939d54122";
+
+    #[test]
+    fn public_key_is_derived_from_private_key() {
+        let private_key = SM2PrivateKey::try_from_bytes(&hex_decode(PRIVATE_KEY).unwrap()).unwrap();
+        assert_eq!(hex_encode(&private_key.to_bytes()), PRIVATE_KEY);
+        assert_eq!(hex_encode(&private_key.public_key().to_bytes()), PUBLIC_KEY);
+    }
+
+    #[test]
+    fn public_key_encodings() {
+        let bytes = hex_decode(PUBLIC_KEY).unwrap();
+        assert_eq!(SM2PublicKey::from_bytes(&bytes).unwrap().to_bytes().to_vec(), bytes);
+        assert_eq!(SM2PublicKey::from_bytes(&bytes[1..]).unwrap().to_bytes().to_vec(), bytes);
+        let mut invalid_tag = bytes.clone();
+        invalid_tag[0] = 0x02;
+        assert!(matches!(SM2PublicKey::from_bytes(&invalid_tag), Err(Error::InvalidKey)));
+        let mut off_curve = bytes.clone();
+        off_curve[64] ^= 1;
+        assert!(matches!(SM2PublicKey::from_bytes(&off_curve), Err(Error::InvalidKey)));
+        assert!(matches!(SM2PublicKey::from_bytes(&bytes[..33]), Err(Error::InvalidKeyLength)));
+    }
+
+    #[test]
+    fn out_of_range_private_key_is_rejected() {
+        assert!(matches!(SM2PrivateKey::from_bytes([0; 32]), Err(Error::InvalidKey)));
+        assert!(matches!(SM2PrivateKey::try_from_bytes(&hex_decode(ORDER_MINUS_ONE).unwrap()), Err(Error::InvalidKey)));
+        assert!(matches!(SM2PrivateKey::from_bytes([0xff; 32]), Err(Error::InvalidKey)));
+        assert!(matches!(SM2PrivateKey::try_from_bytes(&[1; 31]), Err(Error::InvalidKeyLength)));
+        let mut largest = hex_decode(ORDER_MINUS_ONE).unwrap();
+        largest[31] -= 1;
+        assert!(SM2PrivateKey::try_from_bytes(&largest).is_ok());
+    }
+
+    #[test]
+    fn generated_key_round_trips() {
+        let private_key = SM2PrivateKey::generate().unwrap();
+        let restored = SM2PrivateKey::from_bytes(private_key.to_bytes()).unwrap();
+        assert_eq!(restored.public_key().to_bytes(), private_key.public_key().to_bytes());
+    }
+}
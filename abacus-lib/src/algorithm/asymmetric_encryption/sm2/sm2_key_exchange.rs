@@ -0,0 +1,166 @@
+use crate::error::Error;
+use crate::algorithm::digest::sm3::sm3_constant::SM3_DIGEST_BYTE_LENGTH;
+use crate::algorithm::digest::sm3::sm3_digest::SM3Digest;
+use crate::math::bigint::big_uint::BigUint;
+use crate::util::constant_time::constant_time_eq;
+use super::sm2_constant::{SM2_NUMBER_BYTE_LENGTH, SM2_PUBLIC_KEY_BYTE_LENGTH, SM2_DEFAULT_ID};
+use super::sm2_kdf::sm2_kdf;
+use super::sm2_key::{SM2PrivateKey, SM2PublicKey};
+use super::sm2_signature::sm2_compute_z;
+
+const SM2_KEY_EXCHANGE_W: usize = 127;
+const SM2_KEY_EXCHANGE_INITIATOR_TAG: u8 = 0x03;
+const SM2_KEY_EXCHANGE_RESPONDER_TAG: u8 = 0x02;
+
+#[inline(always)]
+fn reduce_x(x: &[u8]) -> BigUint {
+    let mut x_bar = BigUint::from_be_bytes(&x[(x.len() - SM2_KEY_EXCHANGE_W.div_ceil(8))..]);
+    x_bar.set_bit(SM2_KEY_EXCHANGE_W);
+    x_bar
+}
+
+fn compute_confirmation(tag: u8, y: &[u8], inner_hash: &[u8; SM3_DIGEST_BYTE_LENGTH]) -> [u8; SM3_DIGEST_BYTE_LENGTH] {
+    let mut sm3 = SM3Digest::new();
+    sm3.push_data(&[tag]);
+    sm3.push_data(y);
+    sm3.push_data(inner_hash);
+    sm3.compute_digest();
+    sm3.get_digest_bytes()
+}
+
+#[derive(Clone, Copy)]
+pub enum SM2KeyExchangeRole {
+    Initiator,
+    Responder,
+}
+
+pub struct SM2KeyAgreement {
+    shared_key: Vec<u8>,
+    confirmation: [u8; SM3_DIGEST_BYTE_LENGTH],
+    peer_confirmation: [u8; SM3_DIGEST_BYTE_LENGTH],
+}
+
+impl SM2KeyAgreement {
+    pub fn shared_key(&self) -> &[u8] {
+        &self.shared_key
+    }
+
+    pub fn confirmation(&self) -> [u8; SM3_DIGEST_BYTE_LENGTH] {
+        self.confirmation
+    }
+
+    pub fn verify_peer_confirmation(&self, peer_confirmation: &[u8]) -> bool {
+        constant_time_eq(&self.peer_confirmation, peer_confirmation)
+    }
+}
+
+pub struct SM2KeyExchange {
+    role: SM2KeyExchangeRole,
+    private_key: SM2PrivateKey,
+    z: [u8; SM3_DIGEST_BYTE_LENGTH],
+    r: BigUint,
+    ephemeral_public_key: SM2PublicKey,
+}
+
+impl SM2KeyExchange {
+    pub fn new(role: SM2KeyExchangeRole, private_key: &SM2PrivateKey) -> Result<SM2KeyExchange, Error> {
+        SM2KeyExchange::with_id(role, private_key, SM2_DEFAULT_ID)
+    }
+
+    pub fn with_id(role: SM2KeyExchangeRole, private_key: &SM2PrivateKey, id: &[u8]) -> Result<SM2KeyExchange, Error> {
+        let z = sm2_compute_z(id, private_key.public_key())?;
+        let curve = private_key.curve();
+        let r = BigUint::random_range(&BigUint::one(), curve.order())?;
+        let (x, y) = curve.generator().multiply(curve, &r).affine_coordinates(curve).unwrap();
+        let instance = SM2KeyExchange {
+            role,
+            private_key: private_key.clone(),
+            z,
+            r,
+            ephemeral_public_key: SM2PublicKey::from_bytes(&[x.to_be_bytes_with_length(SM2_NUMBER_BYTE_LENGTH).unwrap(), y.to_be_bytes_with_length(SM2_NUMBER_BYTE_LENGTH).unwrap()].concat())?,
+        };
+        Ok(instance)
+    }
+
+    pub fn ephemeral_public_key(&self) -> [u8; SM2_PUBLIC_KEY_BYTE_LENGTH] {
+        self.ephemeral_public_key.to_bytes()
+    }
+
+    pub fn compute_key(self, peer_public_key: &SM2PublicKey, peer_id: &[u8], peer_ephemeral_public_key: &[u8], key_byte_length: usize) -> Result<SM2KeyAgreement, Error> {
+        let peer_z = sm2_compute_z(peer_id, peer_public_key)?;
+        let peer_ephemeral_public_key = SM2PublicKey::from_bytes(peer_ephemeral_public_key)?;
+        let own_ephemeral_bytes = self.ephemeral_public_key.to_bytes();
+        let peer_ephemeral_bytes = peer_ephemeral_public_key.to_bytes();
+        let curve = self.private_key.curve();
+        let order = curve.scalar_field();
+        let own_x_bar = reduce_x(&own_ephemeral_bytes[1..(1 + SM2_NUMBER_BYTE_LENGTH)]);
+        let peer_x_bar = reduce_x(&peer_ephemeral_bytes[1..(1 + SM2_NUMBER_BYTE_LENGTH)]);
+        let t = order.mod_add(self.private_key.scalar(), &order.mod_mul(&own_x_bar, &self.r));
+        let point = peer_public_key.point().add(curve, &peer_ephemeral_public_key.point().multiply(curve, &peer_x_bar)).multiply(curve, &t);
+        let (x, y) = point.affine_coordinates(curve).ok_or(Error::InvalidKey)?;
+        let (x, y) = (x.to_be_bytes_with_length(SM2_NUMBER_BYTE_LENGTH).unwrap(), y.to_be_bytes_with_length(SM2_NUMBER_BYTE_LENGTH).unwrap());
+        let (initiator_z, responder_z, initiator_ephemeral_bytes, responder_ephemeral_bytes) = match self.role {
+            SM2KeyExchangeRole::Initiator => (&self.z, &peer_z, &own_ephemeral_bytes, &peer_ephemeral_bytes),
+            SM2KeyExchangeRole::Responder => (&peer_z, &self.z, &peer_ephemeral_bytes, &own_ephemeral_bytes),
+        };
+        let shared_key = sm2_kdf(&[&x[..], &y[..], initiator_z, responder_z].concat(), key_byte_length);
+        let mut sm3 = SM3Digest::new();
+        sm3.push_data(&x);
+        sm3.push_data(initiator_z);
+        sm3.push_data(responder_z);
+        sm3.push_data(&initiator_ephemeral_bytes[1..]);
+        sm3.push_data(&responder_ephemeral_bytes[1..]);
+        sm3.compute_digest();
+        let inner_hash = sm3.get_digest_bytes();
+        let initiator_confirmation = compute_confirmation(SM2_KEY_EXCHANGE_INITIATOR_TAG, &y, &inner_hash);
+        let responder_confirmation = compute_confirmation(SM2_KEY_EXCHANGE_RESPONDER_TAG, &y, &inner_hash);
+        let (confirmation, peer_confirmation) = match self.role {
+            SM2KeyExchangeRole::Initiator => (initiator_confirmation, responder_confirmation),
+            SM2KeyExchangeRole::Responder => (responder_confirmation, initiator_confirmation),
+        };
+        let instance = SM2KeyAgreement {
+            shared_key,
+            confirmation,
+            peer_confirmation,
+        };
+        Ok(instance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::hex::hex_decode;
+    use super::super::sm2_key::SM2PrivateKey;
+    use super::{SM2KeyExchange, SM2KeyExchangeRole};
+
+    #[test]
+    fn both_roles_agree() {
+        let initiator_key = SM2PrivateKey::try_from_bytes(&hex_decode("3945208f7b2144b13f36e38ac6d39f95889393692860b51a42fb81ef4df7c5b8").unwrap()).unwrap();
+        let responder_key = SM2PrivateKey::generate().unwrap();
+        let initiator = SM2KeyExchange::with_id(SM2KeyExchangeRole::Initiator, &initiator_key, b"alice").unwrap();
+        let responder = SM2KeyExchange::with_id(SM2KeyExchangeRole::Responder, &responder_key, b"bob").unwrap();
+        let initiator_ephemeral_public_key = initiator.ephemeral_public_key();
+        let responder_ephemeral_public_key = responder.ephemeral_public_key();
+        let initiator_agreement = initiator.compute_key(responder_key.public_key(), b"bob", &responder_ephemeral_public_key, 40).unwrap();
+        let responder_agreement = responder.compute_key(initiator_key.public_key(), b"alice", &initiator_ephemeral_public_key, 40).unwrap();
+        assert_eq!(initiator_agreement.shared_key().len(), 40);
+        assert_eq!(initiator_agreement.shared_key(), responder_agreement.shared_key());
+        assert!(initiator_agreement.verify_peer_confirmation(&responder_agreement.confirmation()));
+        assert!(responder_agreement.verify_peer_confirmation(&initiator_agreement.confirmation()));
+        assert!(!initiator_agreement.verify_peer_confirmation(&initiator_agreement.confirmation()));
+    }
+
+    #[test]
+    fn mismatched_id_breaks_agreement() {
+        let initiator_key = SM2PrivateKey::generate().unwrap();
+        let responder_key = SM2PrivateKey::generate().unwrap();
+        let initiator = SM2KeyExchange::new(SM2KeyExchangeRole::Initiator, &initiator_key).unwrap();
+        let responder = SM2KeyExchange::new(SM2KeyExchangeRole::Responder, &responder_key).unwrap();
+        let initiator_ephemeral_public_key = initiator.ephemeral_public_key();
+        let responder_ephemeral_public_key = responder.ephemeral_public_key();
+        let initiator_agreement = initiator.compute_key(responder_key.public_key(), b"mallory", &responder_ephemeral_public_key, 16).unwrap();
+        let responder_agreement = responder.compute_key(initiator_key.public_key(), b"1234567812345678", &initiator_ephemeral_public_key, 16).unwrap();
+        assert_ne!(initiator_agreement.shared_key(), responder_agreement.shared_key());
+        assert!(!responder_agreement.verify_peer_confirmation(&initiator_agreement.confirmation()));
+    }
+}
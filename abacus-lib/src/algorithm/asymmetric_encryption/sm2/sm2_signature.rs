@@ -0,0 +1,232 @@
+use crate::error::Error;
+use crate::algorithm::digest::sm3::sm3_constant::SM3_DIGEST_BYTE_LENGTH;
+use crate::algorithm::digest::sm3::sm3_digest::SM3Digest;
+use crate::math::bigint::big_uint::BigUint;
+use super::sm2_constant::{SM2_NUMBER_BYTE_LENGTH, SM2_SIGNATURE_BYTE_LENGTH, SM2_ID_BYTE_MAX_LENGTH, SM2_DEFAULT_ID};
+use super::sm2_key::{SM2PrivateKey, SM2PublicKey};
+
+#[inline(always)]
+fn check_id(id: &[u8]) -> Result<(), Error> {
+    if id.len() > SM2_ID_BYTE_MAX_LENGTH {
+        return Err(Error::InvalidParameter);
+    }
+    Ok(())
+}
+
+fn compute_z(id: &[u8], public_key: &SM2PublicKey) -> [u8; SM3_DIGEST_BYTE_LENGTH] {
+    let curve = public_key.curve();
+    let mut sm3 = SM3Digest::new();
+    sm3.push_data(&((id.len() * 8) as u16).to_be_bytes());
+    sm3.push_data(id);
+    for parameter in [curve.coefficient_a(), curve.coefficient_b(), curve.generator_x(), curve.generator_y()] {
+        sm3.push_data(&parameter.to_be_bytes_with_length(SM2_NUMBER_BYTE_LENGTH).unwrap());
+    }
+    sm3.push_data(&public_key.to_bytes()[1..]);
+    sm3.compute_digest();
+    sm3.get_digest_bytes()
+}
+
+pub fn sm2_compute_z(id: &[u8], public_key: &SM2PublicKey) -> Result<[u8; SM3_DIGEST_BYTE_LENGTH], Error> {
+    check_id(id)?;
+    Ok(compute_z(id, public_key))
+}
+
+pub fn sm2_sign_digest(digest: &[u8; SM3_DIGEST_BYTE_LENGTH], private_key: &SM2PrivateKey) -> Result<[u8; SM2_SIGNATURE_BYTE_LENGTH], Error> {
+    let curve = private_key.curve();
+    let order = curve.scalar_field();
+    let e = BigUint::from_be_bytes(digest);
+    let d = private_key.scalar();
+    let inverse = order.mod_inverse_prime_constant_time(&order.mod_add(d, &BigUint::one()));
+    loop {
+        let k = BigUint::random_range(&BigUint::one(), curve.order())?;
+        let (x1, _) = curve.generator().multiply(curve, &k).affine_coordinates(curve).unwrap();
+        let r = order.mod_add(&e, &x1);
+        if r.is_zero() || order.mod_add(&r, &k).is_zero() {
+            continue;
+        }
+        let s = order.mod_mul(&inverse, &order.mod_sub(&k, &order.mod_mul(&r, d)));
+        if s.is_zero() {
+            continue;
+        }
+        let mut signature = [0; SM2_SIGNATURE_BYTE_LENGTH];
+        signature[..SM2_NUMBER_BYTE_LENGTH].copy_from_slice(&r.to_be_bytes_with_length(SM2_NUMBER_BYTE_LENGTH).unwrap());
+        signature[SM2_NUMBER_BYTE_LENGTH..].copy_from_slice(&s.to_be_bytes_with_length(SM2_NUMBER_BYTE_LENGTH).unwrap());
+        return Ok(signature);
+    }
+}
+
+pub fn sm2_verify_digest(digest: &[u8; SM3_DIGEST_BYTE_LENGTH], public_key: &SM2PublicKey, signature: &[u8]) -> bool {
+    if signature.len() != SM2_SIGNATURE_BYTE_LENGTH {
+        return false;
+    }
+    let curve = public_key.curve();
+    let order = curve.scalar_field();
+    let r = BigUint::from_be_bytes(&signature[..SM2_NUMBER_BYTE_LENGTH]);
+    let s = BigUint::from_be_bytes(&signature[SM2_NUMBER_BYTE_LENGTH..]);
+    if r.is_zero() || s.is_zero() || &r >= curve.order() || &s >= curve.order() {
+        return false;
+    }
+    let t = order.mod_add(&r, &s);
+    if t.is_zero() {
+        return false;
+    }
+    let point = curve.generator().multiply(curve, &s).add(curve, &public_key.point().multiply(curve, &t));
+    match point.affine_coordinates(curve) {
+        Some((x1, _)) => {
+            let e = BigUint::from_be_bytes(digest);
+            order.mod_add(&e, &x1) == r
+        }
+        None => false,
+    }
+}
+
+pub struct SM2Signer {
+    private_key: SM2PrivateKey,
+    sm3: SM3Digest,
+}
+
+impl SM2Signer {
+    pub fn new(private_key: &SM2PrivateKey) -> SM2Signer {
+        SM2Signer::with_z(private_key, &compute_z(SM2_DEFAULT_ID, private_key.public_key()))
+    }
+
+    pub fn with_id(private_key: &SM2PrivateKey, id: &[u8]) -> Result<SM2Signer, Error> {
+        Ok(SM2Signer::with_z(private_key, &sm2_compute_z(id, private_key.public_key())?))
+    }
+
+    fn with_z(private_key: &SM2PrivateKey, z: &[u8; SM3_DIGEST_BYTE_LENGTH]) -> SM2Signer {
+        let mut sm3 = SM3Digest::new();
+        sm3.push_data(z);
+        SM2Signer {
+            private_key: private_key.clone(),
+            sm3,
+        }
+    }
+
+    pub fn push_data(&mut self, data: &[u8]) {
+        self.sm3.push_data(data)
+    }
+
+    pub fn sign(mut self) -> Result<[u8; SM2_SIGNATURE_BYTE_LENGTH], Error> {
+        self.sm3.compute_digest();
+        sm2_sign_digest(&self.sm3.get_digest_bytes(), &self.private_key)
+    }
+}
+
+pub struct SM2Verifier {
+    public_key: SM2PublicKey,
+    sm3: SM3Digest,
+}
+
+impl SM2Verifier {
+    pub fn new(public_key: &SM2PublicKey) -> SM2Verifier {
+        SM2Verifier::with_z(public_key, &compute_z(SM2_DEFAULT_ID, public_key))
+    }
+
+    pub fn with_id(public_key: &SM2PublicKey, id: &[u8]) -> Result<SM2Verifier, Error> {
+        Ok(SM2Verifier::with_z(public_key, &sm2_compute_z(id, public_key)?))
+    }
+
+    fn with_z(public_key: &SM2PublicKey, z: &[u8; SM3_DIGEST_BYTE_LENGTH]) -> SM2Verifier {
+        let mut sm3 = SM3Digest::new();
+        sm3.push_data(z);
+        SM2Verifier {
+            public_key: public_key.clone(),
+            sm3,
+        }
+    }
+
+    pub fn push_data(&mut self, data: &[u8]) {
+        self.sm3.push_data(data)
+    }
+
+    pub fn verify(mut self, signature: &[u8]) -> bool {
+        self.sm3.compute_digest();
+        sm2_verify_digest(&self.sm3.get_digest_bytes(), &self.public_key, signature)
+    }
+}
+
+pub fn sm2_sign_data(data: &[u8], private_key: &SM2PrivateKey) -> Result<[u8; SM2_SIGNATURE_BYTE_LENGTH], Error> {
+    sm2_sign_data_with_id(data, private_key, SM2_DEFAULT_ID)
+}
+
+pub fn sm2_sign_data_with_id(data: &[u8], private_key: &SM2PrivateKey, id: &[u8]) -> Result<[u8; SM2_SIGNATURE_BYTE_LENGTH], Error> {
+    let mut signer = SM2Signer::with_id(private_key, id)?;
+    signer.push_data(data);
+    signer.sign()
+}
+
+pub fn sm2_verify_data(data: &[u8], public_key: &SM2PublicKey, signature: &[u8]) -> bool {
+    sm2_verify_data_with_id(data, public_key, SM2_DEFAULT_ID, signature)
+}
+
+pub fn sm2_verify_data_with_id(data: &[u8], public_key: &SM2PublicKey, id: &[u8], signature: &[u8]) -> bool {
+    match SM2Verifier::with_id(public_key, id) {
+        Ok(mut verifier) => {
+            verifier.push_data(data);
+            verifier.verify(signature)
+        }
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::util::hex::hex_decode;
+    use super::super::sm2_constant::SM2_ID_BYTE_MAX_LENGTH;
+    use super::super::sm2_key::{SM2PrivateKey, SM2PublicKey};
+    use super::{SM2Signer, SM2Verifier, sm2_compute_z, sm2_sign_data, sm2_sign_data_with_id, sm2_verify_data, sm2_verify_data_with_id};
+
+    const PRIVATE_KEY: &str = "3945208f7b2144b13f36e38ac6d39f95889393692860b51a42fb81ef4df7c5b8";
+    const PUBLIC_KEY: &str = concat!(
+        "04",
+        "09f9df311e5421a150dd7d161e4bc5c672179fad1833fc076bb08ff356f35020",
+        "ccea490ce26775a52dc6ea718cc1aa600aed05fbf35e084a6632f6072da9ad13",
+    );
+    const SIGNATURE: &str = concat!(
+        "397360a2f1a7547cf6756e3871084c7dcc13273f368b9bf94b86bdbed925f6ab",
+        "1e9be06e8ad24271512b00eb41a669c5c74479f0811cc2f15837e4bf476ed5ed",
+    );
+
+    #[test]
+    fn openssl_signature_is_verified() {
+        let public_key = SM2PublicKey::from_bytes(&hex_decode(PUBLIC_KEY).unwrap()).unwrap();
+        let signature = hex_decode(SIGNATURE).unwrap();
+        assert!(sm2_verify_data(b"message digest", &public_key, &signature));
+        assert!(!sm2_verify_data(b"message digesT", &public_key, &signature));
+        assert!(!sm2_verify_data_with_id(b"message digest", &public_key, b"alice", &signature));
+        let mut tampered = signature.clone();
+        tampered[63] ^= 1;
+        assert!(!sm2_verify_data(b"message digest", &public_key, &tampered));
+        assert!(!sm2_verify_data(b"message digest", &public_key, &signature[..63]));
+        assert!(!sm2_verify_data(b"message digest", &public_key, &[0; 64]));
+    }
+
+    #[test]
+    fn signature_round_trips() {
+        let private_key = SM2PrivateKey::try_from_bytes(&hex_decode(PRIVATE_KEY).unwrap()).unwrap();
+        let signature = sm2_sign_data(b"message digest", &private_key).unwrap();
+        assert!(sm2_verify_data(b"message digest", private_key.public_key(), &signature));
+        let mut signer = SM2Signer::new(&private_key);
+        signer.push_data(b"message ");
+        signer.push_data(b"digest");
+        let signature = signer.sign().unwrap();
+        let mut verifier = SM2Verifier::new(private_key.public_key());
+        verifier.push_data(b"message digest");
+        assert!(verifier.verify(&signature));
+    }
+
+    #[test]
+    fn oversized_id_is_rejected() {
+        let private_key = SM2PrivateKey::from_bytes([0x11; 32]).unwrap();
+        let id = vec![0x61; SM2_ID_BYTE_MAX_LENGTH + 1];
+        assert!(matches!(sm2_compute_z(&id, private_key.public_key()), Err(Error::InvalidParameter)));
+        assert!(matches!(SM2Signer::with_id(&private_key, &id), Err(Error::InvalidParameter)));
+        assert!(matches!(SM2Verifier::with_id(private_key.public_key(), &id), Err(Error::InvalidParameter)));
+        assert!(matches!(sm2_sign_data_with_id(b"abc", &private_key, &id), Err(Error::InvalidParameter)));
+        let signature = sm2_sign_data_with_id(b"abc", &private_key, b"alice").unwrap();
+        assert!(sm2_verify_data_with_id(b"abc", private_key.public_key(), b"alice", &signature));
+        assert!(!sm2_verify_data_with_id(b"abc", private_key.public_key(), &id, &signature));
+    }
+}
@@ -0,0 +1,15 @@
+pub trait Digest {
+    const ALGORITHM_NAME: &'static str;
+    const DIGEST_BYTE_LENGTH: usize;
+    const BUFFER_BYTE_LENGTH: usize;
+
+    type DigestBytes: AsRef<[u8]> + AsMut<[u8]> + Copy;
+
+    fn new() -> Self;
+
+    fn get_digest_bytes(&mut self) -> Self::DigestBytes;
+
+    fn compute_digest(&mut self);
+
+    fn push_data(&mut self, data: &[u8]);
+}
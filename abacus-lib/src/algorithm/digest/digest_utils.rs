@@ -0,0 +1,107 @@
+use std::fs::File;
+use std::io::{ErrorKind, Read};
+use std::path::Path;
+use crate::error::Error;
+use super::digest_trait::Digest;
+
+const FILE_READ_SIZE: usize = 0x40000;
+
+pub struct DigestUtils;
+
+impl DigestUtils {
+    pub fn data_digest<D: Digest>(data: &[u8]) -> D::DigestBytes {
+        let mut digest = D::new();
+        digest.push_data(data);
+        digest.compute_digest();
+        digest.get_digest_bytes()
+    }
+
+    pub fn file_digest<D: Digest, P: AsRef<Path>>(file_path: P) -> Result<D::DigestBytes, Error> {
+        let mut file = File::open(file_path)?;
+        DigestUtils::reader_digest::<D, File>(&mut file)
+    }
+
+    pub fn reader_digest<D: Digest, R: Read>(reader: &mut R) -> Result<D::DigestBytes, Error> {
+        let mut buffer = vec![0; FILE_READ_SIZE];
+        let mut digest = D::new();
+        loop {
+            let read_size = match reader.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(read_size) => read_size,
+                Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+                Err(error) => return Err(Error::IO(error)),
+            };
+            digest.push_data(&buffer[..read_size]);
+        }
+        digest.compute_digest();
+        Ok(digest.get_digest_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Error, ErrorKind, Read};
+    use super::DigestUtils;
+    use super::super::sha2::sha2_256_digest::SHA2_256Digest;
+    use super::super::sm3::sm3_digest::SM3Digest;
+
+    struct ShortReader<'a> {
+        data: &'a [u8],
+        position: usize,
+        read_count: usize,
+    }
+
+    impl<'a> ShortReader<'a> {
+        fn new(data: &'a [u8]) -> ShortReader<'a> {
+            ShortReader {
+                data,
+                position: 0,
+                read_count: 0,
+            }
+        }
+    }
+
+    impl Read for ShortReader<'_> {
+        fn read(&mut self, buffer: &mut [u8]) -> Result<usize, Error> {
+            self.read_count += 1;
+            if self.read_count.is_multiple_of(7) {
+                return Err(Error::new(ErrorKind::Interrupted, "interrupted"));
+            }
+            let read_size = (self.read_count % 3 + 1).min(buffer.len()).min(self.data.len() - self.position);
+            buffer[..read_size].copy_from_slice(&self.data[self.position..(self.position + read_size)]);
+            self.position += read_size;
+            Ok(read_size)
+        }
+    }
+
+    fn test_data(length: usize) -> Vec<u8> {
+        (0..length).map(|i| (i * 31 + 7) as u8).collect()
+    }
+
+    #[test]
+    fn reader_digest_matches_data_digest_on_short_reads() {
+        for length in [0, 1, 63, 64, 65, 200, 1000] {
+            let data = test_data(length);
+            let sm3_digest = DigestUtils::reader_digest::<SM3Digest, _>(&mut ShortReader::new(&data)).unwrap();
+            assert_eq!(sm3_digest, DigestUtils::data_digest::<SM3Digest>(&data));
+            let sha256_digest = DigestUtils::reader_digest::<SHA2_256Digest, _>(&mut ShortReader::new(&data)).unwrap();
+            assert_eq!(sha256_digest, DigestUtils::data_digest::<SHA2_256Digest>(&data));
+        }
+    }
+
+    #[test]
+    fn reader_digest_reads_until_end_of_data() {
+        let data = test_data(200);
+        let mut reader = ShortReader::new(&data);
+        DigestUtils::reader_digest::<SM3Digest, _>(&mut reader).unwrap();
+        assert_eq!(reader.position, data.len());
+    }
+
+    #[test]
+    fn reader_digest_of_empty_input() {
+        let sm3_digest = DigestUtils::reader_digest::<SM3Digest, _>(&mut ShortReader::new(&[])).unwrap();
+        assert_eq!(sm3_digest[..4], [0x1a, 0xb2, 0x1d, 0x83]);
+        let sha256_digest = DigestUtils::reader_digest::<SHA2_256Digest, _>(&mut ShortReader::new(&[])).unwrap();
+        assert_eq!(sha256_digest[..4], [0xe3, 0xb0, 0xc4, 0x42]);
+    }
+}
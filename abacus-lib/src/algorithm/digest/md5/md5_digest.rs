@@ -1,3 +1,4 @@
+use super::super::digest_trait::Digest;
 use super::md5_constant::{MD5_BUFFER_BYTE_LENGTH, MD5_DIGEST_BYTE_LENGTH, MD5_DATA_BYTE_MAX_LENGTH};
 
 #[inline(always)]
@@ -56,7 +57,7 @@ fn round_48_64(x1: &mut u32, x2: u32, x3: u32, x4: u32, k: u32, s: u32, t: u32)
 #[inline(always)]
 fn fill_to_bytes(digest_bytes: &mut [u8; MD5_DIGEST_BYTE_LENGTH], x: u32, index: usize) {
     let word_bytes = x.to_le_bytes();
-    digest_bytes[index * 4 + 0] = word_bytes[0];
+    digest_bytes[index * 4] = word_bytes[0];
     digest_bytes[index * 4 + 1] = word_bytes[1];
     digest_bytes[index * 4 + 2] = word_bytes[2];
     digest_bytes[index * 4 + 3] = word_bytes[3];
@@ -84,17 +85,22 @@ pub struct MD5Digest {
     remains_data: Vec<u8>,
 }
 
+impl Default for MD5Digest {
+    fn default() -> Self {
+        MD5Digest::new()
+    }
+}
+
 impl MD5Digest {
     pub fn new() -> MD5Digest {
-        let instance = MD5Digest {
+        MD5Digest {
             a: 0x67452301,
             b: 0xefcdab89,
             c: 0x98badcfe,
             d: 0x10325476,
             total_length: 0,
             remains_data: Vec::new()
-        };
-        instance
+        }
     }
 
     pub fn get_digest_bytes(&mut self) -> [u8; MD5_DIGEST_BYTE_LENGTH] {
@@ -110,9 +116,7 @@ impl MD5Digest {
         let remains_data_length = self.remains_data.len();
         let min_padding_byte_length = remains_data_length + MD5_DATA_BYTE_MAX_LENGTH + 1;
         let mut buffer: [u8; MD5_BUFFER_BYTE_LENGTH] = [0; MD5_BUFFER_BYTE_LENGTH];
-        for i in 0..self.remains_data.len() {
-            buffer[i] = self.remains_data[i];
-        }
+        buffer[..remains_data_length].copy_from_slice(&self.remains_data);
         self.remains_data.clear();
         buffer[remains_data_length] = 0x80;
         if min_padding_byte_length > MD5_BUFFER_BYTE_LENGTH {
@@ -134,24 +138,19 @@ impl MD5Digest {
         if remains_data_length > 0 && remains_data_length + data.len() >= MD5_BUFFER_BYTE_LENGTH {
             offset = MD5_BUFFER_BYTE_LENGTH - remains_data_length;
             let mut buffer: [u8; MD5_BUFFER_BYTE_LENGTH] = [0; MD5_BUFFER_BYTE_LENGTH];
-            for i in 0..self.remains_data.len() {
-                buffer[i] = self.remains_data[i];
-            }
+            buffer[..remains_data_length].copy_from_slice(&self.remains_data);
             self.remains_data.clear();
-            for i in remains_data_length..MD5_BUFFER_BYTE_LENGTH {
-                buffer[i] = data[i - remains_data_length];
-            }
+            buffer[remains_data_length..].copy_from_slice(&data[..offset]);
             self.update(&buffer);
         }
         let buffer_count = (data.len() - offset) / MD5_BUFFER_BYTE_LENGTH;
-        for i in 0..buffer_count  {
-            self.update(data[(i * MD5_BUFFER_BYTE_LENGTH + offset)..(i * MD5_BUFFER_BYTE_LENGTH + offset + MD5_BUFFER_BYTE_LENGTH)].try_into().unwrap());
-        }
-        for i in (buffer_count * MD5_BUFFER_BYTE_LENGTH + offset)..data.len() {
-            self.remains_data.push(data[i]);
+        for block in data[offset..(buffer_count * MD5_BUFFER_BYTE_LENGTH + offset)].chunks_exact(MD5_BUFFER_BYTE_LENGTH) {
+            self.update(block.try_into().unwrap());
         }
+        self.remains_data.extend_from_slice(&data[(buffer_count * MD5_BUFFER_BYTE_LENGTH + offset)..]);
     }
 
+    #[allow(clippy::zero_prefixed_literal)]
     fn update(&mut self, buffer: &[u8; MD5_BUFFER_BYTE_LENGTH]) {
         let mut a = self.a;
         let mut b = self.b;
@@ -243,3 +242,27 @@ impl MD5Digest {
         self.d = self.d.wrapping_add(d);
     }
 }
+
+impl Digest for MD5Digest {
+    const ALGORITHM_NAME: &'static str = "MD5";
+    const DIGEST_BYTE_LENGTH: usize = MD5_DIGEST_BYTE_LENGTH;
+    const BUFFER_BYTE_LENGTH: usize = MD5_BUFFER_BYTE_LENGTH;
+
+    type DigestBytes = [u8; MD5_DIGEST_BYTE_LENGTH];
+
+    fn new() -> MD5Digest {
+        MD5Digest::new()
+    }
+
+    fn get_digest_bytes(&mut self) -> [u8; MD5_DIGEST_BYTE_LENGTH] {
+        MD5Digest::get_digest_bytes(self)
+    }
+
+    fn compute_digest(&mut self) {
+        MD5Digest::compute_digest(self)
+    }
+
+    fn push_data(&mut self, data: &[u8]) {
+        MD5Digest::push_data(self, data)
+    }
+}
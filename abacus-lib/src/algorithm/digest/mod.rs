@@ -1,3 +1,6 @@
+pub mod digest_trait;
+pub mod digest_utils;
 pub mod sm3;
 pub mod md5;
 pub mod sha2;
+pub mod sha3;
@@ -4,3 +4,9 @@ pub mod sha2_256_digest;
 pub mod sha2_224_digest;
 pub mod sha2_utils;
 pub mod sha2_256_base_digest;
+pub mod sha2_512_type;
+pub mod sha2_512_digest;
+pub mod sha2_384_digest;
+pub mod sha2_512_224_digest;
+pub mod sha2_512_256_digest;
+pub mod sha2_512_base_digest;
@@ -42,6 +42,7 @@ fn big_endian_word(buffer: &[u8; SHA2_256_BUFFER_BYTE_LENGTH], i: usize) -> u32
 }
 
 #[inline(always)]
+#[allow(clippy::too_many_arguments)]
 fn round_00_64(x1: u32, x2: u32, x3: u32, x4: &mut u32, x5: u32, x6: u32, x7: u32, x8: &mut u32, t: u32, k: u32) {
     let tt = t.wrapping_add(*x8).wrapping_add(sigma01(x5)).wrapping_add(ch(x5, x6, x7)).wrapping_add(k);
     *x8 = sigma00(x1).wrapping_add(ma(x1, x2, x3)).wrapping_add(tt);
@@ -51,7 +52,7 @@ fn round_00_64(x1: u32, x2: u32, x3: u32, x4: &mut u32, x5: u32, x6: u32, x7: u3
 #[inline(always)]
 fn fill_to_bytes(digest_bytes: &mut [u8; SHA2_256_DIGEST_BYTE_LENGTH], x: u32, index: usize) {
     let word_bytes = x.to_be_bytes();
-    digest_bytes[index * 4 + 0] = word_bytes[0];
+    digest_bytes[index * 4] = word_bytes[0];
     digest_bytes[index * 4 + 1] = word_bytes[1];
     digest_bytes[index * 4 + 2] = word_bytes[2];
     digest_bytes[index * 4 + 3] = word_bytes[3];
@@ -86,7 +87,7 @@ pub struct SHA2_256BaseDigest {
 impl SHA2_256BaseDigest {
     pub fn new(sha256_type: SHA2_256Type) -> SHA2_256BaseDigest {
         let init_value = sha256_type.init_value();
-        let instance = SHA2_256BaseDigest {
+        SHA2_256BaseDigest {
             a: init_value[0],
             b: init_value[1],
             c: init_value[2],
@@ -97,8 +98,7 @@ impl SHA2_256BaseDigest {
             h: init_value[7],
             total_length: 0,
             remains_data: Vec::new()
-        };
-        instance
+        }
     }
 
     pub fn get_digest_bytes(&mut self) -> [u8; SHA2_256_DIGEST_BYTE_LENGTH] {
@@ -118,9 +118,7 @@ impl SHA2_256BaseDigest {
         let remains_data_length = self.remains_data.len();
         let min_padding_byte_length = remains_data_length + SHA2_256_DATA_BYTE_MAX_LENGTH + 1;
         let mut buffer: [u8; SHA2_256_BUFFER_BYTE_LENGTH] = [0; SHA2_256_BUFFER_BYTE_LENGTH];
-        for i in 0..self.remains_data.len() {
-            buffer[i] = self.remains_data[i];
-        }
+        buffer[..remains_data_length].copy_from_slice(&self.remains_data);
         self.remains_data.clear();
         buffer[remains_data_length] = 0x80;
         if min_padding_byte_length > SHA2_256_BUFFER_BYTE_LENGTH {
@@ -142,22 +140,16 @@ impl SHA2_256BaseDigest {
         if remains_data_length > 0 && remains_data_length + data.len() >= SHA2_256_BUFFER_BYTE_LENGTH {
             offset = SHA2_256_BUFFER_BYTE_LENGTH - remains_data_length;
             let mut buffer: [u8; SHA2_256_BUFFER_BYTE_LENGTH] = [0; SHA2_256_BUFFER_BYTE_LENGTH];
-            for i in 0..self.remains_data.len() {
-                buffer[i] = self.remains_data[i];
-            }
+            buffer[..remains_data_length].copy_from_slice(&self.remains_data);
             self.remains_data.clear();
-            for i in remains_data_length..SHA2_256_BUFFER_BYTE_LENGTH {
-                buffer[i] = data[i - remains_data_length];
-            }
+            buffer[remains_data_length..].copy_from_slice(&data[..offset]);
             self.update(&buffer);
         }
         let buffer_count = (data.len() - offset) / SHA2_256_BUFFER_BYTE_LENGTH;
-        for i in 0..buffer_count  {
-            self.update(data[(i * SHA2_256_BUFFER_BYTE_LENGTH + offset)..(i * SHA2_256_BUFFER_BYTE_LENGTH + offset + SHA2_256_BUFFER_BYTE_LENGTH)].try_into().unwrap());
-        }
-        for i in (buffer_count * SHA2_256_BUFFER_BYTE_LENGTH + offset)..data.len() {
-            self.remains_data.push(data[i]);
+        for block in data[offset..(buffer_count * SHA2_256_BUFFER_BYTE_LENGTH + offset)].chunks_exact(SHA2_256_BUFFER_BYTE_LENGTH) {
+            self.update(block.try_into().unwrap());
         }
+        self.remains_data.extend_from_slice(&data[(buffer_count * SHA2_256_BUFFER_BYTE_LENGTH + offset)..]);
     }
 
     fn update(&mut self, buffer: &[u8; SHA2_256_BUFFER_BYTE_LENGTH]) {
@@ -1,17 +1,23 @@
+use super::super::digest_trait::Digest;
 use super::sha2_256_base_digest::SHA2_256BaseDigest;
 use super::sha2_256_type::SHA2_256Type;
-use super::sha2_constant::SHA2_256_DIGEST_BYTE_LENGTH;
+use super::sha2_constant::{SHA2_256_DIGEST_BYTE_LENGTH, SHA2_256_BUFFER_BYTE_LENGTH};
 
 pub struct SHA2_256Digest {
     sha2_256_base_digest: SHA2_256BaseDigest
 }
 
+impl Default for SHA2_256Digest {
+    fn default() -> Self {
+        SHA2_256Digest::new()
+    }
+}
+
 impl SHA2_256Digest {
     pub fn new() -> SHA2_256Digest {
-        let instance = SHA2_256Digest {
+        SHA2_256Digest {
             sha2_256_base_digest: SHA2_256BaseDigest::new(SHA2_256Type::SHA256)
-        };
-        instance
+        }
     }
 
     pub fn get_digest_bytes(&mut self) -> [u8; SHA2_256_DIGEST_BYTE_LENGTH] {
@@ -26,3 +32,27 @@ impl SHA2_256Digest {
         self.sha2_256_base_digest.push_data(data)
     }
 }
+
+impl Digest for SHA2_256Digest {
+    const ALGORITHM_NAME: &'static str = "SHA-256";
+    const DIGEST_BYTE_LENGTH: usize = SHA2_256_DIGEST_BYTE_LENGTH;
+    const BUFFER_BYTE_LENGTH: usize = SHA2_256_BUFFER_BYTE_LENGTH;
+
+    type DigestBytes = [u8; SHA2_256_DIGEST_BYTE_LENGTH];
+
+    fn new() -> SHA2_256Digest {
+        SHA2_256Digest::new()
+    }
+
+    fn get_digest_bytes(&mut self) -> [u8; SHA2_256_DIGEST_BYTE_LENGTH] {
+        SHA2_256Digest::get_digest_bytes(self)
+    }
+
+    fn compute_digest(&mut self) {
+        SHA2_256Digest::compute_digest(self)
+    }
+
+    fn push_data(&mut self, data: &[u8]) {
+        SHA2_256Digest::push_data(self, data)
+    }
+}
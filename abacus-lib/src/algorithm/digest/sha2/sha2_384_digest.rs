@@ -0,0 +1,98 @@
+use super::super::digest_trait::Digest;
+use super::sha2_512_base_digest::SHA2_512BaseDigest;
+use super::sha2_512_type::SHA2_512Type;
+use super::sha2_constant::{SHA2_384_DIGEST_BYTE_LENGTH, SHA2_512_BUFFER_BYTE_LENGTH};
+
+pub struct SHA2_384Digest {
+    sha2_512_base_digest: SHA2_512BaseDigest
+}
+
+impl Default for SHA2_384Digest {
+    fn default() -> Self {
+        SHA2_384Digest::new()
+    }
+}
+
+impl SHA2_384Digest {
+    pub fn new() -> SHA2_384Digest {
+        SHA2_384Digest {
+            sha2_512_base_digest: SHA2_512BaseDigest::new(SHA2_512Type::SHA384)
+        }
+    }
+
+    pub fn get_digest_bytes(&mut self) -> [u8; SHA2_384_DIGEST_BYTE_LENGTH] {
+        self.sha2_512_base_digest.get_digest_bytes()[..SHA2_384_DIGEST_BYTE_LENGTH].try_into().unwrap()
+    }
+
+    pub fn compute_digest(&mut self) {
+        self.sha2_512_base_digest.compute_digest()
+    }
+
+    pub fn push_data(&mut self, data: &[u8]) {
+        self.sha2_512_base_digest.push_data(data)
+    }
+}
+
+impl Digest for SHA2_384Digest {
+    const ALGORITHM_NAME: &'static str = "SHA-384";
+    const DIGEST_BYTE_LENGTH: usize = SHA2_384_DIGEST_BYTE_LENGTH;
+    const BUFFER_BYTE_LENGTH: usize = SHA2_512_BUFFER_BYTE_LENGTH;
+
+    type DigestBytes = [u8; SHA2_384_DIGEST_BYTE_LENGTH];
+
+    fn new() -> SHA2_384Digest {
+        SHA2_384Digest::new()
+    }
+
+    fn get_digest_bytes(&mut self) -> [u8; SHA2_384_DIGEST_BYTE_LENGTH] {
+        SHA2_384Digest::get_digest_bytes(self)
+    }
+
+    fn compute_digest(&mut self) {
+        SHA2_384Digest::compute_digest(self)
+    }
+
+    fn push_data(&mut self, data: &[u8]) {
+        SHA2_384Digest::push_data(self, data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::hex::hex_encode;
+    use super::SHA2_384Digest;
+
+    const TWO_BLOCK_MESSAGE: &[u8] = b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrst";
+
+    fn digest(data: &[u8]) -> String {
+        let mut digest = SHA2_384Digest::new();
+        digest.push_data(data);
+        digest.compute_digest();
+        hex_encode(&digest.get_digest_bytes())
+    }
+
+    #[test]
+    fn empty_message() {
+        assert_eq!(digest(b""), "38b060a751ac96384cd9327eb1b1e36a21fdb71114be07434c0cc7bf63f6e1da274edebfe76f65fbd51ad2f14898b95b");
+    }
+
+    #[test]
+    fn one_block_message() {
+        assert_eq!(digest(b"abc"), "cb00753f45a35e8bb5a03d699ac65007272c32ab0eded1631a8b605a43ff5bed8086072ba1e7cc2358baeca134c825a7");
+    }
+
+    #[test]
+    fn two_block_message() {
+        assert_eq!(digest(TWO_BLOCK_MESSAGE), "868ab176f2c420834107ed4c4aecc849af9dd58e1a8566c7c0c30e695661189e281387c43ecc52a53eac1050284d98eb");
+    }
+
+    #[test]
+    fn two_block_message_pushed_in_pieces() {
+        let mut digest = SHA2_384Digest::new();
+        for piece in TWO_BLOCK_MESSAGE.chunks(7) {
+            digest.push_data(piece);
+        }
+        digest.compute_digest();
+        assert_eq!(hex_encode(&digest.get_digest_bytes()), "868ab176f2c420834107ed4c4aecc849af9dd58e1a8566c7c0c30e695661189e281387c43ecc52a53eac1050284d98eb");
+    }
+}
@@ -0,0 +1,98 @@
+use super::super::digest_trait::Digest;
+use super::sha2_512_base_digest::SHA2_512BaseDigest;
+use super::sha2_512_type::SHA2_512Type;
+use super::sha2_constant::{SHA2_512_224_DIGEST_BYTE_LENGTH, SHA2_512_BUFFER_BYTE_LENGTH};
+
+pub struct SHA2_512_224Digest {
+    sha2_512_base_digest: SHA2_512BaseDigest
+}
+
+impl Default for SHA2_512_224Digest {
+    fn default() -> Self {
+        SHA2_512_224Digest::new()
+    }
+}
+
+impl SHA2_512_224Digest {
+    pub fn new() -> SHA2_512_224Digest {
+        SHA2_512_224Digest {
+            sha2_512_base_digest: SHA2_512BaseDigest::new(SHA2_512Type::SHA512_224)
+        }
+    }
+
+    pub fn get_digest_bytes(&mut self) -> [u8; SHA2_512_224_DIGEST_BYTE_LENGTH] {
+        self.sha2_512_base_digest.get_digest_bytes()[..SHA2_512_224_DIGEST_BYTE_LENGTH].try_into().unwrap()
+    }
+
+    pub fn compute_digest(&mut self) {
+        self.sha2_512_base_digest.compute_digest()
+    }
+
+    pub fn push_data(&mut self, data: &[u8]) {
+        self.sha2_512_base_digest.push_data(data)
+    }
+}
+
+impl Digest for SHA2_512_224Digest {
+    const ALGORITHM_NAME: &'static str = "SHA-512/224";
+    const DIGEST_BYTE_LENGTH: usize = SHA2_512_224_DIGEST_BYTE_LENGTH;
+    const BUFFER_BYTE_LENGTH: usize = SHA2_512_BUFFER_BYTE_LENGTH;
+
+    type DigestBytes = [u8; SHA2_512_224_DIGEST_BYTE_LENGTH];
+
+    fn new() -> SHA2_512_224Digest {
+        SHA2_512_224Digest::new()
+    }
+
+    fn get_digest_bytes(&mut self) -> [u8; SHA2_512_224_DIGEST_BYTE_LENGTH] {
+        SHA2_512_224Digest::get_digest_bytes(self)
+    }
+
+    fn compute_digest(&mut self) {
+        SHA2_512_224Digest::compute_digest(self)
+    }
+
+    fn push_data(&mut self, data: &[u8]) {
+        SHA2_512_224Digest::push_data(self, data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::hex::hex_encode;
+    use super::SHA2_512_224Digest;
+
+    const TWO_BLOCK_MESSAGE: &[u8] = b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrst";
+
+    fn digest(data: &[u8]) -> String {
+        let mut digest = SHA2_512_224Digest::new();
+        digest.push_data(data);
+        digest.compute_digest();
+        hex_encode(&digest.get_digest_bytes())
+    }
+
+    #[test]
+    fn empty_message() {
+        assert_eq!(digest(b""), "6ed0dd02806fa89e25de060c19d3ac86cabb87d6a0ddd05c333b84f4");
+    }
+
+    #[test]
+    fn one_block_message() {
+        assert_eq!(digest(b"abc"), "4634270f707b6a54daae7530460842e20e37ed265ceee9a43e8924aa");
+    }
+
+    #[test]
+    fn two_block_message() {
+        assert_eq!(digest(TWO_BLOCK_MESSAGE), "9b54fd675a5134f2dc8a34d14da90e2718e66456f3df9a513cb7eb80");
+    }
+
+    #[test]
+    fn two_block_message_pushed_in_pieces() {
+        let mut digest = SHA2_512_224Digest::new();
+        for piece in TWO_BLOCK_MESSAGE.chunks(7) {
+            digest.push_data(piece);
+        }
+        digest.compute_digest();
+        assert_eq!(hex_encode(&digest.get_digest_bytes()), "9b54fd675a5134f2dc8a34d14da90e2718e66456f3df9a513cb7eb80");
+    }
+}
@@ -0,0 +1,98 @@
+use super::super::digest_trait::Digest;
+use super::sha2_512_base_digest::SHA2_512BaseDigest;
+use super::sha2_512_type::SHA2_512Type;
+use super::sha2_constant::{SHA2_512_256_DIGEST_BYTE_LENGTH, SHA2_512_BUFFER_BYTE_LENGTH};
+
+pub struct SHA2_512_256Digest {
+    sha2_512_base_digest: SHA2_512BaseDigest
+}
+
+impl Default for SHA2_512_256Digest {
+    fn default() -> Self {
+        SHA2_512_256Digest::new()
+    }
+}
+
+impl SHA2_512_256Digest {
+    pub fn new() -> SHA2_512_256Digest {
+        SHA2_512_256Digest {
+            sha2_512_base_digest: SHA2_512BaseDigest::new(SHA2_512Type::SHA512_256)
+        }
+    }
+
+    pub fn get_digest_bytes(&mut self) -> [u8; SHA2_512_256_DIGEST_BYTE_LENGTH] {
+        self.sha2_512_base_digest.get_digest_bytes()[..SHA2_512_256_DIGEST_BYTE_LENGTH].try_into().unwrap()
+    }
+
+    pub fn compute_digest(&mut self) {
+        self.sha2_512_base_digest.compute_digest()
+    }
+
+    pub fn push_data(&mut self, data: &[u8]) {
+        self.sha2_512_base_digest.push_data(data)
+    }
+}
+
+impl Digest for SHA2_512_256Digest {
+    const ALGORITHM_NAME: &'static str = "SHA-512/256";
+    const DIGEST_BYTE_LENGTH: usize = SHA2_512_256_DIGEST_BYTE_LENGTH;
+    const BUFFER_BYTE_LENGTH: usize = SHA2_512_BUFFER_BYTE_LENGTH;
+
+    type DigestBytes = [u8; SHA2_512_256_DIGEST_BYTE_LENGTH];
+
+    fn new() -> SHA2_512_256Digest {
+        SHA2_512_256Digest::new()
+    }
+
+    fn get_digest_bytes(&mut self) -> [u8; SHA2_512_256_DIGEST_BYTE_LENGTH] {
+        SHA2_512_256Digest::get_digest_bytes(self)
+    }
+
+    fn compute_digest(&mut self) {
+        SHA2_512_256Digest::compute_digest(self)
+    }
+
+    fn push_data(&mut self, data: &[u8]) {
+        SHA2_512_256Digest::push_data(self, data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::hex::hex_encode;
+    use super::SHA2_512_256Digest;
+
+    const TWO_BLOCK_MESSAGE: &[u8] = b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrst";
+
+    fn digest(data: &[u8]) -> String {
+        let mut digest = SHA2_512_256Digest::new();
+        digest.push_data(data);
+        digest.compute_digest();
+        hex_encode(&digest.get_digest_bytes())
+    }
+
+    #[test]
+    fn empty_message() {
+        assert_eq!(digest(b""), "c672b8d1ef56ed28ab87c3622c5114069bdd3ad7b8f9737498d0c01ecef0967a");
+    }
+
+    #[test]
+    fn one_block_message() {
+        assert_eq!(digest(b"abc"), "53048e2681941ef99b2e29b76b4c7dabe4c2d0c634fc6d46e0e2f13107e7af23");
+    }
+
+    #[test]
+    fn two_block_message() {
+        assert_eq!(digest(TWO_BLOCK_MESSAGE), "1955cb7dc4bc04d4f127b7cbbd73a8201120f905f9f447e47f5d8ab9a94a1365");
+    }
+
+    #[test]
+    fn two_block_message_pushed_in_pieces() {
+        let mut digest = SHA2_512_256Digest::new();
+        for piece in TWO_BLOCK_MESSAGE.chunks(7) {
+            digest.push_data(piece);
+        }
+        digest.compute_digest();
+        assert_eq!(hex_encode(&digest.get_digest_bytes()), "1955cb7dc4bc04d4f127b7cbbd73a8201120f905f9f447e47f5d8ab9a94a1365");
+    }
+}
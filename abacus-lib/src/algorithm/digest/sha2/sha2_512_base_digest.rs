@@ -0,0 +1,401 @@
+use super::sha2_512_type::SHA2_512Type;
+use super::sha2_constant::{SHA2_512_BUFFER_BYTE_LENGTH, SHA2_512_DIGEST_BYTE_LENGTH, SHA2_512_DATA_BYTE_MAX_LENGTH};
+
+#[inline(always)]
+fn sigma00(x: u64) -> u64 {
+    x.rotate_left(36) ^ x.rotate_left(30) ^ x.rotate_left(25)
+}
+
+#[inline(always)]
+fn sigma01(x: u64) -> u64 {
+    x.rotate_left(50) ^ x.rotate_left(46) ^ x.rotate_left(23)
+}
+
+#[inline(always)]
+fn sigma10(x: u64) -> u64 {
+    x.rotate_left(63) ^ x.rotate_left(56) ^ (x >> 7)
+}
+
+#[inline(always)]
+fn sigma11(x: u64) -> u64 {
+    x.rotate_left(45) ^ x.rotate_left(3) ^ (x >> 6)
+}
+
+#[inline(always)]
+fn ch(x: u64, y: u64, z: u64) -> u64 {
+    z ^ (x & (y ^ z))
+}
+
+#[inline(always)]
+fn ma(x: u64, y: u64, z: u64) -> u64 {
+    (x & y) ^ (x & z) ^ (y & z)
+}
+
+#[inline(always)]
+fn expand(a: u64, b: u64, c: u64, d: u64) -> u64 {
+    a.wrapping_add(sigma10(b)).wrapping_add(sigma11(c)).wrapping_add(d)
+}
+
+#[inline(always)]
+fn big_endian_word(buffer: &[u8; SHA2_512_BUFFER_BYTE_LENGTH], i: usize) -> u64 {
+    u64::from_be_bytes(buffer[(i * 8)..(i * 8 + 8)].try_into().unwrap())
+}
+
+#[inline(always)]
+#[allow(clippy::too_many_arguments)]
+fn round_00_80(x1: u64, x2: u64, x3: u64, x4: &mut u64, x5: u64, x6: u64, x7: u64, x8: &mut u64, t: u64, k: u64) {
+    let tt = t.wrapping_add(*x8).wrapping_add(sigma01(x5)).wrapping_add(ch(x5, x6, x7)).wrapping_add(k);
+    *x8 = sigma00(x1).wrapping_add(ma(x1, x2, x3)).wrapping_add(tt);
+    *x4 = x4.wrapping_add(tt);
+}
+
+#[inline(always)]
+fn fill_to_bytes(digest_bytes: &mut [u8; SHA2_512_DIGEST_BYTE_LENGTH], x: u64, index: usize) {
+    let word_bytes = x.to_be_bytes();
+    digest_bytes[index * 8] = word_bytes[0];
+    digest_bytes[index * 8 + 1] = word_bytes[1];
+    digest_bytes[index * 8 + 2] = word_bytes[2];
+    digest_bytes[index * 8 + 3] = word_bytes[3];
+    digest_bytes[index * 8 + 4] = word_bytes[4];
+    digest_bytes[index * 8 + 5] = word_bytes[5];
+    digest_bytes[index * 8 + 6] = word_bytes[6];
+    digest_bytes[index * 8 + 7] = word_bytes[7];
+}
+
+#[inline(always)]
+fn put_data_length(buffer: &mut [u8; SHA2_512_BUFFER_BYTE_LENGTH], length: u128) {
+    let length_bytes = length.to_be_bytes();
+    buffer[SHA2_512_BUFFER_BYTE_LENGTH - 1] = length_bytes[15];
+    buffer[SHA2_512_BUFFER_BYTE_LENGTH - 2] = length_bytes[14];
+    buffer[SHA2_512_BUFFER_BYTE_LENGTH - 3] = length_bytes[13];
+    buffer[SHA2_512_BUFFER_BYTE_LENGTH - 4] = length_bytes[12];
+    buffer[SHA2_512_BUFFER_BYTE_LENGTH - 5] = length_bytes[11];
+    buffer[SHA2_512_BUFFER_BYTE_LENGTH - 6] = length_bytes[10];
+    buffer[SHA2_512_BUFFER_BYTE_LENGTH - 7] = length_bytes[9];
+    buffer[SHA2_512_BUFFER_BYTE_LENGTH - 8] = length_bytes[8];
+    buffer[SHA2_512_BUFFER_BYTE_LENGTH - 9] = length_bytes[7];
+    buffer[SHA2_512_BUFFER_BYTE_LENGTH - 10] = length_bytes[6];
+    buffer[SHA2_512_BUFFER_BYTE_LENGTH - 11] = length_bytes[5];
+    buffer[SHA2_512_BUFFER_BYTE_LENGTH - 12] = length_bytes[4];
+    buffer[SHA2_512_BUFFER_BYTE_LENGTH - 13] = length_bytes[3];
+    buffer[SHA2_512_BUFFER_BYTE_LENGTH - 14] = length_bytes[2];
+    buffer[SHA2_512_BUFFER_BYTE_LENGTH - 15] = length_bytes[1];
+    buffer[SHA2_512_BUFFER_BYTE_LENGTH - 16] = length_bytes[0];
+}
+
+pub struct SHA2_512BaseDigest {
+    a: u64,
+    b: u64,
+    c: u64,
+    d: u64,
+    e: u64,
+    f: u64,
+    g: u64,
+    h: u64,
+    total_length: u128,
+    remains_data: Vec<u8>,
+}
+
+impl SHA2_512BaseDigest {
+    pub fn new(sha512_type: SHA2_512Type) -> SHA2_512BaseDigest {
+        let init_value = sha512_type.init_value();
+        SHA2_512BaseDigest {
+            a: init_value[0],
+            b: init_value[1],
+            c: init_value[2],
+            d: init_value[3],
+            e: init_value[4],
+            f: init_value[5],
+            g: init_value[6],
+            h: init_value[7],
+            total_length: 0,
+            remains_data: Vec::new()
+        }
+    }
+
+    pub fn get_digest_bytes(&mut self) -> [u8; SHA2_512_DIGEST_BYTE_LENGTH] {
+        let mut digest_bytes = [0; SHA2_512_DIGEST_BYTE_LENGTH];
+        fill_to_bytes(&mut digest_bytes, self.a, 0);
+        fill_to_bytes(&mut digest_bytes, self.b, 1);
+        fill_to_bytes(&mut digest_bytes, self.c, 2);
+        fill_to_bytes(&mut digest_bytes, self.d, 3);
+        fill_to_bytes(&mut digest_bytes, self.e, 4);
+        fill_to_bytes(&mut digest_bytes, self.f, 5);
+        fill_to_bytes(&mut digest_bytes, self.g, 6);
+        fill_to_bytes(&mut digest_bytes, self.h, 7);
+        digest_bytes
+    }
+
+    pub fn compute_digest(&mut self) {
+        let remains_data_length = self.remains_data.len();
+        let min_padding_byte_length = remains_data_length + SHA2_512_DATA_BYTE_MAX_LENGTH + 1;
+        let mut buffer: [u8; SHA2_512_BUFFER_BYTE_LENGTH] = [0; SHA2_512_BUFFER_BYTE_LENGTH];
+        buffer[..remains_data_length].copy_from_slice(&self.remains_data);
+        self.remains_data.clear();
+        buffer[remains_data_length] = 0x80;
+        if min_padding_byte_length > SHA2_512_BUFFER_BYTE_LENGTH {
+            self.update(&buffer);
+            buffer.fill(0x00);
+            put_data_length(&mut buffer, self.total_length);
+            self.update(&buffer);
+        } else {
+            put_data_length(&mut buffer, self.total_length);
+            self.update(&buffer);
+        }
+    }
+
+    pub fn push_data(&mut self, data: &[u8]) {
+        let data_length = (data.len() as u128) << 3;
+        self.total_length += data_length;
+        let remains_data_length = self.remains_data.len();
+        let mut offset: usize = 0;
+        if remains_data_length > 0 && remains_data_length + data.len() >= SHA2_512_BUFFER_BYTE_LENGTH {
+            offset = SHA2_512_BUFFER_BYTE_LENGTH - remains_data_length;
+            let mut buffer: [u8; SHA2_512_BUFFER_BYTE_LENGTH] = [0; SHA2_512_BUFFER_BYTE_LENGTH];
+            buffer[..remains_data_length].copy_from_slice(&self.remains_data);
+            self.remains_data.clear();
+            buffer[remains_data_length..].copy_from_slice(&data[..offset]);
+            self.update(&buffer);
+        }
+        let buffer_count = (data.len() - offset) / SHA2_512_BUFFER_BYTE_LENGTH;
+        for block in data[offset..(buffer_count * SHA2_512_BUFFER_BYTE_LENGTH + offset)].chunks_exact(SHA2_512_BUFFER_BYTE_LENGTH) {
+            self.update(block.try_into().unwrap());
+        }
+        self.remains_data.extend_from_slice(&data[(buffer_count * SHA2_512_BUFFER_BYTE_LENGTH + offset)..]);
+    }
+
+    fn update(&mut self, buffer: &[u8; SHA2_512_BUFFER_BYTE_LENGTH]) {
+        let mut a = self.a;
+        let mut b = self.b;
+        let mut c = self.c;
+        let mut d = self.d;
+        let mut e = self.e;
+        let mut f = self.f;
+        let mut g = self.g;
+        let mut h = self.h;
+        let mut w00 = big_endian_word(buffer, 0);
+        let mut w01 = big_endian_word(buffer, 1);
+        let mut w02 = big_endian_word(buffer, 2);
+        let mut w03 = big_endian_word(buffer, 3);
+        let mut w04 = big_endian_word(buffer, 4);
+        let mut w05 = big_endian_word(buffer, 5);
+        let mut w06 = big_endian_word(buffer, 6);
+        let mut w07 = big_endian_word(buffer, 7);
+        let mut w08 = big_endian_word(buffer, 8);
+        let mut w09 = big_endian_word(buffer, 9);
+        let mut w10 = big_endian_word(buffer, 10);
+        let mut w11 = big_endian_word(buffer, 11);
+        let mut w12 = big_endian_word(buffer, 12);
+        let mut w13 = big_endian_word(buffer, 13);
+        let mut w14 = big_endian_word(buffer, 14);
+        let mut w15 = big_endian_word(buffer, 15);
+        round_00_80(a, b, c, &mut d, e, f, g, &mut h, w00, 0x428a2f98d728ae22);
+        round_00_80(h, a, b, &mut c, d, e, f, &mut g, w01, 0x7137449123ef65cd);
+        round_00_80(g, h, a, &mut b, c, d, e, &mut f, w02, 0xb5c0fbcfec4d3b2f);
+        round_00_80(f, g, h, &mut a, b, c, d, &mut e, w03, 0xe9b5dba58189dbbc);
+        round_00_80(e, f, g, &mut h, a, b, c, &mut d, w04, 0x3956c25bf348b538);
+        round_00_80(d, e, f, &mut g, h, a, b, &mut c, w05, 0x59f111f1b605d019);
+        round_00_80(c, d, e, &mut f, g, h, a, &mut b, w06, 0x923f82a4af194f9b);
+        round_00_80(b, c, d, &mut e, f, g, h, &mut a, w07, 0xab1c5ed5da6d8118);
+        round_00_80(a, b, c, &mut d, e, f, g, &mut h, w08, 0xd807aa98a3030242);
+        round_00_80(h, a, b, &mut c, d, e, f, &mut g, w09, 0x12835b0145706fbe);
+        round_00_80(g, h, a, &mut b, c, d, e, &mut f, w10, 0x243185be4ee4b28c);
+        round_00_80(f, g, h, &mut a, b, c, d, &mut e, w11, 0x550c7dc3d5ffb4e2);
+        round_00_80(e, f, g, &mut h, a, b, c, &mut d, w12, 0x72be5d74f27b896f);
+        round_00_80(d, e, f, &mut g, h, a, b, &mut c, w13, 0x80deb1fe3b1696b1);
+        round_00_80(c, d, e, &mut f, g, h, a, &mut b, w14, 0x9bdc06a725c71235);
+        round_00_80(b, c, d, &mut e, f, g, h, &mut a, w15, 0xc19bf174cf692694);
+        w00 = expand(w00, w01, w14, w09);
+        round_00_80(a, b, c, &mut d, e, f, g, &mut h, w00, 0xe49b69c19ef14ad2);
+        w01 = expand(w01, w02, w15, w10);
+        round_00_80(h, a, b, &mut c, d, e, f, &mut g, w01, 0xefbe4786384f25e3);
+        w02 = expand(w02, w03, w00, w11);
+        round_00_80(g, h, a, &mut b, c, d, e, &mut f, w02, 0x0fc19dc68b8cd5b5);
+        w03 = expand(w03, w04, w01, w12);
+        round_00_80(f, g, h, &mut a, b, c, d, &mut e, w03, 0x240ca1cc77ac9c65);
+        w04 = expand(w04, w05, w02, w13);
+        round_00_80(e, f, g, &mut h, a, b, c, &mut d, w04, 0x2de92c6f592b0275);
+        w05 = expand(w05, w06, w03, w14);
+        round_00_80(d, e, f, &mut g, h, a, b, &mut c, w05, 0x4a7484aa6ea6e483);
+        w06 = expand(w06, w07, w04, w15);
+        round_00_80(c, d, e, &mut f, g, h, a, &mut b, w06, 0x5cb0a9dcbd41fbd4);
+        w07 = expand(w07, w08, w05, w00);
+        round_00_80(b, c, d, &mut e, f, g, h, &mut a, w07, 0x76f988da831153b5);
+        w08 = expand(w08, w09, w06, w01);
+        round_00_80(a, b, c, &mut d, e, f, g, &mut h, w08, 0x983e5152ee66dfab);
+        w09 = expand(w09, w10, w07, w02);
+        round_00_80(h, a, b, &mut c, d, e, f, &mut g, w09, 0xa831c66d2db43210);
+        w10 = expand(w10, w11, w08, w03);
+        round_00_80(g, h, a, &mut b, c, d, e, &mut f, w10, 0xb00327c898fb213f);
+        w11 = expand(w11, w12, w09, w04);
+        round_00_80(f, g, h, &mut a, b, c, d, &mut e, w11, 0xbf597fc7beef0ee4);
+        w12 = expand(w12, w13, w10, w05);
+        round_00_80(e, f, g, &mut h, a, b, c, &mut d, w12, 0xc6e00bf33da88fc2);
+        w13 = expand(w13, w14, w11, w06);
+        round_00_80(d, e, f, &mut g, h, a, b, &mut c, w13, 0xd5a79147930aa725);
+        w14 = expand(w14, w15, w12, w07);
+        round_00_80(c, d, e, &mut f, g, h, a, &mut b, w14, 0x06ca6351e003826f);
+        w15 = expand(w15, w00, w13, w08);
+        round_00_80(b, c, d, &mut e, f, g, h, &mut a, w15, 0x142929670a0e6e70);
+        w00 = expand(w00, w01, w14, w09);
+        round_00_80(a, b, c, &mut d, e, f, g, &mut h, w00, 0x27b70a8546d22ffc);
+        w01 = expand(w01, w02, w15, w10);
+        round_00_80(h, a, b, &mut c, d, e, f, &mut g, w01, 0x2e1b21385c26c926);
+        w02 = expand(w02, w03, w00, w11);
+        round_00_80(g, h, a, &mut b, c, d, e, &mut f, w02, 0x4d2c6dfc5ac42aed);
+        w03 = expand(w03, w04, w01, w12);
+        round_00_80(f, g, h, &mut a, b, c, d, &mut e, w03, 0x53380d139d95b3df);
+        w04 = expand(w04, w05, w02, w13);
+        round_00_80(e, f, g, &mut h, a, b, c, &mut d, w04, 0x650a73548baf63de);
+        w05 = expand(w05, w06, w03, w14);
+        round_00_80(d, e, f, &mut g, h, a, b, &mut c, w05, 0x766a0abb3c77b2a8);
+        w06 = expand(w06, w07, w04, w15);
+        round_00_80(c, d, e, &mut f, g, h, a, &mut b, w06, 0x81c2c92e47edaee6);
+        w07 = expand(w07, w08, w05, w00);
+        round_00_80(b, c, d, &mut e, f, g, h, &mut a, w07, 0x92722c851482353b);
+        w08 = expand(w08, w09, w06, w01);
+        round_00_80(a, b, c, &mut d, e, f, g, &mut h, w08, 0xa2bfe8a14cf10364);
+        w09 = expand(w09, w10, w07, w02);
+        round_00_80(h, a, b, &mut c, d, e, f, &mut g, w09, 0xa81a664bbc423001);
+        w10 = expand(w10, w11, w08, w03);
+        round_00_80(g, h, a, &mut b, c, d, e, &mut f, w10, 0xc24b8b70d0f89791);
+        w11 = expand(w11, w12, w09, w04);
+        round_00_80(f, g, h, &mut a, b, c, d, &mut e, w11, 0xc76c51a30654be30);
+        w12 = expand(w12, w13, w10, w05);
+        round_00_80(e, f, g, &mut h, a, b, c, &mut d, w12, 0xd192e819d6ef5218);
+        w13 = expand(w13, w14, w11, w06);
+        round_00_80(d, e, f, &mut g, h, a, b, &mut c, w13, 0xd69906245565a910);
+        w14 = expand(w14, w15, w12, w07);
+        round_00_80(c, d, e, &mut f, g, h, a, &mut b, w14, 0xf40e35855771202a);
+        w15 = expand(w15, w00, w13, w08);
+        round_00_80(b, c, d, &mut e, f, g, h, &mut a, w15, 0x106aa07032bbd1b8);
+        w00 = expand(w00, w01, w14, w09);
+        round_00_80(a, b, c, &mut d, e, f, g, &mut h, w00, 0x19a4c116b8d2d0c8);
+        w01 = expand(w01, w02, w15, w10);
+        round_00_80(h, a, b, &mut c, d, e, f, &mut g, w01, 0x1e376c085141ab53);
+        w02 = expand(w02, w03, w00, w11);
+        round_00_80(g, h, a, &mut b, c, d, e, &mut f, w02, 0x2748774cdf8eeb99);
+        w03 = expand(w03, w04, w01, w12);
+        round_00_80(f, g, h, &mut a, b, c, d, &mut e, w03, 0x34b0bcb5e19b48a8);
+        w04 = expand(w04, w05, w02, w13);
+        round_00_80(e, f, g, &mut h, a, b, c, &mut d, w04, 0x391c0cb3c5c95a63);
+        w05 = expand(w05, w06, w03, w14);
+        round_00_80(d, e, f, &mut g, h, a, b, &mut c, w05, 0x4ed8aa4ae3418acb);
+        w06 = expand(w06, w07, w04, w15);
+        round_00_80(c, d, e, &mut f, g, h, a, &mut b, w06, 0x5b9cca4f7763e373);
+        w07 = expand(w07, w08, w05, w00);
+        round_00_80(b, c, d, &mut e, f, g, h, &mut a, w07, 0x682e6ff3d6b2b8a3);
+        w08 = expand(w08, w09, w06, w01);
+        round_00_80(a, b, c, &mut d, e, f, g, &mut h, w08, 0x748f82ee5defb2fc);
+        w09 = expand(w09, w10, w07, w02);
+        round_00_80(h, a, b, &mut c, d, e, f, &mut g, w09, 0x78a5636f43172f60);
+        w10 = expand(w10, w11, w08, w03);
+        round_00_80(g, h, a, &mut b, c, d, e, &mut f, w10, 0x84c87814a1f0ab72);
+        w11 = expand(w11, w12, w09, w04);
+        round_00_80(f, g, h, &mut a, b, c, d, &mut e, w11, 0x8cc702081a6439ec);
+        w12 = expand(w12, w13, w10, w05);
+        round_00_80(e, f, g, &mut h, a, b, c, &mut d, w12, 0x90befffa23631e28);
+        w13 = expand(w13, w14, w11, w06);
+        round_00_80(d, e, f, &mut g, h, a, b, &mut c, w13, 0xa4506cebde82bde9);
+        w14 = expand(w14, w15, w12, w07);
+        round_00_80(c, d, e, &mut f, g, h, a, &mut b, w14, 0xbef9a3f7b2c67915);
+        w15 = expand(w15, w00, w13, w08);
+        round_00_80(b, c, d, &mut e, f, g, h, &mut a, w15, 0xc67178f2e372532b);
+        w00 = expand(w00, w01, w14, w09);
+        round_00_80(a, b, c, &mut d, e, f, g, &mut h, w00, 0xca273eceea26619c);
+        w01 = expand(w01, w02, w15, w10);
+        round_00_80(h, a, b, &mut c, d, e, f, &mut g, w01, 0xd186b8c721c0c207);
+        w02 = expand(w02, w03, w00, w11);
+        round_00_80(g, h, a, &mut b, c, d, e, &mut f, w02, 0xeada7dd6cde0eb1e);
+        w03 = expand(w03, w04, w01, w12);
+        round_00_80(f, g, h, &mut a, b, c, d, &mut e, w03, 0xf57d4f7fee6ed178);
+        w04 = expand(w04, w05, w02, w13);
+        round_00_80(e, f, g, &mut h, a, b, c, &mut d, w04, 0x06f067aa72176fba);
+        w05 = expand(w05, w06, w03, w14);
+        round_00_80(d, e, f, &mut g, h, a, b, &mut c, w05, 0x0a637dc5a2c898a6);
+        w06 = expand(w06, w07, w04, w15);
+        round_00_80(c, d, e, &mut f, g, h, a, &mut b, w06, 0x113f9804bef90dae);
+        w07 = expand(w07, w08, w05, w00);
+        round_00_80(b, c, d, &mut e, f, g, h, &mut a, w07, 0x1b710b35131c471b);
+        w08 = expand(w08, w09, w06, w01);
+        round_00_80(a, b, c, &mut d, e, f, g, &mut h, w08, 0x28db77f523047d84);
+        w09 = expand(w09, w10, w07, w02);
+        round_00_80(h, a, b, &mut c, d, e, f, &mut g, w09, 0x32caab7b40c72493);
+        w10 = expand(w10, w11, w08, w03);
+        round_00_80(g, h, a, &mut b, c, d, e, &mut f, w10, 0x3c9ebe0a15c9bebc);
+        w11 = expand(w11, w12, w09, w04);
+        round_00_80(f, g, h, &mut a, b, c, d, &mut e, w11, 0x431d67c49c100d4c);
+        w12 = expand(w12, w13, w10, w05);
+        round_00_80(e, f, g, &mut h, a, b, c, &mut d, w12, 0x4cc5d4becb3e42b6);
+        w13 = expand(w13, w14, w11, w06);
+        round_00_80(d, e, f, &mut g, h, a, b, &mut c, w13, 0x597f299cfc657e2a);
+        w14 = expand(w14, w15, w12, w07);
+        round_00_80(c, d, e, &mut f, g, h, a, &mut b, w14, 0x5fcb6fab3ad6faec);
+        w15 = expand(w15, w00, w13, w08);
+        round_00_80(b, c, d, &mut e, f, g, h, &mut a, w15, 0x6c44198c4a475817);
+        self.a = self.a.wrapping_add(a);
+        self.b = self.b.wrapping_add(b);
+        self.c = self.c.wrapping_add(c);
+        self.d = self.d.wrapping_add(d);
+        self.e = self.e.wrapping_add(e);
+        self.f = self.f.wrapping_add(f);
+        self.g = self.g.wrapping_add(g);
+        self.h = self.h.wrapping_add(h);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::hex::hex_encode;
+    use super::super::sha2_512_type::SHA2_512Type;
+    use super::SHA2_512BaseDigest;
+
+    fn digest(sha512_type: SHA2_512Type, data: &[u8]) -> String {
+        let mut digest = SHA2_512BaseDigest::new(sha512_type);
+        digest.push_data(data);
+        digest.compute_digest();
+        hex_encode(&digest.get_digest_bytes())
+    }
+
+    #[test]
+    fn padding_fits_in_last_block() {
+        assert_eq!(digest(SHA2_512Type::SHA512, &[b'a'; 111]), concat!(
+            "fa9121c7b32b9e01733d034cfc78cbf67f926c7ed83e82200ef8681819692176",
+            "0b4beff48404df811b953828274461673c68d04e297b0eb7b2b4d60fc6b566a2",
+        ));
+    }
+
+    #[test]
+    fn padding_spills_into_extra_block() {
+        assert_eq!(digest(SHA2_512Type::SHA512, &[b'a'; 112]), concat!(
+            "c01d080efd492776a1c43bd23dd99d0a2e626d481e16782e75d54c2503b5dc32",
+            "bd05f0f1ba33e568b88fd2d970929b719ecbb152f58f130a407c8830604b70ca",
+        ));
+        assert!(digest(SHA2_512Type::SHA384, &[b'a'; 112]).starts_with(concat!(
+            "187d4e07cb306103c69967bf544d0dfbe9042577599c73c3",
+            "30abc0cb64c61236d5ed565ee19119d8c31779a38f791fcd",
+        )));
+    }
+
+    #[test]
+    fn exact_block_message() {
+        assert_eq!(digest(SHA2_512Type::SHA512, &[b'a'; 128]), concat!(
+            "b73d1929aa615934e61a871596b3f3b33359f42b8175602e89f7e06e5f658a24",
+            "3667807ed300314b95cacdd579f3e33abdfbe351909519a846d465c59582f321",
+        ));
+    }
+
+    #[test]
+    fn incremental_push_matches_one_shot() {
+        let data: Vec<u8> = (0..300).map(|i| i as u8).collect();
+        let expected = digest(SHA2_512Type::SHA512, &data);
+        for chunk_length in [1, 7, 111, 112, 127, 128, 129] {
+            let mut digest = SHA2_512BaseDigest::new(SHA2_512Type::SHA512);
+            for chunk in data.chunks(chunk_length) {
+                digest.push_data(chunk);
+            }
+            digest.compute_digest();
+            assert_eq!(hex_encode(&digest.get_digest_bytes()), expected);
+        }
+    }
+}
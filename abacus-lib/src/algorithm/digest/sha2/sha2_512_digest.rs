@@ -0,0 +1,98 @@
+use super::super::digest_trait::Digest;
+use super::sha2_512_base_digest::SHA2_512BaseDigest;
+use super::sha2_512_type::SHA2_512Type;
+use super::sha2_constant::{SHA2_512_DIGEST_BYTE_LENGTH, SHA2_512_BUFFER_BYTE_LENGTH};
+
+pub struct SHA2_512Digest {
+    sha2_512_base_digest: SHA2_512BaseDigest
+}
+
+impl Default for SHA2_512Digest {
+    fn default() -> Self {
+        SHA2_512Digest::new()
+    }
+}
+
+impl SHA2_512Digest {
+    pub fn new() -> SHA2_512Digest {
+        SHA2_512Digest {
+            sha2_512_base_digest: SHA2_512BaseDigest::new(SHA2_512Type::SHA512)
+        }
+    }
+
+    pub fn get_digest_bytes(&mut self) -> [u8; SHA2_512_DIGEST_BYTE_LENGTH] {
+        self.sha2_512_base_digest.get_digest_bytes()
+    }
+
+    pub fn compute_digest(&mut self) {
+        self.sha2_512_base_digest.compute_digest()
+    }
+
+    pub fn push_data(&mut self, data: &[u8]) {
+        self.sha2_512_base_digest.push_data(data)
+    }
+}
+
+impl Digest for SHA2_512Digest {
+    const ALGORITHM_NAME: &'static str = "SHA-512";
+    const DIGEST_BYTE_LENGTH: usize = SHA2_512_DIGEST_BYTE_LENGTH;
+    const BUFFER_BYTE_LENGTH: usize = SHA2_512_BUFFER_BYTE_LENGTH;
+
+    type DigestBytes = [u8; SHA2_512_DIGEST_BYTE_LENGTH];
+
+    fn new() -> SHA2_512Digest {
+        SHA2_512Digest::new()
+    }
+
+    fn get_digest_bytes(&mut self) -> [u8; SHA2_512_DIGEST_BYTE_LENGTH] {
+        SHA2_512Digest::get_digest_bytes(self)
+    }
+
+    fn compute_digest(&mut self) {
+        SHA2_512Digest::compute_digest(self)
+    }
+
+    fn push_data(&mut self, data: &[u8]) {
+        SHA2_512Digest::push_data(self, data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::hex::hex_encode;
+    use super::SHA2_512Digest;
+
+    const TWO_BLOCK_MESSAGE: &[u8] = b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrst";
+
+    fn digest(data: &[u8]) -> String {
+        let mut digest = SHA2_512Digest::new();
+        digest.push_data(data);
+        digest.compute_digest();
+        hex_encode(&digest.get_digest_bytes())
+    }
+
+    #[test]
+    fn empty_message() {
+        assert_eq!(digest(b""), "cf83e1357eefb8bdf1542850d66d8007d620e4050b5715dc83f4a921d36ce9ce47d0d13c5d85f2b0ff8318d2877eec2f63b931bd47417a81a538327af927da3e");
+    }
+
+    #[test]
+    fn one_block_message() {
+        assert_eq!(digest(b"abc"), "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f");
+    }
+
+    #[test]
+    fn two_block_message() {
+        assert_eq!(digest(TWO_BLOCK_MESSAGE), "e65131bf0c4f1acae17f1359802d481af7d2ca542b9eee20eadfaf73a82c628c3b2bdfd7da2f757eeffcf47dbb6f91cc252640bc7e3f9d656752e8a26394a9e5");
+    }
+
+    #[test]
+    fn two_block_message_pushed_in_pieces() {
+        let mut digest = SHA2_512Digest::new();
+        for piece in TWO_BLOCK_MESSAGE.chunks(7) {
+            digest.push_data(piece);
+        }
+        digest.compute_digest();
+        assert_eq!(hex_encode(&digest.get_digest_bytes()), "e65131bf0c4f1acae17f1359802d481af7d2ca542b9eee20eadfaf73a82c628c3b2bdfd7da2f757eeffcf47dbb6f91cc252640bc7e3f9d656752e8a26394a9e5");
+    }
+}
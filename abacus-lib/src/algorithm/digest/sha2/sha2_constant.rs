@@ -3,3 +3,10 @@ pub const SHA2_224_DIGEST_BYTE_LENGTH: usize = 28;
 pub const SHA2_256_BUFFER_BYTE_LENGTH: usize = 64;
 pub const SHA2_256_DATA_BYTE_MAX_LENGTH: usize = 8;
 pub const SHA2_256_DIGEST_INIT_VALUE_LENGTH: usize = 8;
+pub const SHA2_512_DIGEST_BYTE_LENGTH: usize = 64;
+pub const SHA2_384_DIGEST_BYTE_LENGTH: usize = 48;
+pub const SHA2_512_256_DIGEST_BYTE_LENGTH: usize = 32;
+pub const SHA2_512_224_DIGEST_BYTE_LENGTH: usize = 28;
+pub const SHA2_512_BUFFER_BYTE_LENGTH: usize = 128;
+pub const SHA2_512_DATA_BYTE_MAX_LENGTH: usize = 16;
+pub const SHA2_512_DIGEST_INIT_VALUE_LENGTH: usize = 8;
@@ -1,57 +1,87 @@
-use std::fs::File;
 use std::io::Read;
+use std::path::Path;
+use crate::error::Error;
+use super::super::digest_utils::DigestUtils;
 use super::sha2_256_digest::SHA2_256Digest;
 use super::sha2_224_digest::SHA2_224Digest;
-use super::sha2_constant::{SHA2_256_DIGEST_BYTE_LENGTH, SHA2_224_DIGEST_BYTE_LENGTH};
+use super::sha2_384_digest::SHA2_384Digest;
+use super::sha2_512_digest::SHA2_512Digest;
+use super::sha2_512_224_digest::SHA2_512_224Digest;
+use super::sha2_512_256_digest::SHA2_512_256Digest;
+use super::sha2_constant::{SHA2_256_DIGEST_BYTE_LENGTH, SHA2_224_DIGEST_BYTE_LENGTH, SHA2_384_DIGEST_BYTE_LENGTH, SHA2_512_DIGEST_BYTE_LENGTH, SHA2_512_224_DIGEST_BYTE_LENGTH, SHA2_512_256_DIGEST_BYTE_LENGTH};
 
-const FILE_READ_SIZE: usize = 0x40000;
-
-pub(crate) struct SHA2Utils;
+pub struct SHA2Utils;
 
 impl SHA2Utils {
     pub fn sha2_256_data_digest(data: &[u8]) -> [u8; SHA2_256_DIGEST_BYTE_LENGTH] {
-        let mut sha256 = SHA2_256Digest::new();
-        sha256.push_data(data);
-        sha256.compute_digest();
-        sha256.get_digest_bytes()
+        DigestUtils::data_digest::<SHA2_256Digest>(data)
     }
 
     pub fn sha2_224_data_digest(data: &[u8]) -> [u8; SHA2_224_DIGEST_BYTE_LENGTH] {
-        let mut sha256 = SHA2_224Digest::new();
-        sha256.push_data(data);
-        sha256.compute_digest();
-        sha256.get_digest_bytes()
-    }
-
-    pub fn sha2_256_file_digest(file_path: String) -> Result<[u8; SHA2_256_DIGEST_BYTE_LENGTH], std::io::Error> {
-        let mut file = File::open(file_path)?;
-        let mut buffer = [0; FILE_READ_SIZE];
-        let mut sha256 = SHA2_256Digest::new();
-        loop {
-            let read_size = file.read(&mut buffer)?;
-            if read_size < FILE_READ_SIZE {
-                sha256.push_data(&buffer[..read_size]);
-                break;
-            }
-            sha256.push_data(&buffer);
-        }
-        sha256.compute_digest();
-        Ok(sha256.get_digest_bytes())
-    }
-
-    pub fn sha2_224_file_digest(file_path: String) -> Result<[u8; SHA2_224_DIGEST_BYTE_LENGTH], std::io::Error> {
-        let mut file = File::open(file_path)?;
-        let mut buffer = [0; FILE_READ_SIZE];
-        let mut sha256 = SHA2_224Digest::new();
-        loop {
-            let read_size = file.read(&mut buffer)?;
-            if read_size < FILE_READ_SIZE {
-                sha256.push_data(&buffer[..read_size]);
-                break;
-            }
-            sha256.push_data(&buffer);
-        }
-        sha256.compute_digest();
-        Ok(sha256.get_digest_bytes())
+        DigestUtils::data_digest::<SHA2_224Digest>(data)
+    }
+
+    pub fn sha2_384_data_digest(data: &[u8]) -> [u8; SHA2_384_DIGEST_BYTE_LENGTH] {
+        DigestUtils::data_digest::<SHA2_384Digest>(data)
+    }
+
+    pub fn sha2_512_data_digest(data: &[u8]) -> [u8; SHA2_512_DIGEST_BYTE_LENGTH] {
+        DigestUtils::data_digest::<SHA2_512Digest>(data)
+    }
+
+    pub fn sha2_512_224_data_digest(data: &[u8]) -> [u8; SHA2_512_224_DIGEST_BYTE_LENGTH] {
+        DigestUtils::data_digest::<SHA2_512_224Digest>(data)
+    }
+
+    pub fn sha2_512_256_data_digest(data: &[u8]) -> [u8; SHA2_512_256_DIGEST_BYTE_LENGTH] {
+        DigestUtils::data_digest::<SHA2_512_256Digest>(data)
+    }
+
+    pub fn sha2_256_file_digest<P: AsRef<Path>>(file_path: P) -> Result<[u8; SHA2_256_DIGEST_BYTE_LENGTH], Error> {
+        DigestUtils::file_digest::<SHA2_256Digest, P>(file_path)
+    }
+
+    pub fn sha2_224_file_digest<P: AsRef<Path>>(file_path: P) -> Result<[u8; SHA2_224_DIGEST_BYTE_LENGTH], Error> {
+        DigestUtils::file_digest::<SHA2_224Digest, P>(file_path)
+    }
+
+    pub fn sha2_384_file_digest<P: AsRef<Path>>(file_path: P) -> Result<[u8; SHA2_384_DIGEST_BYTE_LENGTH], Error> {
+        DigestUtils::file_digest::<SHA2_384Digest, P>(file_path)
+    }
+
+    pub fn sha2_512_file_digest<P: AsRef<Path>>(file_path: P) -> Result<[u8; SHA2_512_DIGEST_BYTE_LENGTH], Error> {
+        DigestUtils::file_digest::<SHA2_512Digest, P>(file_path)
+    }
+
+    pub fn sha2_512_224_file_digest<P: AsRef<Path>>(file_path: P) -> Result<[u8; SHA2_512_224_DIGEST_BYTE_LENGTH], Error> {
+        DigestUtils::file_digest::<SHA2_512_224Digest, P>(file_path)
+    }
+
+    pub fn sha2_512_256_file_digest<P: AsRef<Path>>(file_path: P) -> Result<[u8; SHA2_512_256_DIGEST_BYTE_LENGTH], Error> {
+        DigestUtils::file_digest::<SHA2_512_256Digest, P>(file_path)
+    }
+
+    pub fn sha2_256_reader_digest<R: Read>(reader: &mut R) -> Result<[u8; SHA2_256_DIGEST_BYTE_LENGTH], Error> {
+        DigestUtils::reader_digest::<SHA2_256Digest, R>(reader)
+    }
+
+    pub fn sha2_224_reader_digest<R: Read>(reader: &mut R) -> Result<[u8; SHA2_224_DIGEST_BYTE_LENGTH], Error> {
+        DigestUtils::reader_digest::<SHA2_224Digest, R>(reader)
+    }
+
+    pub fn sha2_384_reader_digest<R: Read>(reader: &mut R) -> Result<[u8; SHA2_384_DIGEST_BYTE_LENGTH], Error> {
+        DigestUtils::reader_digest::<SHA2_384Digest, R>(reader)
+    }
+
+    pub fn sha2_512_reader_digest<R: Read>(reader: &mut R) -> Result<[u8; SHA2_512_DIGEST_BYTE_LENGTH], Error> {
+        DigestUtils::reader_digest::<SHA2_512Digest, R>(reader)
+    }
+
+    pub fn sha2_512_224_reader_digest<R: Read>(reader: &mut R) -> Result<[u8; SHA2_512_224_DIGEST_BYTE_LENGTH], Error> {
+        DigestUtils::reader_digest::<SHA2_512_224Digest, R>(reader)
+    }
+
+    pub fn sha2_512_256_reader_digest<R: Read>(reader: &mut R) -> Result<[u8; SHA2_512_256_DIGEST_BYTE_LENGTH], Error> {
+        DigestUtils::reader_digest::<SHA2_512_256Digest, R>(reader)
     }
 }
@@ -0,0 +1,88 @@
+use super::super::digest_trait::Digest;
+use super::sha3_base_digest::SHA3BaseDigest;
+use super::sha3_type::SHA3Type;
+use super::sha3_constant::{KECCAK_256_DIGEST_BYTE_LENGTH, KECCAK_256_BUFFER_BYTE_LENGTH};
+
+pub struct Keccak256Digest {
+    sha3_base_digest: SHA3BaseDigest
+}
+
+impl Default for Keccak256Digest {
+    fn default() -> Self {
+        Keccak256Digest::new()
+    }
+}
+
+impl Keccak256Digest {
+    pub fn new() -> Keccak256Digest {
+        Keccak256Digest {
+            sha3_base_digest: SHA3BaseDigest::new(SHA3Type::Keccak256)
+        }
+    }
+
+    pub fn get_digest_bytes(&mut self) -> [u8; KECCAK_256_DIGEST_BYTE_LENGTH] {
+        let mut digest_bytes = [0; KECCAK_256_DIGEST_BYTE_LENGTH];
+        self.sha3_base_digest.get_digest_bytes(&mut digest_bytes);
+        digest_bytes
+    }
+
+    pub fn compute_digest(&mut self) {
+        self.sha3_base_digest.compute_digest()
+    }
+
+    pub fn push_data(&mut self, data: &[u8]) {
+        self.sha3_base_digest.push_data(data)
+    }
+}
+
+impl Digest for Keccak256Digest {
+    const ALGORITHM_NAME: &'static str = "Keccak-256";
+    const DIGEST_BYTE_LENGTH: usize = KECCAK_256_DIGEST_BYTE_LENGTH;
+    const BUFFER_BYTE_LENGTH: usize = KECCAK_256_BUFFER_BYTE_LENGTH;
+
+    type DigestBytes = [u8; KECCAK_256_DIGEST_BYTE_LENGTH];
+
+    fn new() -> Keccak256Digest {
+        Keccak256Digest::new()
+    }
+
+    fn get_digest_bytes(&mut self) -> [u8; KECCAK_256_DIGEST_BYTE_LENGTH] {
+        Keccak256Digest::get_digest_bytes(self)
+    }
+
+    fn compute_digest(&mut self) {
+        Keccak256Digest::compute_digest(self)
+    }
+
+    fn push_data(&mut self, data: &[u8]) {
+        Keccak256Digest::push_data(self, data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::hex::hex_encode;
+    use super::Keccak256Digest;
+
+    fn digest(data: &[u8]) -> String {
+        let mut digest = Keccak256Digest::new();
+        digest.push_data(data);
+        digest.compute_digest();
+        hex_encode(&digest.get_digest_bytes())
+    }
+
+    #[test]
+    fn empty_message() {
+        assert_eq!(digest(b""), "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470");
+    }
+
+    #[test]
+    fn short_message() {
+        assert_eq!(digest(b"abc"), "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45");
+    }
+
+    #[test]
+    fn multi_rate_message() {
+        assert_eq!(digest(&[0xa3; 200]), "3a57666b048777f2c953dc4456f45a2588e1cb6f2da760122d530ac2ce607d4a");
+    }
+}
@@ -0,0 +1,13 @@
+pub mod sha3_constant;
+pub mod sha3_keccak;
+pub mod sha3_type;
+pub mod sha3_base_digest;
+pub mod sha3_xof_reader;
+pub mod sha3_224_digest;
+pub mod sha3_256_digest;
+pub mod sha3_384_digest;
+pub mod sha3_512_digest;
+pub mod keccak_256_digest;
+pub mod shake_128_digest;
+pub mod shake_256_digest;
+pub mod sha3_utils;
@@ -0,0 +1,103 @@
+use super::super::digest_trait::Digest;
+use super::sha3_base_digest::SHA3BaseDigest;
+use super::sha3_type::SHA3Type;
+use super::sha3_constant::{SHA3_224_DIGEST_BYTE_LENGTH, SHA3_224_BUFFER_BYTE_LENGTH};
+
+pub struct SHA3_224Digest {
+    sha3_base_digest: SHA3BaseDigest
+}
+
+impl Default for SHA3_224Digest {
+    fn default() -> Self {
+        SHA3_224Digest::new()
+    }
+}
+
+impl SHA3_224Digest {
+    pub fn new() -> SHA3_224Digest {
+        SHA3_224Digest {
+            sha3_base_digest: SHA3BaseDigest::new(SHA3Type::SHA3_224)
+        }
+    }
+
+    pub fn get_digest_bytes(&mut self) -> [u8; SHA3_224_DIGEST_BYTE_LENGTH] {
+        let mut digest_bytes = [0; SHA3_224_DIGEST_BYTE_LENGTH];
+        self.sha3_base_digest.get_digest_bytes(&mut digest_bytes);
+        digest_bytes
+    }
+
+    pub fn compute_digest(&mut self) {
+        self.sha3_base_digest.compute_digest()
+    }
+
+    pub fn push_data(&mut self, data: &[u8]) {
+        self.sha3_base_digest.push_data(data)
+    }
+}
+
+impl Digest for SHA3_224Digest {
+    const ALGORITHM_NAME: &'static str = "SHA3-224";
+    const DIGEST_BYTE_LENGTH: usize = SHA3_224_DIGEST_BYTE_LENGTH;
+    const BUFFER_BYTE_LENGTH: usize = SHA3_224_BUFFER_BYTE_LENGTH;
+
+    type DigestBytes = [u8; SHA3_224_DIGEST_BYTE_LENGTH];
+
+    fn new() -> SHA3_224Digest {
+        SHA3_224Digest::new()
+    }
+
+    fn get_digest_bytes(&mut self) -> [u8; SHA3_224_DIGEST_BYTE_LENGTH] {
+        SHA3_224Digest::get_digest_bytes(self)
+    }
+
+    fn compute_digest(&mut self) {
+        SHA3_224Digest::compute_digest(self)
+    }
+
+    fn push_data(&mut self, data: &[u8]) {
+        SHA3_224Digest::push_data(self, data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::hex::hex_encode;
+    use super::SHA3_224Digest;
+
+    fn digest(data: &[u8]) -> String {
+        let mut digest = SHA3_224Digest::new();
+        digest.push_data(data);
+        digest.compute_digest();
+        hex_encode(&digest.get_digest_bytes())
+    }
+
+    #[test]
+    fn empty_message() {
+        assert_eq!(digest(b""), "6b4e03423667dbb73b6e15454f0eb1abd4597f9a1b078e3f5b5a6bc7");
+    }
+
+    #[test]
+    fn short_message() {
+        assert_eq!(digest(b"abc"), "e642824c3f8cf24ad09234ee7d3c766fc9a3a5168d0c94ad73b46fdf");
+    }
+
+    #[test]
+    fn two_block_message() {
+        assert_eq!(digest(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"), "8a24108b154ada21c9fd5574494479ba5c7e7ab76ef264ead0fcce33");
+    }
+
+    #[test]
+    fn multi_rate_message() {
+        assert_eq!(digest(&[0xa3; 200]), "9376816aba503f72f96ce7eb65ac095deee3be4bf9bbc2a1cb7e11e0");
+    }
+
+    #[test]
+    fn multi_rate_message_pushed_in_pieces() {
+        let mut digest = SHA3_224Digest::new();
+        for piece in [0xa3; 200].chunks(11) {
+            digest.push_data(piece);
+        }
+        digest.compute_digest();
+        assert_eq!(hex_encode(&digest.get_digest_bytes()), "9376816aba503f72f96ce7eb65ac095deee3be4bf9bbc2a1cb7e11e0");
+    }
+}
@@ -0,0 +1,103 @@
+use super::super::digest_trait::Digest;
+use super::sha3_base_digest::SHA3BaseDigest;
+use super::sha3_type::SHA3Type;
+use super::sha3_constant::{SHA3_256_DIGEST_BYTE_LENGTH, SHA3_256_BUFFER_BYTE_LENGTH};
+
+pub struct SHA3_256Digest {
+    sha3_base_digest: SHA3BaseDigest
+}
+
+impl Default for SHA3_256Digest {
+    fn default() -> Self {
+        SHA3_256Digest::new()
+    }
+}
+
+impl SHA3_256Digest {
+    pub fn new() -> SHA3_256Digest {
+        SHA3_256Digest {
+            sha3_base_digest: SHA3BaseDigest::new(SHA3Type::SHA3_256)
+        }
+    }
+
+    pub fn get_digest_bytes(&mut self) -> [u8; SHA3_256_DIGEST_BYTE_LENGTH] {
+        let mut digest_bytes = [0; SHA3_256_DIGEST_BYTE_LENGTH];
+        self.sha3_base_digest.get_digest_bytes(&mut digest_bytes);
+        digest_bytes
+    }
+
+    pub fn compute_digest(&mut self) {
+        self.sha3_base_digest.compute_digest()
+    }
+
+    pub fn push_data(&mut self, data: &[u8]) {
+        self.sha3_base_digest.push_data(data)
+    }
+}
+
+impl Digest for SHA3_256Digest {
+    const ALGORITHM_NAME: &'static str = "SHA3-256";
+    const DIGEST_BYTE_LENGTH: usize = SHA3_256_DIGEST_BYTE_LENGTH;
+    const BUFFER_BYTE_LENGTH: usize = SHA3_256_BUFFER_BYTE_LENGTH;
+
+    type DigestBytes = [u8; SHA3_256_DIGEST_BYTE_LENGTH];
+
+    fn new() -> SHA3_256Digest {
+        SHA3_256Digest::new()
+    }
+
+    fn get_digest_bytes(&mut self) -> [u8; SHA3_256_DIGEST_BYTE_LENGTH] {
+        SHA3_256Digest::get_digest_bytes(self)
+    }
+
+    fn compute_digest(&mut self) {
+        SHA3_256Digest::compute_digest(self)
+    }
+
+    fn push_data(&mut self, data: &[u8]) {
+        SHA3_256Digest::push_data(self, data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::hex::hex_encode;
+    use super::SHA3_256Digest;
+
+    fn digest(data: &[u8]) -> String {
+        let mut digest = SHA3_256Digest::new();
+        digest.push_data(data);
+        digest.compute_digest();
+        hex_encode(&digest.get_digest_bytes())
+    }
+
+    #[test]
+    fn empty_message() {
+        assert_eq!(digest(b""), "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a");
+    }
+
+    #[test]
+    fn short_message() {
+        assert_eq!(digest(b"abc"), "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532");
+    }
+
+    #[test]
+    fn two_block_message() {
+        assert_eq!(digest(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"), "41c0dba2a9d6240849100376a8235e2c82e1b9998a999e21db32dd97496d3376");
+    }
+
+    #[test]
+    fn multi_rate_message() {
+        assert_eq!(digest(&[0xa3; 200]), "79f38adec5c20307a98ef76e8324afbfd46cfd81b22e3973c65fa1bd9de31787");
+    }
+
+    #[test]
+    fn multi_rate_message_pushed_in_pieces() {
+        let mut digest = SHA3_256Digest::new();
+        for piece in [0xa3; 200].chunks(11) {
+            digest.push_data(piece);
+        }
+        digest.compute_digest();
+        assert_eq!(hex_encode(&digest.get_digest_bytes()), "79f38adec5c20307a98ef76e8324afbfd46cfd81b22e3973c65fa1bd9de31787");
+    }
+}
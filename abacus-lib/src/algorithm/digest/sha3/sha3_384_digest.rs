@@ -0,0 +1,103 @@
+use super::super::digest_trait::Digest;
+use super::sha3_base_digest::SHA3BaseDigest;
+use super::sha3_type::SHA3Type;
+use super::sha3_constant::{SHA3_384_DIGEST_BYTE_LENGTH, SHA3_384_BUFFER_BYTE_LENGTH};
+
+pub struct SHA3_384Digest {
+    sha3_base_digest: SHA3BaseDigest
+}
+
+impl Default for SHA3_384Digest {
+    fn default() -> Self {
+        SHA3_384Digest::new()
+    }
+}
+
+impl SHA3_384Digest {
+    pub fn new() -> SHA3_384Digest {
+        SHA3_384Digest {
+            sha3_base_digest: SHA3BaseDigest::new(SHA3Type::SHA3_384)
+        }
+    }
+
+    pub fn get_digest_bytes(&mut self) -> [u8; SHA3_384_DIGEST_BYTE_LENGTH] {
+        let mut digest_bytes = [0; SHA3_384_DIGEST_BYTE_LENGTH];
+        self.sha3_base_digest.get_digest_bytes(&mut digest_bytes);
+        digest_bytes
+    }
+
+    pub fn compute_digest(&mut self) {
+        self.sha3_base_digest.compute_digest()
+    }
+
+    pub fn push_data(&mut self, data: &[u8]) {
+        self.sha3_base_digest.push_data(data)
+    }
+}
+
+impl Digest for SHA3_384Digest {
+    const ALGORITHM_NAME: &'static str = "SHA3-384";
+    const DIGEST_BYTE_LENGTH: usize = SHA3_384_DIGEST_BYTE_LENGTH;
+    const BUFFER_BYTE_LENGTH: usize = SHA3_384_BUFFER_BYTE_LENGTH;
+
+    type DigestBytes = [u8; SHA3_384_DIGEST_BYTE_LENGTH];
+
+    fn new() -> SHA3_384Digest {
+        SHA3_384Digest::new()
+    }
+
+    fn get_digest_bytes(&mut self) -> [u8; SHA3_384_DIGEST_BYTE_LENGTH] {
+        SHA3_384Digest::get_digest_bytes(self)
+    }
+
+    fn compute_digest(&mut self) {
+        SHA3_384Digest::compute_digest(self)
+    }
+
+    fn push_data(&mut self, data: &[u8]) {
+        SHA3_384Digest::push_data(self, data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::hex::hex_encode;
+    use super::SHA3_384Digest;
+
+    fn digest(data: &[u8]) -> String {
+        let mut digest = SHA3_384Digest::new();
+        digest.push_data(data);
+        digest.compute_digest();
+        hex_encode(&digest.get_digest_bytes())
+    }
+
+    #[test]
+    fn empty_message() {
+        assert_eq!(digest(b""), "0c63a75b845e4f7d01107d852e4c2485c51a50aaaa94fc61995e71bbee983a2ac3713831264adb47fb6bd1e058d5f004");
+    }
+
+    #[test]
+    fn short_message() {
+        assert_eq!(digest(b"abc"), "ec01498288516fc926459f58e2c6ad8df9b473cb0fc08c2596da7cf0e49be4b298d88cea927ac7f539f1edf228376d25");
+    }
+
+    #[test]
+    fn two_block_message() {
+        assert_eq!(digest(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"), "991c665755eb3a4b6bbdfb75c78a492e8c56a22c5c4d7e429bfdbc32b9d4ad5aa04a1f076e62fea19eef51acd0657c22");
+    }
+
+    #[test]
+    fn multi_rate_message() {
+        assert_eq!(digest(&[0xa3; 200]), "1881de2ca7e41ef95dc4732b8f5f002b189cc1e42b74168ed1732649ce1dbcdd76197a31fd55ee989f2d7050dd473e8f");
+    }
+
+    #[test]
+    fn multi_rate_message_pushed_in_pieces() {
+        let mut digest = SHA3_384Digest::new();
+        for piece in [0xa3; 200].chunks(11) {
+            digest.push_data(piece);
+        }
+        digest.compute_digest();
+        assert_eq!(hex_encode(&digest.get_digest_bytes()), "1881de2ca7e41ef95dc4732b8f5f002b189cc1e42b74168ed1732649ce1dbcdd76197a31fd55ee989f2d7050dd473e8f");
+    }
+}
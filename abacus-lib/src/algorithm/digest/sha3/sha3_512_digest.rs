@@ -0,0 +1,103 @@
+use super::super::digest_trait::Digest;
+use super::sha3_base_digest::SHA3BaseDigest;
+use super::sha3_type::SHA3Type;
+use super::sha3_constant::{SHA3_512_DIGEST_BYTE_LENGTH, SHA3_512_BUFFER_BYTE_LENGTH};
+
+pub struct SHA3_512Digest {
+    sha3_base_digest: SHA3BaseDigest
+}
+
+impl Default for SHA3_512Digest {
+    fn default() -> Self {
+        SHA3_512Digest::new()
+    }
+}
+
+impl SHA3_512Digest {
+    pub fn new() -> SHA3_512Digest {
+        SHA3_512Digest {
+            sha3_base_digest: SHA3BaseDigest::new(SHA3Type::SHA3_512)
+        }
+    }
+
+    pub fn get_digest_bytes(&mut self) -> [u8; SHA3_512_DIGEST_BYTE_LENGTH] {
+        let mut digest_bytes = [0; SHA3_512_DIGEST_BYTE_LENGTH];
+        self.sha3_base_digest.get_digest_bytes(&mut digest_bytes);
+        digest_bytes
+    }
+
+    pub fn compute_digest(&mut self) {
+        self.sha3_base_digest.compute_digest()
+    }
+
+    pub fn push_data(&mut self, data: &[u8]) {
+        self.sha3_base_digest.push_data(data)
+    }
+}
+
+impl Digest for SHA3_512Digest {
+    const ALGORITHM_NAME: &'static str = "SHA3-512";
+    const DIGEST_BYTE_LENGTH: usize = SHA3_512_DIGEST_BYTE_LENGTH;
+    const BUFFER_BYTE_LENGTH: usize = SHA3_512_BUFFER_BYTE_LENGTH;
+
+    type DigestBytes = [u8; SHA3_512_DIGEST_BYTE_LENGTH];
+
+    fn new() -> SHA3_512Digest {
+        SHA3_512Digest::new()
+    }
+
+    fn get_digest_bytes(&mut self) -> [u8; SHA3_512_DIGEST_BYTE_LENGTH] {
+        SHA3_512Digest::get_digest_bytes(self)
+    }
+
+    fn compute_digest(&mut self) {
+        SHA3_512Digest::compute_digest(self)
+    }
+
+    fn push_data(&mut self, data: &[u8]) {
+        SHA3_512Digest::push_data(self, data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::hex::hex_encode;
+    use super::SHA3_512Digest;
+
+    fn digest(data: &[u8]) -> String {
+        let mut digest = SHA3_512Digest::new();
+        digest.push_data(data);
+        digest.compute_digest();
+        hex_encode(&digest.get_digest_bytes())
+    }
+
+    #[test]
+    fn empty_message() {
+        assert_eq!(digest(b""), "a69f73cca23a9ac5c8b567dc185a756e97c982164fe25859e0d1dcc1475c80a615b2123af1f5f94c11e3e9402c3ac558f500199d95b6d3e301758586281dcd26");
+    }
+
+    #[test]
+    fn short_message() {
+        assert_eq!(digest(b"abc"), "b751850b1a57168a5693cd924b6b096e08f621827444f70d884f5d0240d2712e10e116e9192af3c91a7ec57647e3934057340b4cf408d5a56592f8274eec53f0");
+    }
+
+    #[test]
+    fn two_block_message() {
+        assert_eq!(digest(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"), "04a371e84ecfb5b8b77cb48610fca8182dd457ce6f326a0fd3d7ec2f1e91636dee691fbe0c985302ba1b0d8dc78c086346b533b49c030d99a27daf1139d6e75e");
+    }
+
+    #[test]
+    fn multi_rate_message() {
+        assert_eq!(digest(&[0xa3; 200]), "e76dfad22084a8b1467fcf2ffa58361bec7628edf5f3fdc0e4805dc48caeeca81b7c13c30adf52a3659584739a2df46be589c51ca1a4a8416df6545a1ce8ba00");
+    }
+
+    #[test]
+    fn multi_rate_message_pushed_in_pieces() {
+        let mut digest = SHA3_512Digest::new();
+        for piece in [0xa3; 200].chunks(11) {
+            digest.push_data(piece);
+        }
+        digest.compute_digest();
+        assert_eq!(hex_encode(&digest.get_digest_bytes()), "e76dfad22084a8b1467fcf2ffa58361bec7628edf5f3fdc0e4805dc48caeeca81b7c13c30adf52a3659584739a2df46be589c51ca1a4a8416df6545a1ce8ba00");
+    }
+}
@@ -0,0 +1,123 @@
+use super::sha3_keccak::keccak_f1600;
+use super::sha3_type::SHA3Type;
+use super::sha3_constant::KECCAK_STATE_WORD_LENGTH;
+
+#[inline(always)]
+fn state_byte(state: &[u64; KECCAK_STATE_WORD_LENGTH], i: usize) -> u8 {
+    state[i / 8].to_le_bytes()[i % 8]
+}
+
+#[inline(always)]
+fn absorb_block(state: &mut [u64; KECCAK_STATE_WORD_LENGTH], buffer: &[u8]) {
+    for i in 0..(buffer.len() / 8) {
+        state[i] ^= u64::from_le_bytes(buffer[(i * 8)..(i * 8 + 8)].try_into().unwrap());
+    }
+    keccak_f1600(state);
+}
+
+#[derive(Clone)]
+pub struct SHA3BaseDigest {
+    state: [u64; KECCAK_STATE_WORD_LENGTH],
+    rate: usize,
+    domain_suffix: u8,
+    squeeze_offset: usize,
+    remains_data: Vec<u8>,
+}
+
+impl SHA3BaseDigest {
+    pub fn new(sha3_type: SHA3Type) -> SHA3BaseDigest {
+        SHA3BaseDigest {
+            state: [0; KECCAK_STATE_WORD_LENGTH],
+            rate: sha3_type.rate(),
+            domain_suffix: sha3_type.domain_suffix(),
+            squeeze_offset: 0,
+            remains_data: Vec::new(),
+        }
+    }
+
+    pub fn get_digest_bytes(&self, digest_bytes: &mut [u8]) {
+        for (i, byte) in digest_bytes.iter_mut().enumerate() {
+            *byte = state_byte(&self.state, i);
+        }
+    }
+
+    pub fn compute_digest(&mut self) {
+        let remains_data_length = self.remains_data.len();
+        let mut buffer = vec![0; self.rate];
+        buffer[..remains_data_length].copy_from_slice(&self.remains_data);
+        self.remains_data.clear();
+        buffer[remains_data_length] ^= self.domain_suffix;
+        buffer[self.rate - 1] ^= 0x80;
+        absorb_block(&mut self.state, &buffer);
+        self.squeeze_offset = 0;
+    }
+
+    pub fn push_data(&mut self, data: &[u8]) {
+        let remains_data_length = self.remains_data.len();
+        let mut offset: usize = 0;
+        if remains_data_length > 0 && remains_data_length + data.len() >= self.rate {
+            offset = self.rate - remains_data_length;
+            self.remains_data.extend_from_slice(&data[..offset]);
+            absorb_block(&mut self.state, &self.remains_data);
+            self.remains_data.clear();
+        }
+        let buffer_count = (data.len() - offset) / self.rate;
+        for i in 0..buffer_count {
+            absorb_block(&mut self.state, &data[(i * self.rate + offset)..(i * self.rate + offset + self.rate)]);
+        }
+        self.remains_data.extend_from_slice(&data[(buffer_count * self.rate + offset)..]);
+    }
+
+    pub fn squeeze_data(&mut self, output: &mut [u8]) {
+        for byte in output.iter_mut() {
+            if self.squeeze_offset == self.rate {
+                keccak_f1600(&mut self.state);
+                self.squeeze_offset = 0;
+            }
+            *byte = state_byte(&self.state, self.squeeze_offset);
+            self.squeeze_offset += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::hex::hex_encode;
+    use super::super::sha3_type::SHA3Type;
+    use super::SHA3BaseDigest;
+
+    fn digest(data: &[u8]) -> String {
+        let mut digest = SHA3BaseDigest::new(SHA3Type::SHA3_256);
+        digest.push_data(data);
+        digest.compute_digest();
+        let mut digest_bytes = [0; 32];
+        digest.get_digest_bytes(&mut digest_bytes);
+        hex_encode(&digest_bytes)
+    }
+
+    #[test]
+    fn padding_shares_last_rate_byte() {
+        assert_eq!(digest(&[b'a'; 135]), "8094bb53c44cfb1e67b7c30447f9a1c33696d2463ecc1d9c92538913392843c9");
+    }
+
+    #[test]
+    fn padding_fills_extra_block() {
+        assert_eq!(digest(&[b'a'; 136]), "3fc5559f14db8e453a0a3091edbd2bc25e11528d81c66fa570a4efdcc2695ee1");
+    }
+
+    #[test]
+    fn incremental_push_matches_one_shot() {
+        let data: Vec<u8> = (0..400).map(|i| i as u8).collect();
+        let expected = digest(&data);
+        for chunk_length in [1, 7, 135, 136, 137] {
+            let mut digest = SHA3BaseDigest::new(SHA3Type::SHA3_256);
+            for chunk in data.chunks(chunk_length) {
+                digest.push_data(chunk);
+            }
+            digest.compute_digest();
+            let mut digest_bytes = [0; 32];
+            digest.get_digest_bytes(&mut digest_bytes);
+            assert_eq!(hex_encode(&digest_bytes), expected);
+        }
+    }
+}
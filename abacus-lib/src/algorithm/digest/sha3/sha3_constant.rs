@@ -0,0 +1,31 @@
+pub const SHA3_224_DIGEST_BYTE_LENGTH: usize = 28;
+pub const SHA3_256_DIGEST_BYTE_LENGTH: usize = 32;
+pub const SHA3_384_DIGEST_BYTE_LENGTH: usize = 48;
+pub const SHA3_512_DIGEST_BYTE_LENGTH: usize = 64;
+pub const KECCAK_256_DIGEST_BYTE_LENGTH: usize = 32;
+pub const SHA3_224_BUFFER_BYTE_LENGTH: usize = 144;
+pub const SHA3_256_BUFFER_BYTE_LENGTH: usize = 136;
+pub const SHA3_384_BUFFER_BYTE_LENGTH: usize = 104;
+pub const SHA3_512_BUFFER_BYTE_LENGTH: usize = 72;
+pub const KECCAK_256_BUFFER_BYTE_LENGTH: usize = 136;
+pub const SHAKE_128_BUFFER_BYTE_LENGTH: usize = 168;
+pub const SHAKE_256_BUFFER_BYTE_LENGTH: usize = 136;
+pub const KECCAK_STATE_WORD_LENGTH: usize = 25;
+pub const KECCAK_ROUND_LENGTH: usize = 24;
+
+pub const KECCAK_ROUND_CONSTANT: [u64; KECCAK_ROUND_LENGTH] = [
+    0x0000000000000001, 0x0000000000008082, 0x800000000000808a, 0x8000000080008000,
+    0x000000000000808b, 0x0000000080000001, 0x8000000080008081, 0x8000000000008009,
+    0x000000000000008a, 0x0000000000000088, 0x0000000080008009, 0x000000008000000a,
+    0x000000008000808b, 0x800000000000008b, 0x8000000000008089, 0x8000000000008003,
+    0x8000000000008002, 0x8000000000000080, 0x000000000000800a, 0x800000008000000a,
+    0x8000000080008081, 0x8000000000008080, 0x0000000080000001, 0x8000000080008008,
+];
+
+pub const KECCAK_ROTATION: [u32; KECCAK_STATE_WORD_LENGTH] = [
+    0, 1, 62, 28, 27,
+    36, 44, 6, 55, 20,
+    3, 10, 43, 25, 39,
+    41, 45, 15, 21, 8,
+    18, 2, 61, 56, 14,
+];
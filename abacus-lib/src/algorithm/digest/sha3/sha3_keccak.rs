@@ -0,0 +1,71 @@
+use super::sha3_constant::{KECCAK_STATE_WORD_LENGTH, KECCAK_ROUND_CONSTANT, KECCAK_ROTATION};
+
+#[inline(always)]
+fn theta(state: &mut [u64; KECCAK_STATE_WORD_LENGTH]) {
+    let mut c: [u64; 5] = [0; 5];
+    for x in 0..5 {
+        c[x] = state[x] ^ state[x + 5] ^ state[x + 10] ^ state[x + 15] ^ state[x + 20];
+    }
+    for x in 0..5 {
+        let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+        for y in 0..5 {
+            state[x + y * 5] ^= d;
+        }
+    }
+}
+
+#[inline(always)]
+fn rho_pi(state: &[u64; KECCAK_STATE_WORD_LENGTH], b: &mut [u64; KECCAK_STATE_WORD_LENGTH]) {
+    for x in 0..5 {
+        for y in 0..5 {
+            b[y + ((2 * x + 3 * y) % 5) * 5] = state[x + y * 5].rotate_left(KECCAK_ROTATION[x + y * 5]);
+        }
+    }
+}
+
+#[inline(always)]
+fn chi(state: &mut [u64; KECCAK_STATE_WORD_LENGTH], b: &[u64; KECCAK_STATE_WORD_LENGTH]) {
+    for y in 0..5 {
+        for x in 0..5 {
+            state[x + y * 5] = b[x + y * 5] ^ (!b[(x + 1) % 5 + y * 5] & b[(x + 2) % 5 + y * 5]);
+        }
+    }
+}
+
+pub fn keccak_f1600(state: &mut [u64; KECCAK_STATE_WORD_LENGTH]) {
+    let mut b: [u64; KECCAK_STATE_WORD_LENGTH] = [0; KECCAK_STATE_WORD_LENGTH];
+    for round_constant in KECCAK_ROUND_CONSTANT {
+        theta(state);
+        rho_pi(state, &mut b);
+        chi(state, &b);
+        state[0] ^= round_constant;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::keccak_f1600;
+
+    const ZERO_STATE_PERMUTED: [u64; 25] = [
+        0xf1258f7940e1dde7, 0x84d5ccf933c0478a, 0xd598261ea65aa9ee, 0xbd1547306f80494d, 0x8b284e056253d057,
+        0xff97a42d7f8e6fd4, 0x90fee5a0a44647c4, 0x8c5bda0cd6192e76, 0xad30a6f71b19059c, 0x30935ab7d08ffc64,
+        0xeb5aa93f2317d635, 0xa9a6e6260d712103, 0x81a57c16dbcf555f, 0x43b831cd0347c826, 0x01f22f1a11a5569f,
+        0x05e5635a21d9ae61, 0x64befef28cc970f2, 0x613670957bc46611, 0xb87c5a554fd00ecb, 0x8c3ee88a1ccf32c8,
+        0x940c7922ae3a2614, 0x1841f924a2c509e4, 0x16f53526e70465c2, 0x75f644e97f30a13b, 0xeaf1ff7b5ceca249,
+    ];
+
+    #[test]
+    fn zero_state_permutation() {
+        let mut state = [0; 25];
+        keccak_f1600(&mut state);
+        assert_eq!(state, ZERO_STATE_PERMUTED);
+    }
+
+    #[test]
+    fn repeated_permutation() {
+        let mut state = [0; 25];
+        keccak_f1600(&mut state);
+        keccak_f1600(&mut state);
+        assert_eq!(state[..5], [0x2d5c954df96ecb3c, 0x6a332cd07057b56d, 0x093d8d1270d76b6c, 0x8a20d9b25569d094, 0x4f9c4f99e5e7f156]);
+    }
+}
@@ -0,0 +1,33 @@
+use super::sha3_constant::{SHA3_224_BUFFER_BYTE_LENGTH, SHA3_256_BUFFER_BYTE_LENGTH, SHA3_384_BUFFER_BYTE_LENGTH, SHA3_512_BUFFER_BYTE_LENGTH, KECCAK_256_BUFFER_BYTE_LENGTH, SHAKE_128_BUFFER_BYTE_LENGTH, SHAKE_256_BUFFER_BYTE_LENGTH};
+
+pub enum SHA3Type {
+    SHA3_224,
+    SHA3_256,
+    SHA3_384,
+    SHA3_512,
+    Keccak256,
+    SHAKE128,
+    SHAKE256,
+}
+
+impl SHA3Type {
+    pub fn rate(&self) -> usize {
+        match self {
+            SHA3Type::SHA3_224 => SHA3_224_BUFFER_BYTE_LENGTH,
+            SHA3Type::SHA3_256 => SHA3_256_BUFFER_BYTE_LENGTH,
+            SHA3Type::SHA3_384 => SHA3_384_BUFFER_BYTE_LENGTH,
+            SHA3Type::SHA3_512 => SHA3_512_BUFFER_BYTE_LENGTH,
+            SHA3Type::Keccak256 => KECCAK_256_BUFFER_BYTE_LENGTH,
+            SHA3Type::SHAKE128 => SHAKE_128_BUFFER_BYTE_LENGTH,
+            SHA3Type::SHAKE256 => SHAKE_256_BUFFER_BYTE_LENGTH,
+        }
+    }
+
+    pub fn domain_suffix(&self) -> u8 {
+        match self {
+            SHA3Type::SHA3_224 | SHA3Type::SHA3_256 | SHA3Type::SHA3_384 | SHA3Type::SHA3_512 => 0x06,
+            SHA3Type::Keccak256 => 0x01,
+            SHA3Type::SHAKE128 | SHA3Type::SHAKE256 => 0x1f,
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use std::io::Read;
+use std::path::Path;
+use crate::error::Error;
+use super::super::digest_utils::DigestUtils;
+use super::sha3_224_digest::SHA3_224Digest;
+use super::sha3_256_digest::SHA3_256Digest;
+use super::sha3_384_digest::SHA3_384Digest;
+use super::sha3_512_digest::SHA3_512Digest;
+use super::keccak_256_digest::Keccak256Digest;
+use super::shake_128_digest::SHAKE128Digest;
+use super::shake_256_digest::SHAKE256Digest;
+use super::sha3_constant::{SHA3_224_DIGEST_BYTE_LENGTH, SHA3_256_DIGEST_BYTE_LENGTH, SHA3_384_DIGEST_BYTE_LENGTH, SHA3_512_DIGEST_BYTE_LENGTH, KECCAK_256_DIGEST_BYTE_LENGTH};
+
+pub struct SHA3Utils;
+
+impl SHA3Utils {
+    pub fn sha3_224_data_digest(data: &[u8]) -> [u8; SHA3_224_DIGEST_BYTE_LENGTH] {
+        DigestUtils::data_digest::<SHA3_224Digest>(data)
+    }
+
+    pub fn sha3_256_data_digest(data: &[u8]) -> [u8; SHA3_256_DIGEST_BYTE_LENGTH] {
+        DigestUtils::data_digest::<SHA3_256Digest>(data)
+    }
+
+    pub fn sha3_384_data_digest(data: &[u8]) -> [u8; SHA3_384_DIGEST_BYTE_LENGTH] {
+        DigestUtils::data_digest::<SHA3_384Digest>(data)
+    }
+
+    pub fn sha3_512_data_digest(data: &[u8]) -> [u8; SHA3_512_DIGEST_BYTE_LENGTH] {
+        DigestUtils::data_digest::<SHA3_512Digest>(data)
+    }
+
+    pub fn keccak_256_data_digest(data: &[u8]) -> [u8; KECCAK_256_DIGEST_BYTE_LENGTH] {
+        DigestUtils::data_digest::<Keccak256Digest>(data)
+    }
+
+    pub fn sha3_224_file_digest<P: AsRef<Path>>(file_path: P) -> Result<[u8; SHA3_224_DIGEST_BYTE_LENGTH], Error> {
+        DigestUtils::file_digest::<SHA3_224Digest, P>(file_path)
+    }
+
+    pub fn sha3_256_file_digest<P: AsRef<Path>>(file_path: P) -> Result<[u8; SHA3_256_DIGEST_BYTE_LENGTH], Error> {
+        DigestUtils::file_digest::<SHA3_256Digest, P>(file_path)
+    }
+
+    pub fn sha3_384_file_digest<P: AsRef<Path>>(file_path: P) -> Result<[u8; SHA3_384_DIGEST_BYTE_LENGTH], Error> {
+        DigestUtils::file_digest::<SHA3_384Digest, P>(file_path)
+    }
+
+    pub fn sha3_512_file_digest<P: AsRef<Path>>(file_path: P) -> Result<[u8; SHA3_512_DIGEST_BYTE_LENGTH], Error> {
+        DigestUtils::file_digest::<SHA3_512Digest, P>(file_path)
+    }
+
+    pub fn keccak_256_file_digest<P: AsRef<Path>>(file_path: P) -> Result<[u8; KECCAK_256_DIGEST_BYTE_LENGTH], Error> {
+        DigestUtils::file_digest::<Keccak256Digest, P>(file_path)
+    }
+
+    pub fn sha3_224_reader_digest<R: Read>(reader: &mut R) -> Result<[u8; SHA3_224_DIGEST_BYTE_LENGTH], Error> {
+        DigestUtils::reader_digest::<SHA3_224Digest, R>(reader)
+    }
+
+    pub fn sha3_256_reader_digest<R: Read>(reader: &mut R) -> Result<[u8; SHA3_256_DIGEST_BYTE_LENGTH], Error> {
+        DigestUtils::reader_digest::<SHA3_256Digest, R>(reader)
+    }
+
+    pub fn sha3_384_reader_digest<R: Read>(reader: &mut R) -> Result<[u8; SHA3_384_DIGEST_BYTE_LENGTH], Error> {
+        DigestUtils::reader_digest::<SHA3_384Digest, R>(reader)
+    }
+
+    pub fn sha3_512_reader_digest<R: Read>(reader: &mut R) -> Result<[u8; SHA3_512_DIGEST_BYTE_LENGTH], Error> {
+        DigestUtils::reader_digest::<SHA3_512Digest, R>(reader)
+    }
+
+    pub fn keccak_256_reader_digest<R: Read>(reader: &mut R) -> Result<[u8; KECCAK_256_DIGEST_BYTE_LENGTH], Error> {
+        DigestUtils::reader_digest::<Keccak256Digest, R>(reader)
+    }
+
+    pub fn shake_128_data_digest(data: &[u8], digest_byte_length: usize) -> Vec<u8> {
+        let mut shake = SHAKE128Digest::new();
+        shake.push_data(data);
+        shake.compute_digest();
+        shake.get_digest_bytes(digest_byte_length)
+    }
+
+    pub fn shake_256_data_digest(data: &[u8], digest_byte_length: usize) -> Vec<u8> {
+        let mut shake = SHAKE256Digest::new();
+        shake.push_data(data);
+        shake.compute_digest();
+        shake.get_digest_bytes(digest_byte_length)
+    }
+}
@@ -0,0 +1,69 @@
+use std::io::Read;
+use super::sha3_base_digest::SHA3BaseDigest;
+
+pub struct SHA3XOFReader {
+    sha3_base_digest: SHA3BaseDigest
+}
+
+impl SHA3XOFReader {
+    pub(crate) fn new(sha3_base_digest: SHA3BaseDigest) -> SHA3XOFReader {
+        SHA3XOFReader {
+            sha3_base_digest
+        }
+    }
+
+    pub fn read_data(&mut self, output: &mut [u8]) {
+        self.sha3_base_digest.squeeze_data(output)
+    }
+}
+
+impl Read for SHA3XOFReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.read_data(buf);
+        Ok(buf.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+    use crate::util::hex::hex_encode;
+    use super::super::sha3_base_digest::SHA3BaseDigest;
+    use super::super::sha3_type::SHA3Type;
+    use super::SHA3XOFReader;
+
+    fn reader(data: &[u8]) -> SHA3XOFReader {
+        let mut digest = SHA3BaseDigest::new(SHA3Type::SHAKE128);
+        digest.push_data(data);
+        digest.compute_digest();
+        SHA3XOFReader::new(digest)
+    }
+
+    #[test]
+    fn squeeze_across_rate_boundary() {
+        let mut output = [0; 400];
+        reader(b"abc").read_data(&mut output);
+        assert_eq!(hex_encode(&output[165..175]), "47584e6aa01b3f5af057");
+        for lengths in [[167, 2, 231], [168, 168, 64], [1, 335, 64], [169, 1, 230]] {
+            let mut reader = reader(b"abc");
+            let mut pieces = Vec::new();
+            for length in lengths {
+                let mut piece = vec![0; length];
+                reader.read_data(&mut piece);
+                pieces.extend_from_slice(&piece);
+            }
+            assert_eq!(pieces, output);
+        }
+    }
+
+    #[test]
+    fn read_trait_matches_read_data() {
+        let mut expected = [0; 200];
+        reader(b"abc").read_data(&mut expected);
+        let mut reader = reader(b"abc");
+        let mut output = [0; 200];
+        reader.read_exact(&mut output[..100]).unwrap();
+        reader.read_exact(&mut output[100..]).unwrap();
+        assert_eq!(output, expected);
+    }
+}
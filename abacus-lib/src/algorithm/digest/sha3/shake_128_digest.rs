@@ -0,0 +1,85 @@
+use super::sha3_base_digest::SHA3BaseDigest;
+use super::sha3_type::SHA3Type;
+use super::sha3_xof_reader::SHA3XOFReader;
+
+pub struct SHAKE128Digest {
+    sha3_base_digest: SHA3BaseDigest
+}
+
+impl Default for SHAKE128Digest {
+    fn default() -> Self {
+        SHAKE128Digest::new()
+    }
+}
+
+impl SHAKE128Digest {
+    pub fn new() -> SHAKE128Digest {
+        SHAKE128Digest {
+            sha3_base_digest: SHA3BaseDigest::new(SHA3Type::SHAKE128)
+        }
+    }
+
+    pub fn get_digest_bytes(&mut self, digest_byte_length: usize) -> Vec<u8> {
+        let mut digest_bytes = vec![0; digest_byte_length];
+        self.get_xof_reader().read_data(&mut digest_bytes);
+        digest_bytes
+    }
+
+    pub fn get_xof_reader(&self) -> SHA3XOFReader {
+        SHA3XOFReader::new(self.sha3_base_digest.clone())
+    }
+
+    pub fn compute_digest(&mut self) {
+        self.sha3_base_digest.compute_digest()
+    }
+
+    pub fn push_data(&mut self, data: &[u8]) {
+        self.sha3_base_digest.push_data(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::hex::hex_encode;
+    use super::SHAKE128Digest;
+
+    fn digest(data: &[u8], digest_byte_length: usize) -> String {
+        let mut digest = SHAKE128Digest::new();
+        digest.push_data(data);
+        digest.compute_digest();
+        hex_encode(&digest.get_digest_bytes(digest_byte_length))
+    }
+
+    #[test]
+    fn empty_message() {
+        assert_eq!(digest(b"", 32), "7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef26");
+    }
+
+    #[test]
+    fn short_message() {
+        assert_eq!(digest(b"abc", 32), "5881092dd818bf5cf8a3ddb793fbcba74097d5c526a6d35f97b83351940f2cc8");
+    }
+
+    #[test]
+    fn multi_rate_message() {
+        assert_eq!(digest(&[0xa3; 200], 32), "131ab8d2b594946b9c81333f9bb6e0ce75c3b93104fa3469d3917457385da037");
+    }
+
+    #[test]
+    fn output_longer_than_rate() {
+        assert_eq!(digest(b"", 512), "7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef263cb1eea988004b93103cfb0aeefd2a686e01fa4a58e8a3639ca8a1e3f9ae57e235b8cc873c23dc62b8d260169afa2f75ab916a58d974918835d25e6a435085b2badfd6dfaac359a5efbb7bcc4b59d538df9a04302e10c8bc1cbf1a0b3a5120ea17cda7cfad765f5623474d368ccca8af0007cd9f5e4c849f167a580b14aabdefaee7eef47cb0fca9767be1fda69419dfb927e9df07348b196691abaeb580b32def58538b8d23f87732ea63b02b4fa0f4873360e2841928cd60dd4cee8cc0d4c922a96188d032675c8ac850933c7aff1533b94c834adbb69c6115bad4692d8619f90b0cdf8a7b9c264029ac185b70b83f2801f2f4b3f70c593ea3aeeb613a7f1b1de33fd75081f592305f2e4526edc09631b10958f464d889f31ba010250fda7f1368ec2967fc84ef2ae9aff268e0b1700affc6820b523a3d917135f2dff2ee06bfe72b3124721d4a26c04e53a75e30e73a7a9c4a95d91c55d495e9f51dd0b5e9d83c6d5e8ce803aa62b8d654db53d09b8dcff273cdfeb573fad8bcd45578bec2e770d01efde86e721a3f7c6cce275dabe6e2143f1af18da7efddc4c7b70b5e345db93cc936bea323491ccb38a388f546a9ff00dd4e1300b9b2153d2041d205b443e41b45a653f2a5c4492c1add544512dda2529833462b71a41a45be97290b6f");
+    }
+
+    #[test]
+    fn xof_reader_reads_in_pieces() {
+        let mut digest = SHAKE128Digest::new();
+        digest.push_data(b"abc");
+        digest.compute_digest();
+        let mut reader = digest.get_xof_reader();
+        let mut output = vec![0; 512];
+        for piece in output.chunks_mut(37) {
+            reader.read_data(piece);
+        }
+        assert_eq!(hex_encode(&output), "5881092dd818bf5cf8a3ddb793fbcba74097d5c526a6d35f97b83351940f2cc844c50af32acd3f2cdd066568706f509bc1bdde58295dae3f891a9a0fca5783789a41f8611214ce612394df286a62d1a2252aa94db9c538956c717dc2bed4f232a0294c857c730aa16067ac1062f1201fb0d377cfb9cde4c63599b27f3462bba4a0ed296c801f9ff7f57302bb3076ee145f97a32ae68e76ab66c48d51675bd49acc29082f5647584e6aa01b3f5af057805f973ff8ecb8b226ac32ada6f01c1fcd4818cb006aa5b4cdb3611eb1e533c8964cacfdf31012cd3fb744d02225b988b475375faad996eb1b9176ecb0f8b2871723d6dbb804e23357e50732f5cfc904b1319795000d7361d9e5e1b77b4b8f5774aa1482cfa58f83096bdb2e06a3eed543a38919b57ecbec737f4086be007f8ef80094ceea8807193d46e9be540b6e99b4c1c71507095028a024e8d39aa8f4c5854cedd50d30a223e7d54e9a24f0a2526b31002afbd1b4ebea69c8400c3deb4c1c35d6dbb75651b284076f5fde47b4a0586ee173e30bd4d08f2bc59c6114bdd745d20876bee2bf800bd7d8b5e51536c844c73256f7d1ada1870c7bbaf83af10a6fdd7c02967811815459cfd02d67b936e975c6007c63ea7ae087f0a6b0a1319668bb61788eaa3d3b78e3f2061adcdead407085901803ec6f17f0ec650a292198275211a56bf13f0bf7241268b50d3f1ec8");
+    }
+}
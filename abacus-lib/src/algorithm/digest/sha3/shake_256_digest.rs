@@ -0,0 +1,85 @@
+use super::sha3_base_digest::SHA3BaseDigest;
+use super::sha3_type::SHA3Type;
+use super::sha3_xof_reader::SHA3XOFReader;
+
+pub struct SHAKE256Digest {
+    sha3_base_digest: SHA3BaseDigest
+}
+
+impl Default for SHAKE256Digest {
+    fn default() -> Self {
+        SHAKE256Digest::new()
+    }
+}
+
+impl SHAKE256Digest {
+    pub fn new() -> SHAKE256Digest {
+        SHAKE256Digest {
+            sha3_base_digest: SHA3BaseDigest::new(SHA3Type::SHAKE256)
+        }
+    }
+
+    pub fn get_digest_bytes(&mut self, digest_byte_length: usize) -> Vec<u8> {
+        let mut digest_bytes = vec![0; digest_byte_length];
+        self.get_xof_reader().read_data(&mut digest_bytes);
+        digest_bytes
+    }
+
+    pub fn get_xof_reader(&self) -> SHA3XOFReader {
+        SHA3XOFReader::new(self.sha3_base_digest.clone())
+    }
+
+    pub fn compute_digest(&mut self) {
+        self.sha3_base_digest.compute_digest()
+    }
+
+    pub fn push_data(&mut self, data: &[u8]) {
+        self.sha3_base_digest.push_data(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::hex::hex_encode;
+    use super::SHAKE256Digest;
+
+    fn digest(data: &[u8], digest_byte_length: usize) -> String {
+        let mut digest = SHAKE256Digest::new();
+        digest.push_data(data);
+        digest.compute_digest();
+        hex_encode(&digest.get_digest_bytes(digest_byte_length))
+    }
+
+    #[test]
+    fn empty_message() {
+        assert_eq!(digest(b"", 64), "46b9dd2b0ba88d13233b3feb743eeb243fcd52ea62b81b82b50c27646ed5762fd75dc4ddd8c0f200cb05019d67b592f6fc821c49479ab48640292eacb3b7c4be");
+    }
+
+    #[test]
+    fn short_message() {
+        assert_eq!(digest(b"abc", 64), "483366601360a8771c6863080cc4114d8db44530f8f1e1ee4f94ea37e78b5739d5a15bef186a5386c75744c0527e1faa9f8726e462a12a4feb06bd8801e751e4");
+    }
+
+    #[test]
+    fn multi_rate_message() {
+        assert_eq!(digest(&[0xa3; 200], 64), "cd8a920ed141aa0407a22d59288652e9d9f1a7ee0c1e7c1ca699424da84a904d2d700caae7396ece96604440577da4f3aa22aeb8857f961c4cd8e06f0ae6610b");
+    }
+
+    #[test]
+    fn output_longer_than_rate() {
+        assert_eq!(digest(b"", 512), "46b9dd2b0ba88d13233b3feb743eeb243fcd52ea62b81b82b50c27646ed5762fd75dc4ddd8c0f200cb05019d67b592f6fc821c49479ab48640292eacb3b7c4be141e96616fb13957692cc7edd0b45ae3dc07223c8e92937bef84bc0eab862853349ec75546f58fb7c2775c38462c5010d846c185c15111e595522a6bcd16cf86f3d122109e3b1fdd943b6aec468a2d621a7c06c6a957c62b54dafc3be87567d677231395f6147293b68ceab7a9e0c58d864e8efde4e1b9a46cbe854713672f5caaae314ed9083dab4b099f8e300f01b8650f1f4b1d8fcf3f3cb53fb8e9eb2ea203bdc970f50ae55428a91f7f53ac266b28419c3778a15fd248d339ede785fb7f5a1aaa96d313eacc890936c173cdcd0fab882c45755feb3aed96d477ff96390bf9a66d1368b208e21f7c10d04a3dbd4e360633e5db4b602601c14cea737db3dcf722632cc77851cbdde2aaf0a33a07b373445df490cc8fc1e4160ff118378f11f0477de055a81a9eda57a4a2cfb0c83929d310912f729ec6cfa36c6ac6a75837143045d791cc85eff5b21932f23861bcf23a52b5da67eaf7baae0f5fb1369db78f3ac45f8c4ac5671d85735cdddb09d2b1e34a1fc066ff4a162cb263d6541274ae2fcc865f618abe27c124cd8b074ccd516301b91875824d09958f341ef274bdab0bae316339894304e35877b0c28a9b1fd166c796b9cc258a064a8f57e27f2a");
+    }
+
+    #[test]
+    fn xof_reader_reads_in_pieces() {
+        let mut digest = SHAKE256Digest::new();
+        digest.push_data(b"abc");
+        digest.compute_digest();
+        let mut reader = digest.get_xof_reader();
+        let mut output = vec![0; 512];
+        for piece in output.chunks_mut(37) {
+            reader.read_data(piece);
+        }
+        assert_eq!(hex_encode(&output), "483366601360a8771c6863080cc4114d8db44530f8f1e1ee4f94ea37e78b5739d5a15bef186a5386c75744c0527e1faa9f8726e462a12a4feb06bd8801e751e41385141204f329979fd3047a13c5657724ada64d2470157b3cdc288620944d78dbcddbd912993f0913f164fb2ce95131a2d09a3e6d51cbfc622720d7a75c6334e8a2d7ec71a7cc29cf0ea610eeff1a588290a53000faa79932becec0bd3cd0b33a7e5d397fed1ada9442b99903f4dcfd8559ed3950faf40fe6f3b5d710ed3b677513771af6bfe11934817e8762d9896ba579d88d84ba7aa3cdc7055f6796f195bd9ae788f2f5bb96100d6bbaff7fbc6eea24d4449a2477d172a5507dcc931412fc346b1bb39b878330e026b12ddf384af3334560ea1d363966caa7d8ddcbec7da52b42215c11d5f8ee57f341e399343ce63a752fc5edec99124a0eb314403e5f358b8b83d05be2d2970099284b00dcc33d7c753d1f752ab743325bc53d91aa671e50f9c3f93abf6e9662f90145c61954f2abbd26edad1553ea3a626f359e8f79ade16384e151755c47e822fc74c5d7100fd31f667564c6debc7d20d99e109f22abcbb8c86c0c1146333903422252a5d34a896770b6cb99a1f33935bf87a8e47b0549dc2539de9e6a9d1e596111b660cb6b3e0040b4d4916f886dd0b6f1a702849440b99d6088e20203aebafa8e9dffa94ed35ef1f41f5fdf549fbcc5a0f68298");
+    }
+}
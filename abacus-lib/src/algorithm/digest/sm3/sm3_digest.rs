@@ -1,3 +1,4 @@
+use super::super::digest_trait::Digest;
 use super::sm3_constant::{SM3_BUFFER_BYTE_LENGTH, SM3_DIGEST_BYTE_LENGTH, SM3_DATA_BYTE_MAX_LENGTH};
 
 #[inline(always)]
@@ -41,6 +42,7 @@ fn big_endian_word(buffer: &[u8; SM3_BUFFER_BYTE_LENGTH], i: usize) -> u32 {
 }
 
 #[inline(always)]
+#[allow(clippy::too_many_arguments)]
 fn round_00_16(x1: u32, x2: &mut u32, x3: u32, x4: &mut u32, x5: u32, x6: &mut u32, x7: u32, x8: &mut u32, t: u32, w: u32, ww: u32) {
     let a_rl12 = x1.rotate_left(12);
     let ss1 = a_rl12.wrapping_add(x5).wrapping_add(t).rotate_left(7);
@@ -54,6 +56,7 @@ fn round_00_16(x1: u32, x2: &mut u32, x3: u32, x4: &mut u32, x5: u32, x6: &mut u
 }
 
 #[inline(always)]
+#[allow(clippy::too_many_arguments)]
 fn round_16_64(x1: u32, x2: &mut u32, x3: u32, x4: &mut u32, x5: u32, x6: &mut u32, x7: u32, x8: &mut u32, t: u32, w: u32, ww: u32) {
     let a_rl12 = x1.rotate_left(12);
     let ss1 = a_rl12.wrapping_add(x5).wrapping_add(t).rotate_left(7);
@@ -69,7 +72,7 @@ fn round_16_64(x1: u32, x2: &mut u32, x3: u32, x4: &mut u32, x5: u32, x6: &mut u
 #[inline(always)]
 fn fill_to_bytes(digest_bytes: &mut [u8; SM3_DIGEST_BYTE_LENGTH], x: u32, index: usize) {
     let word_bytes = x.to_be_bytes();
-    digest_bytes[index * 4 + 0] = word_bytes[0];
+    digest_bytes[index * 4] = word_bytes[0];
     digest_bytes[index * 4 + 1] = word_bytes[1];
     digest_bytes[index * 4 + 2] = word_bytes[2];
     digest_bytes[index * 4 + 3] = word_bytes[3];
@@ -101,9 +104,15 @@ pub struct SM3Digest {
     remains_data: Vec<u8>,
 }
 
+impl Default for SM3Digest {
+    fn default() -> Self {
+        SM3Digest::new()
+    }
+}
+
 impl SM3Digest {
     pub fn new() -> SM3Digest {
-        let instance = SM3Digest {
+        SM3Digest {
             a: 0x7380166f,
             b: 0x4914b2b9,
             c: 0x172442d7,
@@ -114,8 +123,7 @@ impl SM3Digest {
             h: 0xb0fb0e4e,
             total_length: 0,
             remains_data: Vec::new(),
-        };
-        instance
+        }
     }
 
     pub fn get_digest_bytes(&mut self) -> [u8; SM3_DIGEST_BYTE_LENGTH] {
@@ -135,9 +143,7 @@ impl SM3Digest {
         let remains_data_length = self.remains_data.len();
         let min_padding_byte_length = remains_data_length + SM3_DATA_BYTE_MAX_LENGTH + 1;
         let mut buffer: [u8; SM3_BUFFER_BYTE_LENGTH] = [0; SM3_BUFFER_BYTE_LENGTH];
-        for i in 0..self.remains_data.len() {
-            buffer[i] = self.remains_data[i];
-        }
+        buffer[..remains_data_length].copy_from_slice(&self.remains_data);
         self.remains_data.clear();
         buffer[remains_data_length] = 0x80;
         if min_padding_byte_length > SM3_BUFFER_BYTE_LENGTH {
@@ -159,22 +165,16 @@ impl SM3Digest {
         if remains_data_length > 0 && remains_data_length + data.len() >= SM3_BUFFER_BYTE_LENGTH {
             offset = SM3_BUFFER_BYTE_LENGTH - remains_data_length;
             let mut buffer: [u8; SM3_BUFFER_BYTE_LENGTH] = [0; SM3_BUFFER_BYTE_LENGTH];
-            for i in 0..self.remains_data.len() {
-                buffer[i] = self.remains_data[i];
-            }
+            buffer[..remains_data_length].copy_from_slice(&self.remains_data);
             self.remains_data.clear();
-            for i in remains_data_length..SM3_BUFFER_BYTE_LENGTH {
-                buffer[i] = data[i - remains_data_length];
-            }
+            buffer[remains_data_length..].copy_from_slice(&data[..offset]);
             self.update(&buffer);
         }
         let buffer_count = (data.len() - offset) / SM3_BUFFER_BYTE_LENGTH;
-        for i in 0..buffer_count  {
-            self.update(data[(i * SM3_BUFFER_BYTE_LENGTH + offset)..(i * SM3_BUFFER_BYTE_LENGTH + offset + SM3_BUFFER_BYTE_LENGTH)].try_into().unwrap());
-        }
-        for i in (buffer_count * SM3_BUFFER_BYTE_LENGTH + offset)..data.len() {
-            self.remains_data.push(data[i]);
+        for block in data[offset..(buffer_count * SM3_BUFFER_BYTE_LENGTH + offset)].chunks_exact(SM3_BUFFER_BYTE_LENGTH) {
+            self.update(block.try_into().unwrap());
         }
+        self.remains_data.extend_from_slice(&data[(buffer_count * SM3_BUFFER_BYTE_LENGTH + offset)..]);
     }
 
     fn update(&mut self, buffer: &[u8; SM3_BUFFER_BYTE_LENGTH]) {
@@ -328,3 +328,27 @@ impl SM3Digest {
         self.h ^= h;
     }
 }
+
+impl Digest for SM3Digest {
+    const ALGORITHM_NAME: &'static str = "SM3";
+    const DIGEST_BYTE_LENGTH: usize = SM3_DIGEST_BYTE_LENGTH;
+    const BUFFER_BYTE_LENGTH: usize = SM3_BUFFER_BYTE_LENGTH;
+
+    type DigestBytes = [u8; SM3_DIGEST_BYTE_LENGTH];
+
+    fn new() -> SM3Digest {
+        SM3Digest::new()
+    }
+
+    fn get_digest_bytes(&mut self) -> [u8; SM3_DIGEST_BYTE_LENGTH] {
+        SM3Digest::get_digest_bytes(self)
+    }
+
+    fn compute_digest(&mut self) {
+        SM3Digest::compute_digest(self)
+    }
+
+    fn push_data(&mut self, data: &[u8]) {
+        SM3Digest::push_data(self, data)
+    }
+}
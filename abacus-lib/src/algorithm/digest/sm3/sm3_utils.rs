@@ -1,33 +1,22 @@
-use std::fs::File;
 use std::io::Read;
+use std::path::Path;
+use crate::error::Error;
+use super::super::digest_utils::DigestUtils;
 use super::sm3_digest::SM3Digest;
 use super::sm3_constant::SM3_DIGEST_BYTE_LENGTH;
 
-const FILE_READ_SIZE: usize = 0x40000;
-
-pub(crate) struct SM3Utils;
+pub struct SM3Utils;
 
 impl SM3Utils {
     pub fn sm3_data_digest(data: &[u8]) -> [u8; SM3_DIGEST_BYTE_LENGTH] {
-        let mut sm3 = SM3Digest::new();
-        sm3.push_data(data);
-        sm3.compute_digest();
-        sm3.get_digest_bytes()
+        DigestUtils::data_digest::<SM3Digest>(data)
+    }
+
+    pub fn sm3_file_digest<P: AsRef<Path>>(file_path: P) -> Result<[u8; SM3_DIGEST_BYTE_LENGTH], Error> {
+        DigestUtils::file_digest::<SM3Digest, P>(file_path)
     }
 
-    pub fn sm3_file_digest(file_path: String) -> Result<[u8; SM3_DIGEST_BYTE_LENGTH], std::io::Error> {
-        let mut file = File::open(file_path)?;
-        let mut buffer = [0; FILE_READ_SIZE];
-        let mut sm3 = SM3Digest::new();
-        loop {
-            let read_size = file.read(&mut buffer)?;
-            if read_size < FILE_READ_SIZE {
-                sm3.push_data(&buffer[..read_size]);
-                break;
-            }
-            sm3.push_data(&buffer);
-        }
-        sm3.compute_digest();
-        Ok(sm3.get_digest_bytes())
+    pub fn sm3_reader_digest<R: Read>(reader: &mut R) -> Result<[u8; SM3_DIGEST_BYTE_LENGTH], Error> {
+        DigestUtils::reader_digest::<SM3Digest, R>(reader)
     }
 }
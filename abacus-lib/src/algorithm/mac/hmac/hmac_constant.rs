@@ -0,0 +1,2 @@
+pub const HMAC_INNER_PAD: u8 = 0x36;
+pub const HMAC_OUTER_PAD: u8 = 0x5c;
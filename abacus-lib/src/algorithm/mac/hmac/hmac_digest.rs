@@ -0,0 +1,125 @@
+use crate::algorithm::digest::digest_trait::Digest;
+use crate::util::constant_time::constant_time_eq;
+use super::hmac_constant::{HMAC_INNER_PAD, HMAC_OUTER_PAD};
+
+pub struct HMACDigest<D: Digest> {
+    inner_digest: D,
+    outer_digest: D,
+}
+
+impl<D: Digest> HMACDigest<D> {
+    pub fn new(key: &[u8]) -> HMACDigest<D> {
+        let mut key_block = vec![0; D::BUFFER_BYTE_LENGTH];
+        if key.len() > D::BUFFER_BYTE_LENGTH {
+            let mut key_digest = D::new();
+            key_digest.push_data(key);
+            key_digest.compute_digest();
+            let key_digest_bytes = key_digest.get_digest_bytes();
+            key_block[..D::DIGEST_BYTE_LENGTH].copy_from_slice(key_digest_bytes.as_ref());
+        } else {
+            key_block[..key.len()].copy_from_slice(key);
+        }
+        let mut inner_digest = D::new();
+        let mut outer_digest = D::new();
+        inner_digest.push_data(&key_block.iter().map(|k| k ^ HMAC_INNER_PAD).collect::<Vec<u8>>());
+        outer_digest.push_data(&key_block.iter().map(|k| k ^ HMAC_OUTER_PAD).collect::<Vec<u8>>());
+        key_block.fill(0x00);
+        HMACDigest {
+            inner_digest,
+            outer_digest,
+        }
+    }
+
+    pub fn get_digest_bytes(&mut self) -> D::DigestBytes {
+        self.outer_digest.get_digest_bytes()
+    }
+
+    pub fn compute_digest(&mut self) {
+        self.inner_digest.compute_digest();
+        let inner_digest_bytes = self.inner_digest.get_digest_bytes();
+        self.outer_digest.push_data(inner_digest_bytes.as_ref());
+        self.outer_digest.compute_digest();
+    }
+
+    pub fn push_data(&mut self, data: &[u8]) {
+        self.inner_digest.push_data(data)
+    }
+
+    pub fn verify_digest_bytes(&mut self, digest_bytes: &[u8]) -> bool {
+        constant_time_eq(self.get_digest_bytes().as_ref(), digest_bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithm::digest::digest_trait::Digest;
+    use crate::algorithm::digest::sha2::sha2_256_digest::SHA2_256Digest;
+    use crate::algorithm::digest::sm3::sm3_digest::SM3Digest;
+    use crate::util::hex::hex_encode;
+    use super::HMACDigest;
+
+    const LONG_MESSAGE: &[u8] = b"This is a test using a larger than block-size key and a larger than block-size data. The key needs to be hashed before being used by the HMAC algorithm.";
+
+    fn hmac<D: Digest>(key: &[u8], data: &[u8]) -> String {
+        let mut hmac = HMACDigest::<D>::new(key);
+        hmac.push_data(data);
+        hmac.compute_digest();
+        hex_encode(hmac.get_digest_bytes().as_ref())
+    }
+
+    fn rfc_4231_inputs() -> Vec<(Vec<u8>, Vec<u8>)> {
+        vec![
+            (vec![0x0b; 20], b"Hi There".to_vec()),
+            (b"Jefe".to_vec(), b"what do ya want for nothing?".to_vec()),
+            (vec![0xaa; 20], vec![0xdd; 50]),
+            ((0x01..=0x19).collect(), vec![0xcd; 50]),
+            (vec![0x0c; 20], b"Test With Truncation".to_vec()),
+            (vec![0xaa; 131], b"Test Using Larger Than Block-Size Key - Hash Key First".to_vec()),
+            (vec![0xaa; 131], LONG_MESSAGE.to_vec()),
+        ]
+    }
+
+    #[test]
+    fn hmac_sha256_rfc_4231() {
+        let expected = [
+            "b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7",
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843",
+            "773ea91e36800e46854db8ebd09181a72959098b3ef8c122d9635514ced565fe",
+            "82558a389a443c0ea4cc819899f2083a85f0faa3e578f8077a2e3ff46729665b",
+            "a3b6167473100ee06e0c796c2955552b",
+            "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54",
+            "9b09ffa71b942fcb27635fbcd5b0e944bfdc63644f0713938a7f51535c3a35e2",
+        ];
+        for ((key, data), expected) in rfc_4231_inputs().iter().zip(expected) {
+            assert_eq!(&hmac::<SHA2_256Digest>(key, data)[..expected.len()], expected);
+        }
+    }
+
+    #[test]
+    fn hmac_sm3_rfc_4231_inputs() {
+        let expected = [
+            "51b00d1fb49832bfb01c3ce27848e59f871d9ba938dc563b338ca964755cce70",
+            "2e87f1d16862e6d964b50a5200bf2b10b764faa9680a296a2405f24bec39f882",
+            "dd9421e1c725bdf52ec1aa34edadb3c97f5951a83a2fa93f73a7902bc1dcc777",
+            "b57c79be03472aeb8cada581dea332cb2ba83d19cb1b052dd07194def75fb8cd",
+            "f68127c9f91764f5fb820d537adc8330d3aa6ccc0016575fe3975bfe8d522ecb",
+            "b4fd844e13342002f0b2e0690ea7741f1497d993a70494cea601e657bedf67a0",
+            "5acbdeb0c8c1ef3a99088fe51c0a1d5f4e1c175935f016aee74eb8056db18acb",
+        ];
+        for ((key, data), expected) in rfc_4231_inputs().iter().zip(expected) {
+            assert_eq!(hmac::<SM3Digest>(key, data), expected);
+        }
+    }
+
+    #[test]
+    fn verify_rejects_modified_tag() {
+        let mut hmac = HMACDigest::<SM3Digest>::new(b"Jefe");
+        hmac.push_data(b"what do ya want for nothing?");
+        hmac.compute_digest();
+        let mut tag = hmac.get_digest_bytes();
+        assert!(hmac.verify_digest_bytes(&tag));
+        tag[31] ^= 0x01;
+        assert!(!hmac.verify_digest_bytes(&tag));
+        assert!(!hmac.verify_digest_bytes(&tag[..16]));
+    }
+}
@@ -0,0 +1,79 @@
+use crate::algorithm::digest::digest_trait::Digest;
+use crate::algorithm::digest::sha2::sha2_256_digest::SHA2_256Digest;
+use crate::algorithm::digest::sha2::sha2_constant::SHA2_256_DIGEST_BYTE_LENGTH;
+use crate::algorithm::digest::sm3::sm3_digest::SM3Digest;
+use crate::algorithm::digest::sm3::sm3_constant::SM3_DIGEST_BYTE_LENGTH;
+use super::hmac_digest::HMACDigest;
+
+pub struct HMACUtils;
+
+impl HMACUtils {
+    pub fn hmac_data_digest<D: Digest>(key: &[u8], data: &[u8]) -> D::DigestBytes {
+        let mut hmac = HMACDigest::<D>::new(key);
+        hmac.push_data(data);
+        hmac.compute_digest();
+        hmac.get_digest_bytes()
+    }
+
+    pub fn hmac_data_verify<D: Digest>(key: &[u8], data: &[u8], digest_bytes: &[u8]) -> bool {
+        let mut hmac = HMACDigest::<D>::new(key);
+        hmac.push_data(data);
+        hmac.compute_digest();
+        hmac.verify_digest_bytes(digest_bytes)
+    }
+
+    pub fn hmac_sha2_256_data_digest(key: &[u8], data: &[u8]) -> [u8; SHA2_256_DIGEST_BYTE_LENGTH] {
+        HMACUtils::hmac_data_digest::<SHA2_256Digest>(key, data)
+    }
+
+    pub fn hmac_sm3_data_digest(key: &[u8], data: &[u8]) -> [u8; SM3_DIGEST_BYTE_LENGTH] {
+        HMACUtils::hmac_data_digest::<SM3Digest>(key, data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithm::digest::sha2::sha2_256_digest::SHA2_256Digest;
+    use crate::algorithm::digest::sha2::sha2_512_digest::SHA2_512Digest;
+    use crate::algorithm::digest::sha3::sha3_256_digest::SHA3_256Digest;
+    use crate::algorithm::digest::sm3::sm3_digest::SM3Digest;
+    use crate::util::hex::hex_encode;
+    use super::HMACUtils;
+
+    #[test]
+    fn key_longer_than_block() {
+        let digest_bytes = HMACUtils::hmac_data_digest::<SHA2_512Digest>(&[0xaa; 131], b"Test Using Larger Than Block-Size Key - Hash Key First");
+        assert_eq!(hex_encode(&digest_bytes), concat!(
+            "80b24263c7c1a3ebb71493c1dd7be8b49b46d1f41b4aeec1121b013783f8f352",
+            "6b56d037e05f2598bd0fd2215d6a1e5295e64f73f63f0aec8b915a985d786598",
+        ));
+        let key: Vec<u8> = (0..200).collect();
+        let digest_bytes = HMACUtils::hmac_data_digest::<SHA3_256Digest>(&key, b"abc");
+        assert_eq!(hex_encode(&digest_bytes), "1f7dfd49a98b93988cd1c0ad6ab34f848063aca63e48b445c116442da812edb1");
+    }
+
+    #[test]
+    fn key_at_block_boundary() {
+        let key: Vec<u8> = (0..65).collect();
+        assert_eq!(hex_encode(&HMACUtils::hmac_sha2_256_data_digest(&key[..64], b"abc")), "6ab541b4869dca71c4ca11d8bb1b02533b789a557583161429292c7404bc21f6");
+        assert_eq!(hex_encode(&HMACUtils::hmac_sha2_256_data_digest(&key, b"abc")), "dfbffee4671bad00ed5d1e1999d55ed3b0cc774ac357f9ebf649c1612414fcec");
+    }
+
+    #[test]
+    fn verify_rejects_mismatch() {
+        let digest_bytes = HMACUtils::hmac_sm3_data_digest(b"Jefe", b"what do ya want for nothing?");
+        assert!(HMACUtils::hmac_data_verify::<SM3Digest>(b"Jefe", b"what do ya want for nothing?", &digest_bytes));
+        assert!(!HMACUtils::hmac_data_verify::<SM3Digest>(b"Jefe", b"what do ya want for nothing!", &digest_bytes));
+        assert!(!HMACUtils::hmac_data_verify::<SM3Digest>(b"jefe", b"what do ya want for nothing?", &digest_bytes));
+        for i in 0..digest_bytes.len() {
+            let mut tampered = digest_bytes;
+            tampered[i] ^= 0x80;
+            assert!(!HMACUtils::hmac_data_verify::<SM3Digest>(b"Jefe", b"what do ya want for nothing?", &tampered));
+        }
+        assert!(!HMACUtils::hmac_data_verify::<SM3Digest>(b"Jefe", b"what do ya want for nothing?", &digest_bytes[..31]));
+        assert!(!HMACUtils::hmac_data_verify::<SM3Digest>(b"Jefe", b"what do ya want for nothing?", &[]));
+        let digest_bytes = HMACUtils::hmac_sha2_256_data_digest(b"Jefe", b"what do ya want for nothing?");
+        assert!(!HMACUtils::hmac_data_verify::<SM3Digest>(b"Jefe", b"what do ya want for nothing?", &digest_bytes));
+        assert!(HMACUtils::hmac_data_verify::<SHA2_256Digest>(b"Jefe", b"what do ya want for nothing?", &digest_bytes));
+    }
+}
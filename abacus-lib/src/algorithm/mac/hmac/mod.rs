@@ -0,0 +1,3 @@
+pub mod hmac_constant;
+pub mod hmac_digest;
+pub mod hmac_utils;
@@ -0,0 +1,3 @@
+pub mod poly1305_constant;
+pub mod poly1305_digest;
+pub mod poly1305_utils;
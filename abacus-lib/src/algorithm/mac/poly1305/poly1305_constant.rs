@@ -0,0 +1,3 @@
+pub const POLY1305_KEY_BYTE_LENGTH: usize = 32;
+pub const POLY1305_BLOCK_BYTE_LENGTH: usize = 16;
+pub const POLY1305_DIGEST_BYTE_LENGTH: usize = 16;
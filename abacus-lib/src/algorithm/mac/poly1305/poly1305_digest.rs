@@ -0,0 +1,133 @@
+use crate::util::constant_time::constant_time_eq;
+use super::poly1305_constant::{POLY1305_KEY_BYTE_LENGTH, POLY1305_BLOCK_BYTE_LENGTH, POLY1305_DIGEST_BYTE_LENGTH};
+
+const LIMB_MASK: u32 = 0x3ffffff;
+
+#[inline(always)]
+fn little_endian_word(buffer: &[u8], i: usize) -> u32 {
+    u32::from_le_bytes(buffer[i..(i + 4)].try_into().unwrap())
+}
+
+pub struct Poly1305Digest {
+    r: [u32; 5],
+    pad: [u32; 4],
+    h: [u32; 5],
+    buffer: [u8; POLY1305_BLOCK_BYTE_LENGTH],
+    buffer_length: usize,
+    digest: [u8; POLY1305_DIGEST_BYTE_LENGTH],
+}
+
+impl Poly1305Digest {
+    pub fn new(key: [u8; POLY1305_KEY_BYTE_LENGTH]) -> Poly1305Digest {
+        Poly1305Digest {
+            r: [
+                little_endian_word(&key, 0) & 0x3ffffff,
+                (little_endian_word(&key, 3) >> 2) & 0x3ffff03,
+                (little_endian_word(&key, 6) >> 4) & 0x3ffc0ff,
+                (little_endian_word(&key, 9) >> 6) & 0x3f03fff,
+                (little_endian_word(&key, 12) >> 8) & 0x00fffff,
+            ],
+            pad: [
+                little_endian_word(&key, 16),
+                little_endian_word(&key, 20),
+                little_endian_word(&key, 24),
+                little_endian_word(&key, 28),
+            ],
+            h: [0; 5],
+            buffer: [0; POLY1305_BLOCK_BYTE_LENGTH],
+            buffer_length: 0,
+            digest: [0; POLY1305_DIGEST_BYTE_LENGTH],
+        }
+    }
+
+    fn update(&mut self, block: &[u8; POLY1305_BLOCK_BYTE_LENGTH], high_bit: u32) {
+        let [r0, r1, r2, r3, r4] = self.r.map(|r| r as u64);
+        let (s1, s2, s3, s4) = (r1 * 5, r2 * 5, r3 * 5, r4 * 5);
+        let h0 = (self.h[0] + (little_endian_word(block, 0) & LIMB_MASK)) as u64;
+        let h1 = (self.h[1] + ((little_endian_word(block, 3) >> 2) & LIMB_MASK)) as u64;
+        let h2 = (self.h[2] + ((little_endian_word(block, 6) >> 4) & LIMB_MASK)) as u64;
+        let h3 = (self.h[3] + ((little_endian_word(block, 9) >> 6) & LIMB_MASK)) as u64;
+        let h4 = (self.h[4] + ((little_endian_word(block, 12) >> 8) | high_bit)) as u64;
+        let d0 = h0 * r0 + h1 * s4 + h2 * s3 + h3 * s2 + h4 * s1;
+        let mut d1 = h0 * r1 + h1 * r0 + h2 * s4 + h3 * s3 + h4 * s2;
+        let mut d2 = h0 * r2 + h1 * r1 + h2 * r0 + h3 * s4 + h4 * s3;
+        let mut d3 = h0 * r3 + h1 * r2 + h2 * r1 + h3 * r0 + h4 * s4;
+        let mut d4 = h0 * r4 + h1 * r3 + h2 * r2 + h3 * r1 + h4 * r0;
+        d1 += d0 >> 26;
+        d2 += d1 >> 26;
+        d3 += d2 >> 26;
+        d4 += d3 >> 26;
+        let mut h0 = (d0 as u32 & LIMB_MASK) + (d4 >> 26) as u32 * 5;
+        let h1 = (d1 as u32 & LIMB_MASK) + (h0 >> 26);
+        h0 &= LIMB_MASK;
+        self.h = [h0, h1, d2 as u32 & LIMB_MASK, d3 as u32 & LIMB_MASK, d4 as u32 & LIMB_MASK];
+    }
+
+    pub fn get_digest_bytes(&self) -> [u8; POLY1305_DIGEST_BYTE_LENGTH] {
+        self.digest
+    }
+
+    pub fn compute_digest(&mut self) {
+        if self.buffer_length != 0 {
+            let mut block = [0; POLY1305_BLOCK_BYTE_LENGTH];
+            block[..self.buffer_length].copy_from_slice(&self.buffer[..self.buffer_length]);
+            block[self.buffer_length] = 0x01;
+            self.update(&block, 0);
+            self.buffer_length = 0;
+        }
+        let [mut h0, mut h1, mut h2, mut h3, mut h4] = self.h;
+        h2 += h1 >> 26;
+        h1 &= LIMB_MASK;
+        h3 += h2 >> 26;
+        h2 &= LIMB_MASK;
+        h4 += h3 >> 26;
+        h3 &= LIMB_MASK;
+        h0 += (h4 >> 26) * 5;
+        h4 &= LIMB_MASK;
+        h1 += h0 >> 26;
+        h0 &= LIMB_MASK;
+        let mut g0 = h0 + 5;
+        let mut g1 = h1 + (g0 >> 26);
+        g0 &= LIMB_MASK;
+        let mut g2 = h2 + (g1 >> 26);
+        g1 &= LIMB_MASK;
+        let mut g3 = h3 + (g2 >> 26);
+        g2 &= LIMB_MASK;
+        let mut g4 = (h4 + (g3 >> 26)).wrapping_sub(1 << 26);
+        g3 &= LIMB_MASK;
+        let select_mask = (g4 >> 31).wrapping_sub(1);
+        g0 &= select_mask;
+        g1 &= select_mask;
+        g2 &= select_mask;
+        g3 &= select_mask;
+        g4 &= select_mask;
+        h0 = (h0 & !select_mask) | g0;
+        h1 = (h1 & !select_mask) | g1;
+        h2 = (h2 & !select_mask) | g2;
+        h3 = (h3 & !select_mask) | g3;
+        h4 = (h4 & !select_mask) | g4;
+        let words = [h0 | (h1 << 26), (h1 >> 6) | (h2 << 20), (h2 >> 12) | (h3 << 14), (h3 >> 18) | (h4 << 8)];
+        let mut carry: u64 = 0;
+        for (i, (&word, &pad)) in words.iter().zip(self.pad.iter()).enumerate() {
+            carry += word as u64 + pad as u64;
+            self.digest[(i * 4)..(i * 4 + 4)].copy_from_slice(&(carry as u32).to_le_bytes());
+            carry >>= 32;
+        }
+    }
+
+    pub fn push_data(&mut self, data: &[u8]) {
+        for &byte in data {
+            self.buffer[self.buffer_length] = byte;
+            self.buffer_length += 1;
+            if self.buffer_length == POLY1305_BLOCK_BYTE_LENGTH {
+                let block = self.buffer;
+                self.update(&block, 1 << 24);
+                self.buffer_length = 0;
+            }
+        }
+    }
+
+    pub fn verify_digest_bytes(&self, digest_bytes: &[u8]) -> bool {
+        constant_time_eq(&self.digest, digest_bytes)
+    }
+}
@@ -0,0 +1,20 @@
+use super::poly1305_constant::{POLY1305_KEY_BYTE_LENGTH, POLY1305_DIGEST_BYTE_LENGTH};
+use super::poly1305_digest::Poly1305Digest;
+
+pub struct Poly1305Utils;
+
+impl Poly1305Utils {
+    pub fn poly1305_data_digest(key: [u8; POLY1305_KEY_BYTE_LENGTH], data: &[u8]) -> [u8; POLY1305_DIGEST_BYTE_LENGTH] {
+        let mut poly1305 = Poly1305Digest::new(key);
+        poly1305.push_data(data);
+        poly1305.compute_digest();
+        poly1305.get_digest_bytes()
+    }
+
+    pub fn poly1305_data_verify(key: [u8; POLY1305_KEY_BYTE_LENGTH], data: &[u8], digest_bytes: &[u8]) -> bool {
+        let mut poly1305 = Poly1305Digest::new(key);
+        poly1305.push_data(data);
+        poly1305.compute_digest();
+        poly1305.verify_digest_bytes(digest_bytes)
+    }
+}
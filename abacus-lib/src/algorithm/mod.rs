@@ -1,2 +1,4 @@
 pub mod digest;
+pub mod mac;
 pub mod symmetric_encryption;
+pub mod asymmetric_encryption;
@@ -0,0 +1,111 @@
+use crate::error::Error;
+use super::super::block_mode::cbc_mode::CBCMode;
+use super::super::block_mode::padding::Padding;
+use super::aes_constant::AES_BLOCK_BYTE_LENGTH;
+use super::aes_encryption::AESEncryption;
+
+pub fn aes_cbc_encrypt_data(origin_data: &[u8], key: &[u8], iv: [u8; AES_BLOCK_BYTE_LENGTH]) -> Result<Vec<u8>, Error> {
+    CBCMode::new(AESEncryption::with_key(key)?, iv).encrypt_data(origin_data)
+}
+
+pub fn aes_cbc_decrypt_data(enciphered_data: &[u8], key: &[u8], iv: [u8; AES_BLOCK_BYTE_LENGTH]) -> Result<Vec<u8>, Error> {
+    CBCMode::new(AESEncryption::with_key(key)?, iv).decrypt_data(enciphered_data)
+}
+
+pub fn aes_cbc_encrypt_data_with_padding(origin_data: &[u8], key: &[u8], iv: [u8; AES_BLOCK_BYTE_LENGTH], padding: Padding) -> Result<Vec<u8>, Error> {
+    CBCMode::new(AESEncryption::with_key(key)?, iv).encrypt_data_with_padding(origin_data, padding)
+}
+
+pub fn aes_cbc_decrypt_data_with_padding(enciphered_data: &[u8], key: &[u8], iv: [u8; AES_BLOCK_BYTE_LENGTH], padding: Padding) -> Result<Vec<u8>, Error> {
+    CBCMode::new(AESEncryption::with_key(key)?, iv).decrypt_data_with_padding(enciphered_data, padding)
+}
+
+pub fn aes_cbc_encrypt_data_in_place(data: &mut [u8], key: &[u8], iv: [u8; AES_BLOCK_BYTE_LENGTH]) -> Result<(), Error> {
+    CBCMode::new(AESEncryption::with_key(key)?, iv).encrypt_data_in_place(data)
+}
+
+pub fn aes_cbc_decrypt_data_in_place(data: &mut [u8], key: &[u8], iv: [u8; AES_BLOCK_BYTE_LENGTH]) -> Result<(), Error> {
+    CBCMode::new(AESEncryption::with_key(key)?, iv).decrypt_data_in_place(data)
+}
+
+pub fn aes_cbc_encrypt_data_into(origin_data: &[u8], enciphered_data: &mut [u8], key: &[u8], iv: [u8; AES_BLOCK_BYTE_LENGTH]) -> Result<(), Error> {
+    CBCMode::new(AESEncryption::with_key(key)?, iv).encrypt_data_into(origin_data, enciphered_data)
+}
+
+pub fn aes_cbc_decrypt_data_into(enciphered_data: &[u8], origin_data: &mut [u8], key: &[u8], iv: [u8; AES_BLOCK_BYTE_LENGTH]) -> Result<(), Error> {
+    CBCMode::new(AESEncryption::with_key(key)?, iv).decrypt_data_into(enciphered_data, origin_data)
+}
+
+pub fn aes_cbc_encrypt_data_with_padding_in_place(buffer: &mut [u8], data_length: usize, key: &[u8], iv: [u8; AES_BLOCK_BYTE_LENGTH], padding: Padding) -> Result<usize, Error> {
+    CBCMode::new(AESEncryption::with_key(key)?, iv).encrypt_data_with_padding_in_place(buffer, data_length, padding)
+}
+
+pub fn aes_cbc_decrypt_data_with_padding_in_place(data: &mut [u8], key: &[u8], iv: [u8; AES_BLOCK_BYTE_LENGTH], padding: Padding) -> Result<usize, Error> {
+    CBCMode::new(AESEncryption::with_key(key)?, iv).decrypt_data_with_padding_in_place(data, padding)
+}
+
+pub fn try_aes_cbc_encrypt_data(origin_data: &[u8], key: &[u8], iv: &[u8]) -> Result<Vec<u8>, Error> {
+    let aes = AESEncryption::with_key(key)?;
+    let iv: [u8; AES_BLOCK_BYTE_LENGTH] = iv.try_into().map_err(|_| Error::InvalidIVLength)?;
+    CBCMode::new(aes, iv).encrypt_data(origin_data)
+}
+
+pub fn try_aes_cbc_decrypt_data(enciphered_data: &[u8], key: &[u8], iv: &[u8]) -> Result<Vec<u8>, Error> {
+    let aes = AESEncryption::with_key(key)?;
+    let iv: [u8; AES_BLOCK_BYTE_LENGTH] = iv.try_into().map_err(|_| Error::InvalidIVLength)?;
+    CBCMode::new(aes, iv).decrypt_data(enciphered_data)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::util::hex::{hex_decode, hex_encode};
+    use super::{aes_cbc_decrypt_data, aes_cbc_encrypt_data, try_aes_cbc_encrypt_data};
+
+    const IV: [u8; 16] = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f];
+    const ORIGIN_DATA: &str = concat!(
+        "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e51",
+        "30c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710",
+    );
+    const VECTORS: [(&str, &str); 3] = [
+        (
+            "2b7e151628aed2a6abf7158809cf4f3c",
+            concat!(
+                "7649abac8119b246cee98e9b12e9197d5086cb9b507219ee95db113a917678b2",
+                "73bed6b8e3c1743b7116e69e222295163ff1caa1681fac09120eca307586e1a7",
+            ),
+        ),
+        (
+            "8e73b0f7da0e6452c810f32b809079e562f8ead2522c6b7b",
+            concat!(
+                "4f021db243bc633d7178183a9fa071e8b4d9ada9ad7dedf4e5e738763f69145a",
+                "571b242012fb7ae07fa9baac3df102e008b0e27988598881d920a9e64f5615cd",
+            ),
+        ),
+        (
+            "603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4",
+            concat!(
+                "f58c4c04d6e5f1ba779eabfb5f7bfbd69cfc4e967edb808d679f777bc6702c7d",
+                "39f23369a9d9bacfa530e26304231461b2eb05e2c39be9fcda6c19078c6a9d1b",
+            ),
+        ),
+    ];
+
+    #[test]
+    fn sp800_38a_vectors() {
+        let origin_data = hex_decode(ORIGIN_DATA).unwrap();
+        for (key, expected) in VECTORS {
+            let key = hex_decode(key).unwrap();
+            let enciphered_data = aes_cbc_encrypt_data(&origin_data, &key, IV).unwrap();
+            assert_eq!(hex_encode(&enciphered_data), expected);
+            assert_eq!(aes_cbc_decrypt_data(&enciphered_data, &key, IV).unwrap(), origin_data);
+        }
+    }
+
+    #[test]
+    fn invalid_key_length_is_rejected() {
+        let origin_data = hex_decode(ORIGIN_DATA).unwrap();
+        assert!(matches!(aes_cbc_encrypt_data(&origin_data, &[0u8; 20], IV), Err(Error::InvalidKeyLength)));
+        assert!(matches!(try_aes_cbc_encrypt_data(&origin_data, &[0u8; 16], &IV[..12]), Err(Error::InvalidIVLength)));
+    }
+}
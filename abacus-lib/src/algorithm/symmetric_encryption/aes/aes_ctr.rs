@@ -0,0 +1,140 @@
+use crate::error::Error;
+use super::super::block_mode::ctr_counter::CTRCounter;
+use super::super::block_mode::ctr_mode::CTRMode;
+use super::aes_constant::AES_BLOCK_BYTE_LENGTH;
+use super::aes_encryption::AESEncryption;
+
+pub struct AESCTREncryption {
+    ctr_mode: CTRMode<AESEncryption>,
+}
+
+impl AESCTREncryption {
+    pub fn new(key: &[u8], iv: [u8; AES_BLOCK_BYTE_LENGTH], ctr_counter: CTRCounter) -> Result<AESCTREncryption, Error> {
+        Ok(AESCTREncryption {
+            ctr_mode: CTRMode::new(AESEncryption::with_key(key)?, iv, ctr_counter)?,
+        })
+    }
+
+    pub fn seek(&mut self, position: u64) {
+        self.ctr_mode.seek(position)
+    }
+
+    pub fn position(&self) -> u64 {
+        self.ctr_mode.position()
+    }
+
+    pub fn apply_key_stream(&mut self, data: &mut [u8]) -> Result<(), Error> {
+        self.ctr_mode.apply_key_stream(data)
+    }
+
+    pub fn apply_key_stream_into(&mut self, input_data: &[u8], output_data: &mut [u8]) -> Result<(), Error> {
+        self.ctr_mode.apply_key_stream_into(input_data, output_data)
+    }
+}
+
+pub fn aes_ctr_encrypt_data(origin_data: &[u8], key: &[u8], iv: [u8; AES_BLOCK_BYTE_LENGTH], ctr_counter: CTRCounter) -> Result<Vec<u8>, Error> {
+    aes_ctr_encrypt_data_at(origin_data, key, iv, ctr_counter, 0)
+}
+
+pub fn aes_ctr_decrypt_data(enciphered_data: &[u8], key: &[u8], iv: [u8; AES_BLOCK_BYTE_LENGTH], ctr_counter: CTRCounter) -> Result<Vec<u8>, Error> {
+    aes_ctr_encrypt_data_at(enciphered_data, key, iv, ctr_counter, 0)
+}
+
+pub fn aes_ctr_encrypt_data_at(origin_data: &[u8], key: &[u8], iv: [u8; AES_BLOCK_BYTE_LENGTH], ctr_counter: CTRCounter, position: u64) -> Result<Vec<u8>, Error> {
+    let mut aes_ctr = AESCTREncryption::new(key, iv, ctr_counter)?;
+    let mut enciphered_data = origin_data.to_vec();
+    aes_ctr.seek(position);
+    aes_ctr.apply_key_stream(&mut enciphered_data)?;
+    Ok(enciphered_data)
+}
+
+pub fn aes_ctr_decrypt_data_at(enciphered_data: &[u8], key: &[u8], iv: [u8; AES_BLOCK_BYTE_LENGTH], ctr_counter: CTRCounter, position: u64) -> Result<Vec<u8>, Error> {
+    aes_ctr_encrypt_data_at(enciphered_data, key, iv, ctr_counter, position)
+}
+
+pub fn aes_ctr_encrypt_data_in_place(data: &mut [u8], key: &[u8], iv: [u8; AES_BLOCK_BYTE_LENGTH], ctr_counter: CTRCounter) -> Result<(), Error> {
+    AESCTREncryption::new(key, iv, ctr_counter)?.apply_key_stream(data)
+}
+
+pub fn aes_ctr_decrypt_data_in_place(data: &mut [u8], key: &[u8], iv: [u8; AES_BLOCK_BYTE_LENGTH], ctr_counter: CTRCounter) -> Result<(), Error> {
+    AESCTREncryption::new(key, iv, ctr_counter)?.apply_key_stream(data)
+}
+
+pub fn aes_ctr_encrypt_data_into(origin_data: &[u8], enciphered_data: &mut [u8], key: &[u8], iv: [u8; AES_BLOCK_BYTE_LENGTH], ctr_counter: CTRCounter) -> Result<(), Error> {
+    AESCTREncryption::new(key, iv, ctr_counter)?.apply_key_stream_into(origin_data, enciphered_data)
+}
+
+pub fn aes_ctr_decrypt_data_into(enciphered_data: &[u8], origin_data: &mut [u8], key: &[u8], iv: [u8; AES_BLOCK_BYTE_LENGTH], ctr_counter: CTRCounter) -> Result<(), Error> {
+    AESCTREncryption::new(key, iv, ctr_counter)?.apply_key_stream_into(enciphered_data, origin_data)
+}
+
+pub fn try_aes_ctr_encrypt_data(origin_data: &[u8], key: &[u8], iv: &[u8], ctr_counter: CTRCounter) -> Result<Vec<u8>, Error> {
+    let aes = AESEncryption::with_key(key)?;
+    let iv: [u8; AES_BLOCK_BYTE_LENGTH] = iv.try_into().map_err(|_| Error::InvalidIVLength)?;
+    CTRMode::new(aes, iv, ctr_counter)?.encrypt_data(origin_data)
+}
+
+pub fn try_aes_ctr_decrypt_data(enciphered_data: &[u8], key: &[u8], iv: &[u8], ctr_counter: CTRCounter) -> Result<Vec<u8>, Error> {
+    let aes = AESEncryption::with_key(key)?;
+    let iv: [u8; AES_BLOCK_BYTE_LENGTH] = iv.try_into().map_err(|_| Error::InvalidIVLength)?;
+    CTRMode::new(aes, iv, ctr_counter)?.decrypt_data(enciphered_data)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::util::hex::{hex_decode, hex_encode};
+    use super::super::super::block_mode::ctr_counter::{CTRCounter, CTREndianness};
+    use super::{aes_ctr_decrypt_data, aes_ctr_encrypt_data, try_aes_ctr_encrypt_data};
+
+    const CTR_IV: [u8; 16] = [0xf0, 0xf1, 0xf2, 0xf3, 0xf4, 0xf5, 0xf6, 0xf7, 0xf8, 0xf9, 0xfa, 0xfb, 0xfc, 0xfd, 0xfe, 0xff];
+    const ORIGIN_DATA: &str = concat!(
+        "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e51",
+        "30c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710",
+    );
+    const VECTORS: [(&str, &str); 3] = [
+        (
+            "2b7e151628aed2a6abf7158809cf4f3c",
+            concat!(
+                "874d6191b620e3261bef6864990db6ce9806f66b7970fdff8617187bb9fffdff",
+                "5ae4df3edbd5d35e5b4f09020db03eab1e031dda2fbe03d1792170a0f3009cee",
+            ),
+        ),
+        (
+            "8e73b0f7da0e6452c810f32b809079e562f8ead2522c6b7b",
+            concat!(
+                "1abc932417521ca24f2b0459fe7e6e0b090339ec0aa6faefd5ccc2c6f4ce8e94",
+                "1e36b26bd1ebc670d1bd1d665620abf74f78a7f6d29809585a97daec58c6b050",
+            ),
+        ),
+        (
+            "603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4",
+            concat!(
+                "601ec313775789a5b7a7f504bbf3d228f443e3ca4d62b59aca84e990cacaf5c5",
+                "2b0930daa23de94ce87017ba2d84988ddfc9c58db67aada613c2dd08457941a6",
+            ),
+        ),
+    ];
+
+    fn counter() -> CTRCounter {
+        CTRCounter::new(16, CTREndianness::BigEndian).unwrap()
+    }
+
+    #[test]
+    fn sp800_38a_vectors() {
+        let origin_data = hex_decode(ORIGIN_DATA).unwrap();
+        for (key, expected) in VECTORS {
+            let key = hex_decode(key).unwrap();
+            let enciphered_data = aes_ctr_encrypt_data(&origin_data, &key, CTR_IV, counter()).unwrap();
+            assert_eq!(hex_encode(&enciphered_data), expected);
+            assert_eq!(aes_ctr_decrypt_data(&enciphered_data, &key, CTR_IV, counter()).unwrap(), origin_data);
+        }
+    }
+
+    #[test]
+    fn invalid_key_length_is_rejected() {
+        let origin_data = hex_decode(ORIGIN_DATA).unwrap();
+        assert!(matches!(aes_ctr_encrypt_data(&origin_data, &[0u8; 20], CTR_IV, counter()), Err(Error::InvalidKeyLength)));
+        assert!(matches!(try_aes_ctr_encrypt_data(&origin_data, &[0u8; 16], &CTR_IV[..12], counter()), Err(Error::InvalidIVLength)));
+    }
+}
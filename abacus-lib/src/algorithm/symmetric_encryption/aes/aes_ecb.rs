@@ -0,0 +1,96 @@
+use crate::error::Error;
+use super::super::block_mode::ecb_mode::ECBMode;
+use super::super::block_mode::padding::Padding;
+use super::aes_encryption::AESEncryption;
+
+pub fn aes_ecb_encrypt_data(origin_data: &[u8], key: &[u8]) -> Result<Vec<u8>, Error> {
+    ECBMode::new(AESEncryption::with_key(key)?).encrypt_data(origin_data)
+}
+
+pub fn aes_ecb_decrypt_data(enciphered_data: &[u8], key: &[u8]) -> Result<Vec<u8>, Error> {
+    ECBMode::new(AESEncryption::with_key(key)?).decrypt_data(enciphered_data)
+}
+
+pub fn aes_ecb_encrypt_data_with_padding(origin_data: &[u8], key: &[u8], padding: Padding) -> Result<Vec<u8>, Error> {
+    ECBMode::new(AESEncryption::with_key(key)?).encrypt_data_with_padding(origin_data, padding)
+}
+
+pub fn aes_ecb_decrypt_data_with_padding(enciphered_data: &[u8], key: &[u8], padding: Padding) -> Result<Vec<u8>, Error> {
+    ECBMode::new(AESEncryption::with_key(key)?).decrypt_data_with_padding(enciphered_data, padding)
+}
+
+pub fn aes_ecb_encrypt_data_in_place(data: &mut [u8], key: &[u8]) -> Result<(), Error> {
+    ECBMode::new(AESEncryption::with_key(key)?).encrypt_data_in_place(data)
+}
+
+pub fn aes_ecb_decrypt_data_in_place(data: &mut [u8], key: &[u8]) -> Result<(), Error> {
+    ECBMode::new(AESEncryption::with_key(key)?).decrypt_data_in_place(data)
+}
+
+pub fn aes_ecb_encrypt_data_into(origin_data: &[u8], enciphered_data: &mut [u8], key: &[u8]) -> Result<(), Error> {
+    ECBMode::new(AESEncryption::with_key(key)?).encrypt_data_into(origin_data, enciphered_data)
+}
+
+pub fn aes_ecb_decrypt_data_into(enciphered_data: &[u8], origin_data: &mut [u8], key: &[u8]) -> Result<(), Error> {
+    ECBMode::new(AESEncryption::with_key(key)?).decrypt_data_into(enciphered_data, origin_data)
+}
+
+pub fn aes_ecb_encrypt_data_with_padding_in_place(buffer: &mut [u8], data_length: usize, key: &[u8], padding: Padding) -> Result<usize, Error> {
+    ECBMode::new(AESEncryption::with_key(key)?).encrypt_data_with_padding_in_place(buffer, data_length, padding)
+}
+
+pub fn aes_ecb_decrypt_data_with_padding_in_place(data: &mut [u8], key: &[u8], padding: Padding) -> Result<usize, Error> {
+    ECBMode::new(AESEncryption::with_key(key)?).decrypt_data_with_padding_in_place(data, padding)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::util::hex::{hex_decode, hex_encode};
+    use super::{aes_ecb_decrypt_data, aes_ecb_encrypt_data};
+
+    const ORIGIN_DATA: &str = concat!(
+        "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e51",
+        "30c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710",
+    );
+    const VECTORS: [(&str, &str); 3] = [
+        (
+            "2b7e151628aed2a6abf7158809cf4f3c",
+            concat!(
+                "3ad77bb40d7a3660a89ecaf32466ef97f5d3d58503b9699de785895a96fdbaaf",
+                "43b1cd7f598ece23881b00e3ed0306887b0c785e27e8ad3f8223207104725dd4",
+            ),
+        ),
+        (
+            "8e73b0f7da0e6452c810f32b809079e562f8ead2522c6b7b",
+            concat!(
+                "bd334f1d6e45f25ff712a214571fa5cc974104846d0ad3ad7734ecb3ecee4eef",
+                "ef7afd2270e2e60adce0ba2face6444e9a4b41ba738d6c72fb16691603c18e0e",
+            ),
+        ),
+        (
+            "603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4",
+            concat!(
+                "f3eed1bdb5d2a03c064b5a7e3db181f8591ccb10d410ed26dc5ba74a31362870",
+                "b6ed21b99ca6f4f9f153e7b1beafed1d23304b7a39f9f3ff067d8d8f9e24ecc7",
+            ),
+        ),
+    ];
+
+    #[test]
+    fn sp800_38a_vectors() {
+        let origin_data = hex_decode(ORIGIN_DATA).unwrap();
+        for (key, expected) in VECTORS {
+            let key = hex_decode(key).unwrap();
+            let enciphered_data = aes_ecb_encrypt_data(&origin_data, &key).unwrap();
+            assert_eq!(hex_encode(&enciphered_data), expected);
+            assert_eq!(aes_ecb_decrypt_data(&enciphered_data, &key).unwrap(), origin_data);
+        }
+    }
+
+    #[test]
+    fn invalid_key_length_is_rejected() {
+        let origin_data = hex_decode(ORIGIN_DATA).unwrap();
+        assert!(matches!(aes_ecb_encrypt_data(&origin_data, &[0u8; 20]), Err(Error::InvalidKeyLength)));
+    }
+}
@@ -0,0 +1,213 @@
+use crate::error::Error;
+use super::super::block_mode::block_cipher::BlockCipher;
+use super::aes_constant::{AES_128_KEY_BYTE_LENGTH, AES_192_KEY_BYTE_LENGTH, AES_256_KEY_BYTE_LENGTH, AES_BLOCK_BYTE_LENGTH, AES_ROUND_KEY_LENGTH, AES_S, AES_INVERSE_S, AES_T0, AES_T1, AES_T2, AES_T3, AES_INVERSE_T0, AES_INVERSE_T1, AES_INVERSE_T2, AES_INVERSE_T3, AES_RCON};
+
+#[inline(always)]
+fn big_endian_word(buffer: &[u8], i: usize) -> u32 {
+    u32::from_be_bytes(buffer[(i * 4)..(i * 4 + 4)].try_into().unwrap())
+}
+
+#[inline(always)]
+fn big_endian_bytes(w: u32, bytes: &mut [u8; AES_BLOCK_BYTE_LENGTH], index: usize) {
+    bytes[(index * 4)..(index * 4 + 4)].copy_from_slice(&w.to_be_bytes());
+}
+
+#[inline(always)]
+fn sub_word(x: u32) -> u32 {
+    let xx = x.to_be_bytes();
+    u32::from_be_bytes([AES_S[xx[0] as usize], AES_S[xx[1] as usize], AES_S[xx[2] as usize], AES_S[xx[3] as usize]])
+}
+
+#[inline(always)]
+fn inverse_mix_word(x: u32) -> u32 {
+    let xx = x.to_be_bytes();
+    AES_INVERSE_T0[AES_S[xx[0] as usize] as usize] ^ AES_INVERSE_T1[AES_S[xx[1] as usize] as usize] ^ AES_INVERSE_T2[AES_S[xx[2] as usize] as usize] ^ AES_INVERSE_T3[AES_S[xx[3] as usize] as usize]
+}
+
+#[inline(always)]
+fn round_main(x0: u32, x1: u32, x2: u32, x3: u32, k: u32) -> u32 {
+    AES_T0[(x0 >> 24) as usize] ^ AES_T1[((x1 >> 16) & 0xff) as usize] ^ AES_T2[((x2 >> 8) & 0xff) as usize] ^ AES_T3[(x3 & 0xff) as usize] ^ k
+}
+
+#[inline(always)]
+fn round_last(x0: u32, x1: u32, x2: u32, x3: u32, k: u32) -> u32 {
+    u32::from_be_bytes([AES_S[(x0 >> 24) as usize], AES_S[((x1 >> 16) & 0xff) as usize], AES_S[((x2 >> 8) & 0xff) as usize], AES_S[(x3 & 0xff) as usize]]) ^ k
+}
+
+#[inline(always)]
+fn inverse_round_main(x0: u32, x1: u32, x2: u32, x3: u32, k: u32) -> u32 {
+    AES_INVERSE_T0[(x0 >> 24) as usize] ^ AES_INVERSE_T1[((x1 >> 16) & 0xff) as usize] ^ AES_INVERSE_T2[((x2 >> 8) & 0xff) as usize] ^ AES_INVERSE_T3[(x3 & 0xff) as usize] ^ k
+}
+
+#[inline(always)]
+fn inverse_round_last(x0: u32, x1: u32, x2: u32, x3: u32, k: u32) -> u32 {
+    u32::from_be_bytes([AES_INVERSE_S[(x0 >> 24) as usize], AES_INVERSE_S[((x1 >> 16) & 0xff) as usize], AES_INVERSE_S[((x2 >> 8) & 0xff) as usize], AES_INVERSE_S[(x3 & 0xff) as usize]]) ^ k
+}
+
+pub struct AESEncryption {
+    key: [u8; AES_256_KEY_BYTE_LENGTH],
+    key_byte_length: usize,
+    round_count: usize,
+    round_key: [u32; AES_ROUND_KEY_LENGTH],
+    inverse_round_key: [u32; AES_ROUND_KEY_LENGTH],
+}
+
+impl Default for AESEncryption {
+    fn default() -> Self {
+        AESEncryption::new()
+    }
+}
+
+impl AESEncryption {
+    pub fn new() -> AESEncryption {
+        AESEncryption {
+            key: [0; AES_256_KEY_BYTE_LENGTH],
+            key_byte_length: AES_128_KEY_BYTE_LENGTH,
+            round_count: 10,
+            round_key: [0; AES_ROUND_KEY_LENGTH],
+            inverse_round_key: [0; AES_ROUND_KEY_LENGTH],
+        }
+    }
+
+    pub fn with_key(key: &[u8]) -> Result<AESEncryption, Error> {
+        let mut instance = AESEncryption::new();
+        instance.set_key(key)?;
+        Ok(instance)
+    }
+
+    pub fn set_key(&mut self, key: &[u8]) -> Result<(), Error> {
+        if ![AES_128_KEY_BYTE_LENGTH, AES_192_KEY_BYTE_LENGTH, AES_256_KEY_BYTE_LENGTH].contains(&key.len()) {
+            return Err(Error::InvalidKeyLength);
+        }
+        self.key[..key.len()].copy_from_slice(key);
+        self.key_byte_length = key.len();
+        self.round_count = key.len() / 4 + 6;
+        self.generate_aes_key();
+        Ok(())
+    }
+
+    fn generate_aes_key(&mut self) {
+        let key_word_length = self.key_byte_length / 4;
+        let round_key_length = (self.round_count + 1) * 4;
+        for i in 0..key_word_length {
+            self.round_key[i] = big_endian_word(&self.key, i);
+        }
+        for i in key_word_length..round_key_length {
+            let mut t = self.round_key[i - 1];
+            if i % key_word_length == 0 {
+                t = sub_word(t.rotate_left(8)) ^ AES_RCON[i / key_word_length - 1];
+            } else if key_word_length > 6 && i % key_word_length == 4 {
+                t = sub_word(t);
+            }
+            self.round_key[i] = self.round_key[i - key_word_length] ^ t;
+        }
+        for i in 0..4 {
+            self.inverse_round_key[i] = self.round_key[self.round_count * 4 + i];
+            self.inverse_round_key[self.round_count * 4 + i] = self.round_key[i];
+        }
+        for r in 1..self.round_count {
+            for i in 0..4 {
+                self.inverse_round_key[r * 4 + i] = inverse_mix_word(self.round_key[(self.round_count - r) * 4 + i]);
+            }
+        }
+    }
+
+    pub fn encrypt_block(&self, origin_data: &[u8; AES_BLOCK_BYTE_LENGTH], enciphered_data: &mut [u8; AES_BLOCK_BYTE_LENGTH]) {
+        let k = &self.round_key;
+        let mut w0 = big_endian_word(origin_data, 0) ^ k[0];
+        let mut w1 = big_endian_word(origin_data, 1) ^ k[1];
+        let mut w2 = big_endian_word(origin_data, 2) ^ k[2];
+        let mut w3 = big_endian_word(origin_data, 3) ^ k[3];
+        for r in 1..self.round_count {
+            let t0 = round_main(w0, w1, w2, w3, k[r * 4]);
+            let t1 = round_main(w1, w2, w3, w0, k[r * 4 + 1]);
+            let t2 = round_main(w2, w3, w0, w1, k[r * 4 + 2]);
+            let t3 = round_main(w3, w0, w1, w2, k[r * 4 + 3]);
+            w0 = t0;
+            w1 = t1;
+            w2 = t2;
+            w3 = t3;
+        }
+        let r = self.round_count;
+        big_endian_bytes(round_last(w0, w1, w2, w3, k[r * 4]), enciphered_data, 0);
+        big_endian_bytes(round_last(w1, w2, w3, w0, k[r * 4 + 1]), enciphered_data, 1);
+        big_endian_bytes(round_last(w2, w3, w0, w1, k[r * 4 + 2]), enciphered_data, 2);
+        big_endian_bytes(round_last(w3, w0, w1, w2, k[r * 4 + 3]), enciphered_data, 3);
+    }
+
+    pub fn decrypt_block(&self, enciphered_data: &[u8; AES_BLOCK_BYTE_LENGTH], origin_data: &mut [u8; AES_BLOCK_BYTE_LENGTH]) {
+        let k = &self.inverse_round_key;
+        let mut w0 = big_endian_word(enciphered_data, 0) ^ k[0];
+        let mut w1 = big_endian_word(enciphered_data, 1) ^ k[1];
+        let mut w2 = big_endian_word(enciphered_data, 2) ^ k[2];
+        let mut w3 = big_endian_word(enciphered_data, 3) ^ k[3];
+        for r in 1..self.round_count {
+            let t0 = inverse_round_main(w0, w3, w2, w1, k[r * 4]);
+            let t1 = inverse_round_main(w1, w0, w3, w2, k[r * 4 + 1]);
+            let t2 = inverse_round_main(w2, w1, w0, w3, k[r * 4 + 2]);
+            let t3 = inverse_round_main(w3, w2, w1, w0, k[r * 4 + 3]);
+            w0 = t0;
+            w1 = t1;
+            w2 = t2;
+            w3 = t3;
+        }
+        let r = self.round_count;
+        big_endian_bytes(inverse_round_last(w0, w3, w2, w1, k[r * 4]), origin_data, 0);
+        big_endian_bytes(inverse_round_last(w1, w0, w3, w2, k[r * 4 + 1]), origin_data, 1);
+        big_endian_bytes(inverse_round_last(w2, w1, w0, w3, k[r * 4 + 2]), origin_data, 2);
+        big_endian_bytes(inverse_round_last(w3, w2, w1, w0, k[r * 4 + 3]), origin_data, 3);
+    }
+}
+
+impl BlockCipher for AESEncryption {
+    const ALGORITHM_NAME: &'static str = "AES";
+    const BLOCK_BYTE_LENGTH: usize = AES_BLOCK_BYTE_LENGTH;
+
+    type Block = [u8; AES_BLOCK_BYTE_LENGTH];
+
+    fn encrypt_block(&self, origin_data: &[u8; AES_BLOCK_BYTE_LENGTH], enciphered_data: &mut [u8; AES_BLOCK_BYTE_LENGTH]) {
+        AESEncryption::encrypt_block(self, origin_data, enciphered_data)
+    }
+
+    fn decrypt_block(&self, enciphered_data: &[u8; AES_BLOCK_BYTE_LENGTH], origin_data: &mut [u8; AES_BLOCK_BYTE_LENGTH]) {
+        AESEncryption::decrypt_block(self, enciphered_data, origin_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::util::hex::{hex_decode, hex_encode};
+    use super::super::aes_constant::AES_BLOCK_BYTE_LENGTH;
+    use super::AESEncryption;
+
+    const ORIGIN_DATA: &str = "00112233445566778899aabbccddeeff";
+    const VECTORS: [(&str, &str); 3] = [
+        ("000102030405060708090a0b0c0d0e0f", "69c4e0d86a7b0430d8cdb78070b4c55a"),
+        ("000102030405060708090a0b0c0d0e0f1011121314151617", "dda97ca4864cdfe06eaf70a0ec0d7191"),
+        ("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f", "8ea2b7ca516745bfeafc49904b496089"),
+    ];
+
+    #[test]
+    fn fips_197_vectors() {
+        let origin_data: [u8; AES_BLOCK_BYTE_LENGTH] = hex_decode(ORIGIN_DATA).unwrap().try_into().unwrap();
+        for (key, expected) in VECTORS {
+            let aes = AESEncryption::with_key(&hex_decode(key).unwrap()).unwrap();
+            let mut enciphered_data = [0u8; AES_BLOCK_BYTE_LENGTH];
+            aes.encrypt_block(&origin_data, &mut enciphered_data);
+            assert_eq!(hex_encode(&enciphered_data), expected);
+            let mut deciphered_data = [0u8; AES_BLOCK_BYTE_LENGTH];
+            aes.decrypt_block(&enciphered_data, &mut deciphered_data);
+            assert_eq!(deciphered_data, origin_data);
+        }
+    }
+
+    #[test]
+    fn invalid_key_length_is_rejected() {
+        for key_length in [0, 15, 17, 20, 31, 33] {
+            assert!(matches!(AESEncryption::with_key(&vec![0u8; key_length]), Err(Error::InvalidKeyLength)));
+        }
+        let mut aes = AESEncryption::new();
+        assert!(matches!(aes.set_key(&[0u8; 8]), Err(Error::InvalidKeyLength)));
+    }
+}
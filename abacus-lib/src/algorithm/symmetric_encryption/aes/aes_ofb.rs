@@ -0,0 +1,96 @@
+use crate::error::Error;
+use super::super::block_mode::ofb_mode::OFBMode;
+use super::aes_constant::AES_BLOCK_BYTE_LENGTH;
+use super::aes_encryption::AESEncryption;
+
+pub fn aes_ofb_encrypt_data(origin_data: &[u8], key: &[u8], iv: [u8; AES_BLOCK_BYTE_LENGTH]) -> Result<Vec<u8>, Error> {
+    Ok(OFBMode::new(AESEncryption::with_key(key)?, iv).encrypt_data(origin_data))
+}
+
+pub fn aes_ofb_decrypt_data(enciphered_data: &[u8], key: &[u8], iv: [u8; AES_BLOCK_BYTE_LENGTH]) -> Result<Vec<u8>, Error> {
+    Ok(OFBMode::new(AESEncryption::with_key(key)?, iv).decrypt_data(enciphered_data))
+}
+
+pub fn aes_ofb_encrypt_data_in_place(data: &mut [u8], key: &[u8], iv: [u8; AES_BLOCK_BYTE_LENGTH]) -> Result<(), Error> {
+    OFBMode::new(AESEncryption::with_key(key)?, iv).encrypt_data_in_place(data);
+    Ok(())
+}
+
+pub fn aes_ofb_decrypt_data_in_place(data: &mut [u8], key: &[u8], iv: [u8; AES_BLOCK_BYTE_LENGTH]) -> Result<(), Error> {
+    OFBMode::new(AESEncryption::with_key(key)?, iv).decrypt_data_in_place(data);
+    Ok(())
+}
+
+pub fn aes_ofb_encrypt_data_into(origin_data: &[u8], enciphered_data: &mut [u8], key: &[u8], iv: [u8; AES_BLOCK_BYTE_LENGTH]) -> Result<(), Error> {
+    OFBMode::new(AESEncryption::with_key(key)?, iv).encrypt_data_into(origin_data, enciphered_data)
+}
+
+pub fn aes_ofb_decrypt_data_into(enciphered_data: &[u8], origin_data: &mut [u8], key: &[u8], iv: [u8; AES_BLOCK_BYTE_LENGTH]) -> Result<(), Error> {
+    OFBMode::new(AESEncryption::with_key(key)?, iv).decrypt_data_into(enciphered_data, origin_data)
+}
+
+pub fn try_aes_ofb_encrypt_data(origin_data: &[u8], key: &[u8], iv: &[u8]) -> Result<Vec<u8>, Error> {
+    let aes = AESEncryption::with_key(key)?;
+    let iv: [u8; AES_BLOCK_BYTE_LENGTH] = iv.try_into().map_err(|_| Error::InvalidIVLength)?;
+    Ok(OFBMode::new(aes, iv).encrypt_data(origin_data))
+}
+
+pub fn try_aes_ofb_decrypt_data(enciphered_data: &[u8], key: &[u8], iv: &[u8]) -> Result<Vec<u8>, Error> {
+    let aes = AESEncryption::with_key(key)?;
+    let iv: [u8; AES_BLOCK_BYTE_LENGTH] = iv.try_into().map_err(|_| Error::InvalidIVLength)?;
+    Ok(OFBMode::new(aes, iv).decrypt_data(enciphered_data))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::util::hex::{hex_decode, hex_encode};
+    use super::{aes_ofb_decrypt_data, aes_ofb_encrypt_data, try_aes_ofb_encrypt_data};
+
+    const IV: [u8; 16] = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f];
+    const ORIGIN_DATA: &str = concat!(
+        "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e51",
+        "30c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710",
+    );
+    const VECTORS: [(&str, &str); 3] = [
+        (
+            "2b7e151628aed2a6abf7158809cf4f3c",
+            concat!(
+                "3b3fd92eb72dad20333449f8e83cfb4a7789508d16918f03f53c52dac54ed825",
+                "9740051e9c5fecf64344f7a82260edcc304c6528f659c77866a510d9c1d6ae5e",
+            ),
+        ),
+        (
+            "8e73b0f7da0e6452c810f32b809079e562f8ead2522c6b7b",
+            concat!(
+                "cdc80d6fddf18cab34c25909c99a4174fcc28b8d4c63837c09e81700c1100401",
+                "8d9a9aeac0f6596f559c6d4daf59a5f26d9f200857ca6c3e9cac524bd9acc92a",
+            ),
+        ),
+        (
+            "603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4",
+            concat!(
+                "dc7e84bfda79164b7ecd8486985d38604febdc6740d20b3ac88f6ad82a4fb08d",
+                "71ab47a086e86eedf39d1c5bba97c4080126141d67f37be8538f5a8be740e484",
+            ),
+        ),
+    ];
+
+    #[test]
+    fn sp800_38a_vectors() {
+        let origin_data = hex_decode(ORIGIN_DATA).unwrap();
+        for (key, expected) in VECTORS {
+            let key = hex_decode(key).unwrap();
+            let enciphered_data = aes_ofb_encrypt_data(&origin_data, &key, IV).unwrap();
+            assert_eq!(hex_encode(&enciphered_data), expected);
+            assert_eq!(aes_ofb_decrypt_data(&enciphered_data, &key, IV).unwrap(), origin_data);
+        }
+    }
+
+    #[test]
+    fn invalid_key_length_is_rejected() {
+        let origin_data = hex_decode(ORIGIN_DATA).unwrap();
+        assert!(matches!(aes_ofb_encrypt_data(&origin_data, &[0u8; 20], IV), Err(Error::InvalidKeyLength)));
+        assert!(matches!(try_aes_ofb_encrypt_data(&origin_data, &[0u8; 16], &IV[..12]), Err(Error::InvalidIVLength)));
+    }
+}
@@ -0,0 +1,7 @@
+pub mod aes_constant;
+pub mod aes_encryption;
+pub mod aes_ecb;
+pub mod aes_cbc;
+pub mod aes_ofb;
+pub mod aes_cfb;
+pub mod aes_ctr;
@@ -0,0 +1,10 @@
+pub trait BlockCipher {
+    const ALGORITHM_NAME: &'static str;
+    const BLOCK_BYTE_LENGTH: usize;
+
+    type Block: AsRef<[u8]> + AsMut<[u8]> + Default + Copy;
+
+    fn encrypt_block(&self, origin_data: &Self::Block, enciphered_data: &mut Self::Block);
+
+    fn decrypt_block(&self, enciphered_data: &Self::Block, origin_data: &mut Self::Block);
+}
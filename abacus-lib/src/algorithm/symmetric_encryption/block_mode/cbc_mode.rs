@@ -0,0 +1,214 @@
+use crate::error::Error;
+use super::block_cipher::BlockCipher;
+use super::padding::Padding;
+use super::stream_trait::{StreamEncryptor, StreamDecryptor};
+
+pub struct CBCMode<C: BlockCipher> {
+    cipher: C,
+    iv: C::Block,
+}
+
+impl<C: BlockCipher> CBCMode<C> {
+    pub fn new(cipher: C, iv: C::Block) -> CBCMode<C> {
+        CBCMode {
+            cipher,
+            iv,
+        }
+    }
+
+    fn encrypt_blocks(&self, data: &mut [u8]) {
+        let block_sum = data.len() / C::BLOCK_BYTE_LENGTH;
+        let mut origin_data_block_with_iv = self.iv;
+        let mut enciphered_data_block = C::Block::default();
+        for i in 0..block_sum {
+            let data_block = &mut data[(i * C::BLOCK_BYTE_LENGTH)..((i + 1) * C::BLOCK_BYTE_LENGTH)];
+            for (byte, &data_byte) in origin_data_block_with_iv.as_mut().iter_mut().zip(data_block.iter()) {
+                *byte ^= data_byte;
+            }
+            self.cipher.encrypt_block(&origin_data_block_with_iv, &mut enciphered_data_block);
+            data_block.copy_from_slice(enciphered_data_block.as_ref());
+            origin_data_block_with_iv = enciphered_data_block;
+        }
+    }
+
+    fn decrypt_blocks(&self, data: &mut [u8]) {
+        let block_sum = data.len() / C::BLOCK_BYTE_LENGTH;
+        let mut enciphered_data_block_with_iv = self.iv;
+        let mut enciphered_data_block = C::Block::default();
+        let mut origin_data_block = C::Block::default();
+        for i in 0..block_sum {
+            let data_block = &mut data[(i * C::BLOCK_BYTE_LENGTH)..((i + 1) * C::BLOCK_BYTE_LENGTH)];
+            enciphered_data_block.as_mut().copy_from_slice(data_block);
+            self.cipher.decrypt_block(&enciphered_data_block, &mut origin_data_block);
+            for ((byte, &origin_byte), &iv_byte) in data_block.iter_mut().zip(origin_data_block.as_ref().iter()).zip(enciphered_data_block_with_iv.as_ref().iter()) {
+                *byte = origin_byte ^ iv_byte;
+            }
+            enciphered_data_block_with_iv = enciphered_data_block;
+        }
+    }
+
+    pub fn encrypt_data(&self, origin_data: &[u8]) -> Result<Vec<u8>, Error> {
+        let mut enciphered_data = origin_data.to_vec();
+        self.encrypt_data_in_place(&mut enciphered_data)?;
+        Ok(enciphered_data)
+    }
+
+    pub fn decrypt_data(&self, enciphered_data: &[u8]) -> Result<Vec<u8>, Error> {
+        let mut origin_data = enciphered_data.to_vec();
+        self.decrypt_data_in_place(&mut origin_data)?;
+        Ok(origin_data)
+    }
+
+    pub fn encrypt_data_in_place(&self, data: &mut [u8]) -> Result<(), Error> {
+        if !data.len().is_multiple_of(C::BLOCK_BYTE_LENGTH) {
+            return Err(Error::InvalidDataLength);
+        }
+        self.encrypt_blocks(data);
+        Ok(())
+    }
+
+    pub fn decrypt_data_in_place(&self, data: &mut [u8]) -> Result<(), Error> {
+        if !data.len().is_multiple_of(C::BLOCK_BYTE_LENGTH) {
+            return Err(Error::InvalidDataLength);
+        }
+        self.decrypt_blocks(data);
+        Ok(())
+    }
+
+    pub fn encrypt_data_into(&self, origin_data: &[u8], enciphered_data: &mut [u8]) -> Result<(), Error> {
+        if origin_data.len() != enciphered_data.len() {
+            return Err(Error::InvalidDataLength);
+        }
+        enciphered_data.copy_from_slice(origin_data);
+        self.encrypt_data_in_place(enciphered_data)
+    }
+
+    pub fn decrypt_data_into(&self, enciphered_data: &[u8], origin_data: &mut [u8]) -> Result<(), Error> {
+        if enciphered_data.len() != origin_data.len() {
+            return Err(Error::InvalidDataLength);
+        }
+        origin_data.copy_from_slice(enciphered_data);
+        self.decrypt_data_in_place(origin_data)
+    }
+
+    pub fn encrypt_data_with_padding(&self, origin_data: &[u8], padding: Padding) -> Result<Vec<u8>, Error> {
+        let padded_data = padding.pad_data(origin_data, C::BLOCK_BYTE_LENGTH)?;
+        self.encrypt_data(&padded_data)
+    }
+
+    pub fn decrypt_data_with_padding(&self, enciphered_data: &[u8], padding: Padding) -> Result<Vec<u8>, Error> {
+        let padded_data = self.decrypt_data(enciphered_data)?;
+        Ok(padding.unpad_data(&padded_data, C::BLOCK_BYTE_LENGTH)?.to_vec())
+    }
+
+    pub fn encrypt_data_with_padding_in_place(&self, buffer: &mut [u8], data_length: usize, padding: Padding) -> Result<usize, Error> {
+        let padded_data_length = padding.pad_data_in_place(buffer, data_length, C::BLOCK_BYTE_LENGTH)?;
+        self.encrypt_blocks(&mut buffer[..padded_data_length]);
+        Ok(padded_data_length)
+    }
+
+    pub fn decrypt_data_with_padding_in_place(&self, data: &mut [u8], padding: Padding) -> Result<usize, Error> {
+        self.decrypt_data_in_place(data)?;
+        Ok(padding.unpad_data(data, C::BLOCK_BYTE_LENGTH)?.len())
+    }
+}
+
+pub struct CBCEncryptor<C: BlockCipher> {
+    mode: CBCMode<C>,
+    padding: Padding,
+    remains_data: Vec<u8>,
+}
+
+impl<C: BlockCipher> CBCEncryptor<C> {
+    pub fn new(cipher: C, iv: C::Block, padding: Padding) -> CBCEncryptor<C> {
+        CBCEncryptor {
+            mode: CBCMode::new(cipher, iv),
+            padding,
+            remains_data: Vec::new(),
+        }
+    }
+
+    pub fn update(&mut self, origin_data: &[u8]) -> Vec<u8> {
+        self.remains_data.extend_from_slice(origin_data);
+        let block_data_length = self.remains_data.len() / C::BLOCK_BYTE_LENGTH * C::BLOCK_BYTE_LENGTH;
+        let mut enciphered_data: Vec<u8> = self.remains_data.drain(..block_data_length).collect();
+        self.mode.encrypt_blocks(&mut enciphered_data);
+        if block_data_length > 0 {
+            self.mode.iv.as_mut().copy_from_slice(&enciphered_data[(block_data_length - C::BLOCK_BYTE_LENGTH)..]);
+        }
+        enciphered_data
+    }
+
+    pub fn finalize(self) -> Result<Vec<u8>, Error> {
+        self.mode.encrypt_data_with_padding(&self.remains_data, self.padding)
+    }
+}
+
+impl<C: BlockCipher> StreamEncryptor for CBCEncryptor<C> {
+    fn update(&mut self, origin_data: &[u8]) -> Vec<u8> {
+        CBCEncryptor::update(self, origin_data)
+    }
+
+    fn finalize(self) -> Result<Vec<u8>, Error> {
+        CBCEncryptor::finalize(self)
+    }
+}
+
+pub struct CBCDecryptor<C: BlockCipher> {
+    mode: CBCMode<C>,
+    padding: Padding,
+    remains_data: Vec<u8>,
+}
+
+impl<C: BlockCipher> CBCDecryptor<C> {
+    pub fn new(cipher: C, iv: C::Block, padding: Padding) -> CBCDecryptor<C> {
+        CBCDecryptor {
+            mode: CBCMode::new(cipher, iv),
+            padding,
+            remains_data: Vec::new(),
+        }
+    }
+
+    pub fn update(&mut self, enciphered_data: &[u8]) -> Vec<u8> {
+        self.remains_data.extend_from_slice(enciphered_data);
+        let block_data_length = self.remains_data.len().saturating_sub(1) / C::BLOCK_BYTE_LENGTH * C::BLOCK_BYTE_LENGTH;
+        let mut origin_data = self.remains_data[..block_data_length].to_vec();
+        self.mode.decrypt_blocks(&mut origin_data);
+        if block_data_length > 0 {
+            self.mode.iv.as_mut().copy_from_slice(&self.remains_data[(block_data_length - C::BLOCK_BYTE_LENGTH)..block_data_length]);
+        }
+        self.remains_data.drain(..block_data_length);
+        origin_data
+    }
+
+    pub fn finalize(self) -> Result<Vec<u8>, Error> {
+        self.mode.decrypt_data_with_padding(&self.remains_data, self.padding)
+    }
+}
+
+impl<C: BlockCipher> StreamDecryptor for CBCDecryptor<C> {
+    fn update(&mut self, enciphered_data: &[u8]) -> Vec<u8> {
+        CBCDecryptor::update(self, enciphered_data)
+    }
+
+    fn finalize(self) -> Result<Vec<u8>, Error> {
+        CBCDecryptor::finalize(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use super::super::super::sm4::sm4_cbc::{sm4_cbc_decrypt_data, sm4_cbc_encrypt_data};
+
+    const KEY: [u8; 16] = [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0xfe, 0xdc, 0xba, 0x98, 0x76, 0x54, 0x32, 0x10];
+
+    #[test]
+    fn partial_block_is_rejected() {
+        assert!(matches!(sm4_cbc_encrypt_data(&[0; 37], KEY, [0; 16]), Err(Error::InvalidDataLength)));
+        assert!(matches!(sm4_cbc_decrypt_data(&[0; 15], KEY, [0; 16]), Err(Error::InvalidDataLength)));
+        let enciphered_data = sm4_cbc_encrypt_data(&[0; 32], KEY, [0; 16]).unwrap();
+        assert_eq!(enciphered_data.len(), 32);
+        assert_eq!(sm4_cbc_decrypt_data(&enciphered_data, KEY, [0; 16]).unwrap(), [0; 32]);
+    }
+}
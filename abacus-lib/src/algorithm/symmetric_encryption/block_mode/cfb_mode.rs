@@ -0,0 +1,205 @@
+use crate::error::Error;
+use super::block_cipher::BlockCipher;
+use super::stream_trait::{StreamEncryptor, StreamDecryptor};
+
+pub struct CFBMode<C: BlockCipher> {
+    cipher: C,
+    iv: C::Block,
+}
+
+impl<C: BlockCipher> CFBMode<C> {
+    pub fn new(cipher: C, iv: C::Block) -> CFBMode<C> {
+        CFBMode {
+            cipher,
+            iv,
+        }
+    }
+
+    fn encrypt_blocks(&self, data: &mut [u8]) {
+        let mut key_with_iv = self.iv;
+        let mut key_with_iv_encrypted = C::Block::default();
+        for data_block in data.chunks_mut(C::BLOCK_BYTE_LENGTH) {
+            self.cipher.encrypt_block(&key_with_iv, &mut key_with_iv_encrypted);
+            for (byte, &key_byte) in data_block.iter_mut().zip(key_with_iv_encrypted.as_ref().iter()) {
+                *byte ^= key_byte;
+            }
+            key_with_iv.as_mut()[..data_block.len()].copy_from_slice(data_block);
+        }
+    }
+
+    fn decrypt_blocks(&self, data: &mut [u8]) {
+        let mut key_with_iv = self.iv;
+        let mut key_with_iv_encrypted = C::Block::default();
+        for data_block in data.chunks_mut(C::BLOCK_BYTE_LENGTH) {
+            self.cipher.encrypt_block(&key_with_iv, &mut key_with_iv_encrypted);
+            key_with_iv.as_mut()[..data_block.len()].copy_from_slice(data_block);
+            for (byte, &key_byte) in data_block.iter_mut().zip(key_with_iv_encrypted.as_ref().iter()) {
+                *byte ^= key_byte;
+            }
+        }
+    }
+
+    pub fn encrypt_data(&self, origin_data: &[u8]) -> Vec<u8> {
+        let mut enciphered_data = origin_data.to_vec();
+        self.encrypt_blocks(&mut enciphered_data);
+        enciphered_data
+    }
+
+    pub fn decrypt_data(&self, enciphered_data: &[u8]) -> Vec<u8> {
+        let mut origin_data = enciphered_data.to_vec();
+        self.decrypt_blocks(&mut origin_data);
+        origin_data
+    }
+
+    pub fn encrypt_data_in_place(&self, data: &mut [u8]) {
+        self.encrypt_blocks(data)
+    }
+
+    pub fn decrypt_data_in_place(&self, data: &mut [u8]) {
+        self.decrypt_blocks(data)
+    }
+
+    pub fn encrypt_data_into(&self, origin_data: &[u8], enciphered_data: &mut [u8]) -> Result<(), Error> {
+        if origin_data.len() != enciphered_data.len() {
+            return Err(Error::InvalidDataLength);
+        }
+        enciphered_data.copy_from_slice(origin_data);
+        self.encrypt_data_in_place(enciphered_data);
+        Ok(())
+    }
+
+    pub fn decrypt_data_into(&self, enciphered_data: &[u8], origin_data: &mut [u8]) -> Result<(), Error> {
+        if enciphered_data.len() != origin_data.len() {
+            return Err(Error::InvalidDataLength);
+        }
+        origin_data.copy_from_slice(enciphered_data);
+        self.decrypt_data_in_place(origin_data);
+        Ok(())
+    }
+}
+
+pub struct CFBEncryptor<C: BlockCipher> {
+    cipher: C,
+    feedback: C::Block,
+    key_stream: C::Block,
+    key_stream_offset: usize,
+}
+
+impl<C: BlockCipher> CFBEncryptor<C> {
+    pub fn new(cipher: C, iv: C::Block) -> CFBEncryptor<C> {
+        CFBEncryptor {
+            cipher,
+            feedback: iv,
+            key_stream: C::Block::default(),
+            key_stream_offset: C::BLOCK_BYTE_LENGTH,
+        }
+    }
+
+    pub fn update(&mut self, origin_data: &[u8]) -> Vec<u8> {
+        let mut enciphered_data = origin_data.to_vec();
+        for byte in enciphered_data.iter_mut() {
+            if self.key_stream_offset == C::BLOCK_BYTE_LENGTH {
+                self.cipher.encrypt_block(&self.feedback, &mut self.key_stream);
+                self.key_stream_offset = 0;
+            }
+            *byte ^= self.key_stream.as_ref()[self.key_stream_offset];
+            self.feedback.as_mut()[self.key_stream_offset] = *byte;
+            self.key_stream_offset += 1;
+        }
+        enciphered_data
+    }
+
+    pub fn finalize(self) -> Result<Vec<u8>, Error> {
+        Ok(Vec::new())
+    }
+}
+
+impl<C: BlockCipher> StreamEncryptor for CFBEncryptor<C> {
+    fn update(&mut self, origin_data: &[u8]) -> Vec<u8> {
+        CFBEncryptor::update(self, origin_data)
+    }
+
+    fn finalize(self) -> Result<Vec<u8>, Error> {
+        CFBEncryptor::finalize(self)
+    }
+}
+
+pub struct CFBDecryptor<C: BlockCipher> {
+    cipher: C,
+    feedback: C::Block,
+    key_stream: C::Block,
+    key_stream_offset: usize,
+}
+
+impl<C: BlockCipher> CFBDecryptor<C> {
+    pub fn new(cipher: C, iv: C::Block) -> CFBDecryptor<C> {
+        CFBDecryptor {
+            cipher,
+            feedback: iv,
+            key_stream: C::Block::default(),
+            key_stream_offset: C::BLOCK_BYTE_LENGTH,
+        }
+    }
+
+    pub fn update(&mut self, enciphered_data: &[u8]) -> Vec<u8> {
+        let mut origin_data = enciphered_data.to_vec();
+        for i in 0..origin_data.len() {
+            if self.key_stream_offset == C::BLOCK_BYTE_LENGTH {
+                self.cipher.encrypt_block(&self.feedback, &mut self.key_stream);
+                self.key_stream_offset = 0;
+            }
+            self.feedback.as_mut()[self.key_stream_offset] = enciphered_data[i];
+            origin_data[i] ^= self.key_stream.as_ref()[self.key_stream_offset];
+            self.key_stream_offset += 1;
+        }
+        origin_data
+    }
+
+    pub fn finalize(self) -> Result<Vec<u8>, Error> {
+        Ok(Vec::new())
+    }
+}
+
+impl<C: BlockCipher> StreamDecryptor for CFBDecryptor<C> {
+    fn update(&mut self, enciphered_data: &[u8]) -> Vec<u8> {
+        CFBDecryptor::update(self, enciphered_data)
+    }
+
+    fn finalize(self) -> Result<Vec<u8>, Error> {
+        CFBDecryptor::finalize(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::hex::{hex_decode, hex_encode};
+    use super::super::super::sm4::sm4_encryption::SM4Encryption;
+    use super::super::super::sm4::sm4_cfb::{sm4_cfb_decrypt_data, sm4_cfb_encrypt_data};
+    use super::CFBEncryptor;
+
+    const KEY: [u8; 16] = [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0xfe, 0xdc, 0xba, 0x98, 0x76, 0x54, 0x32, 0x10];
+    const IV: [u8; 16] = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f];
+    const ENCIPHERED_DATA: &str = "06999e6239a36eaa2284fd89eda5f765cab243c911b87479b3c487b45ecea6584a2eeb378d";
+
+    fn origin_data() -> Vec<u8> {
+        (0..37).collect()
+    }
+
+    #[test]
+    fn partial_final_block_is_processed() {
+        let enciphered_data = sm4_cfb_encrypt_data(&origin_data(), KEY, IV);
+        assert_eq!(hex_encode(&enciphered_data), ENCIPHERED_DATA);
+        assert_eq!(sm4_cfb_decrypt_data(&enciphered_data, KEY, IV), origin_data());
+    }
+
+    #[test]
+    fn partial_final_block_matches_stream_encryptor() {
+        let mut encryptor = CFBEncryptor::new(SM4Encryption::with_key(KEY), IV);
+        let mut enciphered_data = Vec::new();
+        for piece in origin_data().chunks(5) {
+            enciphered_data.extend_from_slice(&encryptor.update(piece));
+        }
+        enciphered_data.extend_from_slice(&encryptor.finalize().unwrap());
+        assert_eq!(enciphered_data, hex_decode(ENCIPHERED_DATA).unwrap());
+    }
+}
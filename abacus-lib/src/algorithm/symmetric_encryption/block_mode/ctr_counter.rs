@@ -0,0 +1,104 @@
+use std::ops::Range;
+use crate::error::Error;
+
+pub enum CTREndianness {
+    BigEndian,
+    LittleEndian,
+}
+
+pub struct CTRCounter {
+    counter_byte_length: usize,
+    endianness: CTREndianness,
+}
+
+impl CTRCounter {
+    pub fn new(counter_byte_length: usize, endianness: CTREndianness) -> Result<CTRCounter, Error> {
+        if !(1..=16).contains(&counter_byte_length) {
+            return Err(Error::InvalidParameter);
+        }
+        Ok(CTRCounter {
+            counter_byte_length,
+            endianness,
+        })
+    }
+
+    pub fn counter_byte_length(&self) -> usize {
+        self.counter_byte_length
+    }
+
+    fn counter_field(&self, iv: &[u8]) -> Range<usize> {
+        match self.endianness {
+            CTREndianness::BigEndian => (iv.len() - self.counter_byte_length)..iv.len(),
+            CTREndianness::LittleEndian => 0..self.counter_byte_length,
+        }
+    }
+
+    fn counter(&self, iv: &[u8], block_index: u64) -> Result<u128, Error> {
+        let mut counter_bytes = [0; 16];
+        let counter_field = self.counter_field(iv);
+        let counter_mask = u128::MAX >> (128 - self.counter_byte_length * 8);
+        let counter = match self.endianness {
+            CTREndianness::BigEndian => {
+                counter_bytes[(16 - self.counter_byte_length)..].copy_from_slice(&iv[counter_field]);
+                u128::from_be_bytes(counter_bytes)
+            }
+            CTREndianness::LittleEndian => {
+                counter_bytes[..self.counter_byte_length].copy_from_slice(&iv[counter_field]);
+                u128::from_le_bytes(counter_bytes)
+            }
+        };
+        match counter.checked_add(block_index as u128) {
+            Some(counter) if counter <= counter_mask => Ok(counter),
+            _ => Err(Error::InvalidDataLength),
+        }
+    }
+
+    pub(crate) fn check_block_index(&self, iv: &[u8], block_index: u64) -> Result<(), Error> {
+        self.counter(iv, block_index).map(|_| ())
+    }
+
+    pub(crate) fn fill_counter_block(&self, iv: &[u8], block_index: u64, counter_block: &mut [u8]) -> Result<(), Error> {
+        let counter = self.counter(iv, block_index)?;
+        let counter_field = self.counter_field(iv);
+        counter_block.copy_from_slice(iv);
+        match self.endianness {
+            CTREndianness::BigEndian => counter_block[counter_field].copy_from_slice(&counter.to_be_bytes()[(16 - self.counter_byte_length)..]),
+            CTREndianness::LittleEndian => counter_block[counter_field].copy_from_slice(&counter.to_le_bytes()[..self.counter_byte_length]),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use super::{CTRCounter, CTREndianness};
+
+    #[test]
+    fn counter_length_is_checked() {
+        assert!(matches!(CTRCounter::new(0, CTREndianness::BigEndian), Err(Error::InvalidParameter)));
+        assert!(matches!(CTRCounter::new(17, CTREndianness::LittleEndian), Err(Error::InvalidParameter)));
+        assert_eq!(CTRCounter::new(16, CTREndianness::BigEndian).unwrap().counter_byte_length(), 16);
+    }
+
+    #[test]
+    fn little_endian_counter_carries_into_next_byte() {
+        let counter = CTRCounter::new(2, CTREndianness::LittleEndian).unwrap();
+        let mut counter_block = [0; 8];
+        counter.fill_counter_block(&[0xff, 0x00, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff], 1, &mut counter_block).unwrap();
+        assert_eq!(counter_block, [0x00, 0x01, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff]);
+        counter.fill_counter_block(&[0xff, 0xff, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff], 0, &mut counter_block).unwrap();
+        assert_eq!(counter_block, [0xff, 0xff, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff]);
+    }
+
+    #[test]
+    fn counter_exhaustion_is_rejected() {
+        let counter = CTRCounter::new(2, CTREndianness::LittleEndian).unwrap();
+        let mut counter_block = [0; 8];
+        assert!(matches!(counter.fill_counter_block(&[0xff, 0xff, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff], 1, &mut counter_block), Err(Error::InvalidDataLength)));
+        assert!(matches!(counter.check_block_index(&[0; 8], 0x10000), Err(Error::InvalidDataLength)));
+        assert!(counter.check_block_index(&[0; 8], 0xffff).is_ok());
+        let counter = CTRCounter::new(16, CTREndianness::BigEndian).unwrap();
+        assert!(matches!(counter.check_block_index(&[0xff; 16], 1), Err(Error::InvalidDataLength)));
+    }
+}
@@ -0,0 +1,226 @@
+use crate::error::Error;
+use super::block_cipher::BlockCipher;
+use super::ctr_counter::CTRCounter;
+use super::stream_trait::{StreamEncryptor, StreamDecryptor};
+
+pub struct CTRMode<C: BlockCipher> {
+    cipher: C,
+    iv: C::Block,
+    ctr_counter: CTRCounter,
+    position: u64,
+    key_stream: C::Block,
+    key_stream_block_index: Option<u64>,
+}
+
+impl<C: BlockCipher> CTRMode<C> {
+    pub fn new(cipher: C, iv: C::Block, ctr_counter: CTRCounter) -> Result<CTRMode<C>, Error> {
+        if ctr_counter.counter_byte_length() > C::BLOCK_BYTE_LENGTH {
+            return Err(Error::InvalidParameter);
+        }
+        Ok(CTRMode {
+            cipher,
+            iv,
+            ctr_counter,
+            position: 0,
+            key_stream: C::Block::default(),
+            key_stream_block_index: None,
+        })
+    }
+
+    pub fn seek(&mut self, position: u64) {
+        self.position = position;
+    }
+
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    fn check_key_stream_length(&self, data_length: usize) -> Result<(), Error> {
+        if data_length == 0 {
+            return Ok(());
+        }
+        let end_position = self.position.checked_add(data_length as u64).ok_or(Error::InvalidDataLength)?;
+        self.ctr_counter.check_block_index(self.iv.as_ref(), (end_position - 1) / C::BLOCK_BYTE_LENGTH as u64)
+    }
+
+    pub fn apply_key_stream(&mut self, data: &mut [u8]) -> Result<(), Error> {
+        self.check_key_stream_length(data.len())?;
+        let mut counter_block = C::Block::default();
+        for byte in data.iter_mut() {
+            let block_index = self.position / C::BLOCK_BYTE_LENGTH as u64;
+            if self.key_stream_block_index != Some(block_index) {
+                self.ctr_counter.fill_counter_block(self.iv.as_ref(), block_index, counter_block.as_mut())?;
+                self.cipher.encrypt_block(&counter_block, &mut self.key_stream);
+                self.key_stream_block_index = Some(block_index);
+            }
+            *byte ^= self.key_stream.as_ref()[(self.position % C::BLOCK_BYTE_LENGTH as u64) as usize];
+            self.position += 1;
+        }
+        Ok(())
+    }
+
+    pub fn apply_key_stream_into(&mut self, input_data: &[u8], output_data: &mut [u8]) -> Result<(), Error> {
+        if input_data.len() != output_data.len() {
+            return Err(Error::InvalidDataLength);
+        }
+        output_data.copy_from_slice(input_data);
+        self.apply_key_stream(output_data)
+    }
+
+    pub fn encrypt_data(&mut self, origin_data: &[u8]) -> Result<Vec<u8>, Error> {
+        let mut enciphered_data = origin_data.to_vec();
+        self.apply_key_stream(&mut enciphered_data)?;
+        Ok(enciphered_data)
+    }
+
+    pub fn decrypt_data(&mut self, enciphered_data: &[u8]) -> Result<Vec<u8>, Error> {
+        self.encrypt_data(enciphered_data)
+    }
+}
+
+pub struct CTREncryptor<C: BlockCipher> {
+    mode: CTRMode<C>,
+    key_stream_exhausted: bool,
+}
+
+impl<C: BlockCipher> CTREncryptor<C> {
+    pub fn new(cipher: C, iv: C::Block, ctr_counter: CTRCounter) -> Result<CTREncryptor<C>, Error> {
+        Ok(CTREncryptor {
+            mode: CTRMode::new(cipher, iv, ctr_counter)?,
+            key_stream_exhausted: false,
+        })
+    }
+
+    pub fn update(&mut self, origin_data: &[u8]) -> Result<Vec<u8>, Error> {
+        let result = self.mode.encrypt_data(origin_data);
+        self.key_stream_exhausted |= result.is_err();
+        result
+    }
+
+    pub fn finalize(self) -> Result<Vec<u8>, Error> {
+        if self.key_stream_exhausted {
+            return Err(Error::InvalidDataLength);
+        }
+        Ok(Vec::new())
+    }
+}
+
+impl<C: BlockCipher> StreamEncryptor for CTREncryptor<C> {
+    fn update(&mut self, origin_data: &[u8]) -> Vec<u8> {
+        CTREncryptor::update(self, origin_data).unwrap_or_default()
+    }
+
+    fn finalize(self) -> Result<Vec<u8>, Error> {
+        CTREncryptor::finalize(self)
+    }
+}
+
+pub struct CTRDecryptor<C: BlockCipher> {
+    mode: CTRMode<C>,
+    key_stream_exhausted: bool,
+}
+
+impl<C: BlockCipher> CTRDecryptor<C> {
+    pub fn new(cipher: C, iv: C::Block, ctr_counter: CTRCounter) -> Result<CTRDecryptor<C>, Error> {
+        Ok(CTRDecryptor {
+            mode: CTRMode::new(cipher, iv, ctr_counter)?,
+            key_stream_exhausted: false,
+        })
+    }
+
+    pub fn update(&mut self, enciphered_data: &[u8]) -> Result<Vec<u8>, Error> {
+        let result = self.mode.decrypt_data(enciphered_data);
+        self.key_stream_exhausted |= result.is_err();
+        result
+    }
+
+    pub fn finalize(self) -> Result<Vec<u8>, Error> {
+        if self.key_stream_exhausted {
+            return Err(Error::InvalidDataLength);
+        }
+        Ok(Vec::new())
+    }
+}
+
+impl<C: BlockCipher> StreamDecryptor for CTRDecryptor<C> {
+    fn update(&mut self, enciphered_data: &[u8]) -> Vec<u8> {
+        CTRDecryptor::update(self, enciphered_data).unwrap_or_default()
+    }
+
+    fn finalize(self) -> Result<Vec<u8>, Error> {
+        CTRDecryptor::finalize(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::util::hex::{hex_decode, hex_encode};
+    use super::super::super::blowfish::blowfish_ctr::{blowfish_ctr_encrypt_data, try_blowfish_ctr_encrypt_data};
+    use super::super::super::blowfish::blowfish_encryption::BlowFishEncryption;
+    use super::super::super::sm4::sm4_ctr::{SM4CTREncryption, sm4_ctr_encrypt_data};
+    use super::super::super::sm4::sm4_encryption::SM4Encryption;
+    use super::super::ctr_counter::{CTRCounter, CTREndianness};
+    use super::{CTRDecryptor, CTREncryptor, CTRMode};
+
+    const SM4_KEY: [u8; 16] = [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0xfe, 0xdc, 0xba, 0x98, 0x76, 0x54, 0x32, 0x10];
+    const SM4_IV: [u8; 16] = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f];
+    const SM4_ORIGIN_DATA: &str = concat!(
+        "aaaaaaaaaaaaaaaabbbbbbbbbbbbbbbbccccccccccccccccdddddddddddddddd",
+        "eeeeeeeeeeeeeeeeffffffffffffffffaaaaaaaaaaaaaaaabbbbbbbbbbbbbbbb",
+    );
+    const SM4_ENCIPHERED_DATA: &str = concat!(
+        "ac3236cb970cc20791364c395a1342d1a3cbc1878c6f30cd074cce385cdd70c7",
+        "f234bc0e24c11980fd1286310ce37b926e02fcd0faa0baf38b2933851d824514",
+    );
+
+    fn counter(counter_byte_length: usize) -> CTRCounter {
+        CTRCounter::new(counter_byte_length, CTREndianness::BigEndian).unwrap()
+    }
+
+    #[test]
+    fn sm4_full_width_counter() {
+        let enciphered_data = sm4_ctr_encrypt_data(&hex_decode(SM4_ORIGIN_DATA).unwrap(), SM4_KEY, SM4_IV, counter(16)).unwrap();
+        assert_eq!(hex_encode(&enciphered_data), SM4_ENCIPHERED_DATA);
+    }
+
+    #[test]
+    fn sm4_seek_matches_offset_key_stream() {
+        let origin_data = hex_decode(SM4_ORIGIN_DATA).unwrap();
+        let mut sm4_ctr = SM4CTREncryption::new(SM4_KEY, SM4_IV, counter(4)).unwrap();
+        let mut data = origin_data[37..].to_vec();
+        sm4_ctr.seek(37);
+        sm4_ctr.apply_key_stream(&mut data).unwrap();
+        assert_eq!(sm4_ctr.position(), 64);
+        assert_eq!(hex_encode(&data), SM4_ENCIPHERED_DATA[74..]);
+    }
+
+    #[test]
+    fn counter_wider_than_block_is_rejected() {
+        let key_data = vec![0; 16];
+        assert!(CTRMode::new(BlowFishEncryption::with_key_data(key_data.clone()).unwrap(), [0; 8], counter(8)).is_ok());
+        assert!(matches!(CTRMode::new(BlowFishEncryption::with_key_data(key_data.clone()).unwrap(), [0; 8], counter(9)), Err(Error::InvalidParameter)));
+        assert!(matches!(CTREncryptor::new(BlowFishEncryption::with_key_data(key_data.clone()).unwrap(), [0; 8], counter(16)), Err(Error::InvalidParameter)));
+        assert!(matches!(CTRDecryptor::new(BlowFishEncryption::with_key_data(key_data.clone()).unwrap(), [0; 8], counter(12)), Err(Error::InvalidParameter)));
+        assert!(matches!(blowfish_ctr_encrypt_data(&[0; 8], key_data.clone(), [0; 8], counter(9)), Err(Error::InvalidParameter)));
+        assert!(matches!(try_blowfish_ctr_encrypt_data(&[0; 8], key_data, &[0; 8], counter(9)), Err(Error::InvalidParameter)));
+        assert!(CTRMode::new(SM4Encryption::with_key(SM4_KEY), SM4_IV, counter(16)).is_ok());
+    }
+
+    #[test]
+    fn blowfish_counter_exhaustion_is_rejected() {
+        let key_data = vec![0; 8];
+        let iv = [0x01, 0x02, 0x03, 0x04, 0xff, 0xff, 0xff, 0xff];
+        assert!(blowfish_ctr_encrypt_data(&[0; 8], key_data.clone(), iv, counter(4)).is_ok());
+        assert!(matches!(blowfish_ctr_encrypt_data(&[0; 16], key_data.clone(), iv, counter(4)), Err(Error::InvalidDataLength)));
+        let mut data = [0x5a; 16];
+        let mut ctr_mode = CTRMode::new(BlowFishEncryption::with_key_data(key_data.clone()).unwrap(), iv, counter(4)).unwrap();
+        assert!(matches!(ctr_mode.apply_key_stream(&mut data), Err(Error::InvalidDataLength)));
+        assert_eq!(data, [0x5a; 16]);
+        assert_eq!(ctr_mode.position(), 0);
+        let mut encryptor = CTREncryptor::new(BlowFishEncryption::with_key_data(key_data).unwrap(), iv, counter(4)).unwrap();
+        assert_eq!(encryptor.update(&[0; 8]).unwrap().len(), 8);
+        assert!(matches!(encryptor.update(&[0; 1]), Err(Error::InvalidDataLength)));
+        assert!(matches!(encryptor.finalize(), Err(Error::InvalidDataLength)));
+    }
+}
@@ -0,0 +1,199 @@
+use crate::error::Error;
+use super::block_cipher::BlockCipher;
+use super::padding::Padding;
+use super::stream_trait::{StreamEncryptor, StreamDecryptor};
+
+pub struct ECBMode<C: BlockCipher> {
+    cipher: C,
+}
+
+impl<C: BlockCipher> ECBMode<C> {
+    pub fn new(cipher: C) -> ECBMode<C> {
+        ECBMode {
+            cipher,
+        }
+    }
+
+    fn encrypt_blocks(&self, data: &mut [u8]) {
+        let block_sum = data.len() / C::BLOCK_BYTE_LENGTH;
+        let mut origin_data_block = C::Block::default();
+        let mut enciphered_data_block = C::Block::default();
+        for i in 0..block_sum {
+            let data_block = &mut data[(i * C::BLOCK_BYTE_LENGTH)..((i + 1) * C::BLOCK_BYTE_LENGTH)];
+            origin_data_block.as_mut().copy_from_slice(data_block);
+            self.cipher.encrypt_block(&origin_data_block, &mut enciphered_data_block);
+            data_block.copy_from_slice(enciphered_data_block.as_ref());
+        }
+    }
+
+    fn decrypt_blocks(&self, data: &mut [u8]) {
+        let block_sum = data.len() / C::BLOCK_BYTE_LENGTH;
+        let mut enciphered_data_block = C::Block::default();
+        let mut origin_data_block = C::Block::default();
+        for i in 0..block_sum {
+            let data_block = &mut data[(i * C::BLOCK_BYTE_LENGTH)..((i + 1) * C::BLOCK_BYTE_LENGTH)];
+            enciphered_data_block.as_mut().copy_from_slice(data_block);
+            self.cipher.decrypt_block(&enciphered_data_block, &mut origin_data_block);
+            data_block.copy_from_slice(origin_data_block.as_ref());
+        }
+    }
+
+    pub fn encrypt_data(&self, origin_data: &[u8]) -> Result<Vec<u8>, Error> {
+        let mut enciphered_data = origin_data.to_vec();
+        self.encrypt_data_in_place(&mut enciphered_data)?;
+        Ok(enciphered_data)
+    }
+
+    pub fn decrypt_data(&self, enciphered_data: &[u8]) -> Result<Vec<u8>, Error> {
+        let mut origin_data = enciphered_data.to_vec();
+        self.decrypt_data_in_place(&mut origin_data)?;
+        Ok(origin_data)
+    }
+
+    pub fn encrypt_data_in_place(&self, data: &mut [u8]) -> Result<(), Error> {
+        if !data.len().is_multiple_of(C::BLOCK_BYTE_LENGTH) {
+            return Err(Error::InvalidDataLength);
+        }
+        self.encrypt_blocks(data);
+        Ok(())
+    }
+
+    pub fn decrypt_data_in_place(&self, data: &mut [u8]) -> Result<(), Error> {
+        if !data.len().is_multiple_of(C::BLOCK_BYTE_LENGTH) {
+            return Err(Error::InvalidDataLength);
+        }
+        self.decrypt_blocks(data);
+        Ok(())
+    }
+
+    pub fn encrypt_data_into(&self, origin_data: &[u8], enciphered_data: &mut [u8]) -> Result<(), Error> {
+        if origin_data.len() != enciphered_data.len() {
+            return Err(Error::InvalidDataLength);
+        }
+        enciphered_data.copy_from_slice(origin_data);
+        self.encrypt_data_in_place(enciphered_data)
+    }
+
+    pub fn decrypt_data_into(&self, enciphered_data: &[u8], origin_data: &mut [u8]) -> Result<(), Error> {
+        if enciphered_data.len() != origin_data.len() {
+            return Err(Error::InvalidDataLength);
+        }
+        origin_data.copy_from_slice(enciphered_data);
+        self.decrypt_data_in_place(origin_data)
+    }
+
+    pub fn encrypt_data_with_padding(&self, origin_data: &[u8], padding: Padding) -> Result<Vec<u8>, Error> {
+        let padded_data = padding.pad_data(origin_data, C::BLOCK_BYTE_LENGTH)?;
+        self.encrypt_data(&padded_data)
+    }
+
+    pub fn decrypt_data_with_padding(&self, enciphered_data: &[u8], padding: Padding) -> Result<Vec<u8>, Error> {
+        let padded_data = self.decrypt_data(enciphered_data)?;
+        Ok(padding.unpad_data(&padded_data, C::BLOCK_BYTE_LENGTH)?.to_vec())
+    }
+
+    pub fn encrypt_data_with_padding_in_place(&self, buffer: &mut [u8], data_length: usize, padding: Padding) -> Result<usize, Error> {
+        let padded_data_length = padding.pad_data_in_place(buffer, data_length, C::BLOCK_BYTE_LENGTH)?;
+        self.encrypt_blocks(&mut buffer[..padded_data_length]);
+        Ok(padded_data_length)
+    }
+
+    pub fn decrypt_data_with_padding_in_place(&self, data: &mut [u8], padding: Padding) -> Result<usize, Error> {
+        self.decrypt_data_in_place(data)?;
+        Ok(padding.unpad_data(data, C::BLOCK_BYTE_LENGTH)?.len())
+    }
+}
+
+pub struct ECBEncryptor<C: BlockCipher> {
+    mode: ECBMode<C>,
+    padding: Padding,
+    remains_data: Vec<u8>,
+}
+
+impl<C: BlockCipher> ECBEncryptor<C> {
+    pub fn new(cipher: C, padding: Padding) -> ECBEncryptor<C> {
+        ECBEncryptor {
+            mode: ECBMode::new(cipher),
+            padding,
+            remains_data: Vec::new(),
+        }
+    }
+
+    pub fn update(&mut self, origin_data: &[u8]) -> Vec<u8> {
+        self.remains_data.extend_from_slice(origin_data);
+        let block_data_length = self.remains_data.len() / C::BLOCK_BYTE_LENGTH * C::BLOCK_BYTE_LENGTH;
+        let mut enciphered_data: Vec<u8> = self.remains_data.drain(..block_data_length).collect();
+        self.mode.encrypt_blocks(&mut enciphered_data);
+        enciphered_data
+    }
+
+    pub fn finalize(self) -> Result<Vec<u8>, Error> {
+        self.mode.encrypt_data_with_padding(&self.remains_data, self.padding)
+    }
+}
+
+impl<C: BlockCipher> StreamEncryptor for ECBEncryptor<C> {
+    fn update(&mut self, origin_data: &[u8]) -> Vec<u8> {
+        ECBEncryptor::update(self, origin_data)
+    }
+
+    fn finalize(self) -> Result<Vec<u8>, Error> {
+        ECBEncryptor::finalize(self)
+    }
+}
+
+pub struct ECBDecryptor<C: BlockCipher> {
+    mode: ECBMode<C>,
+    padding: Padding,
+    remains_data: Vec<u8>,
+}
+
+impl<C: BlockCipher> ECBDecryptor<C> {
+    pub fn new(cipher: C, padding: Padding) -> ECBDecryptor<C> {
+        ECBDecryptor {
+            mode: ECBMode::new(cipher),
+            padding,
+            remains_data: Vec::new(),
+        }
+    }
+
+    pub fn update(&mut self, enciphered_data: &[u8]) -> Vec<u8> {
+        self.remains_data.extend_from_slice(enciphered_data);
+        let block_data_length = self.remains_data.len().saturating_sub(1) / C::BLOCK_BYTE_LENGTH * C::BLOCK_BYTE_LENGTH;
+        let mut origin_data = self.remains_data[..block_data_length].to_vec();
+        self.mode.decrypt_blocks(&mut origin_data);
+        self.remains_data.drain(..block_data_length);
+        origin_data
+    }
+
+    pub fn finalize(self) -> Result<Vec<u8>, Error> {
+        self.mode.decrypt_data_with_padding(&self.remains_data, self.padding)
+    }
+}
+
+impl<C: BlockCipher> StreamDecryptor for ECBDecryptor<C> {
+    fn update(&mut self, enciphered_data: &[u8]) -> Vec<u8> {
+        ECBDecryptor::update(self, enciphered_data)
+    }
+
+    fn finalize(self) -> Result<Vec<u8>, Error> {
+        ECBDecryptor::finalize(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use super::super::super::sm4::sm4_ecb::{sm4_ecb_decrypt_data, sm4_ecb_encrypt_data};
+
+    const KEY: [u8; 16] = [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0xfe, 0xdc, 0xba, 0x98, 0x76, 0x54, 0x32, 0x10];
+
+    #[test]
+    fn partial_block_is_rejected() {
+        assert!(matches!(sm4_ecb_encrypt_data(&[0; 37], KEY), Err(Error::InvalidDataLength)));
+        assert!(matches!(sm4_ecb_decrypt_data(&[0; 15], KEY), Err(Error::InvalidDataLength)));
+        let enciphered_data = sm4_ecb_encrypt_data(&[0; 32], KEY).unwrap();
+        assert_eq!(enciphered_data.len(), 32);
+        assert_eq!(sm4_ecb_decrypt_data(&enciphered_data, KEY).unwrap(), [0; 32]);
+    }
+}
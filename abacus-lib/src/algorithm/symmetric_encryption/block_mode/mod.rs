@@ -0,0 +1,9 @@
+pub mod block_cipher;
+pub mod stream_trait;
+pub mod ctr_counter;
+pub mod padding;
+pub mod ecb_mode;
+pub mod cbc_mode;
+pub mod cfb_mode;
+pub mod ofb_mode;
+pub mod ctr_mode;
@@ -0,0 +1,95 @@
+use crate::error::Error;
+use crate::util::random::fill_random_bytes;
+
+#[derive(Clone, Copy)]
+pub enum Padding {
+    PKCS7,
+    ANSIX923,
+    ISO7816,
+    ISO10126,
+    Zero,
+    NoPadding,
+}
+
+impl Padding {
+    fn padding_length(&self, data_length: usize, block_byte_length: usize) -> Result<usize, Error> {
+        let remains_data_length = data_length % block_byte_length;
+        let padding_length = match self {
+            Padding::Zero if remains_data_length == 0 => 0,
+            Padding::NoPadding if remains_data_length != 0 => return Err(Error::InvalidDataLength),
+            Padding::NoPadding => 0,
+            _ => block_byte_length - remains_data_length,
+        };
+        if padding_length > u8::MAX as usize {
+            return Err(Error::InvalidDataLength);
+        }
+        Ok(padding_length)
+    }
+
+    pub fn pad_data(&self, origin_data: &[u8], block_byte_length: usize) -> Result<Vec<u8>, Error> {
+        let padding_length = self.padding_length(origin_data.len(), block_byte_length)?;
+        let mut padded_data = origin_data.to_vec();
+        padded_data.resize(origin_data.len() + padding_length, 0);
+        self.pad_data_in_place(&mut padded_data, origin_data.len(), block_byte_length)?;
+        Ok(padded_data)
+    }
+
+    pub fn pad_data_in_place(&self, buffer: &mut [u8], data_length: usize, block_byte_length: usize) -> Result<usize, Error> {
+        let padding_length = self.padding_length(data_length, block_byte_length)?;
+        if buffer.len() < data_length + padding_length {
+            return Err(Error::InvalidDataLength);
+        }
+        let padding_data = &mut buffer[data_length..(data_length + padding_length)];
+        match self {
+            Padding::PKCS7 => padding_data.fill(padding_length as u8),
+            Padding::ANSIX923 => {
+                padding_data.fill(0x00);
+                padding_data[padding_length - 1] = padding_length as u8;
+            }
+            Padding::ISO7816 => {
+                padding_data.fill(0x00);
+                padding_data[0] = 0x80;
+            }
+            Padding::ISO10126 => {
+                fill_random_bytes(&mut padding_data[..(padding_length - 1)])?;
+                padding_data[padding_length - 1] = padding_length as u8;
+            }
+            Padding::Zero | Padding::NoPadding => padding_data.fill(0x00),
+        }
+        Ok(data_length + padding_length)
+    }
+
+    pub fn unpad_data<'a>(&self, padded_data: &'a [u8], block_byte_length: usize) -> Result<&'a [u8], Error> {
+        if !padded_data.len().is_multiple_of(block_byte_length) {
+            return Err(Error::InvalidDataLength);
+        }
+        let padding_length = match self {
+            Padding::PKCS7 | Padding::ANSIX923 | Padding::ISO10126 => {
+                let padding_length = *padded_data.last().ok_or(Error::InvalidPadding)? as usize;
+                if padding_length == 0 || padding_length > block_byte_length {
+                    return Err(Error::InvalidPadding);
+                }
+                let padding_data = &padded_data[(padded_data.len() - padding_length)..(padded_data.len() - 1)];
+                let padding_valid = match self {
+                    Padding::PKCS7 => padding_data.iter().all(|b| *b as usize == padding_length),
+                    Padding::ANSIX923 => padding_data.iter().all(|b| *b == 0x00),
+                    _ => true,
+                };
+                if !padding_valid {
+                    return Err(Error::InvalidPadding);
+                }
+                padding_length
+            }
+            Padding::ISO7816 => {
+                let data_length = padded_data.iter().rposition(|b| *b != 0x00).ok_or(Error::InvalidPadding)?;
+                if padded_data[data_length] != 0x80 || padded_data.len() - data_length > block_byte_length {
+                    return Err(Error::InvalidPadding);
+                }
+                padded_data.len() - data_length
+            }
+            Padding::Zero => padded_data.iter().rev().take(block_byte_length - 1).take_while(|b| **b == 0x00).count(),
+            Padding::NoPadding => 0,
+        };
+        Ok(&padded_data[..(padded_data.len() - padding_length)])
+    }
+}
@@ -0,0 +1,13 @@
+use crate::error::Error;
+
+pub trait StreamEncryptor {
+    fn update(&mut self, origin_data: &[u8]) -> Vec<u8>;
+
+    fn finalize(self) -> Result<Vec<u8>, Error>;
+}
+
+pub trait StreamDecryptor {
+    fn update(&mut self, enciphered_data: &[u8]) -> Vec<u8>;
+
+    fn finalize(self) -> Result<Vec<u8>, Error>;
+}
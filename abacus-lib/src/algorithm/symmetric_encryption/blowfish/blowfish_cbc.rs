@@ -1,40 +1,77 @@
-use super::blowfish_constant::{BLOWFISH_BLOCK_BYTE_LENGTH};
+use crate::error::Error;
+use super::super::block_mode::cbc_mode::CBCMode;
+use super::super::block_mode::padding::Padding;
+use super::blowfish_constant::BLOWFISH_BLOCK_BYTE_LENGTH;
 use super::blowfish_encryption::BlowFishEncryption;
 
-pub fn blowfish_cbc_encrypt_data(origin_data: &[u8], key_data: Vec<u8>, iv: [u8; BLOWFISH_BLOCK_BYTE_LENGTH]) -> Vec<u8> {
-    let blowfish = BlowFishEncryption::with_key_data(key_data);
-    let block_sum = origin_data.len() / BLOWFISH_BLOCK_BYTE_LENGTH;
-    let enciphered_data_length = block_sum * BLOWFISH_BLOCK_BYTE_LENGTH;
-    let mut enciphered_data: Vec<u8> = Vec::with_capacity(enciphered_data_length);
-    let mut origin_data_block_with_iv = iv;
-    enciphered_data.resize(enciphered_data_length, 0);
-    for i in 0..block_sum {
-        let origin_data_block: &[u8; BLOWFISH_BLOCK_BYTE_LENGTH] = (&origin_data[(i * BLOWFISH_BLOCK_BYTE_LENGTH)..((i + 1) * BLOWFISH_BLOCK_BYTE_LENGTH)]).try_into().unwrap();
-        for j in 0..BLOWFISH_BLOCK_BYTE_LENGTH {
-            origin_data_block_with_iv[j] = origin_data_block[j] ^ origin_data_block_with_iv[j];
-        }
-        let enciphered_data_block: &mut [u8; BLOWFISH_BLOCK_BYTE_LENGTH] = (&mut enciphered_data[(i * BLOWFISH_BLOCK_BYTE_LENGTH)..((i + 1) * BLOWFISH_BLOCK_BYTE_LENGTH)]).try_into().unwrap();
-        blowfish.encrypt_block(&origin_data_block_with_iv, enciphered_data_block);
-        origin_data_block_with_iv = *enciphered_data_block;
+pub fn blowfish_cbc_encrypt_data(origin_data: &[u8], key_data: Vec<u8>, iv: [u8; BLOWFISH_BLOCK_BYTE_LENGTH]) -> Result<Vec<u8>, Error> {
+    CBCMode::new(BlowFishEncryption::with_key_data(key_data)?, iv).encrypt_data(origin_data)
+}
+
+pub fn blowfish_cbc_decrypt_data(enciphered_data: &[u8], key_data: Vec<u8>, iv: [u8; BLOWFISH_BLOCK_BYTE_LENGTH]) -> Result<Vec<u8>, Error> {
+    CBCMode::new(BlowFishEncryption::with_key_data(key_data)?, iv).decrypt_data(enciphered_data)
+}
+
+pub fn blowfish_cbc_encrypt_data_with_padding(origin_data: &[u8], key_data: Vec<u8>, iv: [u8; BLOWFISH_BLOCK_BYTE_LENGTH], padding: Padding) -> Result<Vec<u8>, Error> {
+    CBCMode::new(BlowFishEncryption::with_key_data(key_data)?, iv).encrypt_data_with_padding(origin_data, padding)
+}
+
+pub fn blowfish_cbc_decrypt_data_with_padding(enciphered_data: &[u8], key_data: Vec<u8>, iv: [u8; BLOWFISH_BLOCK_BYTE_LENGTH], padding: Padding) -> Result<Vec<u8>, Error> {
+    CBCMode::new(BlowFishEncryption::with_key_data(key_data)?, iv).decrypt_data_with_padding(enciphered_data, padding)
+}
+
+pub fn blowfish_cbc_encrypt_data_in_place(data: &mut [u8], key_data: Vec<u8>, iv: [u8; BLOWFISH_BLOCK_BYTE_LENGTH]) -> Result<(), Error> {
+    CBCMode::new(BlowFishEncryption::with_key_data(key_data)?, iv).encrypt_data_in_place(data)
+}
+
+pub fn blowfish_cbc_decrypt_data_in_place(data: &mut [u8], key_data: Vec<u8>, iv: [u8; BLOWFISH_BLOCK_BYTE_LENGTH]) -> Result<(), Error> {
+    CBCMode::new(BlowFishEncryption::with_key_data(key_data)?, iv).decrypt_data_in_place(data)
+}
+
+pub fn blowfish_cbc_encrypt_data_into(origin_data: &[u8], enciphered_data: &mut [u8], key_data: Vec<u8>, iv: [u8; BLOWFISH_BLOCK_BYTE_LENGTH]) -> Result<(), Error> {
+    CBCMode::new(BlowFishEncryption::with_key_data(key_data)?, iv).encrypt_data_into(origin_data, enciphered_data)
+}
+
+pub fn blowfish_cbc_decrypt_data_into(enciphered_data: &[u8], origin_data: &mut [u8], key_data: Vec<u8>, iv: [u8; BLOWFISH_BLOCK_BYTE_LENGTH]) -> Result<(), Error> {
+    CBCMode::new(BlowFishEncryption::with_key_data(key_data)?, iv).decrypt_data_into(enciphered_data, origin_data)
+}
+
+pub fn blowfish_cbc_encrypt_data_with_padding_in_place(buffer: &mut [u8], data_length: usize, key_data: Vec<u8>, iv: [u8; BLOWFISH_BLOCK_BYTE_LENGTH], padding: Padding) -> Result<usize, Error> {
+    CBCMode::new(BlowFishEncryption::with_key_data(key_data)?, iv).encrypt_data_with_padding_in_place(buffer, data_length, padding)
+}
+
+pub fn blowfish_cbc_decrypt_data_with_padding_in_place(data: &mut [u8], key_data: Vec<u8>, iv: [u8; BLOWFISH_BLOCK_BYTE_LENGTH], padding: Padding) -> Result<usize, Error> {
+    CBCMode::new(BlowFishEncryption::with_key_data(key_data)?, iv).decrypt_data_with_padding_in_place(data, padding)
+}
+
+pub fn try_blowfish_cbc_encrypt_data(origin_data: &[u8], key_data: Vec<u8>, iv: &[u8]) -> Result<Vec<u8>, Error> {
+    let iv: [u8; BLOWFISH_BLOCK_BYTE_LENGTH] = iv.try_into().map_err(|_| Error::InvalidIVLength)?;
+    blowfish_cbc_encrypt_data(origin_data, key_data, iv)
+}
+
+pub fn try_blowfish_cbc_decrypt_data(enciphered_data: &[u8], key_data: Vec<u8>, iv: &[u8]) -> Result<Vec<u8>, Error> {
+    let iv: [u8; BLOWFISH_BLOCK_BYTE_LENGTH] = iv.try_into().map_err(|_| Error::InvalidIVLength)?;
+    blowfish_cbc_decrypt_data(enciphered_data, key_data, iv)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::util::hex::hex_encode;
+    use super::{blowfish_cbc_decrypt_data, blowfish_cbc_encrypt_data, try_blowfish_cbc_encrypt_data};
+
+    #[test]
+    fn invalid_key_length_is_rejected() {
+        assert!(matches!(blowfish_cbc_encrypt_data(&[0; 8], Vec::new(), [0; 8]), Err(Error::InvalidKeyLength)));
+        assert!(matches!(blowfish_cbc_decrypt_data(&[0; 8], vec![0; 57], [0; 8]), Err(Error::InvalidKeyLength)));
+        assert!(matches!(try_blowfish_cbc_encrypt_data(&[0; 8], Vec::new(), &[0; 8]), Err(Error::InvalidKeyLength)));
+        assert!(matches!(try_blowfish_cbc_encrypt_data(&[0; 8], vec![0; 8], &[0; 7]), Err(Error::InvalidIVLength)));
     }
-    enciphered_data
-}
-
-pub fn blowfish_cbc_decrypt_data(enciphered_data: &[u8], key_data: Vec<u8>, iv: [u8; BLOWFISH_BLOCK_BYTE_LENGTH]) -> Vec<u8> {
-    let blowfish = BlowFishEncryption::with_key_data(key_data);
-    let block_sum = enciphered_data.len() / BLOWFISH_BLOCK_BYTE_LENGTH;
-    let enciphered_data_length = block_sum * BLOWFISH_BLOCK_BYTE_LENGTH;
-    let mut origin_data: Vec<u8> = Vec::with_capacity(enciphered_data_length);
-    let mut enciphered_data_block_with_iv = &iv;
-    origin_data.resize(enciphered_data_length, 0);
-    for i in 0..block_sum {
-        let enciphered_data_block: &[u8; BLOWFISH_BLOCK_BYTE_LENGTH] = (&enciphered_data[(i * BLOWFISH_BLOCK_BYTE_LENGTH)..((i + 1) * BLOWFISH_BLOCK_BYTE_LENGTH)]).try_into().unwrap();
-        let origin_data_block: &mut [u8; BLOWFISH_BLOCK_BYTE_LENGTH] = (&mut origin_data[(i * BLOWFISH_BLOCK_BYTE_LENGTH)..((i + 1) * BLOWFISH_BLOCK_BYTE_LENGTH)]).try_into().unwrap();
-        blowfish.decrypt_block(enciphered_data_block, origin_data_block);
-        for j in 0..BLOWFISH_BLOCK_BYTE_LENGTH {
-            origin_data_block[j] = origin_data_block[j] ^ enciphered_data_block_with_iv[j];
-        }
-        enciphered_data_block_with_iv = enciphered_data_block;
+
+    #[test]
+    fn known_answer_round_trip() {
+        let enciphered_data = blowfish_cbc_encrypt_data(&[0x5a; 24], vec![0x01; 16], [0x02; 8]).unwrap();
+        assert_eq!(hex_encode(&enciphered_data), "b2c8d07a2fc8064d7161d76031e945730a822e017dd1b0f6");
+        assert_eq!(blowfish_cbc_decrypt_data(&enciphered_data, vec![0x01; 16], [0x02; 8]).unwrap(), vec![0x5a; 24]);
     }
-    origin_data
 }
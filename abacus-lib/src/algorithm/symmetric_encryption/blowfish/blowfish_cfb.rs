@@ -1,42 +1,40 @@
-use super::blowfish_constant::{BLOWFISH_BLOCK_BYTE_LENGTH};
+use crate::error::Error;
+use super::super::block_mode::cfb_mode::CFBMode;
+use super::blowfish_constant::BLOWFISH_BLOCK_BYTE_LENGTH;
 use super::blowfish_encryption::BlowFishEncryption;
 
-pub fn blowfish_cfb_encrypt_data(origin_data: &[u8], key_data: Vec<u8>, iv: [u8; BLOWFISH_BLOCK_BYTE_LENGTH]) -> Vec<u8> {
-    let blowfish = BlowFishEncryption::with_key_data(key_data);
-    let block_sum = origin_data.len() / BLOWFISH_BLOCK_BYTE_LENGTH;
-    let enciphered_data_length = block_sum * BLOWFISH_BLOCK_BYTE_LENGTH;
-    let mut key_with_iv = iv;
-    let mut key_with_iv_encrypted = [0; BLOWFISH_BLOCK_BYTE_LENGTH];
-    let mut enciphered_data: Vec<u8> = Vec::with_capacity(enciphered_data_length);
-    enciphered_data.resize(enciphered_data_length, 0);
-    for i in 0..block_sum {
-        blowfish.encrypt_block(&key_with_iv, &mut key_with_iv_encrypted);
-        let origin_data_block: &[u8; BLOWFISH_BLOCK_BYTE_LENGTH] = (&origin_data[(i * BLOWFISH_BLOCK_BYTE_LENGTH)..((i + 1) * BLOWFISH_BLOCK_BYTE_LENGTH)]).try_into().unwrap();
-        let enciphered_data_block: &mut [u8; BLOWFISH_BLOCK_BYTE_LENGTH] = (&mut enciphered_data[(i * BLOWFISH_BLOCK_BYTE_LENGTH)..((i + 1) * BLOWFISH_BLOCK_BYTE_LENGTH)]).try_into().unwrap();
-        for j in 0..BLOWFISH_BLOCK_BYTE_LENGTH {
-            enciphered_data_block[j] = origin_data_block[j] ^ key_with_iv_encrypted[j]
-        }
-        key_with_iv = *enciphered_data_block;
-    }
-    enciphered_data
-}
-
-pub fn blowfish_cfb_decrypt_data(enciphered_data: &[u8], key_data: Vec<u8>, iv: [u8; BLOWFISH_BLOCK_BYTE_LENGTH]) -> Vec<u8> {
-    let blowfish = BlowFishEncryption::with_key_data(key_data);
-    let block_sum = enciphered_data.len() / BLOWFISH_BLOCK_BYTE_LENGTH;
-    let enciphered_data_length = block_sum * BLOWFISH_BLOCK_BYTE_LENGTH;
-    let mut key_with_iv = iv;
-    let mut key_with_iv_encrypted = [0; BLOWFISH_BLOCK_BYTE_LENGTH];
-    let mut origin_data: Vec<u8> = Vec::with_capacity(enciphered_data_length);
-    origin_data.resize(enciphered_data_length, 0);
-    for i in 0..block_sum {
-        blowfish.encrypt_block(&key_with_iv, &mut key_with_iv_encrypted);
-        let enciphered_data_block: &[u8; BLOWFISH_BLOCK_BYTE_LENGTH] = (&enciphered_data[(i * BLOWFISH_BLOCK_BYTE_LENGTH)..((i + 1) * BLOWFISH_BLOCK_BYTE_LENGTH)]).try_into().unwrap();
-        let origin_data_block: &mut [u8; BLOWFISH_BLOCK_BYTE_LENGTH] = (&mut origin_data[(i * BLOWFISH_BLOCK_BYTE_LENGTH)..((i + 1) * BLOWFISH_BLOCK_BYTE_LENGTH)]).try_into().unwrap();
-        for j in 0..BLOWFISH_BLOCK_BYTE_LENGTH {
-            origin_data_block[j] = enciphered_data_block[j] ^ key_with_iv_encrypted[j];
-        }
-        key_with_iv = *enciphered_data_block;
-    }
-    origin_data
+pub fn blowfish_cfb_encrypt_data(origin_data: &[u8], key_data: Vec<u8>, iv: [u8; BLOWFISH_BLOCK_BYTE_LENGTH]) -> Result<Vec<u8>, Error> {
+    Ok(CFBMode::new(BlowFishEncryption::with_key_data(key_data)?, iv).encrypt_data(origin_data))
+}
+
+pub fn blowfish_cfb_decrypt_data(enciphered_data: &[u8], key_data: Vec<u8>, iv: [u8; BLOWFISH_BLOCK_BYTE_LENGTH]) -> Result<Vec<u8>, Error> {
+    Ok(CFBMode::new(BlowFishEncryption::with_key_data(key_data)?, iv).decrypt_data(enciphered_data))
+}
+
+pub fn blowfish_cfb_encrypt_data_in_place(data: &mut [u8], key_data: Vec<u8>, iv: [u8; BLOWFISH_BLOCK_BYTE_LENGTH]) -> Result<(), Error> {
+    CFBMode::new(BlowFishEncryption::with_key_data(key_data)?, iv).encrypt_data_in_place(data);
+    Ok(())
+}
+
+pub fn blowfish_cfb_decrypt_data_in_place(data: &mut [u8], key_data: Vec<u8>, iv: [u8; BLOWFISH_BLOCK_BYTE_LENGTH]) -> Result<(), Error> {
+    CFBMode::new(BlowFishEncryption::with_key_data(key_data)?, iv).decrypt_data_in_place(data);
+    Ok(())
+}
+
+pub fn blowfish_cfb_encrypt_data_into(origin_data: &[u8], enciphered_data: &mut [u8], key_data: Vec<u8>, iv: [u8; BLOWFISH_BLOCK_BYTE_LENGTH]) -> Result<(), Error> {
+    CFBMode::new(BlowFishEncryption::with_key_data(key_data)?, iv).encrypt_data_into(origin_data, enciphered_data)
+}
+
+pub fn blowfish_cfb_decrypt_data_into(enciphered_data: &[u8], origin_data: &mut [u8], key_data: Vec<u8>, iv: [u8; BLOWFISH_BLOCK_BYTE_LENGTH]) -> Result<(), Error> {
+    CFBMode::new(BlowFishEncryption::with_key_data(key_data)?, iv).decrypt_data_into(enciphered_data, origin_data)
+}
+
+pub fn try_blowfish_cfb_encrypt_data(origin_data: &[u8], key_data: Vec<u8>, iv: &[u8]) -> Result<Vec<u8>, Error> {
+    let iv: [u8; BLOWFISH_BLOCK_BYTE_LENGTH] = iv.try_into().map_err(|_| Error::InvalidIVLength)?;
+    blowfish_cfb_encrypt_data(origin_data, key_data, iv)
+}
+
+pub fn try_blowfish_cfb_decrypt_data(enciphered_data: &[u8], key_data: Vec<u8>, iv: &[u8]) -> Result<Vec<u8>, Error> {
+    let iv: [u8; BLOWFISH_BLOCK_BYTE_LENGTH] = iv.try_into().map_err(|_| Error::InvalidIVLength)?;
+    blowfish_cfb_decrypt_data(enciphered_data, key_data, iv)
 }
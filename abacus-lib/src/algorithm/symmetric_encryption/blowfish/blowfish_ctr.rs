@@ -0,0 +1,79 @@
+use crate::error::Error;
+use super::super::block_mode::ctr_counter::CTRCounter;
+use super::super::block_mode::ctr_mode::CTRMode;
+use super::blowfish_constant::BLOWFISH_BLOCK_BYTE_LENGTH;
+use super::blowfish_encryption::BlowFishEncryption;
+
+pub struct BlowFishCTREncryption {
+    ctr_mode: CTRMode<BlowFishEncryption>,
+}
+
+impl BlowFishCTREncryption {
+    pub fn new(key_data: Vec<u8>, iv: [u8; BLOWFISH_BLOCK_BYTE_LENGTH], ctr_counter: CTRCounter) -> Result<BlowFishCTREncryption, Error> {
+        Ok(BlowFishCTREncryption {
+            ctr_mode: CTRMode::new(BlowFishEncryption::with_key_data(key_data)?, iv, ctr_counter)?,
+        })
+    }
+
+    pub fn seek(&mut self, position: u64) {
+        self.ctr_mode.seek(position)
+    }
+
+    pub fn position(&self) -> u64 {
+        self.ctr_mode.position()
+    }
+
+    pub fn apply_key_stream(&mut self, data: &mut [u8]) -> Result<(), Error> {
+        self.ctr_mode.apply_key_stream(data)
+    }
+
+    pub fn apply_key_stream_into(&mut self, input_data: &[u8], output_data: &mut [u8]) -> Result<(), Error> {
+        self.ctr_mode.apply_key_stream_into(input_data, output_data)
+    }
+}
+
+pub fn blowfish_ctr_encrypt_data(origin_data: &[u8], key_data: Vec<u8>, iv: [u8; BLOWFISH_BLOCK_BYTE_LENGTH], ctr_counter: CTRCounter) -> Result<Vec<u8>, Error> {
+    blowfish_ctr_encrypt_data_at(origin_data, key_data, iv, ctr_counter, 0)
+}
+
+pub fn blowfish_ctr_decrypt_data(enciphered_data: &[u8], key_data: Vec<u8>, iv: [u8; BLOWFISH_BLOCK_BYTE_LENGTH], ctr_counter: CTRCounter) -> Result<Vec<u8>, Error> {
+    blowfish_ctr_encrypt_data_at(enciphered_data, key_data, iv, ctr_counter, 0)
+}
+
+pub fn blowfish_ctr_encrypt_data_at(origin_data: &[u8], key_data: Vec<u8>, iv: [u8; BLOWFISH_BLOCK_BYTE_LENGTH], ctr_counter: CTRCounter, position: u64) -> Result<Vec<u8>, Error> {
+    let mut blowfish_ctr = BlowFishCTREncryption::new(key_data, iv, ctr_counter)?;
+    let mut enciphered_data = origin_data.to_vec();
+    blowfish_ctr.seek(position);
+    blowfish_ctr.apply_key_stream(&mut enciphered_data)?;
+    Ok(enciphered_data)
+}
+
+pub fn blowfish_ctr_decrypt_data_at(enciphered_data: &[u8], key_data: Vec<u8>, iv: [u8; BLOWFISH_BLOCK_BYTE_LENGTH], ctr_counter: CTRCounter, position: u64) -> Result<Vec<u8>, Error> {
+    blowfish_ctr_encrypt_data_at(enciphered_data, key_data, iv, ctr_counter, position)
+}
+
+pub fn blowfish_ctr_encrypt_data_in_place(data: &mut [u8], key_data: Vec<u8>, iv: [u8; BLOWFISH_BLOCK_BYTE_LENGTH], ctr_counter: CTRCounter) -> Result<(), Error> {
+    BlowFishCTREncryption::new(key_data, iv, ctr_counter)?.apply_key_stream(data)
+}
+
+pub fn blowfish_ctr_decrypt_data_in_place(data: &mut [u8], key_data: Vec<u8>, iv: [u8; BLOWFISH_BLOCK_BYTE_LENGTH], ctr_counter: CTRCounter) -> Result<(), Error> {
+    BlowFishCTREncryption::new(key_data, iv, ctr_counter)?.apply_key_stream(data)
+}
+
+pub fn blowfish_ctr_encrypt_data_into(origin_data: &[u8], enciphered_data: &mut [u8], key_data: Vec<u8>, iv: [u8; BLOWFISH_BLOCK_BYTE_LENGTH], ctr_counter: CTRCounter) -> Result<(), Error> {
+    BlowFishCTREncryption::new(key_data, iv, ctr_counter)?.apply_key_stream_into(origin_data, enciphered_data)
+}
+
+pub fn blowfish_ctr_decrypt_data_into(enciphered_data: &[u8], origin_data: &mut [u8], key_data: Vec<u8>, iv: [u8; BLOWFISH_BLOCK_BYTE_LENGTH], ctr_counter: CTRCounter) -> Result<(), Error> {
+    BlowFishCTREncryption::new(key_data, iv, ctr_counter)?.apply_key_stream_into(enciphered_data, origin_data)
+}
+
+pub fn try_blowfish_ctr_encrypt_data(origin_data: &[u8], key_data: Vec<u8>, iv: &[u8], ctr_counter: CTRCounter) -> Result<Vec<u8>, Error> {
+    let iv: [u8; BLOWFISH_BLOCK_BYTE_LENGTH] = iv.try_into().map_err(|_| Error::InvalidIVLength)?;
+    blowfish_ctr_encrypt_data(origin_data, key_data, iv, ctr_counter)
+}
+
+pub fn try_blowfish_ctr_decrypt_data(enciphered_data: &[u8], key_data: Vec<u8>, iv: &[u8], ctr_counter: CTRCounter) -> Result<Vec<u8>, Error> {
+    let iv: [u8; BLOWFISH_BLOCK_BYTE_LENGTH] = iv.try_into().map_err(|_| Error::InvalidIVLength)?;
+    blowfish_ctr_decrypt_data(enciphered_data, key_data, iv, ctr_counter)
+}
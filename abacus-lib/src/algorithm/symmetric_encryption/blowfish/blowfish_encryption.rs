@@ -1,9 +1,11 @@
-use super::blowfish_constant::{KEY_P_BYTE_LENGTH, KEY_S_BYTE_LENGTH, BLOWFISH_BLOCK_BYTE_LENGTH, P, S};
+use crate::error::Error;
+use super::super::block_mode::block_cipher::BlockCipher;
+use super::blowfish_constant::{KEY_P_BYTE_LENGTH, KEY_S_BYTE_LENGTH, BLOWFISH_BLOCK_BYTE_LENGTH, BLOWFISH_KEY_BYTE_MIN_LENGTH, BLOWFISH_KEY_BYTE_MAX_LENGTH, P, S};
 
 #[inline(always)]
 fn round(y: &mut u32, x: u32, s: &[u32; KEY_S_BYTE_LENGTH], p: u32) {
     let xx = x.to_be_bytes();
-    *y ^= p ^ (s[xx[0] as usize].wrapping_add(s[0x0100 + xx[1] as usize] ^ s[0x0200 + xx[2] as usize]).wrapping_add(s[0x0300 + xx[3] as usize]));
+    *y ^= p ^ ((s[xx[0] as usize].wrapping_add(s[0x0100 + xx[1] as usize]) ^ s[0x0200 + xx[2] as usize]).wrapping_add(s[0x0300 + xx[3] as usize]));
 }
 
 pub struct BlowFishEncryption {
@@ -12,25 +14,36 @@ pub struct BlowFishEncryption {
     s: [u32; KEY_S_BYTE_LENGTH],
 }
 
+impl Default for BlowFishEncryption {
+    fn default() -> Self {
+        BlowFishEncryption::new()
+    }
+}
+
 impl BlowFishEncryption {
     pub fn new() -> BlowFishEncryption {
-        let instance = BlowFishEncryption {
+        BlowFishEncryption {
             key_data: Vec::new(),
             p: P,
             s: S,
-        };
-        instance
+        }
     }
 
-    pub fn with_key_data(key_data: Vec<u8>) -> BlowFishEncryption {
+    pub fn with_key_data(key_data: Vec<u8>) -> Result<BlowFishEncryption, Error> {
         let mut instance = BlowFishEncryption::new();
-        instance.set_key_data(key_data);
-        instance
+        instance.set_key_data(key_data)?;
+        Ok(instance)
     }
 
-    pub fn set_key_data(&mut self, key_data: Vec<u8>) {
+    pub fn set_key_data(&mut self, key_data: Vec<u8>) -> Result<(), Error> {
+        if !(BLOWFISH_KEY_BYTE_MIN_LENGTH..=BLOWFISH_KEY_BYTE_MAX_LENGTH).contains(&key_data.len()) {
+            return Err(Error::InvalidKeyLength);
+        }
         self.key_data = key_data;
+        self.p = P;
+        self.s = S;
         self.generate_blowfish_key();
+        Ok(())
     }
 
     fn generate_blowfish_key(&mut self) {
@@ -38,8 +51,8 @@ impl BlowFishEncryption {
         let mut index: usize = 0;
         let mut ri: [u8; 4] = [0; 4];
         for i in 0..KEY_P_BYTE_LENGTH {
-            for j in 0..4 {
-                ri[j] = self.key_data[index];
+            for byte in ri.iter_mut() {
+                *byte = self.key_data[index];
                 index += 1;
                 if index == key_data_length {
                     index = 0;
@@ -129,3 +142,63 @@ impl BlowFishEncryption {
         enciphered_data[7] = l_bytes[3];
     }
 }
+
+impl BlockCipher for BlowFishEncryption {
+    const ALGORITHM_NAME: &'static str = "Blowfish";
+    const BLOCK_BYTE_LENGTH: usize = BLOWFISH_BLOCK_BYTE_LENGTH;
+
+    type Block = [u8; BLOWFISH_BLOCK_BYTE_LENGTH];
+
+    fn encrypt_block(&self, origin_data: &[u8; BLOWFISH_BLOCK_BYTE_LENGTH], enciphered_data: &mut [u8; BLOWFISH_BLOCK_BYTE_LENGTH]) {
+        BlowFishEncryption::encrypt_block(self, origin_data, enciphered_data)
+    }
+
+    fn decrypt_block(&self, enciphered_data: &[u8; BLOWFISH_BLOCK_BYTE_LENGTH], origin_data: &mut [u8; BLOWFISH_BLOCK_BYTE_LENGTH]) {
+        BlowFishEncryption::decrypt_block(self, enciphered_data, origin_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::util::hex::{hex_decode, hex_encode};
+    use super::BlowFishEncryption;
+
+    #[test]
+    fn schneier_vectors() {
+        let vectors = [
+            ("0000000000000000", "0000000000000000", "4ef997456198dd78"),
+            ("ffffffffffffffff", "ffffffffffffffff", "51866fd5b85ecb8a"),
+            ("3000000000000000", "1000000000000001", "7d856f9a613063f2"),
+            ("0123456789abcdef", "1111111111111111", "61f9c3802281b096"),
+            ("fedcba9876543210", "0123456789abcdef", "0aceab0fc6a0a28d"),
+        ];
+        for (key, origin_data, enciphered_data) in vectors {
+            let blowfish = BlowFishEncryption::with_key_data(hex_decode(key).unwrap()).unwrap();
+            let mut block = [0; 8];
+            blowfish.encrypt_block(&hex_decode(origin_data).unwrap().try_into().unwrap(), &mut block);
+            assert_eq!(hex_encode(&block), enciphered_data);
+            let mut decrypted_block = [0; 8];
+            blowfish.decrypt_block(&block, &mut decrypted_block);
+            assert_eq!(hex_encode(&decrypted_block), origin_data);
+        }
+    }
+
+    #[test]
+    fn rekeying_starts_from_initial_state() {
+        let mut blowfish = BlowFishEncryption::with_key_data(vec![0xff; 8]).unwrap();
+        blowfish.set_key_data(vec![0; 8]).unwrap();
+        let mut block = [0; 8];
+        blowfish.encrypt_block(&[0; 8], &mut block);
+        assert_eq!(hex_encode(&block), "4ef997456198dd78");
+    }
+
+    #[test]
+    fn invalid_key_length_is_rejected() {
+        for key_length in [0, 3, 57] {
+            assert!(matches!(BlowFishEncryption::with_key_data(vec![0; key_length]), Err(Error::InvalidKeyLength)));
+        }
+        let mut blowfish = BlowFishEncryption::new();
+        assert!(matches!(blowfish.set_key_data(Vec::new()), Err(Error::InvalidKeyLength)));
+    }
+}
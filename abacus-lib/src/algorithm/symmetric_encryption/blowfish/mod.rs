@@ -4,3 +4,4 @@ pub mod blowfish_ecb;
 pub mod blowfish_cbc;
 pub mod blowfish_ofb;
 pub mod blowfish_cfb;
+pub mod blowfish_ctr;
@@ -0,0 +1,9 @@
+pub const CHACHA20_KEY_BYTE_LENGTH: usize = 32;
+pub const CHACHA20_NONCE_BYTE_LENGTH: usize = 12;
+pub const XCHACHA20_NONCE_BYTE_LENGTH: usize = 24;
+pub const HCHACHA20_NONCE_BYTE_LENGTH: usize = 16;
+pub const CHACHA20_BLOCK_BYTE_LENGTH: usize = 64;
+pub const CHACHA20_STATE_LENGTH: usize = 16;
+pub const CHACHA20_POLY1305_TAG_BYTE_LENGTH: usize = 16;
+
+pub const CHACHA20_CONSTANT: [u32; 4] = [0x61707865, 0x3320646e, 0x79622d32, 0x6b206574];
@@ -0,0 +1,228 @@
+use crate::error::Error;
+use super::chacha20_constant::{CHACHA20_KEY_BYTE_LENGTH, CHACHA20_NONCE_BYTE_LENGTH, XCHACHA20_NONCE_BYTE_LENGTH, HCHACHA20_NONCE_BYTE_LENGTH, CHACHA20_BLOCK_BYTE_LENGTH, CHACHA20_STATE_LENGTH, CHACHA20_CONSTANT};
+
+#[inline(always)]
+fn quarter_round(x: &mut [u32; CHACHA20_STATE_LENGTH], a: usize, b: usize, c: usize, d: usize) {
+    x[a] = x[a].wrapping_add(x[b]);
+    x[d] = (x[d] ^ x[a]).rotate_left(16);
+    x[c] = x[c].wrapping_add(x[d]);
+    x[b] = (x[b] ^ x[c]).rotate_left(12);
+    x[a] = x[a].wrapping_add(x[b]);
+    x[d] = (x[d] ^ x[a]).rotate_left(8);
+    x[c] = x[c].wrapping_add(x[d]);
+    x[b] = (x[b] ^ x[c]).rotate_left(7);
+}
+
+#[inline(always)]
+fn double_rounds(x: &mut [u32; CHACHA20_STATE_LENGTH]) {
+    for _ in 0..10 {
+        quarter_round(x, 0, 4, 8, 12);
+        quarter_round(x, 1, 5, 9, 13);
+        quarter_round(x, 2, 6, 10, 14);
+        quarter_round(x, 3, 7, 11, 15);
+        quarter_round(x, 0, 5, 10, 15);
+        quarter_round(x, 1, 6, 11, 12);
+        quarter_round(x, 2, 7, 8, 13);
+        quarter_round(x, 3, 4, 9, 14);
+    }
+}
+
+#[inline(always)]
+fn initial_state(key: &[u8; CHACHA20_KEY_BYTE_LENGTH]) -> [u32; CHACHA20_STATE_LENGTH] {
+    let mut state = [0; CHACHA20_STATE_LENGTH];
+    state[..4].copy_from_slice(&CHACHA20_CONSTANT);
+    for i in 0..8 {
+        state[4 + i] = u32::from_le_bytes(key[(i * 4)..(i * 4 + 4)].try_into().unwrap());
+    }
+    state
+}
+
+pub fn hchacha20(key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; HCHACHA20_NONCE_BYTE_LENGTH]) -> [u8; CHACHA20_KEY_BYTE_LENGTH] {
+    let mut state = initial_state(&key);
+    for i in 0..4 {
+        state[12 + i] = u32::from_le_bytes(nonce[(i * 4)..(i * 4 + 4)].try_into().unwrap());
+    }
+    double_rounds(&mut state);
+    let mut subkey = [0; CHACHA20_KEY_BYTE_LENGTH];
+    for i in 0..4 {
+        subkey[(i * 4)..(i * 4 + 4)].copy_from_slice(&state[i].to_le_bytes());
+        subkey[(16 + i * 4)..(16 + i * 4 + 4)].copy_from_slice(&state[12 + i].to_le_bytes());
+    }
+    subkey
+}
+
+pub struct ChaCha20Encryption {
+    state: [u32; CHACHA20_STATE_LENGTH],
+    initial_counter: u32,
+    position: u64,
+    key_stream: [u8; CHACHA20_BLOCK_BYTE_LENGTH],
+    key_stream_block_index: Option<u64>,
+}
+
+impl ChaCha20Encryption {
+    pub fn new(key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; CHACHA20_NONCE_BYTE_LENGTH], counter: u32) -> ChaCha20Encryption {
+        let mut state = initial_state(&key);
+        for i in 0..3 {
+            state[13 + i] = u32::from_le_bytes(nonce[(i * 4)..(i * 4 + 4)].try_into().unwrap());
+        }
+        ChaCha20Encryption {
+            state,
+            initial_counter: counter,
+            position: 0,
+            key_stream: [0; CHACHA20_BLOCK_BYTE_LENGTH],
+            key_stream_block_index: None,
+        }
+    }
+
+    pub fn try_new(key: &[u8], nonce: &[u8], counter: u32) -> Result<ChaCha20Encryption, Error> {
+        let key: [u8; CHACHA20_KEY_BYTE_LENGTH] = key.try_into().map_err(|_| Error::InvalidKeyLength)?;
+        let nonce: [u8; CHACHA20_NONCE_BYTE_LENGTH] = nonce.try_into().map_err(|_| Error::InvalidNonceLength)?;
+        Ok(ChaCha20Encryption::new(key, nonce, counter))
+    }
+
+    fn generate_key_stream(&mut self, block_index: u64) {
+        let mut state = self.state;
+        state[12] = self.initial_counter + block_index as u32;
+        let mut working_state = state;
+        double_rounds(&mut working_state);
+        for i in 0..CHACHA20_STATE_LENGTH {
+            self.key_stream[(i * 4)..(i * 4 + 4)].copy_from_slice(&working_state[i].wrapping_add(state[i]).to_le_bytes());
+        }
+        self.key_stream_block_index = Some(block_index);
+    }
+
+    pub fn seek(&mut self, position: u64) {
+        self.position = position;
+    }
+
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    fn check_key_stream_length(&self, data_length: usize) -> Result<(), Error> {
+        if data_length == 0 {
+            return Ok(());
+        }
+        let end_position = self.position.checked_add(data_length as u64).ok_or(Error::InvalidDataLength)?;
+        let last_block_index = (end_position - 1) / CHACHA20_BLOCK_BYTE_LENGTH as u64;
+        if last_block_index > (u32::MAX - self.initial_counter) as u64 {
+            return Err(Error::InvalidDataLength);
+        }
+        Ok(())
+    }
+
+    pub fn apply_key_stream(&mut self, data: &mut [u8]) -> Result<(), Error> {
+        self.check_key_stream_length(data.len())?;
+        for byte in data.iter_mut() {
+            let block_index = self.position / CHACHA20_BLOCK_BYTE_LENGTH as u64;
+            if self.key_stream_block_index != Some(block_index) {
+                self.generate_key_stream(block_index);
+            }
+            *byte ^= self.key_stream[(self.position % CHACHA20_BLOCK_BYTE_LENGTH as u64) as usize];
+            self.position += 1;
+        }
+        Ok(())
+    }
+
+    pub fn apply_key_stream_into(&mut self, input_data: &[u8], output_data: &mut [u8]) -> Result<(), Error> {
+        if input_data.len() != output_data.len() {
+            return Err(Error::InvalidDataLength);
+        }
+        output_data.copy_from_slice(input_data);
+        self.apply_key_stream(output_data)
+    }
+
+    pub fn encrypt_data(&mut self, origin_data: &[u8]) -> Result<Vec<u8>, Error> {
+        let mut enciphered_data = origin_data.to_vec();
+        self.apply_key_stream(&mut enciphered_data)?;
+        Ok(enciphered_data)
+    }
+
+    pub fn decrypt_data(&mut self, enciphered_data: &[u8]) -> Result<Vec<u8>, Error> {
+        self.encrypt_data(enciphered_data)
+    }
+}
+
+pub struct XChaCha20Encryption {
+    chacha20: ChaCha20Encryption,
+}
+
+impl XChaCha20Encryption {
+    pub fn new(key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; XCHACHA20_NONCE_BYTE_LENGTH], counter: u32) -> XChaCha20Encryption {
+        XChaCha20Encryption {
+            chacha20: xchacha20_inner_encryption(key, nonce, counter),
+        }
+    }
+
+    pub fn try_new(key: &[u8], nonce: &[u8], counter: u32) -> Result<XChaCha20Encryption, Error> {
+        let key: [u8; CHACHA20_KEY_BYTE_LENGTH] = key.try_into().map_err(|_| Error::InvalidKeyLength)?;
+        let nonce: [u8; XCHACHA20_NONCE_BYTE_LENGTH] = nonce.try_into().map_err(|_| Error::InvalidNonceLength)?;
+        Ok(XChaCha20Encryption::new(key, nonce, counter))
+    }
+
+    pub fn seek(&mut self, position: u64) {
+        self.chacha20.seek(position)
+    }
+
+    pub fn position(&self) -> u64 {
+        self.chacha20.position()
+    }
+
+    pub fn apply_key_stream(&mut self, data: &mut [u8]) -> Result<(), Error> {
+        self.chacha20.apply_key_stream(data)
+    }
+
+    pub fn apply_key_stream_into(&mut self, input_data: &[u8], output_data: &mut [u8]) -> Result<(), Error> {
+        self.chacha20.apply_key_stream_into(input_data, output_data)
+    }
+
+    pub fn encrypt_data(&mut self, origin_data: &[u8]) -> Result<Vec<u8>, Error> {
+        self.chacha20.encrypt_data(origin_data)
+    }
+
+    pub fn decrypt_data(&mut self, enciphered_data: &[u8]) -> Result<Vec<u8>, Error> {
+        self.chacha20.decrypt_data(enciphered_data)
+    }
+}
+
+pub(crate) fn xchacha20_inner_encryption(key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; XCHACHA20_NONCE_BYTE_LENGTH], counter: u32) -> ChaCha20Encryption {
+    let subkey = hchacha20(key, nonce[..HCHACHA20_NONCE_BYTE_LENGTH].try_into().unwrap());
+    let mut inner_nonce = [0; CHACHA20_NONCE_BYTE_LENGTH];
+    inner_nonce[4..].copy_from_slice(&nonce[HCHACHA20_NONCE_BYTE_LENGTH..]);
+    ChaCha20Encryption::new(subkey, inner_nonce, counter)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use super::ChaCha20Encryption;
+
+    const KEY: [u8; 32] = [0; 32];
+    const NONCE: [u8; 12] = [0; 12];
+
+    #[test]
+    fn last_counter_block_is_usable() {
+        let mut chacha20 = ChaCha20Encryption::new(KEY, NONCE, u32::MAX - 1);
+        assert_eq!(chacha20.encrypt_data(&[0; 128]).unwrap().len(), 128);
+        let mut chacha20 = ChaCha20Encryption::new(KEY, NONCE, u32::MAX);
+        assert_eq!(chacha20.encrypt_data(&[0; 64]).unwrap().len(), 64);
+    }
+
+    #[test]
+    fn counter_overflow_is_rejected() {
+        let mut chacha20 = ChaCha20Encryption::new(KEY, NONCE, u32::MAX);
+        let mut data = [0x5a; 65];
+        assert!(matches!(chacha20.apply_key_stream(&mut data), Err(Error::InvalidDataLength)));
+        assert_eq!(data, [0x5a; 65]);
+        assert_eq!(chacha20.position(), 0);
+
+        let mut chacha20 = ChaCha20Encryption::new(KEY, NONCE, 1);
+        chacha20.seek((u32::MAX as u64) * 64 - 1);
+        assert!(chacha20.encrypt_data(&[0; 1]).is_ok());
+        assert!(matches!(chacha20.encrypt_data(&[0; 1]), Err(Error::InvalidDataLength)));
+
+        let mut chacha20 = ChaCha20Encryption::new(KEY, NONCE, 0);
+        chacha20.seek(u64::MAX);
+        assert!(matches!(chacha20.encrypt_data(&[0; 1]), Err(Error::InvalidDataLength)));
+    }
+}
@@ -0,0 +1,482 @@
+use crate::error::Error;
+use crate::util::constant_time::constant_time_eq;
+use crate::algorithm::mac::poly1305::poly1305_constant::{POLY1305_KEY_BYTE_LENGTH, POLY1305_BLOCK_BYTE_LENGTH};
+use crate::algorithm::mac::poly1305::poly1305_digest::Poly1305Digest;
+use super::super::block_mode::stream_trait::{StreamEncryptor, StreamDecryptor};
+use super::chacha20_constant::{CHACHA20_KEY_BYTE_LENGTH, CHACHA20_NONCE_BYTE_LENGTH, XCHACHA20_NONCE_BYTE_LENGTH, CHACHA20_BLOCK_BYTE_LENGTH, CHACHA20_POLY1305_TAG_BYTE_LENGTH};
+use super::chacha20_encryption::{ChaCha20Encryption, xchacha20_inner_encryption};
+
+#[inline(always)]
+fn pad_poly1305(poly1305: &mut Poly1305Digest, data_length: u64) {
+    let remains_data_length = (data_length % POLY1305_BLOCK_BYTE_LENGTH as u64) as usize;
+    if remains_data_length != 0 {
+        poly1305.push_data(&[0; POLY1305_BLOCK_BYTE_LENGTH][remains_data_length..]);
+    }
+}
+
+struct ChaCha20Poly1305State {
+    chacha20: ChaCha20Encryption,
+    poly1305: Poly1305Digest,
+    aad_length: u64,
+    data_length: u64,
+    data_pushed: bool,
+}
+
+impl ChaCha20Poly1305State {
+    fn new(mut chacha20: ChaCha20Encryption) -> ChaCha20Poly1305State {
+        let mut poly1305_key = [0; POLY1305_KEY_BYTE_LENGTH];
+        chacha20.apply_key_stream(&mut poly1305_key).unwrap();
+        chacha20.seek(CHACHA20_BLOCK_BYTE_LENGTH as u64);
+        ChaCha20Poly1305State {
+            chacha20,
+            poly1305: Poly1305Digest::new(poly1305_key),
+            aad_length: 0,
+            data_length: 0,
+            data_pushed: false,
+        }
+    }
+
+    fn push_aad(&mut self, aad: &[u8]) -> Result<(), Error> {
+        if self.data_pushed {
+            return Err(Error::InvalidState);
+        }
+        self.aad_length += aad.len() as u64;
+        self.poly1305.push_data(aad);
+        Ok(())
+    }
+
+    fn push_enciphered_data(&mut self, enciphered_data: &[u8]) {
+        if !self.data_pushed {
+            pad_poly1305(&mut self.poly1305, self.aad_length);
+            self.data_pushed = true;
+        }
+        self.data_length += enciphered_data.len() as u64;
+        self.poly1305.push_data(enciphered_data);
+    }
+
+    fn apply_key_stream(&mut self, data: &mut [u8]) -> Result<(), Error> {
+        self.chacha20.apply_key_stream(data)
+    }
+
+    fn compute_tag(&mut self) -> [u8; CHACHA20_POLY1305_TAG_BYTE_LENGTH] {
+        if !self.data_pushed {
+            pad_poly1305(&mut self.poly1305, self.aad_length);
+            self.data_pushed = true;
+        }
+        pad_poly1305(&mut self.poly1305, self.data_length);
+        self.poly1305.push_data(&self.aad_length.to_le_bytes());
+        self.poly1305.push_data(&self.data_length.to_le_bytes());
+        self.poly1305.compute_digest();
+        self.poly1305.get_digest_bytes()
+    }
+}
+
+pub struct ChaCha20Poly1305Encryptor {
+    state: ChaCha20Poly1305State,
+    key_stream_exhausted: bool,
+}
+
+impl ChaCha20Poly1305Encryptor {
+    pub fn new(key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; CHACHA20_NONCE_BYTE_LENGTH]) -> ChaCha20Poly1305Encryptor {
+        ChaCha20Poly1305Encryptor {
+            state: ChaCha20Poly1305State::new(ChaCha20Encryption::new(key, nonce, 0)),
+            key_stream_exhausted: false,
+        }
+    }
+
+    pub fn try_new(key: &[u8], nonce: &[u8]) -> Result<ChaCha20Poly1305Encryptor, Error> {
+        let instance = ChaCha20Poly1305Encryptor {
+            state: ChaCha20Poly1305State::new(ChaCha20Encryption::try_new(key, nonce, 0)?),
+            key_stream_exhausted: false,
+        };
+        Ok(instance)
+    }
+
+    pub fn push_aad(&mut self, aad: &[u8]) -> Result<(), Error> {
+        self.state.push_aad(aad)
+    }
+
+    pub fn encrypt_data(&mut self, origin_data: &[u8]) -> Result<Vec<u8>, Error> {
+        let mut enciphered_data = origin_data.to_vec();
+        if let Err(error) = self.state.apply_key_stream(&mut enciphered_data) {
+            self.key_stream_exhausted = true;
+            return Err(error);
+        }
+        self.state.push_enciphered_data(&enciphered_data);
+        Ok(enciphered_data)
+    }
+
+    pub fn compute_tag(mut self) -> Vec<u8> {
+        self.state.compute_tag().to_vec()
+    }
+}
+
+pub struct ChaCha20Poly1305Decryptor {
+    state: ChaCha20Poly1305State,
+    enciphered_data: Vec<u8>,
+    tag_data: Vec<u8>,
+}
+
+impl ChaCha20Poly1305Decryptor {
+    pub fn new(key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; CHACHA20_NONCE_BYTE_LENGTH]) -> ChaCha20Poly1305Decryptor {
+        ChaCha20Poly1305Decryptor {
+            state: ChaCha20Poly1305State::new(ChaCha20Encryption::new(key, nonce, 0)),
+            enciphered_data: Vec::new(),
+            tag_data: Vec::new(),
+        }
+    }
+
+    pub fn try_new(key: &[u8], nonce: &[u8]) -> Result<ChaCha20Poly1305Decryptor, Error> {
+        let instance = ChaCha20Poly1305Decryptor {
+            state: ChaCha20Poly1305State::new(ChaCha20Encryption::try_new(key, nonce, 0)?),
+            enciphered_data: Vec::new(),
+            tag_data: Vec::new(),
+        };
+        Ok(instance)
+    }
+
+    pub fn push_aad(&mut self, aad: &[u8]) -> Result<(), Error> {
+        self.state.push_aad(aad)
+    }
+
+    pub fn push_data(&mut self, enciphered_data: &[u8]) {
+        self.state.push_enciphered_data(enciphered_data);
+        self.enciphered_data.extend_from_slice(enciphered_data);
+    }
+
+    pub fn decrypt_data(mut self, tag: &[u8]) -> Result<Vec<u8>, Error> {
+        if !constant_time_eq(&self.state.compute_tag(), tag) {
+            return Err(Error::AuthenticationFailed);
+        }
+        let mut origin_data = self.enciphered_data;
+        self.state.apply_key_stream(&mut origin_data)?;
+        Ok(origin_data)
+    }
+}
+
+pub struct XChaCha20Poly1305Encryptor {
+    encryptor: ChaCha20Poly1305Encryptor,
+}
+
+impl XChaCha20Poly1305Encryptor {
+    pub fn new(key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; XCHACHA20_NONCE_BYTE_LENGTH]) -> XChaCha20Poly1305Encryptor {
+        XChaCha20Poly1305Encryptor {
+            encryptor: ChaCha20Poly1305Encryptor {
+                state: ChaCha20Poly1305State::new(xchacha20_inner_encryption(key, nonce, 0)),
+                key_stream_exhausted: false,
+            },
+        }
+    }
+
+    pub fn try_new(key: &[u8], nonce: &[u8]) -> Result<XChaCha20Poly1305Encryptor, Error> {
+        let key: [u8; CHACHA20_KEY_BYTE_LENGTH] = key.try_into().map_err(|_| Error::InvalidKeyLength)?;
+        let nonce: [u8; XCHACHA20_NONCE_BYTE_LENGTH] = nonce.try_into().map_err(|_| Error::InvalidNonceLength)?;
+        Ok(XChaCha20Poly1305Encryptor::new(key, nonce))
+    }
+
+    pub fn push_aad(&mut self, aad: &[u8]) -> Result<(), Error> {
+        self.encryptor.push_aad(aad)
+    }
+
+    pub fn encrypt_data(&mut self, origin_data: &[u8]) -> Result<Vec<u8>, Error> {
+        self.encryptor.encrypt_data(origin_data)
+    }
+
+    pub fn compute_tag(self) -> Vec<u8> {
+        self.encryptor.compute_tag()
+    }
+}
+
+pub struct XChaCha20Poly1305Decryptor {
+    decryptor: ChaCha20Poly1305Decryptor,
+}
+
+impl XChaCha20Poly1305Decryptor {
+    pub fn new(key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; XCHACHA20_NONCE_BYTE_LENGTH]) -> XChaCha20Poly1305Decryptor {
+        XChaCha20Poly1305Decryptor {
+            decryptor: ChaCha20Poly1305Decryptor {
+                state: ChaCha20Poly1305State::new(xchacha20_inner_encryption(key, nonce, 0)),
+                enciphered_data: Vec::new(),
+                tag_data: Vec::new(),
+            },
+        }
+    }
+
+    pub fn try_new(key: &[u8], nonce: &[u8]) -> Result<XChaCha20Poly1305Decryptor, Error> {
+        let key: [u8; CHACHA20_KEY_BYTE_LENGTH] = key.try_into().map_err(|_| Error::InvalidKeyLength)?;
+        let nonce: [u8; XCHACHA20_NONCE_BYTE_LENGTH] = nonce.try_into().map_err(|_| Error::InvalidNonceLength)?;
+        Ok(XChaCha20Poly1305Decryptor::new(key, nonce))
+    }
+
+    pub fn push_aad(&mut self, aad: &[u8]) -> Result<(), Error> {
+        self.decryptor.push_aad(aad)
+    }
+
+    pub fn push_data(&mut self, enciphered_data: &[u8]) {
+        self.decryptor.push_data(enciphered_data)
+    }
+
+    pub fn decrypt_data(self, tag: &[u8]) -> Result<Vec<u8>, Error> {
+        self.decryptor.decrypt_data(tag)
+    }
+}
+
+impl StreamEncryptor for ChaCha20Poly1305Encryptor {
+    fn update(&mut self, origin_data: &[u8]) -> Vec<u8> {
+        self.encrypt_data(origin_data).unwrap_or_default()
+    }
+
+    fn finalize(self) -> Result<Vec<u8>, Error> {
+        if self.key_stream_exhausted {
+            return Err(Error::InvalidDataLength);
+        }
+        Ok(self.compute_tag())
+    }
+}
+
+impl StreamDecryptor for ChaCha20Poly1305Decryptor {
+    fn update(&mut self, enciphered_data: &[u8]) -> Vec<u8> {
+        self.tag_data.extend_from_slice(enciphered_data);
+        let data_length = self.tag_data.len().saturating_sub(CHACHA20_POLY1305_TAG_BYTE_LENGTH);
+        let data: Vec<u8> = self.tag_data.drain(..data_length).collect();
+        self.push_data(&data);
+        Vec::new()
+    }
+
+    fn finalize(mut self) -> Result<Vec<u8>, Error> {
+        let tag = std::mem::take(&mut self.tag_data);
+        if tag.len() != CHACHA20_POLY1305_TAG_BYTE_LENGTH {
+            return Err(Error::InvalidDataLength);
+        }
+        self.decrypt_data(&tag)
+    }
+}
+
+impl StreamEncryptor for XChaCha20Poly1305Encryptor {
+    fn update(&mut self, origin_data: &[u8]) -> Vec<u8> {
+        StreamEncryptor::update(&mut self.encryptor, origin_data)
+    }
+
+    fn finalize(self) -> Result<Vec<u8>, Error> {
+        StreamEncryptor::finalize(self.encryptor)
+    }
+}
+
+impl StreamDecryptor for XChaCha20Poly1305Decryptor {
+    fn update(&mut self, enciphered_data: &[u8]) -> Vec<u8> {
+        StreamDecryptor::update(&mut self.decryptor, enciphered_data)
+    }
+
+    fn finalize(self) -> Result<Vec<u8>, Error> {
+        StreamDecryptor::finalize(self.decryptor)
+    }
+}
+
+fn encrypt_data_in_place(mut state: ChaCha20Poly1305State, data: &mut [u8], aad: &[u8], tag: &mut [u8]) -> Result<(), Error> {
+    if tag.len() != CHACHA20_POLY1305_TAG_BYTE_LENGTH {
+        return Err(Error::InvalidTagLength);
+    }
+    state.push_aad(aad)?;
+    state.apply_key_stream(data)?;
+    state.push_enciphered_data(data);
+    tag.copy_from_slice(&state.compute_tag());
+    Ok(())
+}
+
+fn decrypt_data_in_place(mut state: ChaCha20Poly1305State, data: &mut [u8], aad: &[u8], tag: &[u8]) -> Result<(), Error> {
+    if tag.len() != CHACHA20_POLY1305_TAG_BYTE_LENGTH {
+        return Err(Error::InvalidTagLength);
+    }
+    state.push_aad(aad)?;
+    state.push_enciphered_data(data);
+    if !constant_time_eq(&state.compute_tag(), tag) {
+        return Err(Error::AuthenticationFailed);
+    }
+    state.apply_key_stream(data)
+}
+
+pub fn chacha20_poly1305_encrypt_data(origin_data: &[u8], key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; CHACHA20_NONCE_BYTE_LENGTH], aad: &[u8]) -> Result<(Vec<u8>, Vec<u8>), Error> {
+    let mut encryptor = ChaCha20Poly1305Encryptor::new(key, nonce);
+    encryptor.push_aad(aad)?;
+    let enciphered_data = encryptor.encrypt_data(origin_data)?;
+    Ok((enciphered_data, encryptor.compute_tag()))
+}
+
+pub fn chacha20_poly1305_decrypt_data(enciphered_data: &[u8], key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; CHACHA20_NONCE_BYTE_LENGTH], aad: &[u8], tag: &[u8]) -> Result<Vec<u8>, Error> {
+    if tag.len() != CHACHA20_POLY1305_TAG_BYTE_LENGTH {
+        return Err(Error::InvalidTagLength);
+    }
+    let mut decryptor = ChaCha20Poly1305Decryptor::new(key, nonce);
+    decryptor.push_aad(aad)?;
+    decryptor.push_data(enciphered_data);
+    decryptor.decrypt_data(tag)
+}
+
+pub fn chacha20_poly1305_encrypt_data_in_place(data: &mut [u8], key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; CHACHA20_NONCE_BYTE_LENGTH], aad: &[u8], tag: &mut [u8]) -> Result<(), Error> {
+    encrypt_data_in_place(ChaCha20Poly1305State::new(ChaCha20Encryption::new(key, nonce, 0)), data, aad, tag)
+}
+
+pub fn chacha20_poly1305_decrypt_data_in_place(data: &mut [u8], key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; CHACHA20_NONCE_BYTE_LENGTH], aad: &[u8], tag: &[u8]) -> Result<(), Error> {
+    decrypt_data_in_place(ChaCha20Poly1305State::new(ChaCha20Encryption::new(key, nonce, 0)), data, aad, tag)
+}
+
+pub fn chacha20_poly1305_encrypt_data_into(origin_data: &[u8], enciphered_data: &mut [u8], key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; CHACHA20_NONCE_BYTE_LENGTH], aad: &[u8], tag: &mut [u8]) -> Result<(), Error> {
+    if origin_data.len() != enciphered_data.len() {
+        return Err(Error::InvalidDataLength);
+    }
+    enciphered_data.copy_from_slice(origin_data);
+    chacha20_poly1305_encrypt_data_in_place(enciphered_data, key, nonce, aad, tag)
+}
+
+pub fn chacha20_poly1305_decrypt_data_into(enciphered_data: &[u8], origin_data: &mut [u8], key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; CHACHA20_NONCE_BYTE_LENGTH], aad: &[u8], tag: &[u8]) -> Result<(), Error> {
+    if enciphered_data.len() != origin_data.len() {
+        return Err(Error::InvalidDataLength);
+    }
+    origin_data.copy_from_slice(enciphered_data);
+    chacha20_poly1305_decrypt_data_in_place(origin_data, key, nonce, aad, tag)
+}
+
+pub fn xchacha20_poly1305_encrypt_data(origin_data: &[u8], key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; XCHACHA20_NONCE_BYTE_LENGTH], aad: &[u8]) -> Result<(Vec<u8>, Vec<u8>), Error> {
+    let mut encryptor = XChaCha20Poly1305Encryptor::new(key, nonce);
+    encryptor.push_aad(aad)?;
+    let enciphered_data = encryptor.encrypt_data(origin_data)?;
+    Ok((enciphered_data, encryptor.compute_tag()))
+}
+
+pub fn xchacha20_poly1305_decrypt_data(enciphered_data: &[u8], key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; XCHACHA20_NONCE_BYTE_LENGTH], aad: &[u8], tag: &[u8]) -> Result<Vec<u8>, Error> {
+    if tag.len() != CHACHA20_POLY1305_TAG_BYTE_LENGTH {
+        return Err(Error::InvalidTagLength);
+    }
+    let mut decryptor = XChaCha20Poly1305Decryptor::new(key, nonce);
+    decryptor.push_aad(aad)?;
+    decryptor.push_data(enciphered_data);
+    decryptor.decrypt_data(tag)
+}
+
+pub fn xchacha20_poly1305_encrypt_data_in_place(data: &mut [u8], key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; XCHACHA20_NONCE_BYTE_LENGTH], aad: &[u8], tag: &mut [u8]) -> Result<(), Error> {
+    encrypt_data_in_place(ChaCha20Poly1305State::new(xchacha20_inner_encryption(key, nonce, 0)), data, aad, tag)
+}
+
+pub fn xchacha20_poly1305_decrypt_data_in_place(data: &mut [u8], key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; XCHACHA20_NONCE_BYTE_LENGTH], aad: &[u8], tag: &[u8]) -> Result<(), Error> {
+    decrypt_data_in_place(ChaCha20Poly1305State::new(xchacha20_inner_encryption(key, nonce, 0)), data, aad, tag)
+}
+
+pub fn xchacha20_poly1305_encrypt_data_into(origin_data: &[u8], enciphered_data: &mut [u8], key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; XCHACHA20_NONCE_BYTE_LENGTH], aad: &[u8], tag: &mut [u8]) -> Result<(), Error> {
+    if origin_data.len() != enciphered_data.len() {
+        return Err(Error::InvalidDataLength);
+    }
+    enciphered_data.copy_from_slice(origin_data);
+    xchacha20_poly1305_encrypt_data_in_place(enciphered_data, key, nonce, aad, tag)
+}
+
+pub fn xchacha20_poly1305_decrypt_data_into(enciphered_data: &[u8], origin_data: &mut [u8], key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; XCHACHA20_NONCE_BYTE_LENGTH], aad: &[u8], tag: &[u8]) -> Result<(), Error> {
+    if enciphered_data.len() != origin_data.len() {
+        return Err(Error::InvalidDataLength);
+    }
+    origin_data.copy_from_slice(enciphered_data);
+    xchacha20_poly1305_decrypt_data_in_place(origin_data, key, nonce, aad, tag)
+}
+
+pub fn try_chacha20_poly1305_encrypt_data(origin_data: &[u8], key: &[u8], nonce: &[u8], aad: &[u8]) -> Result<(Vec<u8>, Vec<u8>), Error> {
+    let mut encryptor = ChaCha20Poly1305Encryptor::try_new(key, nonce)?;
+    encryptor.push_aad(aad)?;
+    let enciphered_data = encryptor.encrypt_data(origin_data)?;
+    Ok((enciphered_data, encryptor.compute_tag()))
+}
+
+pub fn try_chacha20_poly1305_decrypt_data(enciphered_data: &[u8], key: &[u8], nonce: &[u8], aad: &[u8], tag: &[u8]) -> Result<Vec<u8>, Error> {
+    let key: [u8; CHACHA20_KEY_BYTE_LENGTH] = key.try_into().map_err(|_| Error::InvalidKeyLength)?;
+    let nonce: [u8; CHACHA20_NONCE_BYTE_LENGTH] = nonce.try_into().map_err(|_| Error::InvalidNonceLength)?;
+    chacha20_poly1305_decrypt_data(enciphered_data, key, nonce, aad, tag)
+}
+
+pub fn try_xchacha20_poly1305_encrypt_data(origin_data: &[u8], key: &[u8], nonce: &[u8], aad: &[u8]) -> Result<(Vec<u8>, Vec<u8>), Error> {
+    let mut encryptor = XChaCha20Poly1305Encryptor::try_new(key, nonce)?;
+    encryptor.push_aad(aad)?;
+    let enciphered_data = encryptor.encrypt_data(origin_data)?;
+    Ok((enciphered_data, encryptor.compute_tag()))
+}
+
+pub fn try_xchacha20_poly1305_decrypt_data(enciphered_data: &[u8], key: &[u8], nonce: &[u8], aad: &[u8], tag: &[u8]) -> Result<Vec<u8>, Error> {
+    let key: [u8; CHACHA20_KEY_BYTE_LENGTH] = key.try_into().map_err(|_| Error::InvalidKeyLength)?;
+    let nonce: [u8; XCHACHA20_NONCE_BYTE_LENGTH] = nonce.try_into().map_err(|_| Error::InvalidNonceLength)?;
+    xchacha20_poly1305_decrypt_data(enciphered_data, key, nonce, aad, tag)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::util::hex::{hex_decode, hex_encode};
+    use super::super::super::block_mode::stream_trait::StreamDecryptor;
+    use super::{ChaCha20Poly1305Decryptor, ChaCha20Poly1305Encryptor, chacha20_poly1305_decrypt_data, chacha20_poly1305_decrypt_data_in_place, chacha20_poly1305_encrypt_data};
+
+    const NONCE: [u8; 12] = [0x07, 0x00, 0x00, 0x00, 0x40, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47];
+    const AAD: &str = "50515253c0c1c2c3c4c5c6c7";
+    const ORIGIN_DATA: &str = concat!(
+        "4c616469657320616e642047656e746c656d656e206f662074686520636c6173",
+        "73206f66202739393a204966204920636f756c64206f6666657220796f75206f",
+        "6e6c79206f6e652074697020666f7220746865206675747572652c2073756e73",
+        "637265656e20776f756c642062652069742e",
+    );
+    const ENCIPHERED_DATA: &str = concat!(
+        "d31a8d34648e60db7b86afbc53ef7ec2a4aded51296e08fea9e2b5a736ee62d6",
+        "3dbea45e8ca9671282fafb69da92728b1a71de0a9e060b2905d6a5b67ecd3b36",
+        "92ddbd7f2d778b8c9803aee328091b58fab324e4fad675945585808b4831d7bc",
+        "3ff4def08e4b7a9de576d26586cec64b6116",
+    );
+    const TAG: &str = "1ae10b594f09e26a7e902ecbd0600691";
+
+    fn key() -> [u8; 32] {
+        core::array::from_fn(|i| 0x80 + i as u8)
+    }
+
+    fn hex(data: &str) -> Vec<u8> {
+        hex_decode(data).unwrap()
+    }
+
+    #[test]
+    fn rfc_8439_encrypt() {
+        let (enciphered_data, tag) = chacha20_poly1305_encrypt_data(&hex(ORIGIN_DATA), key(), NONCE, &hex(AAD)).unwrap();
+        assert_eq!(hex_encode(&enciphered_data), ENCIPHERED_DATA);
+        assert_eq!(hex_encode(&tag), TAG);
+    }
+
+    #[test]
+    fn rfc_8439_decrypt() {
+        let origin_data = chacha20_poly1305_decrypt_data(&hex(ENCIPHERED_DATA), key(), NONCE, &hex(AAD), &hex(TAG)).unwrap();
+        assert_eq!(origin_data, hex(ORIGIN_DATA));
+    }
+
+    #[test]
+    fn rfc_8439_streaming_decrypt() {
+        let mut decryptor = ChaCha20Poly1305Decryptor::new(key(), NONCE);
+        decryptor.push_aad(&hex(AAD)).unwrap();
+        let mut data = hex(ENCIPHERED_DATA);
+        data.extend_from_slice(&hex(TAG));
+        for piece in data.chunks(9) {
+            assert!(StreamDecryptor::update(&mut decryptor, piece).is_empty());
+        }
+        assert_eq!(StreamDecryptor::finalize(decryptor).unwrap(), hex(ORIGIN_DATA));
+    }
+
+    #[test]
+    fn tag_mismatch_is_rejected() {
+        let mut tag = hex(TAG);
+        tag[0] ^= 1;
+        assert!(matches!(chacha20_poly1305_decrypt_data(&hex(ENCIPHERED_DATA), key(), NONCE, &hex(AAD), &tag), Err(Error::AuthenticationFailed)));
+        let mut data = hex(ENCIPHERED_DATA);
+        assert!(matches!(chacha20_poly1305_decrypt_data_in_place(&mut data, key(), NONCE, &hex(AAD)[1..], &hex(TAG)), Err(Error::AuthenticationFailed)));
+        assert_eq!(data, hex(ENCIPHERED_DATA));
+    }
+
+    #[test]
+    fn aad_after_data_is_rejected() {
+        let mut encryptor = ChaCha20Poly1305Encryptor::new(key(), NONCE);
+        encryptor.push_aad(&hex(AAD)).unwrap();
+        encryptor.encrypt_data(&hex(ORIGIN_DATA)).unwrap();
+        assert!(matches!(encryptor.push_aad(&hex(AAD)), Err(Error::InvalidState)));
+        let mut decryptor = ChaCha20Poly1305Decryptor::new(key(), NONCE);
+        decryptor.push_data(&hex(ENCIPHERED_DATA));
+        assert!(matches!(decryptor.push_aad(&hex(AAD)), Err(Error::InvalidState)));
+    }
+}
@@ -0,0 +1,270 @@
+use crate::error::Error;
+use super::super::block_mode::stream_trait::{StreamEncryptor, StreamDecryptor};
+use super::chacha20_constant::{CHACHA20_KEY_BYTE_LENGTH, CHACHA20_NONCE_BYTE_LENGTH, XCHACHA20_NONCE_BYTE_LENGTH};
+use super::chacha20_encryption::{ChaCha20Encryption, XChaCha20Encryption};
+
+pub struct ChaCha20Encryptor {
+    chacha20: ChaCha20Encryption,
+    key_stream_exhausted: bool,
+}
+
+impl ChaCha20Encryptor {
+    pub fn new(key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; CHACHA20_NONCE_BYTE_LENGTH], counter: u32) -> ChaCha20Encryptor {
+        ChaCha20Encryptor {
+            chacha20: ChaCha20Encryption::new(key, nonce, counter),
+            key_stream_exhausted: false,
+        }
+    }
+
+    pub fn update(&mut self, origin_data: &[u8]) -> Result<Vec<u8>, Error> {
+        let result = self.chacha20.encrypt_data(origin_data);
+        self.key_stream_exhausted |= result.is_err();
+        result
+    }
+
+    pub fn finalize(self) -> Result<Vec<u8>, Error> {
+        if self.key_stream_exhausted {
+            return Err(Error::InvalidDataLength);
+        }
+        Ok(Vec::new())
+    }
+}
+
+impl StreamEncryptor for ChaCha20Encryptor {
+    fn update(&mut self, origin_data: &[u8]) -> Vec<u8> {
+        ChaCha20Encryptor::update(self, origin_data).unwrap_or_default()
+    }
+
+    fn finalize(self) -> Result<Vec<u8>, Error> {
+        ChaCha20Encryptor::finalize(self)
+    }
+}
+
+pub struct ChaCha20Decryptor {
+    chacha20: ChaCha20Encryption,
+    key_stream_exhausted: bool,
+}
+
+impl ChaCha20Decryptor {
+    pub fn new(key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; CHACHA20_NONCE_BYTE_LENGTH], counter: u32) -> ChaCha20Decryptor {
+        ChaCha20Decryptor {
+            chacha20: ChaCha20Encryption::new(key, nonce, counter),
+            key_stream_exhausted: false,
+        }
+    }
+
+    pub fn update(&mut self, enciphered_data: &[u8]) -> Result<Vec<u8>, Error> {
+        let result = self.chacha20.decrypt_data(enciphered_data);
+        self.key_stream_exhausted |= result.is_err();
+        result
+    }
+
+    pub fn finalize(self) -> Result<Vec<u8>, Error> {
+        if self.key_stream_exhausted {
+            return Err(Error::InvalidDataLength);
+        }
+        Ok(Vec::new())
+    }
+}
+
+impl StreamDecryptor for ChaCha20Decryptor {
+    fn update(&mut self, enciphered_data: &[u8]) -> Vec<u8> {
+        ChaCha20Decryptor::update(self, enciphered_data).unwrap_or_default()
+    }
+
+    fn finalize(self) -> Result<Vec<u8>, Error> {
+        ChaCha20Decryptor::finalize(self)
+    }
+}
+
+pub struct XChaCha20Encryptor {
+    xchacha20: XChaCha20Encryption,
+    key_stream_exhausted: bool,
+}
+
+impl XChaCha20Encryptor {
+    pub fn new(key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; XCHACHA20_NONCE_BYTE_LENGTH], counter: u32) -> XChaCha20Encryptor {
+        XChaCha20Encryptor {
+            xchacha20: XChaCha20Encryption::new(key, nonce, counter),
+            key_stream_exhausted: false,
+        }
+    }
+
+    pub fn update(&mut self, origin_data: &[u8]) -> Result<Vec<u8>, Error> {
+        let result = self.xchacha20.encrypt_data(origin_data);
+        self.key_stream_exhausted |= result.is_err();
+        result
+    }
+
+    pub fn finalize(self) -> Result<Vec<u8>, Error> {
+        if self.key_stream_exhausted {
+            return Err(Error::InvalidDataLength);
+        }
+        Ok(Vec::new())
+    }
+}
+
+impl StreamEncryptor for XChaCha20Encryptor {
+    fn update(&mut self, origin_data: &[u8]) -> Vec<u8> {
+        XChaCha20Encryptor::update(self, origin_data).unwrap_or_default()
+    }
+
+    fn finalize(self) -> Result<Vec<u8>, Error> {
+        XChaCha20Encryptor::finalize(self)
+    }
+}
+
+pub struct XChaCha20Decryptor {
+    xchacha20: XChaCha20Encryption,
+    key_stream_exhausted: bool,
+}
+
+impl XChaCha20Decryptor {
+    pub fn new(key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; XCHACHA20_NONCE_BYTE_LENGTH], counter: u32) -> XChaCha20Decryptor {
+        XChaCha20Decryptor {
+            xchacha20: XChaCha20Encryption::new(key, nonce, counter),
+            key_stream_exhausted: false,
+        }
+    }
+
+    pub fn update(&mut self, enciphered_data: &[u8]) -> Result<Vec<u8>, Error> {
+        let result = self.xchacha20.decrypt_data(enciphered_data);
+        self.key_stream_exhausted |= result.is_err();
+        result
+    }
+
+    pub fn finalize(self) -> Result<Vec<u8>, Error> {
+        if self.key_stream_exhausted {
+            return Err(Error::InvalidDataLength);
+        }
+        Ok(Vec::new())
+    }
+}
+
+impl StreamDecryptor for XChaCha20Decryptor {
+    fn update(&mut self, enciphered_data: &[u8]) -> Vec<u8> {
+        XChaCha20Decryptor::update(self, enciphered_data).unwrap_or_default()
+    }
+
+    fn finalize(self) -> Result<Vec<u8>, Error> {
+        XChaCha20Decryptor::finalize(self)
+    }
+}
+
+pub fn chacha20_encrypt_data(origin_data: &[u8], key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; CHACHA20_NONCE_BYTE_LENGTH], counter: u32) -> Result<Vec<u8>, Error> {
+    ChaCha20Encryption::new(key, nonce, counter).encrypt_data(origin_data)
+}
+
+pub fn chacha20_decrypt_data(enciphered_data: &[u8], key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; CHACHA20_NONCE_BYTE_LENGTH], counter: u32) -> Result<Vec<u8>, Error> {
+    ChaCha20Encryption::new(key, nonce, counter).decrypt_data(enciphered_data)
+}
+
+pub fn chacha20_encrypt_data_in_place(data: &mut [u8], key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; CHACHA20_NONCE_BYTE_LENGTH], counter: u32) -> Result<(), Error> {
+    ChaCha20Encryption::new(key, nonce, counter).apply_key_stream(data)
+}
+
+pub fn chacha20_decrypt_data_in_place(data: &mut [u8], key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; CHACHA20_NONCE_BYTE_LENGTH], counter: u32) -> Result<(), Error> {
+    ChaCha20Encryption::new(key, nonce, counter).apply_key_stream(data)
+}
+
+pub fn chacha20_encrypt_data_into(origin_data: &[u8], enciphered_data: &mut [u8], key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; CHACHA20_NONCE_BYTE_LENGTH], counter: u32) -> Result<(), Error> {
+    ChaCha20Encryption::new(key, nonce, counter).apply_key_stream_into(origin_data, enciphered_data)
+}
+
+pub fn chacha20_decrypt_data_into(enciphered_data: &[u8], origin_data: &mut [u8], key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; CHACHA20_NONCE_BYTE_LENGTH], counter: u32) -> Result<(), Error> {
+    ChaCha20Encryption::new(key, nonce, counter).apply_key_stream_into(enciphered_data, origin_data)
+}
+
+pub fn xchacha20_encrypt_data(origin_data: &[u8], key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; XCHACHA20_NONCE_BYTE_LENGTH], counter: u32) -> Result<Vec<u8>, Error> {
+    XChaCha20Encryption::new(key, nonce, counter).encrypt_data(origin_data)
+}
+
+pub fn xchacha20_decrypt_data(enciphered_data: &[u8], key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; XCHACHA20_NONCE_BYTE_LENGTH], counter: u32) -> Result<Vec<u8>, Error> {
+    XChaCha20Encryption::new(key, nonce, counter).decrypt_data(enciphered_data)
+}
+
+pub fn xchacha20_encrypt_data_in_place(data: &mut [u8], key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; XCHACHA20_NONCE_BYTE_LENGTH], counter: u32) -> Result<(), Error> {
+    XChaCha20Encryption::new(key, nonce, counter).apply_key_stream(data)
+}
+
+pub fn xchacha20_decrypt_data_in_place(data: &mut [u8], key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; XCHACHA20_NONCE_BYTE_LENGTH], counter: u32) -> Result<(), Error> {
+    XChaCha20Encryption::new(key, nonce, counter).apply_key_stream(data)
+}
+
+pub fn xchacha20_encrypt_data_into(origin_data: &[u8], enciphered_data: &mut [u8], key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; XCHACHA20_NONCE_BYTE_LENGTH], counter: u32) -> Result<(), Error> {
+    XChaCha20Encryption::new(key, nonce, counter).apply_key_stream_into(origin_data, enciphered_data)
+}
+
+pub fn xchacha20_decrypt_data_into(enciphered_data: &[u8], origin_data: &mut [u8], key: [u8; CHACHA20_KEY_BYTE_LENGTH], nonce: [u8; XCHACHA20_NONCE_BYTE_LENGTH], counter: u32) -> Result<(), Error> {
+    XChaCha20Encryption::new(key, nonce, counter).apply_key_stream_into(enciphered_data, origin_data)
+}
+
+pub fn try_chacha20_encrypt_data(origin_data: &[u8], key: &[u8], nonce: &[u8], counter: u32) -> Result<Vec<u8>, Error> {
+    ChaCha20Encryption::try_new(key, nonce, counter)?.encrypt_data(origin_data)
+}
+
+pub fn try_chacha20_decrypt_data(enciphered_data: &[u8], key: &[u8], nonce: &[u8], counter: u32) -> Result<Vec<u8>, Error> {
+    ChaCha20Encryption::try_new(key, nonce, counter)?.decrypt_data(enciphered_data)
+}
+
+pub fn try_xchacha20_encrypt_data(origin_data: &[u8], key: &[u8], nonce: &[u8], counter: u32) -> Result<Vec<u8>, Error> {
+    XChaCha20Encryption::try_new(key, nonce, counter)?.encrypt_data(origin_data)
+}
+
+pub fn try_xchacha20_decrypt_data(enciphered_data: &[u8], key: &[u8], nonce: &[u8], counter: u32) -> Result<Vec<u8>, Error> {
+    XChaCha20Encryption::try_new(key, nonce, counter)?.decrypt_data(enciphered_data)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::util::hex::{hex_decode, hex_encode};
+    use super::super::super::block_mode::stream_trait::StreamEncryptor;
+    use super::{ChaCha20Encryptor, chacha20_decrypt_data, chacha20_encrypt_data};
+
+    const NONCE: [u8; 12] = [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x4a, 0x00, 0x00, 0x00, 0x00];
+    const ORIGIN_DATA: &str = concat!(
+        "4c616469657320616e642047656e746c656d656e206f662074686520636c6173",
+        "73206f66202739393a204966204920636f756c64206f6666657220796f75206f",
+        "6e6c79206f6e652074697020666f7220746865206675747572652c2073756e73",
+        "637265656e20776f756c642062652069742e",
+    );
+    const ENCIPHERED_DATA: &str = concat!(
+        "6e2e359a2568f98041ba0728dd0d6981e97e7aec1d4360c20a27afccfd9fae0b",
+        "f91b65c5524733ab8f593dabcd62b3571639d624e65152ab8f530c359f0861d8",
+        "07ca0dbf500d6a6156a38e088a22b65e52bc514d16ccf806818ce91ab7793736",
+        "5af90bbf74a35be6b40b8eedf2785e42874d",
+    );
+
+    fn key() -> [u8; 32] {
+        core::array::from_fn(|i| i as u8)
+    }
+
+    #[test]
+    fn rfc_8439_encrypt() {
+        let origin_data = hex_decode(ORIGIN_DATA).unwrap();
+        let enciphered_data = chacha20_encrypt_data(&origin_data, key(), NONCE, 1).unwrap();
+        assert_eq!(hex_encode(&enciphered_data), ENCIPHERED_DATA);
+        assert_eq!(chacha20_decrypt_data(&enciphered_data, key(), NONCE, 1).unwrap(), origin_data);
+    }
+
+    #[test]
+    fn rfc_8439_streaming_encrypt() {
+        let mut encryptor = ChaCha20Encryptor::new(key(), NONCE, 1);
+        let mut enciphered_data = Vec::new();
+        for piece in hex_decode(ORIGIN_DATA).unwrap().chunks(7) {
+            enciphered_data.extend_from_slice(&encryptor.update(piece).unwrap());
+        }
+        enciphered_data.extend_from_slice(&encryptor.finalize().unwrap());
+        assert_eq!(hex_encode(&enciphered_data), ENCIPHERED_DATA);
+    }
+
+    #[test]
+    fn counter_overflow_fails_stream() {
+        assert!(matches!(chacha20_encrypt_data(&[0; 65], key(), NONCE, u32::MAX), Err(Error::InvalidDataLength)));
+        let mut encryptor = ChaCha20Encryptor::new(key(), NONCE, u32::MAX);
+        assert_eq!(StreamEncryptor::update(&mut encryptor, &[0; 64]).len(), 64);
+        assert!(StreamEncryptor::update(&mut encryptor, &[0; 1]).is_empty());
+        assert!(matches!(StreamEncryptor::finalize(encryptor), Err(Error::InvalidDataLength)));
+    }
+}
@@ -0,0 +1,4 @@
+pub mod chacha20_constant;
+pub mod chacha20_encryption;
+pub mod chacha20_stream;
+pub mod chacha20_poly1305;
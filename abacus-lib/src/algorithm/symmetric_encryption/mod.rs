@@ -1,2 +1,6 @@
+pub mod block_mode;
 pub mod sm4;
 pub mod blowfish;
+pub mod aes;
+pub mod chacha20;
+pub mod zuc;
@@ -4,3 +4,6 @@ pub mod sm4_ecb;
 pub mod sm4_cbc;
 pub mod sm4_ofb;
 pub mod sm4_cfb;
+pub mod sm4_gcm;
+pub mod sm4_ccm;
+pub mod sm4_ctr;
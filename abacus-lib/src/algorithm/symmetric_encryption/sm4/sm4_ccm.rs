@@ -0,0 +1,394 @@
+use crate::error::Error;
+use crate::util::constant_time::constant_time_eq;
+use super::super::block_mode::stream_trait::{StreamEncryptor, StreamDecryptor};
+use super::sm4_constant::{KEY_BYTE_LENGTH, SM4_BLOCK_BYTE_LENGTH, SM4_CCM_NONCE_BYTE_MIN_LENGTH, SM4_CCM_NONCE_BYTE_MAX_LENGTH, SM4_CCM_TAG_BYTE_MIN_LENGTH};
+use super::sm4_encryption::SM4Encryption;
+
+#[inline(always)]
+fn check_parameter(nonce: &[u8], tag_byte_length: usize, data_length: usize) -> Result<(), Error> {
+    if !(SM4_CCM_NONCE_BYTE_MIN_LENGTH..=SM4_CCM_NONCE_BYTE_MAX_LENGTH).contains(&nonce.len()) {
+        return Err(Error::InvalidNonceLength);
+    }
+    if !(SM4_CCM_TAG_BYTE_MIN_LENGTH..=SM4_BLOCK_BYTE_LENGTH).contains(&tag_byte_length) || !tag_byte_length.is_multiple_of(2) {
+        return Err(Error::InvalidTagLength);
+    }
+    let length_byte_length = SM4_BLOCK_BYTE_LENGTH - 1 - nonce.len();
+    if length_byte_length < 8 && (data_length as u64) >= (1u64 << (length_byte_length * 8)) {
+        return Err(Error::InvalidDataLength);
+    }
+    Ok(())
+}
+
+#[inline(always)]
+fn counter_block(nonce: &[u8], index: u64) -> Result<[u8; SM4_BLOCK_BYTE_LENGTH], Error> {
+    let length_byte_length = SM4_BLOCK_BYTE_LENGTH - 1 - nonce.len();
+    if length_byte_length < 8 && index >= (1u64 << (length_byte_length * 8)) {
+        return Err(Error::InvalidDataLength);
+    }
+    let mut block = [0; SM4_BLOCK_BYTE_LENGTH];
+    block[0] = (length_byte_length - 1) as u8;
+    block[1..(1 + nonce.len())].copy_from_slice(nonce);
+    let index_bytes = index.to_be_bytes();
+    for i in 0..length_byte_length.min(8) {
+        block[SM4_BLOCK_BYTE_LENGTH - 1 - i] = index_bytes[7 - i];
+    }
+    Ok(block)
+}
+
+struct SM4CCMState {
+    sm4: SM4Encryption,
+    nonce: [u8; SM4_CCM_NONCE_BYTE_MAX_LENGTH],
+    nonce_length: usize,
+    mac: [u8; SM4_BLOCK_BYTE_LENGTH],
+    mac_remains_data: [u8; SM4_BLOCK_BYTE_LENGTH],
+    mac_remains_data_length: usize,
+    key_stream: [u8; SM4_BLOCK_BYTE_LENGTH],
+    key_stream_offset: usize,
+    counter_index: u64,
+    data_length: u64,
+    pushed_data_length: u64,
+    data_length_exceeded: bool,
+    tag_byte_length: usize,
+}
+
+impl SM4CCMState {
+    fn new(key: [u8; KEY_BYTE_LENGTH], nonce: &[u8], aad: &[u8], data_length: usize, tag_byte_length: usize) -> Result<SM4CCMState, Error> {
+        check_parameter(nonce, tag_byte_length, data_length)?;
+        let mut instance = SM4CCMState {
+            sm4: SM4Encryption::with_key(key),
+            nonce: [0; SM4_CCM_NONCE_BYTE_MAX_LENGTH],
+            nonce_length: nonce.len(),
+            mac: [0; SM4_BLOCK_BYTE_LENGTH],
+            mac_remains_data: [0; SM4_BLOCK_BYTE_LENGTH],
+            mac_remains_data_length: 0,
+            key_stream: [0; SM4_BLOCK_BYTE_LENGTH],
+            key_stream_offset: SM4_BLOCK_BYTE_LENGTH,
+            counter_index: 1,
+            data_length: data_length as u64,
+            pushed_data_length: 0,
+            data_length_exceeded: false,
+            tag_byte_length,
+        };
+        instance.nonce[..nonce.len()].copy_from_slice(nonce);
+        let mut first_block = counter_block(nonce, data_length as u64)?;
+        first_block[0] |= (((tag_byte_length - 2) / 2) << 3) as u8;
+        if !aad.is_empty() {
+            first_block[0] |= 0x40;
+        }
+        instance.push_mac_data(&first_block);
+        if !aad.is_empty() {
+            if aad.len() < 0xff00 {
+                instance.push_mac_data(&(aad.len() as u16).to_be_bytes());
+            } else if (aad.len() as u64) < (1u64 << 32) {
+                instance.push_mac_data(&[0xff, 0xfe]);
+                instance.push_mac_data(&(aad.len() as u32).to_be_bytes());
+            } else {
+                instance.push_mac_data(&[0xff, 0xff]);
+                instance.push_mac_data(&(aad.len() as u64).to_be_bytes());
+            }
+            instance.push_mac_data(aad);
+            instance.pad_mac_data();
+        }
+        Ok(instance)
+    }
+
+    fn push_mac_data(&mut self, data: &[u8]) {
+        let mut mac_with_data = [0; SM4_BLOCK_BYTE_LENGTH];
+        for &byte in data {
+            self.mac_remains_data[self.mac_remains_data_length] = byte;
+            self.mac_remains_data_length += 1;
+            if self.mac_remains_data_length == SM4_BLOCK_BYTE_LENGTH {
+                for ((mac_byte, &byte), &data_byte) in mac_with_data.iter_mut().zip(self.mac.iter()).zip(self.mac_remains_data.iter()) {
+                    *mac_byte = byte ^ data_byte;
+                }
+                self.sm4.encrypt_block(&mac_with_data, &mut self.mac);
+                self.mac_remains_data_length = 0;
+            }
+        }
+    }
+
+    fn pad_mac_data(&mut self) {
+        if self.mac_remains_data_length != 0 {
+            let padding_data = [0; SM4_BLOCK_BYTE_LENGTH];
+            self.push_mac_data(&padding_data[..(SM4_BLOCK_BYTE_LENGTH - self.mac_remains_data_length)]);
+        }
+    }
+
+    fn check_data_length(&mut self, data_length: usize) -> Result<(), Error> {
+        if self.data_length_exceeded || data_length as u64 > self.data_length - self.pushed_data_length {
+            self.data_length_exceeded = true;
+            return Err(Error::InvalidDataLength);
+        }
+        Ok(())
+    }
+
+    fn apply_key_stream(&mut self, data: &mut [u8]) -> Result<(), Error> {
+        for byte in data.iter_mut() {
+            if self.key_stream_offset == SM4_BLOCK_BYTE_LENGTH {
+                self.sm4.encrypt_block(&counter_block(&self.nonce[..self.nonce_length], self.counter_index)?, &mut self.key_stream);
+                self.counter_index += 1;
+                self.key_stream_offset = 0;
+            }
+            *byte ^= self.key_stream[self.key_stream_offset];
+            self.key_stream_offset += 1;
+        }
+        self.pushed_data_length += data.len() as u64;
+        Ok(())
+    }
+
+    fn compute_tag(&mut self) -> Result<[u8; SM4_BLOCK_BYTE_LENGTH], Error> {
+        if self.data_length_exceeded || self.pushed_data_length != self.data_length {
+            return Err(Error::InvalidDataLength);
+        }
+        self.pad_mac_data();
+        let mut tag = [0; SM4_BLOCK_BYTE_LENGTH];
+        self.sm4.encrypt_block(&counter_block(&self.nonce[..self.nonce_length], 0)?, &mut tag);
+        for (tag_byte, &mac_byte) in tag.iter_mut().zip(self.mac.iter()) {
+            *tag_byte ^= mac_byte;
+        }
+        Ok(tag)
+    }
+}
+
+pub struct SM4CCMEncryptor {
+    state: SM4CCMState,
+}
+
+impl SM4CCMEncryptor {
+    pub fn new(key: [u8; KEY_BYTE_LENGTH], nonce: &[u8], aad: &[u8], data_length: usize, tag_byte_length: usize) -> Result<SM4CCMEncryptor, Error> {
+        Ok(SM4CCMEncryptor {
+            state: SM4CCMState::new(key, nonce, aad, data_length, tag_byte_length)?,
+        })
+    }
+
+    pub fn try_new(key: &[u8], nonce: &[u8], aad: &[u8], data_length: usize, tag_byte_length: usize) -> Result<SM4CCMEncryptor, Error> {
+        let key: [u8; KEY_BYTE_LENGTH] = key.try_into().map_err(|_| Error::InvalidKeyLength)?;
+        SM4CCMEncryptor::new(key, nonce, aad, data_length, tag_byte_length)
+    }
+
+    pub fn encrypt_data(&mut self, origin_data: &[u8]) -> Result<Vec<u8>, Error> {
+        self.state.check_data_length(origin_data.len())?;
+        let mut enciphered_data = origin_data.to_vec();
+        self.state.apply_key_stream(&mut enciphered_data)?;
+        self.state.push_mac_data(origin_data);
+        Ok(enciphered_data)
+    }
+
+    pub fn compute_tag(mut self) -> Result<Vec<u8>, Error> {
+        Ok(self.state.compute_tag()?[..self.state.tag_byte_length].to_vec())
+    }
+}
+
+pub struct SM4CCMDecryptor {
+    state: SM4CCMState,
+    origin_data: Vec<u8>,
+    tag_data: Vec<u8>,
+}
+
+impl SM4CCMDecryptor {
+    pub fn new(key: [u8; KEY_BYTE_LENGTH], nonce: &[u8], aad: &[u8], data_length: usize, tag_byte_length: usize) -> Result<SM4CCMDecryptor, Error> {
+        Ok(SM4CCMDecryptor {
+            state: SM4CCMState::new(key, nonce, aad, data_length, tag_byte_length)?,
+            origin_data: Vec::new(),
+            tag_data: Vec::new(),
+        })
+    }
+
+    pub fn try_new(key: &[u8], nonce: &[u8], aad: &[u8], data_length: usize, tag_byte_length: usize) -> Result<SM4CCMDecryptor, Error> {
+        let key: [u8; KEY_BYTE_LENGTH] = key.try_into().map_err(|_| Error::InvalidKeyLength)?;
+        SM4CCMDecryptor::new(key, nonce, aad, data_length, tag_byte_length)
+    }
+
+    pub fn push_data(&mut self, enciphered_data: &[u8]) -> Result<(), Error> {
+        self.state.check_data_length(enciphered_data.len())?;
+        let mut origin_data = enciphered_data.to_vec();
+        self.state.apply_key_stream(&mut origin_data)?;
+        self.state.push_mac_data(&origin_data);
+        self.origin_data.extend_from_slice(&origin_data);
+        Ok(())
+    }
+
+    pub fn decrypt_data(mut self, tag: &[u8]) -> Result<Vec<u8>, Error> {
+        if !constant_time_eq(&self.state.compute_tag()?[..self.state.tag_byte_length], tag) {
+            self.origin_data.fill(0x00);
+            return Err(Error::AuthenticationFailed);
+        }
+        Ok(self.origin_data)
+    }
+}
+
+impl StreamEncryptor for SM4CCMEncryptor {
+    fn update(&mut self, origin_data: &[u8]) -> Vec<u8> {
+        self.encrypt_data(origin_data).unwrap_or_default()
+    }
+
+    fn finalize(self) -> Result<Vec<u8>, Error> {
+        self.compute_tag()
+    }
+}
+
+impl StreamDecryptor for SM4CCMDecryptor {
+    fn update(&mut self, enciphered_data: &[u8]) -> Vec<u8> {
+        self.tag_data.extend_from_slice(enciphered_data);
+        let data_length = self.tag_data.len().saturating_sub(self.state.tag_byte_length);
+        let data: Vec<u8> = self.tag_data.drain(..data_length).collect();
+        let _ = self.push_data(&data);
+        Vec::new()
+    }
+
+    fn finalize(mut self) -> Result<Vec<u8>, Error> {
+        let tag = std::mem::take(&mut self.tag_data);
+        if tag.len() != self.state.tag_byte_length {
+            return Err(Error::InvalidDataLength);
+        }
+        self.decrypt_data(&tag)
+    }
+}
+
+pub fn sm4_ccm_encrypt_data(origin_data: &[u8], key: [u8; KEY_BYTE_LENGTH], nonce: &[u8], aad: &[u8], tag_byte_length: usize) -> Result<(Vec<u8>, Vec<u8>), Error> {
+    let mut encryptor = SM4CCMEncryptor::new(key, nonce, aad, origin_data.len(), tag_byte_length)?;
+    let enciphered_data = encryptor.encrypt_data(origin_data)?;
+    Ok((enciphered_data, encryptor.compute_tag()?))
+}
+
+pub fn sm4_ccm_decrypt_data(enciphered_data: &[u8], key: [u8; KEY_BYTE_LENGTH], nonce: &[u8], aad: &[u8], tag: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut decryptor = SM4CCMDecryptor::new(key, nonce, aad, enciphered_data.len(), tag.len())?;
+    decryptor.push_data(enciphered_data)?;
+    decryptor.decrypt_data(tag)
+}
+
+pub fn sm4_ccm_encrypt_data_in_place(data: &mut [u8], key: [u8; KEY_BYTE_LENGTH], nonce: &[u8], aad: &[u8], tag: &mut [u8]) -> Result<(), Error> {
+    let mut state = SM4CCMState::new(key, nonce, aad, data.len(), tag.len())?;
+    state.push_mac_data(data);
+    state.apply_key_stream(data)?;
+    tag.copy_from_slice(&state.compute_tag()?[..tag.len()]);
+    Ok(())
+}
+
+pub fn sm4_ccm_decrypt_data_in_place(data: &mut [u8], key: [u8; KEY_BYTE_LENGTH], nonce: &[u8], aad: &[u8], tag: &[u8]) -> Result<(), Error> {
+    let mut state = SM4CCMState::new(key, nonce, aad, data.len(), tag.len())?;
+    state.apply_key_stream(data)?;
+    state.push_mac_data(data);
+    if !constant_time_eq(&state.compute_tag()?[..tag.len()], tag) {
+        data.fill(0x00);
+        return Err(Error::AuthenticationFailed);
+    }
+    Ok(())
+}
+
+pub fn sm4_ccm_encrypt_data_into(origin_data: &[u8], enciphered_data: &mut [u8], key: [u8; KEY_BYTE_LENGTH], nonce: &[u8], aad: &[u8], tag: &mut [u8]) -> Result<(), Error> {
+    if origin_data.len() != enciphered_data.len() {
+        return Err(Error::InvalidDataLength);
+    }
+    enciphered_data.copy_from_slice(origin_data);
+    sm4_ccm_encrypt_data_in_place(enciphered_data, key, nonce, aad, tag)
+}
+
+pub fn sm4_ccm_decrypt_data_into(enciphered_data: &[u8], origin_data: &mut [u8], key: [u8; KEY_BYTE_LENGTH], nonce: &[u8], aad: &[u8], tag: &[u8]) -> Result<(), Error> {
+    if enciphered_data.len() != origin_data.len() {
+        return Err(Error::InvalidDataLength);
+    }
+    origin_data.copy_from_slice(enciphered_data);
+    sm4_ccm_decrypt_data_in_place(origin_data, key, nonce, aad, tag)
+}
+
+pub fn try_sm4_ccm_encrypt_data(origin_data: &[u8], key: &[u8], nonce: &[u8], aad: &[u8], tag_byte_length: usize) -> Result<(Vec<u8>, Vec<u8>), Error> {
+    let key: [u8; KEY_BYTE_LENGTH] = key.try_into().map_err(|_| Error::InvalidKeyLength)?;
+    sm4_ccm_encrypt_data(origin_data, key, nonce, aad, tag_byte_length)
+}
+
+pub fn try_sm4_ccm_decrypt_data(enciphered_data: &[u8], key: &[u8], nonce: &[u8], aad: &[u8], tag: &[u8]) -> Result<Vec<u8>, Error> {
+    let key: [u8; KEY_BYTE_LENGTH] = key.try_into().map_err(|_| Error::InvalidKeyLength)?;
+    sm4_ccm_decrypt_data(enciphered_data, key, nonce, aad, tag)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::util::hex::{hex_decode, hex_encode};
+    use super::super::super::block_mode::stream_trait::StreamDecryptor;
+    use super::{SM4CCMDecryptor, SM4CCMEncryptor, counter_block, sm4_ccm_decrypt_data, sm4_ccm_encrypt_data};
+
+    const KEY: [u8; 16] = [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0xfe, 0xdc, 0xba, 0x98, 0x76, 0x54, 0x32, 0x10];
+    const NONCE: &str = "00001234567800000000abcd";
+    const AAD: &str = "feedfacedeadbeeffeedfacedeadbeefabaddad2";
+    const ORIGIN_DATA: &str = concat!(
+        "aaaaaaaaaaaaaaaabbbbbbbbbbbbbbbbccccccccccccccccdddddddddddddddd",
+        "eeeeeeeeeeeeeeeeffffffffffffffffeeeeeeeeeeeeeeeeaaaaaaaaaaaaaaaa",
+    );
+    const ENCIPHERED_DATA: &str = concat!(
+        "48af93501fa62adbcd414cce6034d895dda1bf8f132f042098661572e7483094",
+        "fd12e518ce062c98acee28d95df4416bed31a2f04476c18bb40c84a74b97dc5b",
+    );
+    const TAG: &str = "16842d4fa186f56ab33256971fa110f4";
+
+    fn hex(data: &str) -> Vec<u8> {
+        hex_decode(data).unwrap()
+    }
+
+    #[test]
+    fn rfc_8998_encrypt() {
+        let (enciphered_data, tag) = sm4_ccm_encrypt_data(&hex(ORIGIN_DATA), KEY, &hex(NONCE), &hex(AAD), 16).unwrap();
+        assert_eq!(hex_encode(&enciphered_data), ENCIPHERED_DATA);
+        assert_eq!(hex_encode(&tag), TAG);
+    }
+
+    #[test]
+    fn rfc_8998_decrypt() {
+        let origin_data = sm4_ccm_decrypt_data(&hex(ENCIPHERED_DATA), KEY, &hex(NONCE), &hex(AAD), &hex(TAG)).unwrap();
+        assert_eq!(hex_encode(&origin_data), ORIGIN_DATA);
+    }
+
+    #[test]
+    fn rfc_8998_streaming_decrypt() {
+        let mut decryptor = SM4CCMDecryptor::new(KEY, &hex(NONCE), &hex(AAD), hex(ENCIPHERED_DATA).len(), 16).unwrap();
+        let mut enciphered_data = hex(ENCIPHERED_DATA);
+        enciphered_data.extend_from_slice(&hex(TAG));
+        for piece in enciphered_data.chunks(5) {
+            assert!(decryptor.update(piece).is_empty());
+        }
+        assert_eq!(hex_encode(&decryptor.finalize().unwrap()), ORIGIN_DATA);
+    }
+
+    #[test]
+    fn tag_mismatch_is_rejected() {
+        let mut tag = hex(TAG);
+        tag[0] ^= 0x01;
+        assert!(matches!(sm4_ccm_decrypt_data(&hex(ENCIPHERED_DATA), KEY, &hex(NONCE), &hex(AAD), &tag), Err(Error::AuthenticationFailed)));
+        let mut enciphered_data = hex(ENCIPHERED_DATA);
+        enciphered_data[63] ^= 0x01;
+        assert!(matches!(sm4_ccm_decrypt_data(&enciphered_data, KEY, &hex(NONCE), &hex(AAD), &hex(TAG)), Err(Error::AuthenticationFailed)));
+        assert!(matches!(sm4_ccm_decrypt_data(&hex(ENCIPHERED_DATA), KEY, &hex(NONCE), &[], &hex(TAG)), Err(Error::AuthenticationFailed)));
+    }
+
+    #[test]
+    fn data_length_mismatch_is_rejected() {
+        let mut encryptor = SM4CCMEncryptor::new(KEY, &hex(NONCE), &hex(AAD), 64, 16).unwrap();
+        encryptor.encrypt_data(&hex(ORIGIN_DATA)[..63]).unwrap();
+        assert!(matches!(encryptor.compute_tag(), Err(Error::InvalidDataLength)));
+        let mut encryptor = SM4CCMEncryptor::new(KEY, &hex(NONCE), &hex(AAD), 63, 16).unwrap();
+        assert_eq!(encryptor.encrypt_data(&hex(ORIGIN_DATA)[..32]).unwrap().len(), 32);
+        assert!(matches!(encryptor.encrypt_data(&hex(ORIGIN_DATA)[32..]), Err(Error::InvalidDataLength)));
+        assert!(matches!(encryptor.encrypt_data(&hex(ORIGIN_DATA)[32..63]), Err(Error::InvalidDataLength)));
+        assert!(matches!(encryptor.compute_tag(), Err(Error::InvalidDataLength)));
+        let mut decryptor = SM4CCMDecryptor::new(KEY, &hex(NONCE), &hex(AAD), 63, 16).unwrap();
+        assert!(matches!(decryptor.push_data(&hex(ENCIPHERED_DATA)), Err(Error::InvalidDataLength)));
+        assert!(matches!(decryptor.decrypt_data(&hex(TAG)), Err(Error::InvalidDataLength)));
+    }
+
+    #[test]
+    fn counter_exhaustion_is_rejected() {
+        assert!(counter_block(&[0; 13], 0xffff).is_ok());
+        assert!(matches!(counter_block(&[0; 13], 0x10000), Err(Error::InvalidDataLength)));
+        assert!(counter_block(&[0; 7], u64::MAX).is_ok());
+    }
+
+    #[test]
+    fn invalid_parameters_are_rejected() {
+        assert!(matches!(SM4CCMEncryptor::new(KEY, &[0; 6], &[], 16, 16), Err(Error::InvalidNonceLength)));
+        assert!(matches!(SM4CCMEncryptor::new(KEY, &[0; 14], &[], 16, 16), Err(Error::InvalidNonceLength)));
+        assert!(matches!(SM4CCMEncryptor::new(KEY, &hex(NONCE), &[], 16, 5), Err(Error::InvalidTagLength)));
+        assert!(matches!(SM4CCMEncryptor::new(KEY, &[0; 13], &[], 0x10000, 16), Err(Error::InvalidDataLength)));
+        assert!(matches!(SM4CCMEncryptor::try_new(&KEY[1..], &hex(NONCE), &[], 16, 16), Err(Error::InvalidKeyLength)));
+    }
+}
@@ -2,6 +2,12 @@ pub const KEY_BYTE_LENGTH: usize = 16;
 pub const SM4_KEY_BYTE_LENGTH: usize = 32;
 pub const SM4_BLOCK_BYTE_LENGTH: usize = 16;
 pub const SM4_DATA_BYTE_MAX_LENGTH: usize = 8;
+pub const SM4_GCM_NONCE_BYTE_LENGTH: usize = 12;
+pub const SM4_GCM_TAG_BYTE_MIN_LENGTH: usize = 4;
+pub const SM4_GCM_DATA_BYTE_MAX_LENGTH: u64 = (1 << 36) - 32;
+pub const SM4_CCM_NONCE_BYTE_MIN_LENGTH: usize = 7;
+pub const SM4_CCM_NONCE_BYTE_MAX_LENGTH: usize = 13;
+pub const SM4_CCM_TAG_BYTE_MIN_LENGTH: usize = 4;
 
 pub const SM4_S: [u8; 256] = [
     0xD6, 0x90, 0xE9, 0xFE, 0xCC, 0xE1, 0x3D, 0xB7, 0x16, 0xB6, 0x14, 0xC2, 0x28, 0xFB, 0x2C, 0x05,
@@ -0,0 +1,81 @@
+use crate::error::Error;
+use super::super::block_mode::ctr_counter::CTRCounter;
+use super::super::block_mode::ctr_mode::CTRMode;
+use super::sm4_constant::{KEY_BYTE_LENGTH, SM4_BLOCK_BYTE_LENGTH};
+use super::sm4_encryption::SM4Encryption;
+
+pub struct SM4CTREncryption {
+    ctr_mode: CTRMode<SM4Encryption>,
+}
+
+impl SM4CTREncryption {
+    pub fn new(key: [u8; KEY_BYTE_LENGTH], iv: [u8; SM4_BLOCK_BYTE_LENGTH], ctr_counter: CTRCounter) -> Result<SM4CTREncryption, Error> {
+        Ok(SM4CTREncryption {
+            ctr_mode: CTRMode::new(SM4Encryption::with_key(key), iv, ctr_counter)?,
+        })
+    }
+
+    pub fn seek(&mut self, position: u64) {
+        self.ctr_mode.seek(position)
+    }
+
+    pub fn position(&self) -> u64 {
+        self.ctr_mode.position()
+    }
+
+    pub fn apply_key_stream(&mut self, data: &mut [u8]) -> Result<(), Error> {
+        self.ctr_mode.apply_key_stream(data)
+    }
+
+    pub fn apply_key_stream_into(&mut self, input_data: &[u8], output_data: &mut [u8]) -> Result<(), Error> {
+        self.ctr_mode.apply_key_stream_into(input_data, output_data)
+    }
+}
+
+pub fn sm4_ctr_encrypt_data(origin_data: &[u8], key: [u8; KEY_BYTE_LENGTH], iv: [u8; SM4_BLOCK_BYTE_LENGTH], ctr_counter: CTRCounter) -> Result<Vec<u8>, Error> {
+    sm4_ctr_encrypt_data_at(origin_data, key, iv, ctr_counter, 0)
+}
+
+pub fn sm4_ctr_decrypt_data(enciphered_data: &[u8], key: [u8; KEY_BYTE_LENGTH], iv: [u8; SM4_BLOCK_BYTE_LENGTH], ctr_counter: CTRCounter) -> Result<Vec<u8>, Error> {
+    sm4_ctr_encrypt_data_at(enciphered_data, key, iv, ctr_counter, 0)
+}
+
+pub fn sm4_ctr_encrypt_data_at(origin_data: &[u8], key: [u8; KEY_BYTE_LENGTH], iv: [u8; SM4_BLOCK_BYTE_LENGTH], ctr_counter: CTRCounter, position: u64) -> Result<Vec<u8>, Error> {
+    let mut sm4_ctr = SM4CTREncryption::new(key, iv, ctr_counter)?;
+    let mut enciphered_data = origin_data.to_vec();
+    sm4_ctr.seek(position);
+    sm4_ctr.apply_key_stream(&mut enciphered_data)?;
+    Ok(enciphered_data)
+}
+
+pub fn sm4_ctr_decrypt_data_at(enciphered_data: &[u8], key: [u8; KEY_BYTE_LENGTH], iv: [u8; SM4_BLOCK_BYTE_LENGTH], ctr_counter: CTRCounter, position: u64) -> Result<Vec<u8>, Error> {
+    sm4_ctr_encrypt_data_at(enciphered_data, key, iv, ctr_counter, position)
+}
+
+pub fn sm4_ctr_encrypt_data_in_place(data: &mut [u8], key: [u8; KEY_BYTE_LENGTH], iv: [u8; SM4_BLOCK_BYTE_LENGTH], ctr_counter: CTRCounter) -> Result<(), Error> {
+    SM4CTREncryption::new(key, iv, ctr_counter)?.apply_key_stream(data)
+}
+
+pub fn sm4_ctr_decrypt_data_in_place(data: &mut [u8], key: [u8; KEY_BYTE_LENGTH], iv: [u8; SM4_BLOCK_BYTE_LENGTH], ctr_counter: CTRCounter) -> Result<(), Error> {
+    SM4CTREncryption::new(key, iv, ctr_counter)?.apply_key_stream(data)
+}
+
+pub fn sm4_ctr_encrypt_data_into(origin_data: &[u8], enciphered_data: &mut [u8], key: [u8; KEY_BYTE_LENGTH], iv: [u8; SM4_BLOCK_BYTE_LENGTH], ctr_counter: CTRCounter) -> Result<(), Error> {
+    SM4CTREncryption::new(key, iv, ctr_counter)?.apply_key_stream_into(origin_data, enciphered_data)
+}
+
+pub fn sm4_ctr_decrypt_data_into(enciphered_data: &[u8], origin_data: &mut [u8], key: [u8; KEY_BYTE_LENGTH], iv: [u8; SM4_BLOCK_BYTE_LENGTH], ctr_counter: CTRCounter) -> Result<(), Error> {
+    SM4CTREncryption::new(key, iv, ctr_counter)?.apply_key_stream_into(enciphered_data, origin_data)
+}
+
+pub fn try_sm4_ctr_encrypt_data(origin_data: &[u8], key: &[u8], iv: &[u8], ctr_counter: CTRCounter) -> Result<Vec<u8>, Error> {
+    let key: [u8; KEY_BYTE_LENGTH] = key.try_into().map_err(|_| Error::InvalidKeyLength)?;
+    let iv: [u8; SM4_BLOCK_BYTE_LENGTH] = iv.try_into().map_err(|_| Error::InvalidIVLength)?;
+    sm4_ctr_encrypt_data(origin_data, key, iv, ctr_counter)
+}
+
+pub fn try_sm4_ctr_decrypt_data(enciphered_data: &[u8], key: &[u8], iv: &[u8], ctr_counter: CTRCounter) -> Result<Vec<u8>, Error> {
+    let key: [u8; KEY_BYTE_LENGTH] = key.try_into().map_err(|_| Error::InvalidKeyLength)?;
+    let iv: [u8; SM4_BLOCK_BYTE_LENGTH] = iv.try_into().map_err(|_| Error::InvalidIVLength)?;
+    sm4_ctr_decrypt_data(enciphered_data, key, iv, ctr_counter)
+}
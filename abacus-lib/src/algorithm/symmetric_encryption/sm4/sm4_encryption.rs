@@ -1,3 +1,5 @@
+use crate::error::Error;
+use super::super::block_mode::block_cipher::BlockCipher;
 use super::sm4_constant::{KEY_BYTE_LENGTH, SM4_KEY_BYTE_LENGTH, SM4_BLOCK_BYTE_LENGTH, SM4_S, SM4_S_BOX_T0, SM4_S_BOX_T1, SM4_S_BOX_T2, SM4_S_BOX_T3, FK, CK};
 
 #[inline(always)]
@@ -21,13 +23,14 @@ fn big_endian_word(buffer: &[u8; SM4_BLOCK_BYTE_LENGTH], i: usize) -> u32 {
 #[inline(always)]
 fn big_endian_bytes(w: u32, bytes: &mut [u8; SM4_BLOCK_BYTE_LENGTH], index: usize) {
     let ww = w.to_be_bytes();
-    bytes[index * 4 + 0] = ww[0];
+    bytes[index * 4] = ww[0];
     bytes[index * 4 + 1] = ww[1];
     bytes[index * 4 + 2] = ww[2];
     bytes[index * 4 + 3] = ww[3];
 }
 
 #[inline(always)]
+#[allow(clippy::too_many_arguments)]
 fn round_edge(x1: &mut u32, x2: &mut u32, x3: &mut u32, x4: &mut u32, k1: u32, k2: u32, k3: u32, k4: u32) {
     *x1 ^= edge_t(*x2 ^ *x3 ^ *x4 ^ k1);
     *x2 ^= edge_t(*x1 ^ *x3 ^ *x4 ^ k2);
@@ -36,6 +39,7 @@ fn round_edge(x1: &mut u32, x2: &mut u32, x3: &mut u32, x4: &mut u32, k1: u32, k
 }
 
 #[inline(always)]
+#[allow(clippy::too_many_arguments)]
 fn round_main(x1: &mut u32, x2: &mut u32, x3: &mut u32, x4: &mut u32, k1: u32, k2: u32, k3: u32, k4: u32) {
     *x1 ^= main_t(*x2 ^ *x3 ^ *x4 ^ k1);
     *x2 ^= main_t(*x1 ^ *x3 ^ *x4 ^ k2);
@@ -48,13 +52,18 @@ pub struct SM4Encryption {
     sm4_key: [u32; SM4_KEY_BYTE_LENGTH],
 }
 
+impl Default for SM4Encryption {
+    fn default() -> Self {
+        SM4Encryption::new()
+    }
+}
+
 impl SM4Encryption {
     pub fn new() -> SM4Encryption {
-        let instance = SM4Encryption {
+        SM4Encryption {
             key: [0; KEY_BYTE_LENGTH],
             sm4_key: [0; SM4_KEY_BYTE_LENGTH],
-        };
-        instance
+        }
     }
 
     pub fn with_key(key: [u8; KEY_BYTE_LENGTH]) -> SM4Encryption {
@@ -63,6 +72,11 @@ impl SM4Encryption {
         instance
     }
 
+    pub fn try_with_key(key: &[u8]) -> Result<SM4Encryption, Error> {
+        let key: [u8; KEY_BYTE_LENGTH] = key.try_into().map_err(|_| Error::InvalidKeyLength)?;
+        Ok(SM4Encryption::with_key(key))
+    }
+
     pub fn set_key(&mut self, key: [u8; KEY_BYTE_LENGTH]) {
         self.key = key;
         self.generate_sm4_key();
@@ -83,6 +97,7 @@ impl SM4Encryption {
         }
     }
 
+    #[allow(clippy::zero_prefixed_literal)]
     pub fn encrypt_block(&self, origin_data: &[u8; SM4_BLOCK_BYTE_LENGTH], enciphered_data: &mut [u8; SM4_BLOCK_BYTE_LENGTH]) {
         let mut w0 = big_endian_word(origin_data, 0);
         let mut w1 = big_endian_word(origin_data, 1);
@@ -102,6 +117,7 @@ impl SM4Encryption {
         big_endian_bytes(w0, enciphered_data, 3);
     }
 
+    #[allow(clippy::zero_prefixed_literal)]
     pub fn decrypt_block(&self, enciphered_data: &[u8; SM4_BLOCK_BYTE_LENGTH], origin_data: &mut [u8; SM4_BLOCK_BYTE_LENGTH]) {
         let mut w0 = big_endian_word(enciphered_data, 0);
         let mut w1 = big_endian_word(enciphered_data, 1);
@@ -121,3 +137,18 @@ impl SM4Encryption {
         big_endian_bytes(w0, origin_data, 3);
     }
 }
+
+impl BlockCipher for SM4Encryption {
+    const ALGORITHM_NAME: &'static str = "SM4";
+    const BLOCK_BYTE_LENGTH: usize = SM4_BLOCK_BYTE_LENGTH;
+
+    type Block = [u8; SM4_BLOCK_BYTE_LENGTH];
+
+    fn encrypt_block(&self, origin_data: &[u8; SM4_BLOCK_BYTE_LENGTH], enciphered_data: &mut [u8; SM4_BLOCK_BYTE_LENGTH]) {
+        SM4Encryption::encrypt_block(self, origin_data, enciphered_data)
+    }
+
+    fn decrypt_block(&self, enciphered_data: &[u8; SM4_BLOCK_BYTE_LENGTH], origin_data: &mut [u8; SM4_BLOCK_BYTE_LENGTH]) {
+        SM4Encryption::decrypt_block(self, enciphered_data, origin_data)
+    }
+}
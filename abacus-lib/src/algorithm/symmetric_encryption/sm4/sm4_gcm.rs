@@ -0,0 +1,444 @@
+use crate::error::Error;
+use crate::util::constant_time::constant_time_eq;
+use super::super::block_mode::stream_trait::{StreamEncryptor, StreamDecryptor};
+use super::sm4_constant::{KEY_BYTE_LENGTH, SM4_BLOCK_BYTE_LENGTH, SM4_GCM_DATA_BYTE_MAX_LENGTH, SM4_GCM_NONCE_BYTE_LENGTH, SM4_GCM_TAG_BYTE_MIN_LENGTH};
+use super::sm4_encryption::SM4Encryption;
+
+#[inline(always)]
+fn gf_multiply(x: u128, y: u128) -> u128 {
+    let mut z: u128 = 0;
+    let mut v = y;
+    for i in 0..128 {
+        let x_bit = (x >> (127 - i)) & 1;
+        z ^= v & 0u128.wrapping_sub(x_bit);
+        let v_bit = v & 1;
+        v = (v >> 1) ^ ((0xe1 << 120) & 0u128.wrapping_sub(v_bit));
+    }
+    z
+}
+
+#[inline(always)]
+fn increase_counter(counter: &mut [u8; SM4_BLOCK_BYTE_LENGTH]) {
+    let count = u32::from_be_bytes(counter[12..16].try_into().unwrap()).wrapping_add(1);
+    counter[12..16].copy_from_slice(&count.to_be_bytes());
+}
+
+#[inline(always)]
+fn check_parameter(nonce: &[u8], tag_byte_length: usize) -> Result<(), Error> {
+    if nonce.is_empty() {
+        return Err(Error::InvalidNonceLength);
+    }
+    if !(SM4_GCM_TAG_BYTE_MIN_LENGTH..=SM4_BLOCK_BYTE_LENGTH).contains(&tag_byte_length) {
+        return Err(Error::InvalidTagLength);
+    }
+    Ok(())
+}
+
+struct GHash {
+    h: u128,
+    y: u128,
+    remains_data: [u8; SM4_BLOCK_BYTE_LENGTH],
+    remains_data_length: usize,
+}
+
+impl GHash {
+    fn new(h: u128) -> GHash {
+        GHash {
+            h,
+            y: 0,
+            remains_data: [0; SM4_BLOCK_BYTE_LENGTH],
+            remains_data_length: 0,
+        }
+    }
+
+    fn update(&mut self, block: &[u8; SM4_BLOCK_BYTE_LENGTH]) {
+        self.y = gf_multiply(self.y ^ u128::from_be_bytes(*block), self.h);
+    }
+
+    fn push_data(&mut self, data: &[u8]) {
+        for &byte in data {
+            self.remains_data[self.remains_data_length] = byte;
+            self.remains_data_length += 1;
+            if self.remains_data_length == SM4_BLOCK_BYTE_LENGTH {
+                let block = self.remains_data;
+                self.update(&block);
+                self.remains_data_length = 0;
+            }
+        }
+    }
+
+    fn pad_data(&mut self) {
+        if self.remains_data_length != 0 {
+            self.remains_data[self.remains_data_length..].fill(0);
+            let block = self.remains_data;
+            self.update(&block);
+            self.remains_data_length = 0;
+        }
+    }
+}
+
+struct SM4GCMState {
+    sm4: SM4Encryption,
+    ghash: GHash,
+    first_counter: [u8; SM4_BLOCK_BYTE_LENGTH],
+    counter: [u8; SM4_BLOCK_BYTE_LENGTH],
+    key_stream: [u8; SM4_BLOCK_BYTE_LENGTH],
+    key_stream_offset: usize,
+    aad_length: u64,
+    data_length: u64,
+    data_pushed: bool,
+    data_length_exceeded: bool,
+    tag_byte_length: usize,
+}
+
+impl SM4GCMState {
+    fn new(key: [u8; KEY_BYTE_LENGTH], nonce: &[u8], tag_byte_length: usize) -> Result<SM4GCMState, Error> {
+        check_parameter(nonce, tag_byte_length)?;
+        let sm4 = SM4Encryption::with_key(key);
+        let mut h = [0; SM4_BLOCK_BYTE_LENGTH];
+        sm4.encrypt_block(&[0; SM4_BLOCK_BYTE_LENGTH], &mut h);
+        let h = u128::from_be_bytes(h);
+        let mut first_counter = [0; SM4_BLOCK_BYTE_LENGTH];
+        if nonce.len() == SM4_GCM_NONCE_BYTE_LENGTH {
+            first_counter[..SM4_GCM_NONCE_BYTE_LENGTH].copy_from_slice(nonce);
+            first_counter[SM4_BLOCK_BYTE_LENGTH - 1] = 0x01;
+        } else {
+            let mut nonce_ghash = GHash::new(h);
+            nonce_ghash.push_data(nonce);
+            nonce_ghash.pad_data();
+            let mut length_block = [0; SM4_BLOCK_BYTE_LENGTH];
+            length_block[8..].copy_from_slice(&((nonce.len() as u64) << 3).to_be_bytes());
+            nonce_ghash.update(&length_block);
+            first_counter = nonce_ghash.y.to_be_bytes();
+        }
+        let mut counter = first_counter;
+        increase_counter(&mut counter);
+        Ok(SM4GCMState {
+            sm4,
+            ghash: GHash::new(h),
+            first_counter,
+            counter,
+            key_stream: [0; SM4_BLOCK_BYTE_LENGTH],
+            key_stream_offset: SM4_BLOCK_BYTE_LENGTH,
+            aad_length: 0,
+            data_length: 0,
+            data_pushed: false,
+            data_length_exceeded: false,
+            tag_byte_length,
+        })
+    }
+
+    fn push_aad(&mut self, aad: &[u8]) -> Result<(), Error> {
+        if self.data_pushed {
+            return Err(Error::InvalidState);
+        }
+        self.aad_length += aad.len() as u64;
+        self.ghash.push_data(aad);
+        Ok(())
+    }
+
+    fn check_data_length(&mut self, data_length: usize) -> Result<(), Error> {
+        if self.data_length_exceeded || data_length as u64 > SM4_GCM_DATA_BYTE_MAX_LENGTH - self.data_length {
+            self.data_length_exceeded = true;
+            return Err(Error::InvalidDataLength);
+        }
+        Ok(())
+    }
+
+    fn push_enciphered_data(&mut self, enciphered_data: &[u8]) {
+        if !self.data_pushed {
+            self.ghash.pad_data();
+            self.data_pushed = true;
+        }
+        self.data_length += enciphered_data.len() as u64;
+        self.ghash.push_data(enciphered_data);
+    }
+
+    fn apply_key_stream(&mut self, data: &mut [u8]) {
+        for byte in data.iter_mut() {
+            if self.key_stream_offset == SM4_BLOCK_BYTE_LENGTH {
+                self.sm4.encrypt_block(&self.counter, &mut self.key_stream);
+                increase_counter(&mut self.counter);
+                self.key_stream_offset = 0;
+            }
+            *byte ^= self.key_stream[self.key_stream_offset];
+            self.key_stream_offset += 1;
+        }
+    }
+
+    fn compute_tag(&mut self) -> Result<[u8; SM4_BLOCK_BYTE_LENGTH], Error> {
+        if self.data_length_exceeded {
+            return Err(Error::InvalidDataLength);
+        }
+        self.ghash.pad_data();
+        let mut length_block = [0; SM4_BLOCK_BYTE_LENGTH];
+        length_block[..8].copy_from_slice(&(self.aad_length << 3).to_be_bytes());
+        length_block[8..].copy_from_slice(&(self.data_length << 3).to_be_bytes());
+        self.ghash.update(&length_block);
+        let mut tag = [0; SM4_BLOCK_BYTE_LENGTH];
+        self.sm4.encrypt_block(&self.first_counter, &mut tag);
+        Ok((u128::from_be_bytes(tag) ^ self.ghash.y).to_be_bytes())
+    }
+}
+
+pub struct SM4GCMEncryptor {
+    state: SM4GCMState,
+}
+
+impl SM4GCMEncryptor {
+    pub fn new(key: [u8; KEY_BYTE_LENGTH], nonce: &[u8], tag_byte_length: usize) -> Result<SM4GCMEncryptor, Error> {
+        Ok(SM4GCMEncryptor {
+            state: SM4GCMState::new(key, nonce, tag_byte_length)?,
+        })
+    }
+
+    pub fn try_new(key: &[u8], nonce: &[u8], tag_byte_length: usize) -> Result<SM4GCMEncryptor, Error> {
+        let key: [u8; KEY_BYTE_LENGTH] = key.try_into().map_err(|_| Error::InvalidKeyLength)?;
+        SM4GCMEncryptor::new(key, nonce, tag_byte_length)
+    }
+
+    pub fn push_aad(&mut self, aad: &[u8]) -> Result<(), Error> {
+        self.state.push_aad(aad)
+    }
+
+    pub fn encrypt_data(&mut self, origin_data: &[u8]) -> Result<Vec<u8>, Error> {
+        self.state.check_data_length(origin_data.len())?;
+        let mut enciphered_data = origin_data.to_vec();
+        self.state.apply_key_stream(&mut enciphered_data);
+        self.state.push_enciphered_data(&enciphered_data);
+        Ok(enciphered_data)
+    }
+
+    pub fn compute_tag(mut self) -> Result<Vec<u8>, Error> {
+        Ok(self.state.compute_tag()?[..self.state.tag_byte_length].to_vec())
+    }
+}
+
+pub struct SM4GCMDecryptor {
+    state: SM4GCMState,
+    enciphered_data: Vec<u8>,
+    tag_data: Vec<u8>,
+}
+
+impl SM4GCMDecryptor {
+    pub fn new(key: [u8; KEY_BYTE_LENGTH], nonce: &[u8], tag_byte_length: usize) -> Result<SM4GCMDecryptor, Error> {
+        Ok(SM4GCMDecryptor {
+            state: SM4GCMState::new(key, nonce, tag_byte_length)?,
+            enciphered_data: Vec::new(),
+            tag_data: Vec::new(),
+        })
+    }
+
+    pub fn try_new(key: &[u8], nonce: &[u8], tag_byte_length: usize) -> Result<SM4GCMDecryptor, Error> {
+        let key: [u8; KEY_BYTE_LENGTH] = key.try_into().map_err(|_| Error::InvalidKeyLength)?;
+        SM4GCMDecryptor::new(key, nonce, tag_byte_length)
+    }
+
+    pub fn push_aad(&mut self, aad: &[u8]) -> Result<(), Error> {
+        self.state.push_aad(aad)
+    }
+
+    pub fn push_data(&mut self, enciphered_data: &[u8]) -> Result<(), Error> {
+        self.state.check_data_length(enciphered_data.len())?;
+        self.state.push_enciphered_data(enciphered_data);
+        self.enciphered_data.extend_from_slice(enciphered_data);
+        Ok(())
+    }
+
+    pub fn decrypt_data(mut self, tag: &[u8]) -> Result<Vec<u8>, Error> {
+        if !constant_time_eq(&self.state.compute_tag()?[..self.state.tag_byte_length], tag) {
+            return Err(Error::AuthenticationFailed);
+        }
+        let mut origin_data = self.enciphered_data;
+        self.state.apply_key_stream(&mut origin_data);
+        Ok(origin_data)
+    }
+}
+
+impl StreamEncryptor for SM4GCMEncryptor {
+    fn update(&mut self, origin_data: &[u8]) -> Vec<u8> {
+        self.encrypt_data(origin_data).unwrap_or_default()
+    }
+
+    fn finalize(self) -> Result<Vec<u8>, Error> {
+        self.compute_tag()
+    }
+}
+
+impl StreamDecryptor for SM4GCMDecryptor {
+    fn update(&mut self, enciphered_data: &[u8]) -> Vec<u8> {
+        self.tag_data.extend_from_slice(enciphered_data);
+        let data_length = self.tag_data.len().saturating_sub(self.state.tag_byte_length);
+        let data: Vec<u8> = self.tag_data.drain(..data_length).collect();
+        let _ = self.push_data(&data);
+        Vec::new()
+    }
+
+    fn finalize(mut self) -> Result<Vec<u8>, Error> {
+        let tag = std::mem::take(&mut self.tag_data);
+        if tag.len() != self.state.tag_byte_length {
+            return Err(Error::InvalidDataLength);
+        }
+        self.decrypt_data(&tag)
+    }
+}
+
+pub fn sm4_gcm_encrypt_data(origin_data: &[u8], key: [u8; KEY_BYTE_LENGTH], nonce: &[u8], aad: &[u8], tag_byte_length: usize) -> Result<(Vec<u8>, Vec<u8>), Error> {
+    let mut encryptor = SM4GCMEncryptor::new(key, nonce, tag_byte_length)?;
+    encryptor.push_aad(aad)?;
+    let enciphered_data = encryptor.encrypt_data(origin_data)?;
+    Ok((enciphered_data, encryptor.compute_tag()?))
+}
+
+pub fn sm4_gcm_decrypt_data(enciphered_data: &[u8], key: [u8; KEY_BYTE_LENGTH], nonce: &[u8], aad: &[u8], tag: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut decryptor = SM4GCMDecryptor::new(key, nonce, tag.len())?;
+    decryptor.push_aad(aad)?;
+    decryptor.push_data(enciphered_data)?;
+    decryptor.decrypt_data(tag)
+}
+
+pub fn sm4_gcm_encrypt_data_in_place(data: &mut [u8], key: [u8; KEY_BYTE_LENGTH], nonce: &[u8], aad: &[u8], tag: &mut [u8]) -> Result<(), Error> {
+    let mut state = SM4GCMState::new(key, nonce, tag.len())?;
+    state.push_aad(aad)?;
+    state.check_data_length(data.len())?;
+    state.apply_key_stream(data);
+    state.push_enciphered_data(data);
+    tag.copy_from_slice(&state.compute_tag()?[..tag.len()]);
+    Ok(())
+}
+
+pub fn sm4_gcm_decrypt_data_in_place(data: &mut [u8], key: [u8; KEY_BYTE_LENGTH], nonce: &[u8], aad: &[u8], tag: &[u8]) -> Result<(), Error> {
+    let mut state = SM4GCMState::new(key, nonce, tag.len())?;
+    state.push_aad(aad)?;
+    state.check_data_length(data.len())?;
+    state.push_enciphered_data(data);
+    if !constant_time_eq(&state.compute_tag()?[..tag.len()], tag) {
+        return Err(Error::AuthenticationFailed);
+    }
+    state.apply_key_stream(data);
+    Ok(())
+}
+
+pub fn sm4_gcm_encrypt_data_into(origin_data: &[u8], enciphered_data: &mut [u8], key: [u8; KEY_BYTE_LENGTH], nonce: &[u8], aad: &[u8], tag: &mut [u8]) -> Result<(), Error> {
+    if origin_data.len() != enciphered_data.len() {
+        return Err(Error::InvalidDataLength);
+    }
+    enciphered_data.copy_from_slice(origin_data);
+    sm4_gcm_encrypt_data_in_place(enciphered_data, key, nonce, aad, tag)
+}
+
+pub fn sm4_gcm_decrypt_data_into(enciphered_data: &[u8], origin_data: &mut [u8], key: [u8; KEY_BYTE_LENGTH], nonce: &[u8], aad: &[u8], tag: &[u8]) -> Result<(), Error> {
+    if enciphered_data.len() != origin_data.len() {
+        return Err(Error::InvalidDataLength);
+    }
+    origin_data.copy_from_slice(enciphered_data);
+    sm4_gcm_decrypt_data_in_place(origin_data, key, nonce, aad, tag)
+}
+
+pub fn try_sm4_gcm_encrypt_data(origin_data: &[u8], key: &[u8], nonce: &[u8], aad: &[u8], tag_byte_length: usize) -> Result<(Vec<u8>, Vec<u8>), Error> {
+    let key: [u8; KEY_BYTE_LENGTH] = key.try_into().map_err(|_| Error::InvalidKeyLength)?;
+    sm4_gcm_encrypt_data(origin_data, key, nonce, aad, tag_byte_length)
+}
+
+pub fn try_sm4_gcm_decrypt_data(enciphered_data: &[u8], key: &[u8], nonce: &[u8], aad: &[u8], tag: &[u8]) -> Result<Vec<u8>, Error> {
+    let key: [u8; KEY_BYTE_LENGTH] = key.try_into().map_err(|_| Error::InvalidKeyLength)?;
+    sm4_gcm_decrypt_data(enciphered_data, key, nonce, aad, tag)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::util::hex::{hex_decode, hex_encode};
+    use super::super::super::block_mode::stream_trait::StreamDecryptor;
+    use super::super::sm4_constant::SM4_GCM_DATA_BYTE_MAX_LENGTH;
+    use super::{SM4GCMDecryptor, SM4GCMEncryptor, sm4_gcm_decrypt_data, sm4_gcm_encrypt_data};
+
+    const KEY: [u8; 16] = [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0xfe, 0xdc, 0xba, 0x98, 0x76, 0x54, 0x32, 0x10];
+    const NONCE: &str = "00001234567800000000abcd";
+    const AAD: &str = "feedfacedeadbeeffeedfacedeadbeefabaddad2";
+    const ORIGIN_DATA: &str = concat!(
+        "aaaaaaaaaaaaaaaabbbbbbbbbbbbbbbbccccccccccccccccdddddddddddddddd",
+        "eeeeeeeeeeeeeeeeffffffffffffffffeeeeeeeeeeeeeeeeaaaaaaaaaaaaaaaa",
+    );
+    const ENCIPHERED_DATA: &str = concat!(
+        "17f399f08c67d5ee19d0dc9969c4bb7d5fd46fd3756489069157b282bb200735",
+        "d82710ca5c22f0ccfa7cbf93d496ac15a56834cbcf98c397b4024a2691233b8d",
+    );
+    const TAG: &str = "83de3541e4c2b58177e065a9bf7b62ec";
+
+    fn hex(data: &str) -> Vec<u8> {
+        hex_decode(data).unwrap()
+    }
+
+    #[test]
+    fn rfc_8998_encrypt() {
+        let (enciphered_data, tag) = sm4_gcm_encrypt_data(&hex(ORIGIN_DATA), KEY, &hex(NONCE), &hex(AAD), 16).unwrap();
+        assert_eq!(hex_encode(&enciphered_data), ENCIPHERED_DATA);
+        assert_eq!(hex_encode(&tag), TAG);
+    }
+
+    #[test]
+    fn rfc_8998_decrypt() {
+        let origin_data = sm4_gcm_decrypt_data(&hex(ENCIPHERED_DATA), KEY, &hex(NONCE), &hex(AAD), &hex(TAG)).unwrap();
+        assert_eq!(hex_encode(&origin_data), ORIGIN_DATA);
+    }
+
+    #[test]
+    fn rfc_8998_streaming_decrypt() {
+        let mut decryptor = SM4GCMDecryptor::new(KEY, &hex(NONCE), 16).unwrap();
+        decryptor.push_aad(&hex(AAD)).unwrap();
+        let mut enciphered_data = hex(ENCIPHERED_DATA);
+        enciphered_data.extend_from_slice(&hex(TAG));
+        for piece in enciphered_data.chunks(5) {
+            assert!(decryptor.update(piece).is_empty());
+        }
+        assert_eq!(hex_encode(&decryptor.finalize().unwrap()), ORIGIN_DATA);
+    }
+
+    #[test]
+    fn truncated_tag_is_prefix_of_full_tag() {
+        let (_, tag) = sm4_gcm_encrypt_data(&hex(ORIGIN_DATA), KEY, &hex(NONCE), &hex(AAD), 12).unwrap();
+        assert_eq!(hex_encode(&tag), TAG[..24]);
+    }
+
+    #[test]
+    fn tag_mismatch_is_rejected() {
+        let mut tag = hex(TAG);
+        tag[15] ^= 0x01;
+        assert!(matches!(sm4_gcm_decrypt_data(&hex(ENCIPHERED_DATA), KEY, &hex(NONCE), &hex(AAD), &tag), Err(Error::AuthenticationFailed)));
+        let mut enciphered_data = hex(ENCIPHERED_DATA);
+        enciphered_data[0] ^= 0x80;
+        assert!(matches!(sm4_gcm_decrypt_data(&enciphered_data, KEY, &hex(NONCE), &hex(AAD), &hex(TAG)), Err(Error::AuthenticationFailed)));
+        assert!(matches!(sm4_gcm_decrypt_data(&hex(ENCIPHERED_DATA), KEY, &hex(NONCE), &hex(AAD)[1..], &hex(TAG)), Err(Error::AuthenticationFailed)));
+    }
+
+    #[test]
+    fn aad_after_data_is_rejected() {
+        let mut encryptor = SM4GCMEncryptor::new(KEY, &hex(NONCE), 16).unwrap();
+        encryptor.push_aad(&hex(AAD)).unwrap();
+        encryptor.encrypt_data(&hex(ORIGIN_DATA)).unwrap();
+        assert!(matches!(encryptor.push_aad(&hex(AAD)), Err(Error::InvalidState)));
+    }
+
+    #[test]
+    fn data_length_limit_is_enforced() {
+        let mut encryptor = SM4GCMEncryptor::new(KEY, &hex(NONCE), 16).unwrap();
+        encryptor.state.data_length = SM4_GCM_DATA_BYTE_MAX_LENGTH - 64;
+        assert_eq!(encryptor.encrypt_data(&hex(ORIGIN_DATA)).unwrap().len(), 64);
+        assert!(matches!(encryptor.encrypt_data(&[0; 1]), Err(Error::InvalidDataLength)));
+        assert!(encryptor.encrypt_data(&[]).is_err());
+        assert!(matches!(encryptor.compute_tag(), Err(Error::InvalidDataLength)));
+        let mut decryptor = SM4GCMDecryptor::new(KEY, &hex(NONCE), 16).unwrap();
+        decryptor.state.data_length = SM4_GCM_DATA_BYTE_MAX_LENGTH - 63;
+        assert!(matches!(decryptor.push_data(&hex(ENCIPHERED_DATA)), Err(Error::InvalidDataLength)));
+        assert!(decryptor.enciphered_data.is_empty());
+        assert!(matches!(decryptor.decrypt_data(&hex(TAG)), Err(Error::InvalidDataLength)));
+    }
+
+    #[test]
+    fn invalid_parameters_are_rejected() {
+        assert!(matches!(SM4GCMEncryptor::new(KEY, &[], 16), Err(Error::InvalidNonceLength)));
+        assert!(matches!(SM4GCMEncryptor::new(KEY, &hex(NONCE), 17), Err(Error::InvalidTagLength)));
+        assert!(matches!(SM4GCMEncryptor::try_new(&KEY[1..], &hex(NONCE), 16), Err(Error::InvalidKeyLength)));
+    }
+}
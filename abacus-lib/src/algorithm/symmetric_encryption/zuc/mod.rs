@@ -0,0 +1,6 @@
+pub mod zuc_constant;
+pub mod zuc_encryption;
+pub mod zuc_stream;
+pub mod zuc_eea3;
+pub mod zuc_eia3;
+pub mod zuc256_mac;
@@ -0,0 +1,78 @@
+use crate::error::Error;
+use crate::util::constant_time::constant_time_eq;
+use super::zuc_constant::{ZUC256_KEY_BYTE_LENGTH, ZUC256_IV_BYTE_LENGTH, ZUC_WORD_BYTE_LENGTH, ZUC256_MAC_BYTE_LENGTHS, ZUC256_MAC_32_D, ZUC256_MAC_64_D, ZUC256_MAC_128_D};
+use super::zuc_encryption::{ZUCEncryption, key_stream_word};
+
+#[inline(always)]
+fn check_parameter(mac_byte_length: usize) -> Result<(), Error> {
+    if !ZUC256_MAC_BYTE_LENGTHS.contains(&mac_byte_length) {
+        return Err(Error::InvalidTagLength);
+    }
+    Ok(())
+}
+
+#[allow(clippy::needless_range_loop)]
+pub fn zuc256_mac_data_digest(data: &[u8], key: [u8; ZUC256_KEY_BYTE_LENGTH], iv: [u8; ZUC256_IV_BYTE_LENGTH], mac_byte_length: usize) -> Result<Vec<u8>, Error> {
+    check_parameter(mac_byte_length)?;
+    let d = match mac_byte_length {
+        4 => &ZUC256_MAC_32_D,
+        8 => &ZUC256_MAC_64_D,
+        _ => &ZUC256_MAC_128_D,
+    };
+    let mut zuc = ZUCEncryption::with_zuc256_constant(key, iv, d);
+    let mac_bit_length = mac_byte_length * 8;
+    let bit_length = data.len() * 8;
+    let mut key_stream = vec![0; (bit_length + mac_bit_length * 2) / 32 + 1];
+    zuc.generate_key_stream(&mut key_stream);
+    let mac_word_length = mac_byte_length / ZUC_WORD_BYTE_LENGTH;
+    let mut t: Vec<u32> = key_stream[..mac_word_length].to_vec();
+    for i in 0..bit_length {
+        if (data[i / 8] >> (7 - i % 8)) & 1 == 1 {
+            for j in 0..mac_word_length {
+                t[j] ^= key_stream_word(&key_stream, mac_bit_length + i + j * 32);
+            }
+        }
+    }
+    for j in 0..mac_word_length {
+        t[j] ^= key_stream_word(&key_stream, mac_bit_length + bit_length + j * 32);
+    }
+    Ok(t.iter().flat_map(|w| w.to_be_bytes()).collect())
+}
+
+pub fn zuc256_mac_data_verify(data: &[u8], key: [u8; ZUC256_KEY_BYTE_LENGTH], iv: [u8; ZUC256_IV_BYTE_LENGTH], mac: &[u8]) -> bool {
+    match zuc256_mac_data_digest(data, key, iv, mac.len()) {
+        Ok(digest) => constant_time_eq(&digest, mac),
+        Err(_) => false,
+    }
+}
+
+pub fn try_zuc256_mac_data_digest(data: &[u8], key: &[u8], iv: &[u8], mac_byte_length: usize) -> Result<Vec<u8>, Error> {
+    let key: [u8; ZUC256_KEY_BYTE_LENGTH] = key.try_into().map_err(|_| Error::InvalidKeyLength)?;
+    let iv: [u8; ZUC256_IV_BYTE_LENGTH] = iv.try_into().map_err(|_| Error::InvalidIVLength)?;
+    zuc256_mac_data_digest(data, key, iv, mac_byte_length)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::util::hex::{hex_decode, hex_encode};
+    use super::{try_zuc256_mac_data_digest, zuc256_mac_data_digest, zuc256_mac_data_verify};
+
+    #[test]
+    fn zero_key_and_iv_vectors() {
+        let data = [0; 50];
+        for (mac_byte_length, expected) in [(4, "9b972a74"), (8, "673e54990034d38c"), (16, "d85e54bbcb9600967084c952a1654b26")] {
+            let mac = zuc256_mac_data_digest(&data, [0; 32], [0; 25], mac_byte_length).unwrap();
+            assert_eq!(hex_encode(&mac), expected);
+            assert!(zuc256_mac_data_verify(&data, [0; 32], [0; 25], &mac));
+        }
+    }
+
+    #[test]
+    fn invalid_parameters_are_rejected() {
+        assert!(matches!(zuc256_mac_data_digest(&[0; 50], [0; 32], [0; 25], 12), Err(Error::InvalidTagLength)));
+        assert!(!zuc256_mac_data_verify(&[0; 50], [0; 32], [0; 25], &hex_decode("9b972a").unwrap()));
+        assert!(matches!(try_zuc256_mac_data_digest(&[0; 50], &[0; 31], &[0; 25], 4), Err(Error::InvalidKeyLength)));
+        assert!(matches!(try_zuc256_mac_data_digest(&[0; 50], &[0; 32], &[0; 24], 4), Err(Error::InvalidIVLength)));
+    }
+}
@@ -0,0 +1,124 @@
+use crate::error::Error;
+use super::zuc_constant::{ZUC_KEY_BYTE_LENGTH, ZUC_IV_BYTE_LENGTH, ZUC_BEARER_MAX, ZUC_DIRECTION_MAX};
+use super::zuc_encryption::ZUCEncryption;
+
+#[inline(always)]
+fn check_parameter(data_length: usize, bearer: u8, direction: u8, bit_length: usize) -> Result<(), Error> {
+    if bearer > ZUC_BEARER_MAX || direction > ZUC_DIRECTION_MAX {
+        return Err(Error::InvalidParameter);
+    }
+    if bit_length.div_ceil(8) != data_length {
+        return Err(Error::InvalidDataLength);
+    }
+    Ok(())
+}
+
+fn eea3_iv(count: u32, bearer: u8, direction: u8) -> [u8; ZUC_IV_BYTE_LENGTH] {
+    let mut iv = [0; ZUC_IV_BYTE_LENGTH];
+    iv[..4].copy_from_slice(&count.to_be_bytes());
+    iv[4] = (bearer << 3) | (direction << 2);
+    iv.copy_within(..8, 8);
+    iv
+}
+
+pub fn zuc_eea3_encrypt_data_in_place(data: &mut [u8], key: [u8; ZUC_KEY_BYTE_LENGTH], count: u32, bearer: u8, direction: u8, bit_length: usize) -> Result<(), Error> {
+    check_parameter(data.len(), bearer, direction, bit_length)?;
+    ZUCEncryption::new(key, eea3_iv(count, bearer, direction)).apply_key_stream(data);
+    if !bit_length.is_multiple_of(8) {
+        data[bit_length / 8] &= 0xFF << (8 - bit_length % 8);
+    }
+    Ok(())
+}
+
+pub fn zuc_eea3_decrypt_data_in_place(data: &mut [u8], key: [u8; ZUC_KEY_BYTE_LENGTH], count: u32, bearer: u8, direction: u8, bit_length: usize) -> Result<(), Error> {
+    zuc_eea3_encrypt_data_in_place(data, key, count, bearer, direction, bit_length)
+}
+
+pub fn zuc_eea3_encrypt_data(origin_data: &[u8], key: [u8; ZUC_KEY_BYTE_LENGTH], count: u32, bearer: u8, direction: u8, bit_length: usize) -> Result<Vec<u8>, Error> {
+    let mut enciphered_data = origin_data.to_vec();
+    zuc_eea3_encrypt_data_in_place(&mut enciphered_data, key, count, bearer, direction, bit_length)?;
+    Ok(enciphered_data)
+}
+
+pub fn zuc_eea3_decrypt_data(enciphered_data: &[u8], key: [u8; ZUC_KEY_BYTE_LENGTH], count: u32, bearer: u8, direction: u8, bit_length: usize) -> Result<Vec<u8>, Error> {
+    zuc_eea3_encrypt_data(enciphered_data, key, count, bearer, direction, bit_length)
+}
+
+pub fn try_zuc_eea3_encrypt_data(origin_data: &[u8], key: &[u8], count: u32, bearer: u8, direction: u8, bit_length: usize) -> Result<Vec<u8>, Error> {
+    let key: [u8; ZUC_KEY_BYTE_LENGTH] = key.try_into().map_err(|_| Error::InvalidKeyLength)?;
+    zuc_eea3_encrypt_data(origin_data, key, count, bearer, direction, bit_length)
+}
+
+pub fn try_zuc_eea3_decrypt_data(enciphered_data: &[u8], key: &[u8], count: u32, bearer: u8, direction: u8, bit_length: usize) -> Result<Vec<u8>, Error> {
+    try_zuc_eea3_encrypt_data(enciphered_data, key, count, bearer, direction, bit_length)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::util::hex::{hex_decode, hex_encode};
+    use super::{try_zuc_eea3_encrypt_data, zuc_eea3_decrypt_data, zuc_eea3_encrypt_data};
+
+    struct TestSet {
+        key: &'static str,
+        count: u32,
+        bearer: u8,
+        direction: u8,
+        bit_length: usize,
+        origin_data: &'static str,
+        enciphered_data: &'static str,
+    }
+
+    const TEST_SETS: [TestSet; 2] = [
+        TestSet {
+            key: "173d14ba5003731d7a60049470f00a29",
+            count: 0x66035492,
+            bearer: 0x0f,
+            direction: 0,
+            bit_length: 193,
+            origin_data: "6cf65340735552ab0c9752fa6f9025fe0bd675d9005875b200",
+            enciphered_data: "a6c85fc66afb8533aafc2518dfe784940ee1e4b030238cc800",
+        },
+        TestSet {
+            key: "e5bd3ea0eb55ade866c6ac58bd54302a",
+            count: 0x00056823,
+            bearer: 0x18,
+            direction: 1,
+            bit_length: 800,
+            origin_data: concat!(
+                "14a8ef693d678507bbe7270a7f67ff5006c3525b9807e467c4e56000ba338f5d",
+                "429559036751822246c80d3b38f07f4be2d8ff5805f5132229bde93bbbdcaf38",
+                "2bf1ee972fbf9977bada8945847a2a6c9ad34a667554e04d1f7fa2c33241bd8f",
+                "01ba220d",
+            ),
+            enciphered_data: concat!(
+                "131d43e0dea1be5c5a1bfd971d852cbf712d7b4f57961fea3208afa8bca433f4",
+                "56ad09c7417e58bc69cf8866d1353f74865e80781d202dfb3ecff7fcbc3b190f",
+                "e82a204ed0e350fc0f6f2613b2f2bca6df5a473a57a4a00d985ebad880d6f238",
+                "64a07b01",
+            ),
+        },
+    ];
+
+    #[test]
+    fn eea3_test_sets() {
+        for test_set in TEST_SETS.iter() {
+            let key: [u8; 16] = hex_decode(test_set.key).unwrap().try_into().unwrap();
+            let origin_data = hex_decode(test_set.origin_data).unwrap();
+            let enciphered_data = zuc_eea3_encrypt_data(&origin_data, key, test_set.count, test_set.bearer, test_set.direction, test_set.bit_length).unwrap();
+            assert_eq!(hex_encode(&enciphered_data), test_set.enciphered_data);
+            let deciphered_data = zuc_eea3_decrypt_data(&enciphered_data, key, test_set.count, test_set.bearer, test_set.direction, test_set.bit_length).unwrap();
+            assert_eq!(deciphered_data, origin_data);
+        }
+    }
+
+    #[test]
+    fn data_length_must_match_bit_length() {
+        let key = [0; 16];
+        assert!(zuc_eea3_encrypt_data(&[0; 25], key, 0, 0, 0, 193).is_ok());
+        assert!(matches!(zuc_eea3_encrypt_data(&[0; 24], key, 0, 0, 0, 193), Err(Error::InvalidDataLength)));
+        assert!(matches!(zuc_eea3_encrypt_data(&[0; 26], key, 0, 0, 0, 193), Err(Error::InvalidDataLength)));
+        assert!(matches!(zuc_eea3_encrypt_data(&[0; 1], key, 0, 0x20, 0, 8), Err(Error::InvalidParameter)));
+        assert!(matches!(try_zuc_eea3_encrypt_data(&[0; 1], &key[..15], 0, 0, 0, 8), Err(Error::InvalidKeyLength)));
+    }
+}
@@ -0,0 +1,123 @@
+use crate::error::Error;
+use crate::util::constant_time::constant_time_eq;
+use super::zuc_constant::{ZUC_KEY_BYTE_LENGTH, ZUC_IV_BYTE_LENGTH, ZUC_MAC_BYTE_LENGTH, ZUC_BEARER_MAX, ZUC_DIRECTION_MAX};
+use super::zuc_encryption::{ZUCEncryption, key_stream_word};
+
+#[inline(always)]
+fn check_parameter(data_length: usize, bearer: u8, direction: u8, bit_length: usize) -> Result<(), Error> {
+    if bearer > ZUC_BEARER_MAX || direction > ZUC_DIRECTION_MAX {
+        return Err(Error::InvalidParameter);
+    }
+    if bit_length.div_ceil(8) != data_length {
+        return Err(Error::InvalidDataLength);
+    }
+    Ok(())
+}
+
+fn eia3_iv(count: u32, bearer: u8, direction: u8) -> [u8; ZUC_IV_BYTE_LENGTH] {
+    let mut iv = [0; ZUC_IV_BYTE_LENGTH];
+    iv[..4].copy_from_slice(&count.to_be_bytes());
+    iv[4] = bearer << 3;
+    iv.copy_within(..8, 8);
+    iv[8] ^= direction << 7;
+    iv[14] ^= direction << 7;
+    iv
+}
+
+pub fn zuc_eia3_data_digest(data: &[u8], key: [u8; ZUC_KEY_BYTE_LENGTH], count: u32, bearer: u8, direction: u8, bit_length: usize) -> Result<[u8; ZUC_MAC_BYTE_LENGTH], Error> {
+    check_parameter(data.len(), bearer, direction, bit_length)?;
+    let mut zuc = ZUCEncryption::new(key, eia3_iv(count, bearer, direction));
+    let mut key_stream = vec![0; bit_length.div_ceil(32) + 2];
+    zuc.generate_key_stream(&mut key_stream);
+    let mut t: u32 = 0;
+    for i in 0..bit_length {
+        if (data[i / 8] >> (7 - i % 8)) & 1 == 1 {
+            t ^= key_stream_word(&key_stream, i);
+        }
+    }
+    t ^= key_stream_word(&key_stream, bit_length);
+    t ^= key_stream[key_stream.len() - 1];
+    Ok(t.to_be_bytes())
+}
+
+pub fn zuc_eia3_data_verify(data: &[u8], key: [u8; ZUC_KEY_BYTE_LENGTH], count: u32, bearer: u8, direction: u8, bit_length: usize, mac: &[u8]) -> bool {
+    match zuc_eia3_data_digest(data, key, count, bearer, direction, bit_length) {
+        Ok(digest) => constant_time_eq(&digest, mac),
+        Err(_) => false,
+    }
+}
+
+pub fn try_zuc_eia3_data_digest(data: &[u8], key: &[u8], count: u32, bearer: u8, direction: u8, bit_length: usize) -> Result<[u8; ZUC_MAC_BYTE_LENGTH], Error> {
+    let key: [u8; ZUC_KEY_BYTE_LENGTH] = key.try_into().map_err(|_| Error::InvalidKeyLength)?;
+    zuc_eia3_data_digest(data, key, count, bearer, direction, bit_length)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::util::hex::{hex_decode, hex_encode};
+    use super::{zuc_eia3_data_digest, zuc_eia3_data_verify};
+
+    struct TestSet {
+        key: &'static str,
+        count: u32,
+        bearer: u8,
+        direction: u8,
+        bit_length: usize,
+        data: &'static str,
+        mac: &'static str,
+    }
+
+    const TEST_SETS: [TestSet; 3] = [
+        TestSet {
+            key: "00000000000000000000000000000000",
+            count: 0,
+            bearer: 0,
+            direction: 0,
+            bit_length: 1,
+            data: "00",
+            mac: "c8a9595e",
+        },
+        TestSet {
+            key: "47054125561eb2dda94059da05097850",
+            count: 0x561eb2dd,
+            bearer: 0x14,
+            direction: 0,
+            bit_length: 90,
+            data: "000000000000000000000000",
+            mac: "6719a088",
+        },
+        TestSet {
+            key: "c9e6cec4607c72db000aefa88385ab0a",
+            count: 0xa94059da,
+            bearer: 0x0a,
+            direction: 1,
+            bit_length: 577,
+            data: concat!(
+                "983b41d47d780c9e1ad11d7eb70391b1de0b35da2dc62f83e7b78d6306ca0ea0",
+                "7e941b7be91348f9fcb170e2217fecd97f9f68adb16e5d7d21e569d280ed775c",
+                "ebde3f4093c5388100",
+            ),
+            mac: "fae8ff0b",
+        },
+    ];
+
+    #[test]
+    fn eia3_test_sets() {
+        for test_set in TEST_SETS.iter() {
+            let key: [u8; 16] = hex_decode(test_set.key).unwrap().try_into().unwrap();
+            let data = hex_decode(test_set.data).unwrap();
+            let mac = zuc_eia3_data_digest(&data, key, test_set.count, test_set.bearer, test_set.direction, test_set.bit_length).unwrap();
+            assert_eq!(hex_encode(&mac), test_set.mac);
+            assert!(zuc_eia3_data_verify(&data, key, test_set.count, test_set.bearer, test_set.direction, test_set.bit_length, &mac));
+        }
+    }
+
+    #[test]
+    fn data_length_must_match_bit_length() {
+        let key = [0; 16];
+        assert!(matches!(zuc_eia3_data_digest(&[0; 2], key, 0, 0, 0, 1), Err(Error::InvalidDataLength)));
+        assert!(matches!(zuc_eia3_data_digest(&[], key, 0, 0, 0, 1), Err(Error::InvalidDataLength)));
+        assert!(!zuc_eia3_data_verify(&[0; 2], key, 0, 0, 0, 1, &hex_decode("c8a9595e").unwrap()));
+    }
+}
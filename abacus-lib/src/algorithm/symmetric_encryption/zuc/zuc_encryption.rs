@@ -0,0 +1,257 @@
+use crate::error::Error;
+use super::zuc_constant::{ZUC_KEY_BYTE_LENGTH, ZUC_IV_BYTE_LENGTH, ZUC256_KEY_BYTE_LENGTH, ZUC256_IV_BYTE_LENGTH, ZUC_LFSR_LENGTH, ZUC_WORD_BYTE_LENGTH, ZUC_S0, ZUC_S1, ZUC_D, ZUC256_D};
+
+const LFSR_MODULUS: u32 = 0x7fffffff;
+
+#[inline(always)]
+fn add_modulo(a: u32, b: u32) -> u32 {
+    let c = a + b;
+    (c & LFSR_MODULUS) + (c >> 31)
+}
+
+#[inline(always)]
+fn rotate_left_modulo(a: u32, k: u32) -> u32 {
+    ((a << k) | (a >> (31 - k))) & LFSR_MODULUS
+}
+
+#[inline(always)]
+fn l1(x: u32) -> u32 {
+    x ^ x.rotate_left(2) ^ x.rotate_left(10) ^ x.rotate_left(18) ^ x.rotate_left(24)
+}
+
+#[inline(always)]
+fn l2(x: u32) -> u32 {
+    x ^ x.rotate_left(8) ^ x.rotate_left(14) ^ x.rotate_left(22) ^ x.rotate_left(30)
+}
+
+#[inline(always)]
+fn s(x: u32) -> u32 {
+    let xx = x.to_be_bytes();
+    u32::from_be_bytes([ZUC_S0[xx[0] as usize], ZUC_S1[xx[1] as usize], ZUC_S0[xx[2] as usize], ZUC_S1[xx[3] as usize]])
+}
+
+#[inline(always)]
+fn make_lfsr_word(a: u8, d: u32, b: u8, c: u8) -> u32 {
+    ((a as u32) << 23) | (d << 16) | ((b as u32) << 8) | c as u32
+}
+
+pub(crate) fn key_stream_word(key_stream: &[u32], bit_position: usize) -> u32 {
+    let index = bit_position / 32;
+    let offset = (bit_position % 32) as u32;
+    if offset == 0 {
+        key_stream[index]
+    } else {
+        (key_stream[index] << offset) | (key_stream[index + 1] >> (32 - offset))
+    }
+}
+
+pub struct ZUCEncryption {
+    lfsr: [u32; ZUC_LFSR_LENGTH],
+    r1: u32,
+    r2: u32,
+    x: [u32; 4],
+    key_word: [u8; ZUC_WORD_BYTE_LENGTH],
+    key_word_offset: usize,
+}
+
+impl ZUCEncryption {
+    fn with_lfsr(lfsr: [u32; ZUC_LFSR_LENGTH]) -> ZUCEncryption {
+        let mut instance = ZUCEncryption {
+            lfsr,
+            r1: 0,
+            r2: 0,
+            x: [0; 4],
+            key_word: [0; ZUC_WORD_BYTE_LENGTH],
+            key_word_offset: ZUC_WORD_BYTE_LENGTH,
+        };
+        for _ in 0..32 {
+            instance.bit_reorganization();
+            let w = instance.f();
+            instance.lfsr_with_initialization_mode(w >> 1);
+        }
+        instance.bit_reorganization();
+        instance.f();
+        instance.lfsr_with_work_mode();
+        instance
+    }
+
+    pub fn new(key: [u8; ZUC_KEY_BYTE_LENGTH], iv: [u8; ZUC_IV_BYTE_LENGTH]) -> ZUCEncryption {
+        let mut lfsr = [0; ZUC_LFSR_LENGTH];
+        for i in 0..ZUC_LFSR_LENGTH {
+            lfsr[i] = ((key[i] as u32) << 23) | (ZUC_D[i] << 8) | iv[i] as u32;
+        }
+        ZUCEncryption::with_lfsr(lfsr)
+    }
+
+    pub fn try_new(key: &[u8], iv: &[u8]) -> Result<ZUCEncryption, Error> {
+        let key: [u8; ZUC_KEY_BYTE_LENGTH] = key.try_into().map_err(|_| Error::InvalidKeyLength)?;
+        let iv: [u8; ZUC_IV_BYTE_LENGTH] = iv.try_into().map_err(|_| Error::InvalidIVLength)?;
+        Ok(ZUCEncryption::new(key, iv))
+    }
+
+    pub fn new_zuc256(key: [u8; ZUC256_KEY_BYTE_LENGTH], iv: [u8; ZUC256_IV_BYTE_LENGTH]) -> ZUCEncryption {
+        ZUCEncryption::with_zuc256_constant(key, iv, &ZUC256_D)
+    }
+
+    pub fn try_new_zuc256(key: &[u8], iv: &[u8]) -> Result<ZUCEncryption, Error> {
+        let key: [u8; ZUC256_KEY_BYTE_LENGTH] = key.try_into().map_err(|_| Error::InvalidKeyLength)?;
+        let iv: [u8; ZUC256_IV_BYTE_LENGTH] = iv.try_into().map_err(|_| Error::InvalidIVLength)?;
+        if iv[17..].iter().any(|b| *b > 0x3F) {
+            return Err(Error::InvalidParameter);
+        }
+        Ok(ZUCEncryption::new_zuc256(key, iv))
+    }
+
+    pub(crate) fn with_zuc256_constant(key: [u8; ZUC256_KEY_BYTE_LENGTH], iv: [u8; ZUC256_IV_BYTE_LENGTH], d: &[u32; ZUC_LFSR_LENGTH]) -> ZUCEncryption {
+        let k = key;
+        let mut v = [0u32; ZUC256_IV_BYTE_LENGTH];
+        for i in 0..ZUC256_IV_BYTE_LENGTH {
+            v[i] = if i < 17 {iv[i] as u32} else {(iv[i] & 0x3F) as u32};
+        }
+        let lfsr = [
+            make_lfsr_word(k[0], d[0], k[21], k[16]),
+            make_lfsr_word(k[1], d[1], k[22], k[17]),
+            make_lfsr_word(k[2], d[2], k[23], k[18]),
+            make_lfsr_word(k[3], d[3], k[24], k[19]),
+            make_lfsr_word(k[4], d[4], k[25], k[20]),
+            make_lfsr_word(iv[0], d[5] | v[17], k[5], k[26]),
+            make_lfsr_word(iv[1], d[6] | v[18], k[6], k[27]),
+            make_lfsr_word(iv[10], d[7] | v[19], k[7], iv[2]),
+            make_lfsr_word(k[8], d[8] | v[20], iv[3], iv[11]),
+            make_lfsr_word(k[9], d[9] | v[21], iv[12], iv[4]),
+            make_lfsr_word(iv[5], d[10] | v[22], k[10], k[28]),
+            make_lfsr_word(k[11], d[11] | v[23], iv[6], iv[13]),
+            make_lfsr_word(k[12], d[12] | v[24], iv[7], iv[14]),
+            make_lfsr_word(k[13], d[13], iv[15], iv[8]),
+            make_lfsr_word(k[14], d[14] | (k[31] >> 4) as u32, iv[16], iv[9]),
+            make_lfsr_word(k[15], d[15] | (k[31] & 0x0F) as u32, k[30], k[29]),
+        ];
+        ZUCEncryption::with_lfsr(lfsr)
+    }
+
+    fn bit_reorganization(&mut self) {
+        let s = &self.lfsr;
+        self.x[0] = ((s[15] & 0x7fff8000) << 1) | (s[14] & 0xffff);
+        self.x[1] = ((s[11] & 0xffff) << 16) | (s[9] >> 15);
+        self.x[2] = ((s[7] & 0xffff) << 16) | (s[5] >> 15);
+        self.x[3] = ((s[2] & 0xffff) << 16) | (s[0] >> 15);
+    }
+
+    fn f(&mut self) -> u32 {
+        let w = (self.x[0] ^ self.r1).wrapping_add(self.r2);
+        let w1 = self.r1.wrapping_add(self.x[1]);
+        let w2 = self.r2 ^ self.x[2];
+        self.r1 = s(l1((w1 << 16) | (w2 >> 16)));
+        self.r2 = s(l2((w2 << 16) | (w1 >> 16)));
+        w
+    }
+
+    fn lfsr_feedback(&self) -> u32 {
+        let s = &self.lfsr;
+        let mut f = s[0];
+        f = add_modulo(f, rotate_left_modulo(s[0], 8));
+        f = add_modulo(f, rotate_left_modulo(s[4], 20));
+        f = add_modulo(f, rotate_left_modulo(s[10], 21));
+        f = add_modulo(f, rotate_left_modulo(s[13], 17));
+        f = add_modulo(f, rotate_left_modulo(s[15], 15));
+        f
+    }
+
+    fn shift_lfsr(&mut self, mut s16: u32) {
+        if s16 == 0 {
+            s16 = LFSR_MODULUS;
+        }
+        self.lfsr.copy_within(1.., 0);
+        self.lfsr[ZUC_LFSR_LENGTH - 1] = s16;
+    }
+
+    fn lfsr_with_initialization_mode(&mut self, u: u32) {
+        let s16 = add_modulo(self.lfsr_feedback(), u);
+        self.shift_lfsr(s16);
+    }
+
+    fn lfsr_with_work_mode(&mut self) {
+        let s16 = self.lfsr_feedback();
+        self.shift_lfsr(s16);
+    }
+
+    pub fn generate_key_word(&mut self) -> u32 {
+        self.bit_reorganization();
+        let z = self.f() ^ self.x[3];
+        self.lfsr_with_work_mode();
+        z
+    }
+
+    pub fn generate_key_stream(&mut self, key_stream: &mut [u32]) {
+        for word in key_stream.iter_mut() {
+            *word = self.generate_key_word();
+        }
+    }
+
+    pub fn apply_key_stream(&mut self, data: &mut [u8]) {
+        for byte in data.iter_mut() {
+            if self.key_word_offset == ZUC_WORD_BYTE_LENGTH {
+                self.key_word = self.generate_key_word().to_be_bytes();
+                self.key_word_offset = 0;
+            }
+            *byte ^= self.key_word[self.key_word_offset];
+            self.key_word_offset += 1;
+        }
+    }
+
+    pub fn apply_key_stream_into(&mut self, input_data: &[u8], output_data: &mut [u8]) -> Result<(), Error> {
+        if input_data.len() != output_data.len() {
+            return Err(Error::InvalidDataLength);
+        }
+        output_data.copy_from_slice(input_data);
+        self.apply_key_stream(output_data);
+        Ok(())
+    }
+
+    pub fn encrypt_data(&mut self, origin_data: &[u8]) -> Vec<u8> {
+        let mut enciphered_data = origin_data.to_vec();
+        self.apply_key_stream(&mut enciphered_data);
+        enciphered_data
+    }
+
+    pub fn decrypt_data(&mut self, enciphered_data: &[u8]) -> Vec<u8> {
+        self.encrypt_data(enciphered_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::hex::hex_decode;
+    use super::ZUCEncryption;
+
+    fn key_stream(mut zuc: ZUCEncryption, length: usize) -> Vec<u32> {
+        let mut key_stream = vec![0; length];
+        zuc.generate_key_stream(&mut key_stream);
+        key_stream
+    }
+
+    #[test]
+    fn zuc128_key_stream() {
+        assert_eq!(key_stream(ZUCEncryption::new([0x00; 16], [0x00; 16]), 2), [0x27bede74, 0x018082da]);
+        assert_eq!(key_stream(ZUCEncryption::new([0xff; 16], [0xff; 16]), 2), [0x0657cfa0, 0x7096398b]);
+        let zuc = ZUCEncryption::try_new(
+            &hex_decode("3d4c4be96a82fdaeb58f641db17b455b").unwrap(),
+            &hex_decode("84319aa8de6915ca1f6bda6bfbd8c766").unwrap(),
+        ).unwrap();
+        assert_eq!(key_stream(zuc, 2), [0x14f1c272, 0x3279c419]);
+    }
+
+    #[test]
+    fn zuc256_key_stream() {
+        assert_eq!(key_stream(ZUCEncryption::new_zuc256([0x00; 32], [0x00; 25]), 20), [
+            0x58d03ad6, 0x2e032ce2, 0xdafc683a, 0x39bdcb03, 0x52a2bc67, 0xf1b7de74, 0x163ce3a1, 0x01ef5558, 0x9639d75b, 0x95fa681b,
+            0x7f090df7, 0x56391ccc, 0x903b7612, 0x744d544c, 0x17bc3fad, 0x8b163b08, 0x21787c0b, 0x97775bb8, 0x4943c6bb, 0xe8ad8afd,
+        ]);
+        let mut iv = [0xff; 25];
+        iv[17..].fill(0x3f);
+        assert_eq!(key_stream(ZUCEncryption::new_zuc256([0xff; 32], iv), 20), [
+            0x3356cbae, 0xd1a1c18b, 0x6baa4ffe, 0x343f777c, 0x9e15128f, 0x251ab65b, 0x949f7b26, 0xef7157f2, 0x96dd2fa9, 0xdf95e3ee,
+            0x7a5be02e, 0xc32ba585, 0x505af316, 0xc2f9ded2, 0x7cdbd935, 0xe441ce11, 0x15fd0a80, 0xbb7aef67, 0x68989416, 0xb8fac8c2,
+        ]);
+    }
+}
@@ -0,0 +1,128 @@
+use crate::error::Error;
+use super::super::block_mode::stream_trait::{StreamEncryptor, StreamDecryptor};
+use super::zuc_constant::{ZUC_KEY_BYTE_LENGTH, ZUC_IV_BYTE_LENGTH, ZUC256_KEY_BYTE_LENGTH, ZUC256_IV_BYTE_LENGTH};
+use super::zuc_encryption::ZUCEncryption;
+
+pub struct ZUCEncryptor {
+    zuc: ZUCEncryption,
+}
+
+impl ZUCEncryptor {
+    pub fn new(zuc: ZUCEncryption) -> ZUCEncryptor {
+        ZUCEncryptor {
+            zuc,
+        }
+    }
+
+    pub fn update(&mut self, origin_data: &[u8]) -> Vec<u8> {
+        self.zuc.encrypt_data(origin_data)
+    }
+
+    pub fn finalize(self) -> Result<Vec<u8>, Error> {
+        Ok(Vec::new())
+    }
+}
+
+impl StreamEncryptor for ZUCEncryptor {
+    fn update(&mut self, origin_data: &[u8]) -> Vec<u8> {
+        ZUCEncryptor::update(self, origin_data)
+    }
+
+    fn finalize(self) -> Result<Vec<u8>, Error> {
+        ZUCEncryptor::finalize(self)
+    }
+}
+
+pub struct ZUCDecryptor {
+    zuc: ZUCEncryption,
+}
+
+impl ZUCDecryptor {
+    pub fn new(zuc: ZUCEncryption) -> ZUCDecryptor {
+        ZUCDecryptor {
+            zuc,
+        }
+    }
+
+    pub fn update(&mut self, enciphered_data: &[u8]) -> Vec<u8> {
+        self.zuc.decrypt_data(enciphered_data)
+    }
+
+    pub fn finalize(self) -> Result<Vec<u8>, Error> {
+        Ok(Vec::new())
+    }
+}
+
+impl StreamDecryptor for ZUCDecryptor {
+    fn update(&mut self, enciphered_data: &[u8]) -> Vec<u8> {
+        ZUCDecryptor::update(self, enciphered_data)
+    }
+
+    fn finalize(self) -> Result<Vec<u8>, Error> {
+        ZUCDecryptor::finalize(self)
+    }
+}
+
+pub fn zuc_encrypt_data(origin_data: &[u8], key: [u8; ZUC_KEY_BYTE_LENGTH], iv: [u8; ZUC_IV_BYTE_LENGTH]) -> Vec<u8> {
+    ZUCEncryption::new(key, iv).encrypt_data(origin_data)
+}
+
+pub fn zuc_decrypt_data(enciphered_data: &[u8], key: [u8; ZUC_KEY_BYTE_LENGTH], iv: [u8; ZUC_IV_BYTE_LENGTH]) -> Vec<u8> {
+    ZUCEncryption::new(key, iv).decrypt_data(enciphered_data)
+}
+
+pub fn zuc_encrypt_data_in_place(data: &mut [u8], key: [u8; ZUC_KEY_BYTE_LENGTH], iv: [u8; ZUC_IV_BYTE_LENGTH]) {
+    ZUCEncryption::new(key, iv).apply_key_stream(data)
+}
+
+pub fn zuc_decrypt_data_in_place(data: &mut [u8], key: [u8; ZUC_KEY_BYTE_LENGTH], iv: [u8; ZUC_IV_BYTE_LENGTH]) {
+    ZUCEncryption::new(key, iv).apply_key_stream(data)
+}
+
+pub fn zuc_encrypt_data_into(origin_data: &[u8], enciphered_data: &mut [u8], key: [u8; ZUC_KEY_BYTE_LENGTH], iv: [u8; ZUC_IV_BYTE_LENGTH]) -> Result<(), Error> {
+    ZUCEncryption::new(key, iv).apply_key_stream_into(origin_data, enciphered_data)
+}
+
+pub fn zuc_decrypt_data_into(enciphered_data: &[u8], origin_data: &mut [u8], key: [u8; ZUC_KEY_BYTE_LENGTH], iv: [u8; ZUC_IV_BYTE_LENGTH]) -> Result<(), Error> {
+    ZUCEncryption::new(key, iv).apply_key_stream_into(enciphered_data, origin_data)
+}
+
+pub fn zuc256_encrypt_data(origin_data: &[u8], key: [u8; ZUC256_KEY_BYTE_LENGTH], iv: [u8; ZUC256_IV_BYTE_LENGTH]) -> Vec<u8> {
+    ZUCEncryption::new_zuc256(key, iv).encrypt_data(origin_data)
+}
+
+pub fn zuc256_decrypt_data(enciphered_data: &[u8], key: [u8; ZUC256_KEY_BYTE_LENGTH], iv: [u8; ZUC256_IV_BYTE_LENGTH]) -> Vec<u8> {
+    ZUCEncryption::new_zuc256(key, iv).decrypt_data(enciphered_data)
+}
+
+pub fn zuc256_encrypt_data_in_place(data: &mut [u8], key: [u8; ZUC256_KEY_BYTE_LENGTH], iv: [u8; ZUC256_IV_BYTE_LENGTH]) {
+    ZUCEncryption::new_zuc256(key, iv).apply_key_stream(data)
+}
+
+pub fn zuc256_decrypt_data_in_place(data: &mut [u8], key: [u8; ZUC256_KEY_BYTE_LENGTH], iv: [u8; ZUC256_IV_BYTE_LENGTH]) {
+    ZUCEncryption::new_zuc256(key, iv).apply_key_stream(data)
+}
+
+pub fn zuc256_encrypt_data_into(origin_data: &[u8], enciphered_data: &mut [u8], key: [u8; ZUC256_KEY_BYTE_LENGTH], iv: [u8; ZUC256_IV_BYTE_LENGTH]) -> Result<(), Error> {
+    ZUCEncryption::new_zuc256(key, iv).apply_key_stream_into(origin_data, enciphered_data)
+}
+
+pub fn zuc256_decrypt_data_into(enciphered_data: &[u8], origin_data: &mut [u8], key: [u8; ZUC256_KEY_BYTE_LENGTH], iv: [u8; ZUC256_IV_BYTE_LENGTH]) -> Result<(), Error> {
+    ZUCEncryption::new_zuc256(key, iv).apply_key_stream_into(enciphered_data, origin_data)
+}
+
+pub fn try_zuc_encrypt_data(origin_data: &[u8], key: &[u8], iv: &[u8]) -> Result<Vec<u8>, Error> {
+    Ok(ZUCEncryption::try_new(key, iv)?.encrypt_data(origin_data))
+}
+
+pub fn try_zuc_decrypt_data(enciphered_data: &[u8], key: &[u8], iv: &[u8]) -> Result<Vec<u8>, Error> {
+    Ok(ZUCEncryption::try_new(key, iv)?.decrypt_data(enciphered_data))
+}
+
+pub fn try_zuc256_encrypt_data(origin_data: &[u8], key: &[u8], iv: &[u8]) -> Result<Vec<u8>, Error> {
+    Ok(ZUCEncryption::try_new_zuc256(key, iv)?.encrypt_data(origin_data))
+}
+
+pub fn try_zuc256_decrypt_data(enciphered_data: &[u8], key: &[u8], iv: &[u8]) -> Result<Vec<u8>, Error> {
+    Ok(ZUCEncryption::try_new_zuc256(key, iv)?.decrypt_data(enciphered_data))
+}
@@ -0,0 +1,62 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub enum Error {
+    InvalidKeyLength,
+    InvalidKey,
+    InvalidIVLength,
+    InvalidNonceLength,
+    InvalidTagLength,
+    InvalidDataLength,
+    InvalidParameter,
+    InvalidPadding,
+    InvalidEncoding,
+    InvalidState,
+    AuthenticationFailed,
+    UnsupportedAlgorithm,
+    IO(std::io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidKeyLength => write!(f, "invalid key length"),
+            Error::InvalidKey => write!(f, "invalid key"),
+            Error::InvalidIVLength => write!(f, "invalid iv length"),
+            Error::InvalidNonceLength => write!(f, "invalid nonce length"),
+            Error::InvalidTagLength => write!(f, "invalid tag length"),
+            Error::InvalidDataLength => write!(f, "invalid data length"),
+            Error::InvalidParameter => write!(f, "invalid parameter"),
+            Error::InvalidPadding => write!(f, "invalid padding"),
+            Error::InvalidEncoding => write!(f, "invalid encoding"),
+            Error::InvalidState => write!(f, "invalid state"),
+            Error::AuthenticationFailed => write!(f, "authentication failed"),
+            Error::UnsupportedAlgorithm => write!(f, "unsupported algorithm"),
+            Error::IO(error) => write!(f, "io error: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::IO(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Error {
+        Error::IO(error)
+    }
+}
+
+impl From<Error> for std::io::Error {
+    fn from(error: Error) -> std::io::Error {
+        match error {
+            Error::IO(error) => error,
+            error => std::io::Error::new(std::io::ErrorKind::InvalidData, error),
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use std::io::Read;
+use crate::algorithm::symmetric_encryption::block_mode::stream_trait::StreamDecryptor;
+
+const READ_BUFFER_SIZE: usize = 0x4000;
+
+pub struct DecryptReader<R: Read, D: StreamDecryptor> {
+    reader: R,
+    decryptor: Option<D>,
+    origin_data: Vec<u8>,
+    origin_data_offset: usize,
+}
+
+impl<R: Read, D: StreamDecryptor> DecryptReader<R, D> {
+    pub fn new(reader: R, decryptor: D) -> DecryptReader<R, D> {
+        DecryptReader {
+            reader,
+            decryptor: Some(decryptor),
+            origin_data: Vec::new(),
+            origin_data_offset: 0,
+        }
+    }
+
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    fn fill_origin_data(&mut self) -> std::io::Result<()> {
+        let mut buffer = vec![0; READ_BUFFER_SIZE];
+        while self.origin_data_offset == self.origin_data.len() {
+            let decryptor = match self.decryptor.as_mut() {
+                Some(decryptor) => decryptor,
+                None => return Ok(()),
+            };
+            let read_length = self.reader.read(&mut buffer)?;
+            self.origin_data = if read_length == 0 {
+                self.decryptor.take().unwrap().finalize()?
+            } else {
+                decryptor.update(&buffer[..read_length])
+            };
+            self.origin_data_offset = 0;
+        }
+        Ok(())
+    }
+}
+
+impl<R: Read, D: StreamDecryptor> Read for DecryptReader<R, D> {
+    fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+        if buffer.is_empty() {
+            return Ok(0);
+        }
+        self.fill_origin_data()?;
+        let length = buffer.len().min(self.origin_data.len() - self.origin_data_offset);
+        buffer[..length].copy_from_slice(&self.origin_data[self.origin_data_offset..(self.origin_data_offset + length)]);
+        self.origin_data_offset += length;
+        Ok(length)
+    }
+}
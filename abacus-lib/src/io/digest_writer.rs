@@ -0,0 +1,36 @@
+use std::io::Write;
+use crate::algorithm::digest::digest_trait::Digest;
+
+pub struct DigestWriter<D: Digest> {
+    digest: D,
+}
+
+impl<D: Digest> Default for DigestWriter<D> {
+    fn default() -> Self {
+        DigestWriter::new()
+    }
+}
+
+impl<D: Digest> DigestWriter<D> {
+    pub fn new() -> DigestWriter<D> {
+        DigestWriter {
+            digest: D::new(),
+        }
+    }
+
+    pub fn get_digest_bytes(&mut self) -> D::DigestBytes {
+        self.digest.compute_digest();
+        self.digest.get_digest_bytes()
+    }
+}
+
+impl<D: Digest> Write for DigestWriter<D> {
+    fn write(&mut self, data: &[u8]) -> std::io::Result<usize> {
+        self.digest.push_data(data);
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+use std::io::Write;
+use crate::algorithm::symmetric_encryption::block_mode::stream_trait::StreamEncryptor;
+use crate::error::Error;
+
+pub struct EncryptWriter<W: Write, E: StreamEncryptor> {
+    writer: W,
+    encryptor: E,
+}
+
+impl<W: Write, E: StreamEncryptor> EncryptWriter<W, E> {
+    pub fn new(writer: W, encryptor: E) -> EncryptWriter<W, E> {
+        EncryptWriter {
+            writer,
+            encryptor,
+        }
+    }
+
+    pub fn finish(mut self) -> Result<W, Error> {
+        let enciphered_data = self.encryptor.finalize()?;
+        self.writer.write_all(&enciphered_data)?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+impl<W: Write, E: StreamEncryptor> Write for EncryptWriter<W, E> {
+    fn write(&mut self, origin_data: &[u8]) -> std::io::Result<usize> {
+        let enciphered_data = self.encryptor.update(origin_data);
+        self.writer.write_all(&enciphered_data)?;
+        Ok(origin_data.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.writer.flush()
+    }
+}
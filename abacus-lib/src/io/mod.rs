@@ -0,0 +1,3 @@
+pub mod digest_writer;
+pub mod encrypt_writer;
+pub mod decrypt_reader;
@@ -1 +1,5 @@
 pub mod algorithm;
+pub mod error;
+pub mod io;
+pub mod math;
+pub mod util;
@@ -0,0 +1,545 @@
+use std::cmp::Ordering;
+use std::ops::{Add, Sub, Mul, Div, Rem, Shl, Shr};
+use crate::error::Error;
+use crate::util::random::fill_random_bytes;
+use super::bigint_constant::{LIMB_BIT_LENGTH, LIMB_BYTE_LENGTH};
+use super::limb_arithmetic::{limbs_add_assign, limbs_sub_assign, limbs_multiply};
+use super::montgomery_context::MontgomeryContext;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    limbs: Vec<u64>,
+}
+
+impl BigUint {
+    fn normalize(mut self) -> BigUint {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+        self
+    }
+
+    pub fn zero() -> BigUint {
+        BigUint {
+            limbs: Vec::new(),
+        }
+    }
+
+    pub fn one() -> BigUint {
+        BigUint::from_u64(1)
+    }
+
+    pub fn from_u64(value: u64) -> BigUint {
+        BigUint::from_limbs(vec![value])
+    }
+
+    pub fn from_limbs(limbs: Vec<u64>) -> BigUint {
+        let instance = BigUint {
+            limbs,
+        };
+        instance.normalize()
+    }
+
+    pub fn from_be_bytes(bytes: &[u8]) -> BigUint {
+        let mut limbs = Vec::with_capacity(bytes.len().div_ceil(LIMB_BYTE_LENGTH));
+        for chunk in bytes.rchunks(LIMB_BYTE_LENGTH) {
+            let mut limb_bytes = [0; LIMB_BYTE_LENGTH];
+            limb_bytes[(LIMB_BYTE_LENGTH - chunk.len())..].copy_from_slice(chunk);
+            limbs.push(u64::from_be_bytes(limb_bytes));
+        }
+        BigUint::from_limbs(limbs)
+    }
+
+    pub fn to_be_bytes(&self) -> Vec<u8> {
+        self.to_be_bytes_with_length(self.bit_length().div_ceil(8)).unwrap()
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    pub fn to_be_bytes_with_length(&self, byte_length: usize) -> Result<Vec<u8>, Error> {
+        if self.bit_length().div_ceil(8) > byte_length {
+            return Err(Error::InvalidDataLength);
+        }
+        let mut bytes = vec![0; byte_length];
+        for i in 0..self.limbs.len() {
+            let limb_bytes = self.limbs[i].to_le_bytes();
+            for j in 0..LIMB_BYTE_LENGTH {
+                let index = i * LIMB_BYTE_LENGTH + j;
+                if index < byte_length {
+                    bytes[byte_length - 1 - index] = limb_bytes[j];
+                }
+            }
+        }
+        Ok(bytes)
+    }
+
+    pub fn limbs(&self) -> &[u64] {
+        &self.limbs
+    }
+
+    pub(crate) fn to_limbs_with_length(&self, limb_length: usize) -> Result<Vec<u64>, Error> {
+        if self.limbs.len() > limb_length {
+            return Err(Error::InvalidDataLength);
+        }
+        let mut limbs = self.limbs.clone();
+        limbs.resize(limb_length, 0);
+        Ok(limbs)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    pub fn is_one(&self) -> bool {
+        self.limbs == [1]
+    }
+
+    pub fn is_odd(&self) -> bool {
+        self.limbs.first().is_some_and(|limb| limb & 1 == 1)
+    }
+
+    pub fn is_even(&self) -> bool {
+        !self.is_odd()
+    }
+
+    pub fn bit_length(&self) -> usize {
+        match self.limbs.last() {
+            Some(limb) => self.limbs.len() * LIMB_BIT_LENGTH - limb.leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    pub fn bit(&self, index: usize) -> bool {
+        match self.limbs.get(index / LIMB_BIT_LENGTH) {
+            Some(limb) => (limb >> (index % LIMB_BIT_LENGTH)) & 1 == 1,
+            None => false,
+        }
+    }
+
+    pub fn set_bit(&mut self, index: usize) {
+        let limb_index = index / LIMB_BIT_LENGTH;
+        if self.limbs.len() <= limb_index {
+            self.limbs.resize(limb_index + 1, 0);
+        }
+        self.limbs[limb_index] |= 1 << (index % LIMB_BIT_LENGTH);
+    }
+
+    pub fn trailing_zeros(&self) -> usize {
+        for i in 0..self.limbs.len() {
+            if self.limbs[i] != 0 {
+                return i * LIMB_BIT_LENGTH + self.limbs[i].trailing_zeros() as usize;
+            }
+        }
+        0
+    }
+
+    pub fn checked_sub(&self, other: &BigUint) -> Option<BigUint> {
+        if self < other {
+            return None;
+        }
+        let mut limbs = self.limbs.clone();
+        limbs_sub_assign(&mut limbs, &other.limbs);
+        Some(BigUint::from_limbs(limbs))
+    }
+
+    fn div_rem_limb(&self, divisor: u64) -> (BigUint, u64) {
+        let mut quotient = vec![0; self.limbs.len()];
+        let mut remainder: u128 = 0;
+        for i in (0..self.limbs.len()).rev() {
+            let dividend = (remainder << 64) | self.limbs[i] as u128;
+            quotient[i] = (dividend / divisor as u128) as u64;
+            remainder = dividend % divisor as u128;
+        }
+        (BigUint::from_limbs(quotient), remainder as u64)
+    }
+
+    pub fn div_rem(&self, divisor: &BigUint) -> (BigUint, BigUint) {
+        assert!(!divisor.is_zero(), "BigUint: division by zero");
+        if self < divisor {
+            return (BigUint::zero(), self.clone());
+        }
+        if divisor.limbs.len() == 1 {
+            let (quotient, remainder) = self.div_rem_limb(divisor.limbs[0]);
+            return (quotient, BigUint::from_u64(remainder));
+        }
+        let shift = divisor.limbs.last().unwrap().leading_zeros() as usize;
+        let v = (divisor << shift).limbs;
+        let mut u = (self << shift).limbs;
+        u.resize(self.limbs.len() + 1, 0);
+        let n = v.len();
+        let m = u.len() - n - 1;
+        let mut quotient = vec![0u64; m + 1];
+        for j in (0..=m).rev() {
+            let numerator = ((u[j + n] as u128) << 64) | u[j + n - 1] as u128;
+            let mut q_hat = numerator / v[n - 1] as u128;
+            let mut r_hat = numerator % v[n - 1] as u128;
+            while q_hat >> 64 != 0 || q_hat * v[n - 2] as u128 > ((r_hat << 64) | u[j + n - 2] as u128) {
+                q_hat -= 1;
+                r_hat += v[n - 1] as u128;
+                if r_hat >> 64 != 0 {
+                    break;
+                }
+            }
+            let mut carry: u128 = 0;
+            let mut borrow: u64 = 0;
+            for i in 0..n {
+                let product = q_hat * v[i] as u128 + carry;
+                carry = product >> 64;
+                let (t, b1) = u[i + j].overflowing_sub(product as u64);
+                let (t, b2) = t.overflowing_sub(borrow);
+                u[i + j] = t;
+                borrow = b1 as u64 + b2 as u64;
+            }
+            let (t, b1) = u[j + n].overflowing_sub(carry as u64);
+            let (t, b2) = t.overflowing_sub(borrow);
+            u[j + n] = t;
+            quotient[j] = q_hat as u64;
+            if b1 || b2 {
+                quotient[j] -= 1;
+                let carry = limbs_add_assign(&mut u[j..(j + n)], &v);
+                u[j + n] = u[j + n].wrapping_add(carry);
+            }
+        }
+        u.truncate(n);
+        (BigUint::from_limbs(quotient), BigUint::from_limbs(u) >> shift)
+    }
+
+    pub fn gcd(&self, other: &BigUint) -> BigUint {
+        let mut a = self.clone();
+        let mut b = other.clone();
+        while !b.is_zero() {
+            let r = &a % &b;
+            a = b;
+            b = r;
+        }
+        a
+    }
+
+    pub fn mod_add(&self, other: &BigUint, modulus: &BigUint) -> BigUint {
+        (self + other) % modulus
+    }
+
+    pub fn mod_sub(&self, other: &BigUint, modulus: &BigUint) -> BigUint {
+        let a = self % modulus;
+        let b = other % modulus;
+        match a.checked_sub(&b) {
+            Some(difference) => difference,
+            None => &(&a + modulus) - &b,
+        }
+    }
+
+    pub fn mod_mul(&self, other: &BigUint, modulus: &BigUint) -> BigUint {
+        (self * other) % modulus
+    }
+
+    pub fn mod_pow(&self, exponent: &BigUint, modulus: &BigUint) -> BigUint {
+        assert!(!modulus.is_zero(), "BigUint: modulus is zero");
+        if modulus.is_one() {
+            return BigUint::zero();
+        }
+        if modulus.is_odd() {
+            return MontgomeryContext::with_odd_modulus(modulus).mod_pow(self, exponent);
+        }
+        let mut result = BigUint::one() % modulus;
+        let base = self % modulus;
+        for i in (0..exponent.bit_length()).rev() {
+            result = result.mod_mul(&result, modulus);
+            if exponent.bit(i) {
+                result = result.mod_mul(&base, modulus);
+            }
+        }
+        result
+    }
+
+    pub fn mod_inverse(&self, modulus: &BigUint) -> Option<BigUint> {
+        if modulus.is_zero() || modulus.is_one() {
+            return None;
+        }
+        let mut old_r = self % modulus;
+        let mut r = modulus.clone();
+        let mut old_s = BigUint::one();
+        let mut s = BigUint::zero();
+        while !r.is_zero() {
+            let (q, remainder) = old_r.div_rem(&r);
+            old_r = std::mem::replace(&mut r, remainder);
+            let new_s = old_s.mod_sub(&q.mod_mul(&s, modulus), modulus);
+            old_s = std::mem::replace(&mut s, new_s);
+        }
+        if old_r.is_one() {
+            Some(old_s)
+        } else {
+            None
+        }
+    }
+
+    pub fn random_bits(bit_length: usize) -> Result<BigUint, Error> {
+        let mut bytes = vec![0; bit_length.div_ceil(8)];
+        fill_random_bytes(&mut bytes)?;
+        if !bit_length.is_multiple_of(8) {
+            bytes[0] &= (1 << (bit_length % 8)) - 1;
+        }
+        Ok(BigUint::from_be_bytes(&bytes))
+    }
+
+    pub fn random_below(bound: &BigUint) -> Result<BigUint, Error> {
+        if bound.is_zero() {
+            return Err(Error::InvalidParameter);
+        }
+        loop {
+            let candidate = BigUint::random_bits(bound.bit_length())?;
+            if &candidate < bound {
+                return Ok(candidate);
+            }
+        }
+    }
+
+    pub fn random_range(lower: &BigUint, upper: &BigUint) -> Result<BigUint, Error> {
+        let range = upper.checked_sub(lower).ok_or(Error::InvalidParameter)?;
+        Ok(&BigUint::random_below(&range)? + lower)
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &BigUint) -> Ordering {
+        self.limbs.len().cmp(&other.limbs.len()).then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &BigUint) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, other: &BigUint) -> BigUint {
+        let (mut limbs, shorter) = if self.limbs.len() >= other.limbs.len() {(self.limbs.clone(), &other.limbs)} else {(other.limbs.clone(), &self.limbs)};
+        let carry = limbs_add_assign(&mut limbs, shorter);
+        limbs.push(carry);
+        BigUint::from_limbs(limbs)
+    }
+}
+
+impl Sub<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn sub(self, other: &BigUint) -> BigUint {
+        self.checked_sub(other).expect("BigUint: subtraction underflow")
+    }
+}
+
+impl Mul<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, other: &BigUint) -> BigUint {
+        if self.is_zero() || other.is_zero() {
+            return BigUint::zero();
+        }
+        BigUint::from_limbs(limbs_multiply(&self.limbs, &other.limbs))
+    }
+}
+
+impl Div<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn div(self, other: &BigUint) -> BigUint {
+        self.div_rem(other).0
+    }
+}
+
+impl Rem<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn rem(self, other: &BigUint) -> BigUint {
+        self.div_rem(other).1
+    }
+}
+
+impl Shl<usize> for &BigUint {
+    type Output = BigUint;
+
+    fn shl(self, shift: usize) -> BigUint {
+        if self.is_zero() {
+            return BigUint::zero();
+        }
+        let limb_shift = shift / LIMB_BIT_LENGTH;
+        let bit_shift = shift % LIMB_BIT_LENGTH;
+        let mut limbs = vec![0; self.limbs.len() + limb_shift + 1];
+        for i in 0..self.limbs.len() {
+            limbs[i + limb_shift] |= self.limbs[i] << bit_shift;
+            if bit_shift != 0 {
+                limbs[i + limb_shift + 1] |= self.limbs[i] >> (LIMB_BIT_LENGTH - bit_shift);
+            }
+        }
+        BigUint::from_limbs(limbs)
+    }
+}
+
+impl Shr<usize> for &BigUint {
+    type Output = BigUint;
+
+    #[allow(clippy::needless_range_loop)]
+    fn shr(self, shift: usize) -> BigUint {
+        let limb_shift = shift / LIMB_BIT_LENGTH;
+        let bit_shift = shift % LIMB_BIT_LENGTH;
+        if limb_shift >= self.limbs.len() {
+            return BigUint::zero();
+        }
+        let mut limbs = vec![0; self.limbs.len() - limb_shift];
+        for i in 0..limbs.len() {
+            limbs[i] = self.limbs[i + limb_shift] >> bit_shift;
+            if bit_shift != 0 && i + limb_shift + 1 < self.limbs.len() {
+                limbs[i] |= self.limbs[i + limb_shift + 1] << (LIMB_BIT_LENGTH - bit_shift);
+            }
+        }
+        BigUint::from_limbs(limbs)
+    }
+}
+
+macro_rules! forward_owned_binary_operation {
+    ($trait_name:ident, $method_name:ident) => {
+        impl $trait_name<BigUint> for BigUint {
+            type Output = BigUint;
+
+            fn $method_name(self, other: BigUint) -> BigUint {
+                (&self).$method_name(&other)
+            }
+        }
+
+        impl $trait_name<&BigUint> for BigUint {
+            type Output = BigUint;
+
+            fn $method_name(self, other: &BigUint) -> BigUint {
+                (&self).$method_name(other)
+            }
+        }
+
+        impl $trait_name<BigUint> for &BigUint {
+            type Output = BigUint;
+
+            fn $method_name(self, other: BigUint) -> BigUint {
+                self.$method_name(&other)
+            }
+        }
+    };
+}
+
+forward_owned_binary_operation!(Add, add);
+forward_owned_binary_operation!(Sub, sub);
+forward_owned_binary_operation!(Mul, mul);
+forward_owned_binary_operation!(Div, div);
+forward_owned_binary_operation!(Rem, rem);
+
+impl Shl<usize> for BigUint {
+    type Output = BigUint;
+
+    fn shl(self, shift: usize) -> BigUint {
+        &self << shift
+    }
+}
+
+impl Shr<usize> for BigUint {
+    type Output = BigUint;
+
+    fn shr(self, shift: usize) -> BigUint {
+        &self >> shift
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::util::hex::{hex_decode, hex_encode};
+    use super::BigUint;
+
+    const SHIFT_VALUE: &str = "a0a170b33839263059f28c105d1fb17c2390c192cfd3ac94af";
+
+    fn big(data: &str) -> BigUint {
+        BigUint::from_be_bytes(&hex_decode(data).unwrap())
+    }
+
+    fn check_div_rem(dividend: &BigUint, divisor: &BigUint, quotient: &str, remainder: &str) {
+        let (q, r) = dividend.div_rem(divisor);
+        assert_eq!(q, big(quotient));
+        assert_eq!(r, big(remainder));
+        assert!(r < *divisor);
+        assert_eq!(&(&q * divisor) + &r, *dividend);
+    }
+
+    #[test]
+    fn div_rem_add_back_step() {
+        let dividend = big("7fffffffffffffff800000000000000000000000000000000000000000000000");
+        let divisor = big("800000000000000000000000000000000000000000000001");
+        check_div_rem(&dividend, &divisor, "fffffffffffffffe", "7fffffffffffffffffffffffffffffff0000000000000002");
+    }
+
+    #[test]
+    fn div_rem_quotient_digit_all_ones() {
+        let dividend = big("fffffffffffffffffffffffffffffffd00000000000012390000000000000001ffffffffffffedc20000000000ac28f3");
+        let divisor = big("fffffffffffffffffffffffffffffffe0000000000001234");
+        check_div_rem(&dividend, &divisor, "ffffffffffffffffffffffffffffffff0000000000000005", "abcdef");
+    }
+
+    #[test]
+    fn div_rem_small_and_single_limb_divisors() {
+        check_div_rem(&big("0123"), &big("0123456789abcdef0123"), "", "0123");
+        check_div_rem(&big("0123456789abcdef0123456789abcdef"), &big("10"), "00123456789abcdef0123456789abcde", "0f");
+        check_div_rem(&big("0123456789abcdef0123456789abcdef"), &big("0123456789abcdef0123456789abcdef"), "01", "");
+    }
+
+    #[test]
+    fn mod_inverse() {
+        let modulus = big("7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed");
+        let value = big("0181892f902bd23f0824128b2f330c5c7fd0a6a3a4506513270e269e0d37f2a74de452e6b438");
+        assert_eq!(value.mod_inverse(&modulus).unwrap(), big("3b300e03bf78e0f5ed1d68fcc587ba4d0feb69b3c79d6fa41097e789215e5da2"));
+        assert_eq!(BigUint::from_u64(3).mod_inverse(&BigUint::from_u64(11)), Some(BigUint::from_u64(4)));
+    }
+
+    #[test]
+    fn mod_inverse_of_non_invertible_value() {
+        let modulus = big("52eeaa24309121c6a52ff54a3485a128223ebe5b484a8c19bb");
+        assert_eq!(big("35a6224127fdd24d0a360e4201ea0e").mod_inverse(&modulus), None);
+        assert_eq!(BigUint::zero().mod_inverse(&modulus), None);
+        assert_eq!(BigUint::from_u64(5).mod_inverse(&BigUint::one()), None);
+    }
+
+    #[test]
+    fn shifts_across_limb_boundaries() {
+        let value = big(SHIFT_VALUE);
+        let vectors = [
+            (1, "014142e16670724c60b3e51820ba3f62f8472183259fa759295e", "5050b8599c1c93182cf946082e8fd8be11c860c967e9d64a57"),
+            (63, "5050b8599c1c93182cf946082e8fd8be11c860c967e9d64a578000000000000000", "014142e16670724c60b3e51820ba3f62f847"),
+            (64, "a0a170b33839263059f28c105d1fb17c2390c192cfd3ac94af0000000000000000", "a0a170b33839263059f28c105d1fb17c23"),
+            (65, "014142e16670724c60b3e51820ba3f62f8472183259fa759295e0000000000000000", "5050b8599c1c93182cf946082e8fd8be11"),
+            (127, "5050b8599c1c93182cf946082e8fd8be11c860c967e9d64a5780000000000000000000000000000000", "014142e16670724c60b3"),
+            (130, "028285c2cce0e498c167ca3041747ec5f08e43064b3f4eb252bc00000000000000000000000000000000", "28285c2cce0e498c16"),
+        ];
+        for (shift, shifted_left, shifted_right) in vectors {
+            assert_eq!(&value << shift, big(shifted_left));
+            assert_eq!(&value >> shift, big(shifted_right));
+            assert_eq!(&(&value << shift) >> shift, value);
+        }
+        assert!((&value >> 200).is_zero());
+        assert!((&BigUint::zero() << 64).is_zero());
+    }
+
+    #[test]
+    fn byte_encoding_round_trip() {
+        let value = BigUint::from_be_bytes(&hex_decode(&format!("0000{}", SHIFT_VALUE)).unwrap());
+        assert_eq!(value.limbs().len(), 4);
+        assert_eq!(hex_encode(&value.to_be_bytes()), SHIFT_VALUE);
+        assert_eq!(hex_encode(&value.to_be_bytes_with_length(27).unwrap()), format!("0000{}", SHIFT_VALUE));
+        assert!(matches!(value.to_be_bytes_with_length(24), Err(Error::InvalidDataLength)));
+        assert_eq!(BigUint::from_be_bytes(&value.to_be_bytes()), value);
+        assert!(BigUint::from_be_bytes(&[0; 9]).is_zero());
+        assert!(BigUint::zero().to_be_bytes().is_empty());
+    }
+
+    #[test]
+    fn limbs_with_length() {
+        let value = big(SHIFT_VALUE);
+        assert_eq!(value.to_limbs_with_length(5).unwrap()[4], 0);
+        assert!(matches!(value.to_limbs_with_length(3), Err(Error::InvalidDataLength)));
+    }
+}
@@ -0,0 +1,4 @@
+pub const LIMB_BIT_LENGTH: usize = 64;
+pub const LIMB_BYTE_LENGTH: usize = 8;
+pub const WINDOW_BIT_LENGTH: usize = 4;
+pub const WINDOW_TABLE_LENGTH: usize = 1 << WINDOW_BIT_LENGTH;
@@ -0,0 +1,86 @@
+#[inline(always)]
+fn mask(choice: u64) -> u64 {
+    0u64.wrapping_sub(choice & 1)
+}
+
+#[allow(clippy::needless_range_loop)]
+pub fn limbs_add_assign(a: &mut [u64], b: &[u64]) -> u64 {
+    let mut carry: u64 = 0;
+    for i in 0..a.len() {
+        let sum = a[i] as u128 + *b.get(i).unwrap_or(&0) as u128 + carry as u128;
+        a[i] = sum as u64;
+        carry = (sum >> 64) as u64;
+    }
+    carry
+}
+
+#[allow(clippy::needless_range_loop)]
+pub fn limbs_sub_assign(a: &mut [u64], b: &[u64]) -> u64 {
+    let mut borrow: u64 = 0;
+    for i in 0..a.len() {
+        let difference = (a[i] as u128).wrapping_sub(*b.get(i).unwrap_or(&0) as u128).wrapping_sub(borrow as u128);
+        a[i] = difference as u64;
+        borrow = ((difference >> 64) as u64) & 1;
+    }
+    borrow
+}
+
+pub fn limbs_multiply(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut product = vec![0; a.len() + b.len()];
+    for i in 0..b.len() {
+        let mut carry: u64 = 0;
+        for j in 0..a.len() {
+            let t = product[i + j] as u128 + a[j] as u128 * b[i] as u128 + carry as u128;
+            product[i + j] = t as u64;
+            carry = (t >> 64) as u64;
+        }
+        product[i + a.len()] = carry;
+    }
+    product
+}
+
+pub fn limbs_select(a: &[u64], b: &[u64], choice: u64, result: &mut [u64]) {
+    let mask = mask(choice);
+    for i in 0..result.len() {
+        result[i] = (a[i] & !mask) | (b[i] & mask);
+    }
+}
+
+pub fn limbs_conditional_assign(a: &mut [u64], b: &[u64], choice: u64) {
+    let mask = mask(choice);
+    for i in 0..a.len() {
+        a[i] = (a[i] & !mask) | (b[i] & mask);
+    }
+}
+
+pub fn limbs_conditional_swap(a: &mut [u64], b: &mut [u64], choice: u64) {
+    let mask = mask(choice);
+    for i in 0..a.len() {
+        let t = (a[i] ^ b[i]) & mask;
+        a[i] ^= t;
+        b[i] ^= t;
+    }
+}
+
+pub fn limbs_constant_time_eq(a: &[u64], b: &[u64]) -> u64 {
+    let mut difference: u64 = 0;
+    for i in 0..a.len() {
+        difference |= a[i] ^ b[i];
+    }
+    let difference = std::hint::black_box(difference);
+    1 ^ ((difference | difference.wrapping_neg()) >> 63)
+}
+
+pub fn limbs_constant_time_less_than(a: &[u64], b: &[u64]) -> u64 {
+    let mut difference = a.to_vec();
+    limbs_sub_assign(&mut difference, b)
+}
+
+pub fn limbs_constant_time_is_zero(a: &[u64]) -> u64 {
+    let mut accumulator: u64 = 0;
+    for &limb in a {
+        accumulator |= limb;
+    }
+    let accumulator = std::hint::black_box(accumulator);
+    1 ^ ((accumulator | accumulator.wrapping_neg()) >> 63)
+}
@@ -0,0 +1,4 @@
+pub mod bigint_constant;
+pub mod limb_arithmetic;
+pub mod big_uint;
+pub mod montgomery_context;
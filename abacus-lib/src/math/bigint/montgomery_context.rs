@@ -0,0 +1,270 @@
+use crate::error::Error;
+use super::bigint_constant::{LIMB_BIT_LENGTH, WINDOW_BIT_LENGTH, WINDOW_TABLE_LENGTH};
+use super::limb_arithmetic::{limbs_add_assign, limbs_sub_assign, limbs_select, limbs_conditional_assign, limbs_constant_time_eq};
+use super::big_uint::BigUint;
+
+#[derive(Clone, Debug)]
+pub struct MontgomeryContext {
+    modulus: BigUint,
+    modulus_limbs: Vec<u64>,
+    m_prime: u64,
+    r2: Vec<u64>,
+    one: Vec<u64>,
+}
+
+impl MontgomeryContext {
+    pub fn new(modulus: &BigUint) -> Result<MontgomeryContext, Error> {
+        MontgomeryContext::check_parameter(modulus)?;
+        Ok(MontgomeryContext::with_odd_modulus(modulus))
+    }
+
+    pub(crate) fn with_odd_modulus(modulus: &BigUint) -> MontgomeryContext {
+        let limb_length = modulus.limbs().len();
+        let mut inverse: u64 = 1;
+        for _ in 0..6 {
+            inverse = inverse.wrapping_mul(2u64.wrapping_sub(modulus.limbs()[0].wrapping_mul(inverse)));
+        }
+        let r = &BigUint::one() << (limb_length * LIMB_BIT_LENGTH);
+        let r2 = &(&r * &r) % modulus;
+        let one = &r % modulus;
+        MontgomeryContext {
+            modulus: modulus.clone(),
+            modulus_limbs: modulus.limbs().to_vec(),
+            m_prime: inverse.wrapping_neg(),
+            r2: r2.to_limbs_with_length(limb_length).unwrap(),
+            one: one.to_limbs_with_length(limb_length).unwrap(),
+        }
+    }
+
+    fn check_parameter(modulus: &BigUint) -> Result<(), Error> {
+        if modulus.is_even() || modulus.is_one() {
+            return Err(Error::InvalidParameter);
+        }
+        Ok(())
+    }
+
+    pub fn modulus(&self) -> &BigUint {
+        &self.modulus
+    }
+
+    pub(crate) fn limb_length(&self) -> usize {
+        self.modulus_limbs.len()
+    }
+
+    pub(crate) fn reduced_limbs(&self, value: &BigUint) -> Vec<u64> {
+        self.montgomery_multiply(&self.to_montgomery_limbs(value), &self.unit_limbs())
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    pub(crate) fn montgomery_multiply(&self, a: &[u64], b: &[u64]) -> Vec<u64> {
+        let n = self.limb_length();
+        let m = &self.modulus_limbs;
+        let mut t = vec![0u64; n + 2];
+        for i in 0..n {
+            let mut carry: u64 = 0;
+            for j in 0..n {
+                let s = t[j] as u128 + a[j] as u128 * b[i] as u128 + carry as u128;
+                t[j] = s as u64;
+                carry = (s >> 64) as u64;
+            }
+            let s = t[n] as u128 + carry as u128;
+            t[n] = s as u64;
+            t[n + 1] = (s >> 64) as u64;
+            let u = t[0].wrapping_mul(self.m_prime);
+            let s = t[0] as u128 + u as u128 * m[0] as u128;
+            let mut carry = (s >> 64) as u64;
+            for j in 1..n {
+                let s = t[j] as u128 + u as u128 * m[j] as u128 + carry as u128;
+                t[j - 1] = s as u64;
+                carry = (s >> 64) as u64;
+            }
+            let s = t[n] as u128 + carry as u128;
+            t[n - 1] = s as u64;
+            t[n] = t[n + 1] + (s >> 64) as u64;
+        }
+        let mut result = t[..n].to_vec();
+        let mut difference = result.clone();
+        let borrow = limbs_sub_assign(&mut difference, m);
+        limbs_conditional_assign(&mut result, &difference, t[n] | (borrow ^ 1));
+        result
+    }
+
+    fn montgomery_square(&self, a: &[u64]) -> Vec<u64> {
+        self.montgomery_multiply(a, a)
+    }
+
+    pub(crate) fn montgomery_one(&self) -> Vec<u64> {
+        self.one.clone()
+    }
+
+    pub(crate) fn add_limbs(&self, a: &[u64], b: &[u64]) -> Vec<u64> {
+        let mut sum = a.to_vec();
+        let carry = limbs_add_assign(&mut sum, b);
+        let mut difference = sum.clone();
+        let borrow = limbs_sub_assign(&mut difference, &self.modulus_limbs);
+        limbs_conditional_assign(&mut sum, &difference, carry | (borrow ^ 1));
+        sum
+    }
+
+    pub(crate) fn subtract_limbs(&self, a: &[u64], b: &[u64]) -> Vec<u64> {
+        let mut difference = a.to_vec();
+        let borrow = limbs_sub_assign(&mut difference, b);
+        let mut sum = difference.clone();
+        limbs_add_assign(&mut sum, &self.modulus_limbs);
+        limbs_conditional_assign(&mut difference, &sum, borrow);
+        difference
+    }
+
+    fn unit_limbs(&self) -> Vec<u64> {
+        let mut one = vec![0u64; self.limb_length()];
+        one[0] = 1;
+        one
+    }
+
+    pub(crate) fn to_montgomery_limbs(&self, value: &BigUint) -> Vec<u64> {
+        let mut result = vec![0u64; self.limb_length()];
+        for (i, chunk) in value.limbs().chunks(self.limb_length()).rev().enumerate() {
+            let mut chunk = chunk.to_vec();
+            chunk.resize(self.limb_length(), 0);
+            if i != 0 {
+                result = self.montgomery_multiply(&result, &self.r2);
+            }
+            result = self.add_limbs(&result, &self.montgomery_multiply(&chunk, &self.r2));
+        }
+        result
+    }
+
+    pub(crate) fn reduce_montgomery_limbs(&self, value: &[u64]) -> BigUint {
+        BigUint::from_limbs(self.montgomery_multiply(value, &self.unit_limbs()))
+    }
+
+    pub fn to_montgomery(&self, value: &BigUint) -> BigUint {
+        BigUint::from_limbs(self.to_montgomery_limbs(value))
+    }
+
+    pub fn reduce_montgomery(&self, value: &BigUint) -> BigUint {
+        self.reduce_montgomery_limbs(&self.reduced_limbs(value))
+    }
+
+    pub fn montgomery_mul(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        BigUint::from_limbs(self.montgomery_multiply(&self.reduced_limbs(a), &self.reduced_limbs(b)))
+    }
+
+    pub fn mod_mul(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        let product = self.montgomery_multiply(&self.reduced_limbs(a), &self.reduced_limbs(b));
+        BigUint::from_limbs(self.montgomery_multiply(&product, &self.r2))
+    }
+
+    pub fn mod_add(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        BigUint::from_limbs(self.add_limbs(&self.reduced_limbs(a), &self.reduced_limbs(b)))
+    }
+
+    pub fn mod_sub(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        BigUint::from_limbs(self.subtract_limbs(&self.reduced_limbs(a), &self.reduced_limbs(b)))
+    }
+
+    fn window_table(&self, base: &BigUint) -> Vec<Vec<u64>> {
+        let mut table = Vec::with_capacity(WINDOW_TABLE_LENGTH);
+        table.push(self.montgomery_one());
+        table.push(self.to_montgomery_limbs(base));
+        for i in 2..WINDOW_TABLE_LENGTH {
+            let entry = self.montgomery_multiply(&table[i - 1], &table[1]);
+            table.push(entry);
+        }
+        table
+    }
+
+    fn exponent_window(exponent: &[u64], index: usize) -> usize {
+        let bit_index = index * WINDOW_BIT_LENGTH;
+        ((exponent[bit_index / LIMB_BIT_LENGTH] >> (bit_index % LIMB_BIT_LENGTH)) as usize) & (WINDOW_TABLE_LENGTH - 1)
+    }
+
+    pub fn mod_pow(&self, base: &BigUint, exponent: &BigUint) -> BigUint {
+        let table = self.window_table(base);
+        let exponent_limbs = exponent.limbs();
+        let window_count = exponent.bit_length().div_ceil(WINDOW_BIT_LENGTH);
+        let mut result = self.montgomery_one();
+        for i in (0..window_count).rev() {
+            for _ in 0..WINDOW_BIT_LENGTH {
+                result = self.montgomery_square(&result);
+            }
+            let window = MontgomeryContext::exponent_window(exponent_limbs, i);
+            if window != 0 {
+                result = self.montgomery_multiply(&result, &table[window]);
+            }
+        }
+        self.reduce_montgomery_limbs(&result)
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    pub fn mod_pow_constant_time(&self, base: &BigUint, exponent: &BigUint) -> BigUint {
+        let table = self.window_table(base);
+        let exponent_limbs = exponent.to_limbs_with_length(exponent.limbs().len().max(self.limb_length())).unwrap();
+        let window_count = exponent_limbs.len() * LIMB_BIT_LENGTH / WINDOW_BIT_LENGTH;
+        let mut result = self.montgomery_one();
+        let mut entry = vec![0u64; self.limb_length()];
+        for i in (0..window_count).rev() {
+            for _ in 0..WINDOW_BIT_LENGTH {
+                result = self.montgomery_square(&result);
+            }
+            let window = MontgomeryContext::exponent_window(&exponent_limbs, i);
+            for j in 0..WINDOW_TABLE_LENGTH {
+                let choice = limbs_constant_time_eq(&[j as u64], &[window as u64]);
+                let candidate = entry.clone();
+                limbs_select(&candidate, &table[j], choice, &mut entry);
+            }
+            result = self.montgomery_multiply(&result, &entry);
+        }
+        self.reduce_montgomery_limbs(&result)
+    }
+
+    pub fn mod_inverse_prime_constant_time(&self, value: &BigUint) -> BigUint {
+        let exponent = &self.modulus - &BigUint::from_u64(2);
+        self.mod_pow_constant_time(value, &exponent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::util::hex::hex_decode;
+    use super::super::big_uint::BigUint;
+    use super::MontgomeryContext;
+
+    #[test]
+    fn even_or_unit_modulus_is_rejected() {
+        assert!(matches!(MontgomeryContext::new(&BigUint::from_u64(0)), Err(Error::InvalidParameter)));
+        assert!(matches!(MontgomeryContext::new(&BigUint::from_u64(1)), Err(Error::InvalidParameter)));
+        assert!(matches!(MontgomeryContext::new(&BigUint::from_u64(1 << 40)), Err(Error::InvalidParameter)));
+    }
+
+    #[test]
+    fn mod_pow_matches_small_modulus() {
+        let context = MontgomeryContext::new(&BigUint::from_u64(1_000_000_007)).unwrap();
+        assert_eq!(context.mod_pow(&BigUint::from_u64(2), &BigUint::from_u64(100)), BigUint::from_u64(976_371_285));
+        assert_eq!(context.mod_pow_constant_time(&BigUint::from_u64(2), &BigUint::from_u64(100)), BigUint::from_u64(976_371_285));
+    }
+
+    #[test]
+    fn mod_pow_matches_multi_limb_modulus() {
+        let big = |data: &str| BigUint::from_be_bytes(&hex_decode(data).unwrap());
+        let context = MontgomeryContext::new(&big("d23f0824128b2f330c5c7fd0a6a3a4506513270e269e0d37f2a74de452e6b439")).unwrap();
+        let base = big("0160099950d836f675cc81e74ef5e8e25d940ed904759531985d5d9dc9f81818e811892f902b");
+        let exponent = big("0f21ddb66cad4a268d116ece1738f7d93d9c172411e20b8f6b0d549b6f03675a");
+        let result = big("0ce835af256080ea1459d3a4546f2e665482e96c64acc3601f2f194afeeba2c7");
+        assert_eq!(context.mod_pow(&base, &exponent), result);
+        assert_eq!(context.mod_pow_constant_time(&base, &exponent), result);
+        assert_eq!(base.mod_pow(&exponent, context.modulus()), result);
+    }
+
+    #[test]
+    fn reduction_of_oversized_values() {
+        let context = MontgomeryContext::new(&BigUint::from_u64(0xffff_ffff_ffff_ffc5)).unwrap();
+        let value = BigUint::from_limbs(vec![u64::MAX, 0x0123_4567_89ab_cdef, u64::MAX]);
+        let reduced = &value % context.modulus();
+        assert_eq!(context.reduce_montgomery(&context.to_montgomery(&value)), reduced);
+        assert_eq!(context.mod_add(&value, &BigUint::zero()), reduced);
+        assert_eq!(context.mod_mul(&value, &BigUint::one()), reduced);
+        assert_eq!(context.mod_sub(&BigUint::zero(), &value), context.modulus() - &reduced);
+    }
+}
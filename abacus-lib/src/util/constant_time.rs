@@ -0,0 +1,10 @@
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut difference: u8 = 0;
+    for i in 0..a.len() {
+        difference |= a[i] ^ b[i];
+    }
+    std::hint::black_box(difference) == 0
+}
@@ -0,0 +1,109 @@
+use crate::error::Error;
+
+const DER_TAG_INTEGER: u8 = 0x02;
+const DER_TAG_OCTET_STRING: u8 = 0x04;
+const DER_TAG_NULL: u8 = 0x05;
+const DER_TAG_OBJECT_IDENTIFIER: u8 = 0x06;
+const DER_TAG_SEQUENCE: u8 = 0x30;
+
+fn der_encode(tag: u8, content: &[u8]) -> Vec<u8> {
+    let mut encoded = vec![tag];
+    let length = content.len();
+    if length < 0x80 {
+        encoded.push(length as u8);
+    } else {
+        let length_bytes: Vec<u8> = length.to_be_bytes().into_iter().skip_while(|b| *b == 0).collect();
+        encoded.push(0x80 | length_bytes.len() as u8);
+        encoded.extend_from_slice(&length_bytes);
+    }
+    encoded.extend_from_slice(content);
+    encoded
+}
+
+pub fn der_encode_integer(magnitude: &[u8]) -> Vec<u8> {
+    let magnitude: Vec<u8> = magnitude.iter().copied().skip_while(|b| *b == 0).collect();
+    let mut content = Vec::with_capacity(magnitude.len() + 1);
+    if magnitude.first().is_none_or(|b| b & 0x80 != 0) {
+        content.push(0x00);
+    }
+    content.extend_from_slice(&magnitude);
+    der_encode(DER_TAG_INTEGER, &content)
+}
+
+pub fn der_encode_octet_string(data: &[u8]) -> Vec<u8> {
+    der_encode(DER_TAG_OCTET_STRING, data)
+}
+
+pub fn der_encode_null() -> Vec<u8> {
+    der_encode(DER_TAG_NULL, &[])
+}
+
+pub fn der_encode_object_identifier(oid: &[u8]) -> Vec<u8> {
+    der_encode(DER_TAG_OBJECT_IDENTIFIER, oid)
+}
+
+pub fn der_encode_sequence(content: &[u8]) -> Vec<u8> {
+    der_encode(DER_TAG_SEQUENCE, content)
+}
+
+pub struct DERReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> DERReader<'a> {
+    pub fn new(data: &'a [u8]) -> DERReader<'a> {
+        DERReader {
+            data,
+        }
+    }
+
+    fn read(&mut self, tag: u8) -> Result<&'a [u8], Error> {
+        if self.data.len() < 2 || self.data[0] != tag {
+            return Err(Error::InvalidEncoding);
+        }
+        let (length, header_length) = match self.data[1] {
+            length if length < 0x80 => (length as usize, 2),
+            0x80 => return Err(Error::InvalidEncoding),
+            length => {
+                let length_byte_length = (length & 0x7F) as usize;
+                if length_byte_length > size_of::<usize>() || self.data.len() < 2 + length_byte_length || self.data[2] == 0 {
+                    return Err(Error::InvalidEncoding);
+                }
+                let length = self.data[2..(2 + length_byte_length)].iter().fold(0usize, |acc, b| (acc << 8) | *b as usize);
+                if length < 0x80 {
+                    return Err(Error::InvalidEncoding);
+                }
+                (length, 2 + length_byte_length)
+            }
+        };
+        if self.data.len() - header_length < length {
+            return Err(Error::InvalidEncoding);
+        }
+        let content = &self.data[header_length..(header_length + length)];
+        self.data = &self.data[(header_length + length)..];
+        Ok(content)
+    }
+
+    pub fn read_integer(&mut self) -> Result<&'a [u8], Error> {
+        let content = self.read(DER_TAG_INTEGER)?;
+        match content {
+            [] => Err(Error::InvalidEncoding),
+            [first, ..] if first & 0x80 != 0 => Err(Error::InvalidEncoding),
+            [0x00, second, ..] if second & 0x80 == 0 => Err(Error::InvalidEncoding),
+            [0x00, rest @ ..] => Ok(rest),
+            _ => Ok(content),
+        }
+    }
+
+    pub fn read_octet_string(&mut self) -> Result<&'a [u8], Error> {
+        self.read(DER_TAG_OCTET_STRING)
+    }
+
+    pub fn read_sequence(&mut self) -> Result<DERReader<'a>, Error> {
+        Ok(DERReader::new(self.read(DER_TAG_SEQUENCE)?))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+}
@@ -0,0 +1,22 @@
+use crate::error::Error;
+
+fn hex_digit_value(digit: u8) -> Result<u8, Error> {
+    match digit {
+        b'0'..=b'9' => Ok(digit - b'0'),
+        b'a'..=b'f' => Ok(digit - b'a' + 10),
+        b'A'..=b'F' => Ok(digit - b'A' + 10),
+        _ => Err(Error::InvalidEncoding),
+    }
+}
+
+pub fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+pub fn hex_decode(hex: &str) -> Result<Vec<u8>, Error> {
+    let digits = hex.as_bytes();
+    if !digits.len().is_multiple_of(2) {
+        return Err(Error::InvalidEncoding);
+    }
+    digits.chunks_exact(2).map(|pair| Ok((hex_digit_value(pair[0])? << 4) | hex_digit_value(pair[1])?)).collect()
+}
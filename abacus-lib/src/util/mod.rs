@@ -0,0 +1,4 @@
+pub mod constant_time;
+pub mod der;
+pub mod hex;
+pub mod random;
@@ -0,0 +1,64 @@
+use std::io;
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+extern "C" {
+    fn getrandom(buffer: *mut u8, length: usize, flags: u32) -> isize;
+}
+
+#[cfg(any(target_os = "macos", target_os = "ios", target_os = "openbsd"))]
+extern "C" {
+    fn getentropy(buffer: *mut u8, length: usize) -> i32;
+}
+
+#[cfg(any(target_os = "macos", target_os = "ios", target_os = "openbsd"))]
+const GETENTROPY_MAX_BYTE_LENGTH: usize = 256;
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn fill_random_bytes(buffer: &mut [u8]) -> Result<(), io::Error> {
+    let mut filled_length = 0;
+    while filled_length < buffer.len() {
+        let remains_buffer = &mut buffer[filled_length..];
+        let result = unsafe { getrandom(remains_buffer.as_mut_ptr(), remains_buffer.len(), 0) };
+        if result < 0 {
+            let error = io::Error::last_os_error();
+            if error.kind() == io::ErrorKind::Interrupted {
+                continue;
+            }
+            return Err(error);
+        }
+        filled_length += result as usize;
+    }
+    Ok(())
+}
+
+#[cfg(any(target_os = "macos", target_os = "ios", target_os = "openbsd"))]
+pub fn fill_random_bytes(buffer: &mut [u8]) -> Result<(), io::Error> {
+    for chunk in buffer.chunks_mut(GETENTROPY_MAX_BYTE_LENGTH) {
+        if unsafe { getentropy(chunk.as_mut_ptr(), chunk.len()) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+    }
+    Ok(())
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android", target_os = "macos", target_os = "ios", target_os = "openbsd")))]
+pub fn fill_random_bytes(_buffer: &mut [u8]) -> Result<(), io::Error> {
+    Err(io::Error::new(io::ErrorKind::Unsupported, "no system random source is available on this target"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fill_random_bytes;
+
+    #[test]
+    fn fills_whole_buffer() {
+        let mut empty_buffer = [];
+        fill_random_bytes(&mut empty_buffer).unwrap();
+        let mut first_buffer = [0u8; 1024];
+        let mut second_buffer = [0u8; 1024];
+        fill_random_bytes(&mut first_buffer).unwrap();
+        fill_random_bytes(&mut second_buffer).unwrap();
+        assert_ne!(first_buffer, second_buffer);
+        assert!(first_buffer[1008..].iter().any(|b| *b != 0));
+    }
+}